@@ -91,6 +91,9 @@ impl ClaimsOracle {
 
 /// AI Decision record for a specific claim
 /// PDA seeds: ["ai_decision", claim_id]
+///
+/// Deprecated: only used by the unwired `instructions::oracle` handlers.
+/// The canonical decision account is `instructions::ai_processing::AiDecision`.
 #[account]
 #[derive(InitSpace)]
 pub struct AiDecision {
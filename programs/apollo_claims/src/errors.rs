@@ -121,4 +121,61 @@ pub enum ClaimsError {
 
     #[msg("Member flagged for excessive fast-lane usage")]
     MemberFlagged,
+
+    #[msg("Pre-existing condition is within the pre-existing waiting period")]
+    PreexistingWaitingPeriod,
+
+    #[msg("Provider record does not match the claim's provider")]
+    ProviderMismatch,
+
+    #[msg("Provider is already on the watchlist")]
+    ProviderAlreadyWatchlisted,
+
+    #[msg("Provider is not on the watchlist")]
+    ProviderNotWatchlisted,
+
+    #[msg("Shock claim requires DAO signer-set approval")]
+    DaoApprovalRequired,
+
+    #[msg("Attestation tiers must ascend, not lower the count, and fit the attestor registry")]
+    InvalidAttestationTiers,
+
+    #[msg("Attestation window has not lapsed or claim is sufficiently attested")]
+    AttestationNotStale,
+
+    #[msg("Fast-lane config needs a nonzero limit and unique categories")]
+    InvalidFastLaneConfig,
+
+    #[msg("Protocol is paused by governance")]
+    ProtocolPaused,
+
+    #[msg("Claim has an AI decision that must be supplied")]
+    AiDecisionRequired,
+
+    #[msg("Payout blocked: AI decision carries critical fraud flags")]
+    PayoutBlockedForFraud,
+
+    #[msg("Service date is outside the member's coverage period")]
+    ServiceDateOutsideCoverage,
+
+    #[msg("Member has reached the maximum claims for this period")]
+    ClaimRateExceeded,
+
+    #[msg("Coinsurance is below the minimum coverage floor for the protocol phase")]
+    CoinsuranceBelowFloor,
+
+    #[msg("Claim amount is below the minimum claim amount")]
+    ClaimBelowMinimum,
+
+    #[msg("Reinsurance accounts are required while a stop-loss treaty is designated")]
+    StopLossAccountsRequired,
+
+    #[msg("Treaty is not the protocol's specific stop-loss treaty")]
+    StopLossTreatyMismatch,
+
+    #[msg("Claim names a provider; its registry record is required")]
+    ProviderRecordRequired,
+
+    #[msg("Claim ID must be the next ID from the claims config counter")]
+    InvalidClaimId,
 }
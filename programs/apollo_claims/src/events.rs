@@ -1,7 +1,10 @@
 // programs/apollo_claims/src/events.rs
 
-use crate::state::{AttestationRecommendation, ClaimCategory, ClaimStatus};
+use crate::state::{
+    AttestationRecommendation, AttestationTier, ClaimCategory, ClaimStatus, DenialCode, FraudFlags,
+};
 use anchor_lang::prelude::*;
+use apollo_core::phase::ProtocolPhase;
 
 /// Emitted when claims config is initialized
 #[event]
@@ -45,6 +48,17 @@ pub struct ClaimAttested {
     pub timestamp: i64,
 }
 
+/// Emitted when a stalled attestation is escalated to a DAO vote
+#[event]
+pub struct ClaimEscalated {
+    pub claim_id: u64,
+    pub attestation_count: u8,
+    pub required_attestations: u8,
+    pub pending_since: i64,
+    pub escalated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when a claim is approved
 #[event]
 pub struct ClaimApproved {
@@ -52,17 +66,41 @@ pub struct ClaimApproved {
     pub member: Pubkey,
     pub requested_amount: u64,
     pub approved_amount: u64,
+    pub coinsurance_bps: u16,
     pub approver: Pubkey,
     pub timestamp: i64,
 }
 
+/// Emitted when a shock claim's approval is authorized by a DAO signer set
+#[event]
+pub struct ShockClaimDaoApproved {
+    pub claim_id: u64,
+    pub signer_set: Pubkey,
+    pub approvals: u8,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+/// Emitted when an approval takes a member past the specific stop-loss
+/// attachment and the unreported YTD is recorded with reinsurance
+#[event]
+pub struct ClaimReinsuranceRecoverable {
+    pub claim_id: u64,
+    pub member: Pubkey,
+    pub member_ytd_paid: u64,
+    pub attachment_point: u64,
+    pub reported_amount: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when a claim is denied
 #[event]
 pub struct ClaimDenied {
     pub claim_id: u64,
     pub member: Pubkey,
     pub requested_amount: u64,
-    pub reason: String,
+    pub denial_code: DenialCode,
+    pub note_hash: [u8; 32],
     pub denier: Pubkey,
     pub timestamp: i64,
 }
@@ -77,6 +115,25 @@ pub struct ClaimPaid {
     pub timestamp: i64,
 }
 
+/// Emitted when payout is refused because the AI decision carries critical
+/// fraud flags and no committee override exists
+#[event]
+pub struct PayoutBlockedForFraud {
+    pub claim_id: u64,
+    pub member: Pubkey,
+    pub flags: FraudFlags,
+    pub timestamp: i64,
+}
+
+/// Emitted when the committee overrides a fraud hold on a claim
+#[event]
+pub struct FraudOverrideRecorded {
+    pub claim_id: u64,
+    pub approved_by: Pubkey,
+    pub note_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
 /// Emitted when a claim is closed
 #[event]
 pub struct ClaimClosed {
@@ -86,6 +143,18 @@ pub struct ClaimClosed {
     pub timestamp: i64,
 }
 
+/// Emitted when a paid claim account is closed after its settlement window
+#[event]
+pub struct PaidClaimAutoClosed {
+    pub claim_id: u64,
+    pub member: Pubkey,
+    pub total_paid: u64,
+    /// Rent lamports returned to the member who opened the claim
+    pub rent_refunded: u64,
+    pub closed_by: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when a claim is cancelled
 #[event]
 pub struct ClaimCancelled {
@@ -111,6 +180,14 @@ pub struct AttestorAdded {
     pub timestamp: i64,
 }
 
+/// Emitted when attestation tiers are updated
+#[event]
+pub struct AttestationTiersUpdated {
+    pub base_required: u8,
+    pub tiers: Vec<AttestationTier>,
+    pub timestamp: i64,
+}
+
 /// Emitted when an attestor is removed
 #[event]
 pub struct AttestorRemoved {
@@ -160,10 +237,126 @@ pub struct FastLaneRestored {
     pub timestamp: i64,
 }
 
+// =============================================================================
+// PROVIDER REGISTRY EVENTS
+// =============================================================================
+
+/// Emitted when a provider is registered
+#[event]
+pub struct ProviderRegistered {
+    pub provider: Pubkey,
+    pub registered_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a provider is placed on the watchlist
+#[event]
+pub struct ProviderWatchlisted {
+    pub provider: Pubkey,
+    pub denial_rate_bps: u16,
+    /// True if triggered by the denial-rate threshold
+    pub automatic: bool,
+    pub flagged_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a provider is removed from the watchlist
+#[event]
+pub struct ProviderCleared {
+    pub provider: Pubkey,
+    pub cleared_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a submission reaches the member's monthly claim cap
+#[event]
+pub struct ClaimFrequencyCapReached {
+    pub claim_id: u64,
+    pub member: Pubkey,
+    pub claims_this_period: u16,
+    pub max_claims_per_period: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the monthly claim cap is changed
+#[event]
+pub struct ClaimRateLimitUpdated {
+    pub old_max_claims: u16,
+    pub new_max_claims: u16,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the over-limit claim policy is changed
+#[event]
+pub struct LimitPaymentPolicyUpdated {
+    pub allow_partial_limit_payment: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the specific stop-loss treaty approvals report to changes
+#[event]
+pub struct StopLossTreatyUpdated {
+    pub old_treaty: Pubkey,
+    pub new_treaty: Pubkey,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the paid-claim auto-close window changes
+#[event]
+pub struct PaidClaimCloseWindowUpdated {
+    pub old_window: i64,
+    pub new_window: i64,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the minimum claim amount is changed
+#[event]
+pub struct MinClaimAmountUpdated {
+    pub old_min_claim_amount: u64,
+    pub new_min_claim_amount: u64,
+    /// Phase whose default was applied, or read when validating an override
+    pub phase: ProtocolPhase,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an approved claim is reduced to the remaining benefit limit
+#[event]
+pub struct ClaimCappedAtLimit {
+    pub claim_id: u64,
+    pub member: Pubkey,
+    pub covered_amount: u64,
+    pub paid_amount: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a member is added to or removed from the claim cap whitelist
+#[event]
+pub struct ClaimRateWhitelistUpdated {
+    pub member: Pubkey,
+    pub whitelisted: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
 // =============================================================================
 // AI/ML PROCESSING EVENTS
 // =============================================================================
 
+/// Emitted when an approval is refused because its AI decision is too old
+#[event]
+pub struct StaleAiDecision {
+    pub claim_id: u64,
+    pub decided_at: i64,
+    pub decision_age: i64,
+    pub max_decision_age: i64,
+    pub timestamp: i64,
+}
+
 /// Emitted when AI submits a decision for a claim
 // AiDecisionSubmitted, AiDecisionOverturned, and AiDecisionRecorded
 // are defined in ai_oracle.rs (canonical) to avoid duplicate discriminators
@@ -7,8 +7,19 @@
 
 use crate::errors::ClaimsError;
 use crate::events::ClaimStatusChanged;
-use crate::state::{ClaimAccount, ClaimCategory, ClaimStatus, ClaimsConfig};
+use crate::instructions::provider::record_provider_outcome;
+use crate::instructions::resolution::{BenefitLedger, StopLossAccounts};
+use crate::state::{
+    AiFlag, BenefitSchedule, CategoryStats, ClaimAccount, ClaimCategory, ClaimStatus, ClaimsConfig,
+    DenialCode, DenialReason, FraudFlags, MemberBenefitAccumulator, ProviderRecord,
+};
 use anchor_lang::prelude::*;
+use apollo_core::BootstrapConfig;
+use apollo_governance::authorization;
+use apollo_governance::state::DaoConfig;
+use apollo_membership::state::MemberAccount;
+use apollo_reinsurance::program::ApolloReinsurance;
+use apollo_reinsurance::state::{ReinsuranceConfig, ReinsuranceTreaty};
 
 // =============================================================================
 // AI ORACLE STATE
@@ -59,6 +70,9 @@ pub struct AiOracle {
     /// Is oracle active
     pub is_active: bool,
 
+    /// Maximum age (seconds) of a decision that approval may rely on
+    pub max_decision_age: i64,
+
     /// Bump seed
     pub bump: u8,
 }
@@ -66,6 +80,11 @@ pub struct AiOracle {
 impl AiOracle {
     pub const SEED_PREFIX: &'static [u8] = b"ai_oracle";
 
+    pub const DEFAULT_MAX_DECISION_AGE: i64 = 3600; // 1 hour
+
+    /// Capacity of `authorized_signers`
+    pub const MAX_SIGNERS: usize = 5;
+
     // Bootstrap defaults - more conservative
     pub const DEFAULT_MIN_AUTO_APPROVE_CONFIDENCE: u16 = 9500; // 95%
     pub const DEFAULT_MAX_FRAUD_SCORE: u16 = 3000; // 30%
@@ -74,12 +93,93 @@ impl AiOracle {
     pub fn is_authorized_signer(&self, signer: &Pubkey) -> bool {
         self.authorized_signers.contains(signer)
     }
+
+    /// Authorize a new oracle signer key
+    pub fn add_signer(&mut self, signer: Pubkey) -> Result<()> {
+        require!(
+            !self.is_authorized_signer(&signer),
+            ClaimsError::InvalidConfiguration
+        );
+        require!(
+            self.authorized_signers.len() < Self::MAX_SIGNERS,
+            ClaimsError::InvalidConfiguration
+        );
+        self.authorized_signers.push(signer);
+        Ok(())
+    }
+
+    /// Revoke an oracle signer key. Fails if fewer signers than
+    /// `required_sigs` would remain.
+    pub fn remove_signer(&mut self, signer: &Pubkey) -> Result<()> {
+        require!(self.is_authorized_signer(signer), ClaimsError::Unauthorized);
+        require!(
+            self.authorized_signers.len() > self.required_sigs as usize,
+            ClaimsError::InsufficientSigners
+        );
+        self.authorized_signers.retain(|s| s != signer);
+        Ok(())
+    }
+
+    /// Change the signature threshold (1..=number of signers)
+    pub fn set_required_sigs(&mut self, required_sigs: u8) -> Result<()> {
+        require!(required_sigs > 0, ClaimsError::InvalidConfiguration);
+        require!(
+            required_sigs as usize <= self.authorized_signers.len(),
+            ClaimsError::InsufficientSigners
+        );
+        self.required_sigs = required_sigs;
+        Ok(())
+    }
+
+    /// Count a submitted decision by type
+    pub fn record_decision(&mut self, decision: &AiDecisionType) {
+        self.total_decisions = self.total_decisions.saturating_add(1);
+        let counter = match decision {
+            AiDecisionType::AutoApprove => &mut self.auto_approved,
+            AiDecisionType::AutoDeny { .. } => &mut self.auto_denied,
+            AiDecisionType::CommitteeReview => &mut self.escalated_to_committee,
+        };
+        *counter = counter.saturating_add(1);
+        self.update_accuracy();
+    }
+
+    /// Drop a superseded decision from the counts before its replacement
+    /// is recorded
+    pub fn retract_decision(&mut self, decision: &AiDecisionType) {
+        self.total_decisions = self.total_decisions.saturating_sub(1);
+        let counter = match decision {
+            AiDecisionType::AutoApprove => &mut self.auto_approved,
+            AiDecisionType::AutoDeny { .. } => &mut self.auto_denied,
+            AiDecisionType::CommitteeReview => &mut self.escalated_to_committee,
+        };
+        *counter = counter.saturating_sub(1);
+        self.update_accuracy();
+    }
+
+    /// Count a decision the committee overturned
+    pub fn record_overturn(&mut self) {
+        self.decisions_overturned = self.decisions_overturned.saturating_add(1);
+        self.update_accuracy();
+    }
+
+    /// Recompute accuracy as (total - overturned) / total
+    pub fn update_accuracy(&mut self) {
+        if self.total_decisions == 0 {
+            self.accuracy_rate_bps = 10000;
+            return;
+        }
+
+        let accurate = self
+            .total_decisions
+            .saturating_sub(self.decisions_overturned);
+        self.accuracy_rate_bps = ((accurate as u128 * 10000) / self.total_decisions as u128) as u16;
+    }
 }
 
 /// AI Decision record for a specific claim
 /// PDA seeds: ["ai_decision", claim_id]
 #[account]
-#[derive(InitSpace)]
+#[derive(InitSpace, Default)]
 pub struct AiDecision {
     /// Claim this decision is for
     pub claim_id: u64,
@@ -102,10 +202,16 @@ pub struct AiDecision {
     /// Suggested approved amount (may differ from requested)
     pub suggested_amount: u64,
 
+    /// UCR reference price the oracle scored against
+    pub reference_price: u64,
+
     /// Flags raised by AI (up to 5)
     #[max_len(5, 64)]
     pub flags: Vec<String>,
 
+    /// Fraud indicators raised by the oracle or on-chain checks
+    pub fraud_flags: FraudFlags,
+
     /// Oracle signer who submitted this decision
     pub submitted_by: Pubkey,
 
@@ -121,6 +227,89 @@ pub struct AiDecision {
 
 impl AiDecision {
     pub const SEED_PREFIX: &'static [u8] = b"ai_decision";
+
+    /// Record the oracle's scoring, clearing any earlier overturn
+    pub fn record(
+        &mut self,
+        params: &AiDecisionParams,
+        decision: AiDecisionType,
+        flags: Vec<String>,
+        fraud_flags: FraudFlags,
+        submitted_by: Pubkey,
+        current_time: i64,
+    ) {
+        self.claim_id = params.claim_id;
+        self.decision = decision;
+        self.confidence_bps = params.confidence_bps;
+        self.price_score_bps = params.price_score_bps;
+        self.fraud_score_bps = params.fraud_score_bps;
+        self.consistency_score_bps = params.consistency_score_bps;
+        self.suggested_amount = params.suggested_amount;
+        self.reference_price = params.reference_price;
+        self.flags = flags;
+        self.fraud_flags = fraud_flags;
+        self.submitted_by = submitted_by;
+        self.decided_at = current_time;
+        self.overturned = false;
+    }
+
+    /// Full scoring for audit replay
+    pub fn audit(&self) -> AiDecisionAudit {
+        AiDecisionAudit {
+            claim_id: self.claim_id,
+            decision: self.decision.clone(),
+            confidence_bps: self.confidence_bps,
+            price_score_bps: self.price_score_bps,
+            fraud_score_bps: self.fraud_score_bps,
+            consistency_score_bps: self.consistency_score_bps,
+            suggested_amount: self.suggested_amount,
+            reference_price: self.reference_price,
+            flags: self.flags.clone(),
+            critical_flags: self.critical_flags(),
+            submitted_by: self.submitted_by,
+            decided_at: self.decided_at,
+            overturned: self.overturned,
+        }
+    }
+
+    /// Seconds since the decision was made
+    pub fn age(&self, current_time: i64) -> i64 {
+        current_time.saturating_sub(self.decided_at)
+    }
+
+    /// Whether the decision is older than `max_age` and must be re-evaluated
+    pub fn is_stale(&self, current_time: i64, max_age: i64) -> bool {
+        self.age(current_time) > max_age
+    }
+
+    /// Critical fraud flags raised on this decision
+    pub fn critical_flags(&self) -> FraudFlags {
+        self.fraud_flags.critical()
+    }
+
+    /// Whether payout must be held: critical flags and no committee override
+    pub fn blocks_payout(&self, overridden: bool) -> bool {
+        !overridden && self.fraud_flags.has_critical()
+    }
+}
+
+/// AI decision export for regulatory audits (view return)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AiDecisionAudit {
+    pub claim_id: u64,
+    pub decision: AiDecisionType,
+    pub confidence_bps: u16,
+    pub price_score_bps: u16,
+    pub fraud_score_bps: u16,
+    pub consistency_score_bps: u16,
+    pub suggested_amount: u64,
+    pub reference_price: u64,
+    pub flags: Vec<String>,
+    /// Critical fraud indicators that hold payout until overridden
+    pub critical_flags: FraudFlags,
+    pub submitted_by: Pubkey,
+    pub decided_at: i64,
+    pub overturned: bool,
 }
 
 /// AI decision types
@@ -147,21 +336,78 @@ impl Default for AiDecisionType {
 // FAST-LANE AUTO-APPROVAL
 // =============================================================================
 
-/// Fast-lane eligibility criteria
-#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+/// Fast-lane eligibility criteria (DAO-configurable)
+/// PDA seeds: ["fast_lane_eligibility"]
+#[account]
+#[derive(InitSpace)]
 pub struct FastLaneConfig {
-    /// Maximum claim amount for fast-lane (USDC)
+    /// Default maximum claim amount for fast-lane (USDC)
     pub max_amount: u64,
+    /// Per-category maximum, indexed by `ClaimCategory::index()`
+    /// (0 = use `max_amount`)
+    pub category_max_amounts: [u64; ClaimCategory::COUNT],
     /// Eligible categories
+    #[max_len(15)]
     pub eligible_categories: Vec<ClaimCategory>,
     /// Maximum fast-lane claims per member per month
     pub max_per_member_per_month: u8,
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl FastLaneConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"fast_lane_eligibility";
+    pub const MAX_CATEGORIES: usize = 15;
+
+    /// Whether claims in `category` may be auto-approved
+    pub fn is_eligible_category(&self, category: &ClaimCategory) -> bool {
+        self.eligible_categories.contains(category)
+    }
+
+    /// Configured fast-lane cap for `category`, falling back to `max_amount`
+    pub fn max_amount_for(&self, category: ClaimCategory) -> u64 {
+        match self.category_max_amounts[category.index()] {
+            0 => self.max_amount,
+            cap => cap,
+        }
+    }
+
+    /// Largest claim in `category` the fast lane may approve, capped by the
+    /// protocol's auto-approve threshold for the current bootstrap mode
+    pub fn effective_max_amount(&self, category: ClaimCategory, bootstrap_active: bool) -> u64 {
+        self.max_amount_for(category)
+            .min(ClaimsConfig::get_auto_approve_threshold(bootstrap_active))
+    }
+
+    /// Validate a proposed configuration
+    pub fn validate(
+        max_amount: u64,
+        eligible_categories: &[ClaimCategory],
+        max_per_member_per_month: u8,
+    ) -> bool {
+        if max_amount == 0 || max_per_member_per_month == 0 {
+            return false;
+        }
+        if eligible_categories.len() > Self::MAX_CATEGORIES {
+            return false;
+        }
+        // No duplicate categories
+        eligible_categories
+            .iter()
+            .enumerate()
+            .all(|(i, c)| !eligible_categories[..i].contains(c))
+    }
 }
 
 impl Default for FastLaneConfig {
     fn default() -> Self {
+        let mut category_max_amounts = [0; ClaimCategory::COUNT];
+        category_max_amounts[ClaimCategory::Prescription.index()] = 250_000_000; // $250
+        category_max_amounts[ClaimCategory::Laboratory.index()] = 250_000_000; // $250
+
         Self {
             max_amount: 500_000_000, // $500 bootstrap
+            category_max_amounts,
             eligible_categories: vec![
                 ClaimCategory::PrimaryCare,
                 ClaimCategory::Prescription,
@@ -170,6 +416,7 @@ impl Default for FastLaneConfig {
                 ClaimCategory::DiagnosticImaging,
             ],
             max_per_member_per_month: 5,
+            bump: 0,
         }
     }
 }
@@ -242,16 +489,83 @@ pub fn initialize_ai_oracle(
     oracle.max_fraud_score_for_approval_bps = AiOracle::DEFAULT_MAX_FRAUD_SCORE;
     oracle.min_confidence_threshold_bps = AiOracle::DEFAULT_MIN_CONFIDENCE;
     oracle.is_active = true;
+    oracle.max_decision_age = AiOracle::DEFAULT_MAX_DECISION_AGE;
     oracle.bump = ctx.bumps.ai_oracle;
 
     Ok(())
 }
 
+/// Rotate oracle signer keys or change the signature threshold (DAO)
+#[derive(Accounts)]
+pub struct ManageOracleSigners<'info> {
+    #[account(
+        mut,
+        seeds = [AiOracle::SEED_PREFIX],
+        bump = ai_oracle.bump,
+    )]
+    pub ai_oracle: Account<'info, AiOracle>,
+
+    #[account(
+        constraint = authority.key() == ai_oracle.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn add_oracle_signer(ctx: Context<ManageOracleSigners>, signer: Pubkey) -> Result<()> {
+    let oracle = &mut ctx.accounts.ai_oracle;
+    oracle.add_signer(signer)?;
+
+    emit!(OracleSignerAdded {
+        signer,
+        signer_count: oracle.authorized_signers.len() as u8,
+        required_sigs: oracle.required_sigs,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn remove_oracle_signer(ctx: Context<ManageOracleSigners>, signer: Pubkey) -> Result<()> {
+    let oracle = &mut ctx.accounts.ai_oracle;
+    oracle.remove_signer(&signer)?;
+
+    emit!(OracleSignerRemoved {
+        signer,
+        signer_count: oracle.authorized_signers.len() as u8,
+        required_sigs: oracle.required_sigs,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn set_oracle_required_sigs(
+    ctx: Context<ManageOracleSigners>,
+    required_sigs: u8,
+) -> Result<()> {
+    let oracle = &mut ctx.accounts.ai_oracle;
+    let old_required_sigs = oracle.required_sigs;
+    oracle.set_required_sigs(required_sigs)?;
+
+    emit!(OracleRequiredSigsUpdated {
+        old_required_sigs,
+        new_required_sigs: required_sigs,
+        signer_count: oracle.authorized_signers.len() as u8,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 /// Submit AI decision for a claim
 #[derive(Accounts)]
 #[instruction(claim_id: u64)]
 pub struct SubmitAiDecision<'info> {
     #[account(
+        mut,
         seeds = [AiOracle::SEED_PREFIX],
         bump = ai_oracle.bump,
         constraint = ai_oracle.is_active @ ClaimsError::OracleInactive,
@@ -266,6 +580,15 @@ pub struct SubmitAiDecision<'info> {
     )]
     pub claims_config: Account<'info, ClaimsConfig>,
 
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
     #[account(
         mut,
         seeds = [ClaimAccount::SEED_PREFIX, &claim_id.to_le_bytes()],
@@ -274,8 +597,9 @@ pub struct SubmitAiDecision<'info> {
     )]
     pub claim: Account<'info, ClaimAccount>,
 
+    /// Overwritten only when a claim returns to review after a stale decision
     #[account(
-        init,
+        init_if_needed,
         payer = oracle_signer,
         space = 8 + AiDecision::INIT_SPACE,
         seeds = [AiDecision::SEED_PREFIX, &claim_id.to_le_bytes()],
@@ -283,6 +607,73 @@ pub struct SubmitAiDecision<'info> {
     )]
     pub ai_decision: Account<'info, AiDecision>,
 
+    /// Benefit schedule (coinsurance by category)
+    #[account(
+        seeds = [BenefitSchedule::SEED_PREFIX],
+        bump = benefit_schedule.bump,
+    )]
+    pub benefit_schedule: Box<Account<'info, BenefitSchedule>>,
+
+    /// Claimant's membership account (plan year and coverage tier)
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, claim.member.as_ref()],
+        bump = member_account.bump,
+        seeds::program = apollo_membership::ID,
+    )]
+    pub member_account: Box<Account<'info, MemberAccount>>,
+
+    /// Claimant's plan-year benefit utilization
+    #[account(
+        init_if_needed,
+        payer = oracle_signer,
+        space = 8 + MemberBenefitAccumulator::INIT_SPACE,
+        seeds = [MemberBenefitAccumulator::SEED_PREFIX, claim.member.as_ref()],
+        bump
+    )]
+    pub benefit_accumulator: Box<Account<'info, MemberBenefitAccumulator>>,
+
+    /// Protocol-wide per-category counters
+    #[account(
+        mut,
+        seeds = [CategoryStats::SEED_PREFIX],
+        bump = category_stats.bump,
+    )]
+    pub category_stats: Box<Account<'info, CategoryStats>>,
+
+    /// Registry record for the claim's provider (required if it names one)
+    #[account(
+        mut,
+        seeds = [ProviderRecord::SEED_PREFIX, provider_record.provider.as_ref()],
+        bump = provider_record.bump,
+    )]
+    pub provider_record: Option<Account<'info, ProviderRecord>>,
+
+    /// Reinsurance config, to report claims past the specific stop-loss
+    /// (the reinsurance accounts are required while a stop-loss treaty is
+    /// designated)
+    #[account(
+        mut,
+        seeds = [b"reinsurance_config"],
+        bump = reinsurance_config.bump,
+        seeds::program = apollo_reinsurance::ID,
+    )]
+    pub reinsurance_config: Option<Box<Account<'info, ReinsuranceConfig>>>,
+
+    /// Specific stop-loss treaty whose attachment the member is checked against
+    #[account(
+        constraint = reinsurance_treaty.key() == claims_config.stop_loss_treaty
+            @ ClaimsError::StopLossTreatyMismatch
+    )]
+    pub reinsurance_treaty: Option<Box<Account<'info, ReinsuranceTreaty>>>,
+
+    /// Member's reinsurance claims accumulator for the policy year
+    /// CHECK: Seeds are validated by the reinsurance program, which creates
+    /// it on the member's first recorded claim
+    #[account(mut)]
+    pub reinsurance_accumulator: Option<UncheckedAccount<'info>>,
+
+    pub reinsurance_program: Option<Program<'info, ApolloReinsurance>>,
+
     #[account(mut)]
     pub oracle_signer: Signer<'info>,
 
@@ -297,49 +688,160 @@ pub struct AiDecisionParams {
     pub fraud_score_bps: u16,
     pub consistency_score_bps: u16,
     pub suggested_amount: u64,
+    pub reference_price: u64,
     pub flags: Vec<String>,
+    /// Fraud indicators found by the oracle
+    pub fraud_flags: FraudFlags,
 }
 
 pub fn submit_ai_decision(ctx: Context<SubmitAiDecision>, params: AiDecisionParams) -> Result<()> {
     let clock = Clock::get()?;
-    let oracle = &ctx.accounts.ai_oracle;
+    let config = &mut ctx.accounts.claims_config;
+    let oracle = &mut ctx.accounts.ai_oracle;
     let claim = &mut ctx.accounts.claim;
     let ai_decision = &mut ctx.accounts.ai_decision;
 
-    // Determine decision based on scores and thresholds
-    let decision = determine_ai_decision(
-        params.confidence_bps,
-        params.fraud_score_bps,
-        oracle.min_auto_approve_confidence_bps,
-        oracle.max_fraud_score_for_approval_bps,
-        oracle.min_confidence_threshold_bps,
-        &params.flags,
-    );
+    // One decision per claim; only a stale one may be replaced
+    if claim.has_ai_decision {
+        require!(
+            ai_decision.is_stale(clock.unix_timestamp, oracle.max_decision_age),
+            ClaimsError::DecisionAlreadyRecorded
+        );
+        oracle.retract_decision(&ai_decision.decision);
+    }
+
+    // Services inside the member's waiting periods are not covered,
+    // regardless of scores
+    let exclusion = if claim.in_waiting_period {
+        Some((
+            AiFlag::WaitingPeriod,
+            DenialCode::WaitingPeriod,
+            "Service date within waiting period",
+        ))
+    } else if claim.in_preexisting_wait {
+        Some((
+            AiFlag::PreexistingCondition,
+            DenialCode::PreexistingCondition,
+            "Pre-existing condition within waiting period",
+        ))
+    } else {
+        None
+    };
+
+    let provider_watchlisted = match ctx.accounts.provider_record.as_ref() {
+        Some(record) => {
+            require!(
+                claim.provider == Some(record.provider),
+                ClaimsError::ProviderMismatch
+            );
+            record.watchlisted
+        }
+        None => {
+            require!(
+                claim.provider.is_none(),
+                ClaimsError::ProviderRecordRequired
+            );
+            false
+        }
+    };
+
+    let mut flags = params.flags.clone();
+    let mut fraud_flags = params.fraud_flags;
+    if claim.timing_anomaly {
+        push_flag(&mut flags, &mut fraud_flags, AiFlag::TimingAnomaly);
+    }
+    if claim.high_frequency {
+        push_flag(&mut flags, &mut fraud_flags, AiFlag::HighFrequency);
+    }
+    let decision = if let Some((flag, _, reason)) = exclusion {
+        push_flag(&mut flags, &mut fraud_flags, flag);
+        AiDecisionType::AutoDeny {
+            reason: reason.to_string(),
+        }
+    } else if provider_watchlisted {
+        // Watchlisted providers always get human review
+        push_flag(&mut flags, &mut fraud_flags, AiFlag::ProviderRisk);
+        AiDecisionType::CommitteeReview
+    } else {
+        // Determine decision based on scores and thresholds
+        determine_ai_decision(
+            params.confidence_bps,
+            params.fraud_score_bps,
+            oracle.min_auto_approve_confidence_bps,
+            oracle.max_fraud_score_for_approval_bps,
+            oracle.min_confidence_threshold_bps,
+            &flags,
+        )
+    };
 
     // Record decision
-    ai_decision.claim_id = params.claim_id;
-    ai_decision.decision = decision.clone();
-    ai_decision.confidence_bps = params.confidence_bps;
-    ai_decision.price_score_bps = params.price_score_bps;
-    ai_decision.fraud_score_bps = params.fraud_score_bps;
-    ai_decision.consistency_score_bps = params.consistency_score_bps;
-    ai_decision.suggested_amount = params.suggested_amount;
-    ai_decision.flags = params.flags;
-    ai_decision.submitted_by = ctx.accounts.oracle_signer.key();
-    ai_decision.decided_at = clock.unix_timestamp;
-    ai_decision.overturned = false;
+    ai_decision.record(
+        &params,
+        decision.clone(),
+        flags,
+        fraud_flags,
+        ctx.accounts.oracle_signer.key(),
+        clock.unix_timestamp,
+    );
     ai_decision.bump = ctx.bumps.ai_decision;
+    claim.has_ai_decision = true;
+    oracle.record_decision(&decision);
 
     // Update claim status based on decision
     let old_status = claim.status;
     match &decision {
+        AiDecisionType::AutoApprove if claim.is_shock_claim => {
+            // Shock claims are never auto-approved; they go to a DAO vote
+            claim.status = ClaimStatus::PendingDaoVote;
+        }
         AiDecisionType::AutoApprove => {
-            claim.status = ClaimStatus::Approved;
-            claim.approved_amount = params.suggested_amount;
+            // Plan pays the suggested allowed amount net of coinsurance
+            let allowed_amount = params.suggested_amount.min(claim.requested_amount);
+            let member_account = &ctx.accounts.member_account;
+            let accumulator = &mut ctx.accounts.benefit_accumulator;
+            if accumulator.member == Pubkey::default() {
+                accumulator.seed(
+                    claim.member,
+                    member_account.plan_year,
+                    ctx.bumps.benefit_accumulator,
+                );
+            }
+
+            let approval = BenefitLedger {
+                config,
+                category_stats: &mut ctx.accounts.category_stats,
+                schedule: &ctx.accounts.benefit_schedule,
+                member_account,
+                accumulator,
+            }
+            .approve(
+                claim,
+                allowed_amount,
+                StopLossAccounts {
+                    config: ctx.accounts.reinsurance_config.as_deref(),
+                    treaty: ctx.accounts.reinsurance_treaty.as_deref(),
+                    accumulator: ctx.accounts.reinsurance_accumulator.as_ref(),
+                    program: ctx.accounts.reinsurance_program.as_ref(),
+                    authority: ctx.accounts.oracle_signer.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                clock.unix_timestamp,
+            )?;
+
+            // Hard benefit limits: pay up to what is left, or deny outright
+            if approval.is_none() {
+                let reason = DenialReason::ExceedsLimit;
+                claim.status = ClaimStatus::Denied;
+                claim.denial_code = reason.code();
+                claim.denial_note_hash = reason.note_hash();
+                config.record_denial(reason.code());
+            }
         }
-        AiDecisionType::AutoDeny { .. } => {
+        AiDecisionType::AutoDeny { reason } => {
             claim.status = ClaimStatus::Denied;
-            // denial_reason would be set from the decision
+            claim.denial_reason = reason.clone();
+            claim.denial_code = exclusion.map_or(DenialCode::Other, |(_, code, _)| code);
+            config.record_denial(claim.denial_code);
         }
         AiDecisionType::CommitteeReview => {
             claim.status = ClaimStatus::PendingAttestation;
@@ -347,6 +849,16 @@ pub fn submit_ai_decision(ctx: Context<SubmitAiDecision>, params: AiDecisionPara
     }
     claim.status_changed_at = clock.unix_timestamp;
 
+    if matches!(claim.status, ClaimStatus::Approved | ClaimStatus::Denied) {
+        record_provider_outcome(
+            ctx.accounts.provider_record.as_mut(),
+            claim.provider,
+            claim.status == ClaimStatus::Denied,
+            ctx.accounts.oracle_signer.key(),
+            clock.unix_timestamp,
+        )?;
+    }
+
     emit!(ClaimStatusChanged {
         claim_id: params.claim_id,
         old_status,
@@ -357,6 +869,16 @@ pub fn submit_ai_decision(ctx: Context<SubmitAiDecision>, params: AiDecisionPara
     Ok(())
 }
 
+/// Append an AI flag if not already present, keeping within the 5-flag limit
+fn push_flag(flags: &mut Vec<String>, fraud_flags: &mut FraudFlags, flag: AiFlag) {
+    fraud_flags.raise(flag);
+    let flag = format!("{:?}", flag);
+    if !flags.contains(&flag) {
+        flags.truncate(4);
+        flags.push(flag);
+    }
+}
+
 /// Determine AI decision based on scores
 fn determine_ai_decision(
     confidence_bps: u16,
@@ -390,6 +912,76 @@ fn determine_ai_decision(
     AiDecisionType::CommitteeReview
 }
 
+/// Set fast-lane eligibility criteria (DAO only)
+#[derive(Accounts)]
+pub struct SetFastLaneConfig<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FastLaneConfig::INIT_SPACE,
+        seeds = [FastLaneConfig::SEED_PREFIX],
+        bump
+    )]
+    pub fast_lane_config: Account<'info, FastLaneConfig>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetFastLaneConfigParams {
+    pub max_amount: u64,
+    /// Per-category caps (0 = use `max_amount`)
+    pub category_max_amounts: [u64; ClaimCategory::COUNT],
+    pub eligible_categories: Vec<ClaimCategory>,
+    pub max_per_member_per_month: u8,
+}
+
+pub fn set_fast_lane_config(
+    ctx: Context<SetFastLaneConfig>,
+    params: SetFastLaneConfigParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.fast_lane_config;
+
+    require!(
+        FastLaneConfig::validate(
+            params.max_amount,
+            &params.eligible_categories,
+            params.max_per_member_per_month,
+        ),
+        ClaimsError::InvalidFastLaneConfig
+    );
+
+    config.max_amount = params.max_amount;
+    config.category_max_amounts = params.category_max_amounts;
+    config.eligible_categories = params.eligible_categories.clone();
+    config.max_per_member_per_month = params.max_per_member_per_month;
+    config.bump = ctx.bumps.fast_lane_config;
+
+    emit!(FastLaneConfigUpdated {
+        max_amount: params.max_amount,
+        category_max_amounts: params.category_max_amounts,
+        eligible_categories: params.eligible_categories,
+        max_per_member_per_month: params.max_per_member_per_month,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 /// Process fast-lane claim (immediate auto-approval for small routine claims)
 #[derive(Accounts)]
 #[instruction(claim_id: u64, month_start: i64)]
@@ -402,6 +994,29 @@ pub struct ProcessFastLane<'info> {
     )]
     pub claims_config: Account<'info, ClaimsConfig>,
 
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        seeds = [FastLaneConfig::SEED_PREFIX],
+        bump = fast_lane_config.bump,
+    )]
+    pub fast_lane_config: Account<'info, FastLaneConfig>,
+
+    /// Core bootstrap config (bootstrap mode lowers the auto-approve limit)
+    #[account(
+        seeds = [BootstrapConfig::SEED_PREFIX],
+        bump = bootstrap_config.bump,
+        seeds::program = apollo_core::ID,
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+
     #[account(
         mut,
         seeds = [ClaimAccount::SEED_PREFIX, &claim_id.to_le_bytes()],
@@ -410,6 +1025,31 @@ pub struct ProcessFastLane<'info> {
     )]
     pub claim: Account<'info, ClaimAccount>,
 
+    /// Benefit schedule (coinsurance by category)
+    #[account(
+        seeds = [BenefitSchedule::SEED_PREFIX],
+        bump = benefit_schedule.bump,
+    )]
+    pub benefit_schedule: Box<Account<'info, BenefitSchedule>>,
+
+    /// Claimant's membership account (plan year and coverage tier)
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, claim.member.as_ref()],
+        bump = member_account.bump,
+        seeds::program = apollo_membership::ID,
+    )]
+    pub member_account: Box<Account<'info, MemberAccount>>,
+
+    /// Claimant's plan-year benefit utilization
+    #[account(
+        init_if_needed,
+        payer = processor,
+        space = 8 + MemberBenefitAccumulator::INIT_SPACE,
+        seeds = [MemberBenefitAccumulator::SEED_PREFIX, claim.member.as_ref()],
+        bump
+    )]
+    pub benefit_accumulator: Box<Account<'info, MemberBenefitAccumulator>>,
+
     #[account(
         init_if_needed,
         payer = processor,
@@ -423,6 +1063,48 @@ pub struct ProcessFastLane<'info> {
     )]
     pub fast_lane_usage: Account<'info, FastLaneUsage>,
 
+    /// Protocol-wide per-category counters
+    #[account(
+        mut,
+        seeds = [CategoryStats::SEED_PREFIX],
+        bump = category_stats.bump,
+    )]
+    pub category_stats: Account<'info, CategoryStats>,
+
+    /// Registry record for the claim's provider (required if it names one)
+    #[account(
+        mut,
+        seeds = [ProviderRecord::SEED_PREFIX, provider_record.provider.as_ref()],
+        bump = provider_record.bump,
+    )]
+    pub provider_record: Option<Account<'info, ProviderRecord>>,
+
+    /// Reinsurance config, to report claims past the specific stop-loss
+    /// (the reinsurance accounts are required while a stop-loss treaty is
+    /// designated)
+    #[account(
+        mut,
+        seeds = [b"reinsurance_config"],
+        bump = reinsurance_config.bump,
+        seeds::program = apollo_reinsurance::ID,
+    )]
+    pub reinsurance_config: Option<Box<Account<'info, ReinsuranceConfig>>>,
+
+    /// Specific stop-loss treaty whose attachment the member is checked against
+    #[account(
+        constraint = reinsurance_treaty.key() == claims_config.stop_loss_treaty
+            @ ClaimsError::StopLossTreatyMismatch
+    )]
+    pub reinsurance_treaty: Option<Box<Account<'info, ReinsuranceTreaty>>>,
+
+    /// Member's reinsurance claims accumulator for the policy year
+    /// CHECK: Seeds are validated by the reinsurance program, which creates
+    /// it on the member's first recorded claim
+    #[account(mut)]
+    pub reinsurance_accumulator: Option<UncheckedAccount<'info>>,
+
+    pub reinsurance_program: Option<Program<'info, ApolloReinsurance>>,
+
     #[account(mut)]
     pub processor: Signer<'info>,
 
@@ -436,18 +1118,29 @@ pub fn process_fast_lane(
 ) -> Result<()> {
     let clock = Clock::get()?;
     let config = &mut ctx.accounts.claims_config;
+    let fast_lane = &ctx.accounts.fast_lane_config;
     let claim = &mut ctx.accounts.claim;
     let usage = &mut ctx.accounts.fast_lane_usage;
 
     // Check fast-lane eligibility
     require!(
-        claim.requested_amount <= config.auto_approve_threshold,
+        !claim.in_waiting_period,
+        ClaimsError::WaitingPeriodNotComplete
+    );
+    require!(
+        !claim.in_preexisting_wait,
+        ClaimsError::PreexistingWaitingPeriod
+    );
+    require!(
+        claim.requested_amount
+            <= fast_lane.effective_max_amount(
+                claim.category,
+                ctx.accounts.bootstrap_config.bootstrap_active
+            ),
         ClaimsError::ExceedsFastLaneLimit
     );
-
-    // Check eligible categories (basic check - can expand)
     require!(
-        is_fast_lane_category(&claim.category),
+        fast_lane.is_eligible_category(&claim.category),
         ClaimsError::CategoryNotEligible
     );
 
@@ -462,22 +1155,81 @@ pub fn process_fast_lane(
     }
 
     require!(
-        usage.claims_used < 5, // Max 5 fast-lane per month
+        usage.claims_used < fast_lane.max_per_member_per_month,
         ClaimsError::FastLaneLimitExceeded
     );
 
+    // The plan pays the request net of coinsurance; a claim the benefit
+    // limits would cut or deny goes through regular review instead
+    let schedule = &ctx.accounts.benefit_schedule;
+    let member_account = &ctx.accounts.member_account;
+    let covered_amount = schedule.covered_amount(claim.category, claim.requested_amount);
+
+    let is_family = member_account.coverage_tier.is_family();
+    let accumulator = &mut ctx.accounts.benefit_accumulator;
+    if accumulator.member == Pubkey::default() {
+        accumulator.seed(
+            claim.member,
+            member_account.plan_year,
+            ctx.bumps.benefit_accumulator,
+        );
+    }
+    require!(
+        accumulator.limit_payable(
+            schedule,
+            is_family,
+            member_account.plan_year,
+            claim.category,
+            covered_amount,
+            false,
+        ) == Some(covered_amount),
+        ClaimsError::ExceedsBenefitLimit
+    );
+
     // Approve via fast-lane
     let old_status = claim.status;
     claim.status = ClaimStatus::Approved;
-    claim.approved_amount = claim.requested_amount;
+    claim.approved_amount = covered_amount;
     claim.status_changed_at = clock.unix_timestamp;
 
+    accumulator.record_approval(
+        schedule,
+        is_family,
+        member_account.plan_year,
+        claim.category,
+        claim.requested_amount,
+        covered_amount,
+    );
+
+    StopLossAccounts {
+        config: ctx.accounts.reinsurance_config.as_deref(),
+        treaty: ctx.accounts.reinsurance_treaty.as_deref(),
+        accumulator: ctx.accounts.reinsurance_accumulator.as_ref(),
+        program: ctx.accounts.reinsurance_program.as_ref(),
+        authority: ctx.accounts.processor.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    }
+    .report(
+        config.stop_loss_treaty,
+        accumulator,
+        claim,
+        clock.unix_timestamp,
+    )?;
+
+    record_provider_outcome(
+        ctx.accounts.provider_record.as_mut(),
+        claim.provider,
+        false,
+        ctx.accounts.processor.key(),
+        clock.unix_timestamp,
+    )?;
+
     // Update usage tracking
     usage.claims_used += 1;
     usage.amount_claimed = usage.amount_claimed.saturating_add(claim.requested_amount);
 
     // Update config stats
-    config.total_claims_approved += 1;
+    config.record_approval(&mut ctx.accounts.category_stats, claim.category);
 
     emit!(ClaimStatusChanged {
         claim_id,
@@ -490,7 +1242,7 @@ pub fn process_fast_lane(
     emit!(FastLaneApproved {
         claim_id,
         member: claim.member,
-        amount: claim.requested_amount,
+        amount: claim.approved_amount,
         category: claim.category,
         monthly_usage: usage.claims_used,
         timestamp: clock.unix_timestamp,
@@ -499,18 +1251,6 @@ pub fn process_fast_lane(
     Ok(())
 }
 
-/// Check if category is eligible for fast-lane
-fn is_fast_lane_category(category: &ClaimCategory) -> bool {
-    matches!(
-        category,
-        ClaimCategory::PrimaryCare
-            | ClaimCategory::Prescription
-            | ClaimCategory::Laboratory
-            | ClaimCategory::Preventive
-            | ClaimCategory::DiagnosticImaging
-    )
-}
-
 /// Get the start of the current month (Unix timestamp)
 fn get_month_start(timestamp: i64) -> i64 {
     // Approximate: 30 days per month
@@ -553,14 +1293,250 @@ pub fn mark_decision_overturned(
     let decision = &mut ctx.accounts.ai_decision;
 
     decision.overturned = true;
-    oracle.decisions_overturned += 1;
+    oracle.record_overturn();
+
+    Ok(())
+}
+
+/// Read the AI decision recorded for a claim
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct GetAiDecision<'info> {
+    #[account(
+        seeds = [AiDecision::SEED_PREFIX, &claim_id.to_le_bytes()],
+        bump = ai_decision.bump,
+    )]
+    pub ai_decision: Account<'info, AiDecision>,
+}
+
+pub fn get_ai_decision(ctx: Context<GetAiDecision>, _claim_id: u64) -> Result<AiDecisionAudit> {
+    Ok(ctx.accounts.ai_decision.audit())
+}
+
+/// Committee reversal of an AI auto-decision
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AiOverride {
+    /// Approve a claim the AI auto-denied
+    Approve { approved_amount: u64 },
+    /// Deny a claim the AI auto-approved
+    Deny { reason: DenialReason },
+}
+
+/// Flip the outcome of an AI auto-decision and record the overturn
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct OverrideAiDecision<'info> {
+    #[account(
+        mut,
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        mut,
+        seeds = [AiOracle::SEED_PREFIX],
+        bump = ai_oracle.bump,
+    )]
+    pub ai_oracle: Account<'info, AiOracle>,
+
+    #[account(
+        mut,
+        seeds = [ClaimAccount::SEED_PREFIX, &claim_id.to_le_bytes()],
+        bump = claim.bump,
+        constraint = matches!(
+            claim.status,
+            ClaimStatus::Approved | ClaimStatus::Denied
+        ) @ ClaimsError::InvalidClaimStatus
+    )]
+    pub claim: Account<'info, ClaimAccount>,
+
+    #[account(
+        mut,
+        seeds = [AiDecision::SEED_PREFIX, &claim_id.to_le_bytes()],
+        bump = ai_decision.bump,
+        constraint = !ai_decision.overturned @ ClaimsError::AlreadyOverturned
+    )]
+    pub ai_decision: Account<'info, AiDecision>,
+
+    /// Benefit schedule (coinsurance by category)
+    #[account(
+        seeds = [BenefitSchedule::SEED_PREFIX],
+        bump = benefit_schedule.bump,
+    )]
+    pub benefit_schedule: Box<Account<'info, BenefitSchedule>>,
+
+    /// Protocol-wide per-category counters
+    #[account(
+        mut,
+        seeds = [CategoryStats::SEED_PREFIX],
+        bump = category_stats.bump,
+    )]
+    pub category_stats: Box<Account<'info, CategoryStats>>,
+
+    /// Claimant's membership account (plan year and coverage tier)
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, claim.member.as_ref()],
+        bump = member_account.bump,
+        seeds::program = apollo_membership::ID,
+    )]
+    pub member_account: Box<Account<'info, MemberAccount>>,
+
+    /// Claimant's plan-year benefit utilization
+    #[account(
+        init_if_needed,
+        payer = approver,
+        space = 8 + MemberBenefitAccumulator::INIT_SPACE,
+        seeds = [MemberBenefitAccumulator::SEED_PREFIX, claim.member.as_ref()],
+        bump
+    )]
+    pub benefit_accumulator: Box<Account<'info, MemberBenefitAccumulator>>,
 
-    // Update accuracy rate
-    if oracle.total_decisions > 0 {
-        let accurate = oracle.total_decisions - oracle.decisions_overturned;
-        oracle.accuracy_rate_bps =
-            ((accurate as u128 * 10000) / oracle.total_decisions as u128) as u16;
+    /// Reinsurance config, to report or take back claims past the specific
+    /// stop-loss (the reinsurance accounts are required while a stop-loss
+    /// treaty is designated)
+    #[account(
+        mut,
+        seeds = [b"reinsurance_config"],
+        bump = reinsurance_config.bump,
+        seeds::program = apollo_reinsurance::ID,
+    )]
+    pub reinsurance_config: Option<Box<Account<'info, ReinsuranceConfig>>>,
+
+    /// Specific stop-loss treaty whose attachment the member is checked against
+    #[account(
+        constraint = reinsurance_treaty.key() == claims_config.stop_loss_treaty
+            @ ClaimsError::StopLossTreatyMismatch
+    )]
+    pub reinsurance_treaty: Option<Box<Account<'info, ReinsuranceTreaty>>>,
+
+    /// Member's reinsurance claims accumulator for the policy year
+    /// CHECK: Seeds are validated by the reinsurance program, which creates
+    /// it on the member's first recorded claim
+    #[account(mut)]
+    pub reinsurance_accumulator: Option<UncheckedAccount<'info>>,
+
+    pub reinsurance_program: Option<Program<'info, ApolloReinsurance>>,
+
+    /// Claims Committee or DAO
+    #[account(
+        mut,
+        constraint = approver.key() == claims_config.authority ||
+                     approver.key() == claims_config.claims_committee @ ClaimsError::Unauthorized
+    )]
+    pub approver: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn override_ai_decision(
+    ctx: Context<OverrideAiDecision>,
+    _claim_id: u64,
+    outcome: AiOverride,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config_info = ctx.accounts.claims_config.to_account_info();
+    let config = &mut ctx.accounts.claims_config;
+    let oracle = &mut ctx.accounts.ai_oracle;
+    let claim = &mut ctx.accounts.claim;
+    let decision = &mut ctx.accounts.ai_decision;
+
+    let member_account = &ctx.accounts.member_account;
+    let accumulator = &mut ctx.accounts.benefit_accumulator;
+    if accumulator.member == Pubkey::default() {
+        accumulator.seed(
+            claim.member,
+            member_account.plan_year,
+            ctx.bumps.benefit_accumulator,
+        );
+    }
+    let ledger = BenefitLedger {
+        config,
+        category_stats: &mut ctx.accounts.category_stats,
+        schedule: &ctx.accounts.benefit_schedule,
+        member_account,
+        accumulator,
+    };
+    let stop_loss = StopLossAccounts {
+        config: ctx.accounts.reinsurance_config.as_deref(),
+        treaty: ctx.accounts.reinsurance_treaty.as_deref(),
+        accumulator: ctx.accounts.reinsurance_accumulator.as_ref(),
+        program: ctx.accounts.reinsurance_program.as_ref(),
+        authority: ctx.accounts.approver.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+    };
+
+    let old_status = claim.status;
+    match (&decision.decision, claim.status, outcome) {
+        (
+            AiDecisionType::AutoDeny { .. },
+            ClaimStatus::Denied,
+            AiOverride::Approve { approved_amount },
+        ) => {
+            // Waiting periods are policy, not an AI judgment call
+            require!(
+                !claim.in_waiting_period,
+                ClaimsError::WaitingPeriodNotComplete
+            );
+            require!(
+                !claim.in_preexisting_wait,
+                ClaimsError::PreexistingWaitingPeriod
+            );
+            require!(
+                approved_amount > 0 && approved_amount <= claim.requested_amount,
+                ClaimsError::InvalidClaimAmount
+            );
+
+            // Benefit limits still apply to an overridden denial
+            ledger
+                .approve(claim, approved_amount, stop_loss, clock.unix_timestamp)?
+                .ok_or(ClaimsError::ExceedsBenefitLimit)?;
+            claim.denial_reason = String::new();
+            claim.denial_code = DenialCode::None;
+            claim.denial_note_hash = [0u8; 32];
+        }
+        (AiDecisionType::AutoApprove, ClaimStatus::Approved, AiOverride::Deny { reason }) => {
+            // Auto-approval allowed the suggested amount
+            let approved_amount = decision.suggested_amount.min(claim.requested_amount);
+            ledger.reverse(claim, approved_amount, stop_loss, config_info)?;
+            claim.approved_amount = 0;
+            claim.status = ClaimStatus::Denied;
+            claim.denial_code = reason.code();
+            claim.denial_note_hash = reason.note_hash();
+            ctx.accounts.claims_config.record_denial(reason.code());
+        }
+        _ => return err!(ClaimsError::InvalidClaimStatus),
     }
+    claim.status_changed_at = clock.unix_timestamp;
+
+    decision.overturned = true;
+    oracle.record_overturn();
+
+    emit!(AiDecisionOverridden {
+        claim_id: claim.claim_id,
+        old_status,
+        new_status: claim.status,
+        approved_amount: claim.approved_amount,
+        overridden_by: ctx.accounts.approver.key(),
+        decisions_overturned: oracle.decisions_overturned,
+        accuracy_rate_bps: oracle.accuracy_rate_bps,
+        timestamp: clock.unix_timestamp,
+    });
+    emit!(ClaimStatusChanged {
+        claim_id: claim.claim_id,
+        old_status,
+        new_status: claim.status,
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -579,4 +1555,53 @@ pub struct FastLaneApproved {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct FastLaneConfigUpdated {
+    pub max_amount: u64,
+    pub category_max_amounts: [u64; ClaimCategory::COUNT],
+    pub eligible_categories: Vec<ClaimCategory>,
+    pub max_per_member_per_month: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleSignerAdded {
+    pub signer: Pubkey,
+    pub signer_count: u8,
+    pub required_sigs: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleSignerRemoved {
+    pub signer: Pubkey,
+    pub signer_count: u8,
+    pub required_sigs: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct OracleRequiredSigsUpdated {
+    pub old_required_sigs: u8,
+    pub new_required_sigs: u8,
+    pub signer_count: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AiDecisionOverridden {
+    pub claim_id: u64,
+    pub old_status: ClaimStatus,
+    pub new_status: ClaimStatus,
+    pub approved_amount: u64,
+    pub overridden_by: Pubkey,
+    pub decisions_overturned: u64,
+    pub accuracy_rate_bps: u16,
+    pub timestamp: i64,
+}
+
 // AiDecisionRecorded is defined in events.rs to avoid duplicate discriminators
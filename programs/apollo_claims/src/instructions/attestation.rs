@@ -1,12 +1,14 @@
 // programs/apollo_claims/src/instructions/attestation.rs
 
 use crate::errors::ClaimsError;
-use crate::events::ClaimAttested;
+use crate::events::{ClaimAttested, ClaimEscalated, FraudOverrideRecorded};
 use crate::state::{
     Attestation, AttestationRecommendation, AttestorRegistry, ClaimAccount, ClaimStatus,
-    ClaimsConfig,
+    ClaimsConfig, FraudOverride,
 };
 use anchor_lang::prelude::*;
+use apollo_governance::authorization;
+use apollo_governance::state::DaoConfig;
 
 /// Attest a claim (committee member review)
 #[derive(Accounts)]
@@ -17,6 +19,15 @@ pub struct AttestClaim<'info> {
     )]
     pub claims_config: Account<'info, ClaimsConfig>,
 
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
     #[account(
         mut,
         seeds = [ClaimAccount::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
@@ -122,12 +133,120 @@ pub fn check_attestations(ctx: Context<CheckAttestations>) -> Result<Attestation
 
     let time_elapsed = clock.unix_timestamp - claim.submitted_at;
     let time_remaining = config.max_attestation_time - time_elapsed;
-    let can_resolve = claim.attestation_count >= config.required_attestations;
+    let required_attestations = config.attestations_required(claim);
+    let can_resolve = claim.attestation_count >= required_attestations;
 
     Ok(AttestationStatus {
         total_attestations: claim.attestation_count,
-        required_attestations: config.required_attestations,
+        required_attestations,
         can_resolve,
         time_remaining: time_remaining.max(0),
     })
 }
+
+/// Escalate a claim whose attestation window lapsed (permissionless crank)
+#[derive(Accounts)]
+pub struct EscalateStaleAttestation<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        mut,
+        seeds = [ClaimAccount::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+        bump = claim.bump,
+        constraint = claim.status == ClaimStatus::PendingAttestation @ ClaimsError::InvalidClaimStatus
+    )]
+    pub claim: Account<'info, ClaimAccount>,
+
+    pub caller: Signer<'info>,
+}
+
+pub fn escalate_stale_attestation(ctx: Context<EscalateStaleAttestation>) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.claims_config;
+    let claim = &mut ctx.accounts.claim;
+
+    let required_attestations = config.attestations_required(claim);
+    require!(
+        claim.is_attestation_stale(
+            clock.unix_timestamp,
+            config.max_attestation_time,
+            required_attestations
+        ),
+        ClaimsError::AttestationNotStale
+    );
+
+    // Stalled committee review goes to the DAO
+    let pending_since = claim.status_changed_at;
+    claim.status = ClaimStatus::PendingDaoVote;
+    claim.status_changed_at = clock.unix_timestamp;
+
+    emit!(ClaimEscalated {
+        claim_id: claim.claim_id,
+        attestation_count: claim.attestation_count,
+        required_attestations,
+        pending_since,
+        escalated_by: ctx.accounts.caller.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Release a claim held for critical AI fraud flags (Claims Committee)
+#[derive(Accounts)]
+pub struct OverrideFraudHold<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        seeds = [ClaimAccount::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+        bump = claim.bump,
+        constraint = claim.status == ClaimStatus::Approved @ ClaimsError::InvalidClaimStatus
+    )]
+    pub claim: Account<'info, ClaimAccount>,
+
+    #[account(
+        init,
+        payer = approver,
+        space = 8 + FraudOverride::INIT_SPACE,
+        seeds = [FraudOverride::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+        bump
+    )]
+    pub fraud_override: Account<'info, FraudOverride>,
+
+    #[account(
+        mut,
+        constraint = approver.key() == claims_config.authority ||
+                     approver.key() == claims_config.claims_committee @ ClaimsError::Unauthorized
+    )]
+    pub approver: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn override_fraud_hold(ctx: Context<OverrideFraudHold>, note_hash: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let fraud_override = &mut ctx.accounts.fraud_override;
+
+    fraud_override.claim_id = ctx.accounts.claim.claim_id;
+    fraud_override.approved_by = ctx.accounts.approver.key();
+    fraud_override.note_hash = note_hash;
+    fraud_override.overridden_at = clock.unix_timestamp;
+    fraud_override.bump = ctx.bumps.fraud_override;
+
+    emit!(FraudOverrideRecorded {
+        claim_id: fraud_override.claim_id,
+        approved_by: fraud_override.approved_by,
+        note_hash,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,90 @@
+// programs/apollo_claims/src/instructions/claim_rate.rs
+
+use crate::errors::ClaimsError;
+use crate::events::{ClaimRateLimitUpdated, ClaimRateWhitelistUpdated};
+use crate::state::{ClaimsConfig, MemberClaimRate};
+use anchor_lang::prelude::*;
+
+/// Change the monthly claim cap (DAO authority)
+#[derive(Accounts)]
+pub struct SetClaimRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_claim_rate_limit(
+    ctx: Context<SetClaimRateLimit>,
+    max_claims_per_period: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.claims_config;
+    let old_max_claims = config.max_claims_per_period;
+    config.max_claims_per_period = max_claims_per_period;
+
+    emit!(ClaimRateLimitUpdated {
+        old_max_claims,
+        new_max_claims: max_claims_per_period,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Whitelist a member for the monthly claim cap (DAO or Claims Committee)
+#[derive(Accounts)]
+#[instruction(member: Pubkey)]
+pub struct SetClaimRateWhitelist<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    /// Created here if the member has not submitted a claim yet
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MemberClaimRate::INIT_SPACE,
+        seeds = [MemberClaimRate::SEED_PREFIX, member.as_ref()],
+        bump
+    )]
+    pub member_claim_rate: Account<'info, MemberClaimRate>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == claims_config.authority ||
+                     authority.key() == claims_config.claims_committee @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_claim_rate_whitelist(
+    ctx: Context<SetClaimRateWhitelist>,
+    member: Pubkey,
+    whitelisted: bool,
+) -> Result<()> {
+    let rate = &mut ctx.accounts.member_claim_rate;
+    rate.member = member;
+    rate.whitelisted = whitelisted;
+    rate.bump = ctx.bumps.member_claim_rate;
+
+    emit!(ClaimRateWhitelistUpdated {
+        member,
+        whitelisted,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
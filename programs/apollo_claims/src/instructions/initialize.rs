@@ -1,9 +1,18 @@
 // programs/apollo_claims/src/instructions/initialize.rs
 
 use crate::errors::ClaimsError;
-use crate::events::{BenefitScheduleUpdated, ClaimsConfigInitialized};
-use crate::state::{AttestorRegistry, BenefitSchedule, CategoryLimit, ClaimsConfig};
+use crate::events::{
+    AttestationTiersUpdated, BenefitScheduleUpdated, ClaimsConfigInitialized,
+    LimitPaymentPolicyUpdated, MinClaimAmountUpdated, PaidClaimCloseWindowUpdated,
+    StopLossTreatyUpdated,
+};
+use crate::state::{
+    AttestationTier, AttestorRegistry, BenefitSchedule, CategoryLimit, ClaimsConfig, DenialCode,
+};
 use anchor_lang::prelude::*;
+use apollo_core::phase::ProtocolPhase;
+use apollo_reinsurance::state::{ReinsuranceConfig, ReinsuranceLayerType, ReinsuranceTreaty};
+use apollo_reserves::state::PhaseManager;
 
 #[derive(Accounts)]
 pub struct InitializeClaimsConfig<'info> {
@@ -39,6 +48,9 @@ pub struct InitializeClaimsParams {
     pub auto_approve_threshold: Option<u64>,
     pub shock_claim_threshold: Option<u64>,
     pub required_attestations: Option<u8>,
+    pub max_claims_per_period: Option<u16>,
+    /// Pay over-limit claims up to the remaining limit (default true)
+    pub allow_partial_limit_payment: Option<bool>,
 }
 
 pub fn handler(ctx: Context<InitializeClaimsConfig>, params: InitializeClaimsParams) -> Result<()> {
@@ -52,6 +64,7 @@ pub fn handler(ctx: Context<InitializeClaimsConfig>, params: InitializeClaimsPar
     config.total_claims_submitted = 0;
     config.total_claims_approved = 0;
     config.total_claims_denied = 0;
+    config.denials_by_reason = [0; DenialCode::COUNT];
     config.total_paid_out = 0;
     config.auto_approve_threshold = params
         .auto_approve_threshold
@@ -62,8 +75,16 @@ pub fn handler(ctx: Context<InitializeClaimsConfig>, params: InitializeClaimsPar
     config.required_attestations = params
         .required_attestations
         .unwrap_or(ClaimsConfig::DEFAULT_REQUIRED_ATTESTATIONS);
+    config.attestation_tiers = vec![];
     config.max_attestation_time = ClaimsConfig::DEFAULT_MAX_ATTESTATION_TIME;
     config.is_active = true;
+    config.max_claims_per_period = params
+        .max_claims_per_period
+        .unwrap_or(ClaimsConfig::DEFAULT_MAX_CLAIMS_PER_PERIOD);
+    config.allow_partial_limit_payment = params.allow_partial_limit_payment.unwrap_or(true);
+    config.paid_claim_close_window = ClaimsConfig::DEFAULT_PAID_CLAIM_CLOSE_WINDOW;
+    config.min_claim_amount = ProtocolPhase::Phase1Hcsm.default_min_claim_amount();
+    config.stop_loss_treaty = Pubkey::default();
     config.bump = ctx.bumps.claims_config;
 
     let registry = &mut ctx.accounts.attestor_registry;
@@ -102,6 +123,16 @@ pub struct SetBenefitSchedule<'info> {
     )]
     pub benefit_schedule: Account<'info, BenefitSchedule>,
 
+    /// Reserves phase manager (the coverage floor depends on the phase)
+    /// CHECK: Address is fixed by seeds; read as Phase 1 until the reserves
+    /// program creates it
+    #[account(
+        seeds = [PhaseManager::SEED_PREFIX],
+        bump,
+        seeds::program = apollo_reserves::ID,
+    )]
+    pub phase_manager: UncheckedAccount<'info>,
+
     #[account(
         mut,
         constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
@@ -132,10 +163,12 @@ pub fn set_benefit_schedule(
 ) -> Result<()> {
     let clock = Clock::get()?;
 
-    require!(
-        params.coinsurance_bps <= 10000,
-        ClaimsError::InvalidBenefitSchedule
-    );
+    BenefitSchedule::validate_coinsurance(
+        params.coinsurance_bps,
+        params.category_limits.as_deref().unwrap_or_default(),
+        current_phase(&ctx.accounts.phase_manager)?,
+    )?;
+    BenefitSchedule::validate_carryover(params.category_limits.as_deref().unwrap_or_default())?;
     require!(
         params.individual_annual_max > 0,
         ClaimsError::InvalidBenefitSchedule
@@ -172,6 +205,20 @@ pub fn set_benefit_schedule(
     Ok(())
 }
 
+/// Current protocol phase (Phase 1 until the phase manager exists)
+pub(crate) fn current_phase(phase_manager: &UncheckedAccount) -> Result<ProtocolPhase> {
+    if phase_manager.data_is_empty() {
+        return Ok(ProtocolPhase::Phase1Hcsm);
+    }
+    require_keys_eq!(
+        *phase_manager.owner,
+        apollo_reserves::ID,
+        ClaimsError::InvalidConfiguration
+    );
+    let data = phase_manager.try_borrow_data()?;
+    Ok(PhaseManager::try_deserialize(&mut &data[..])?.current_phase)
+}
+
 /// Add an attestor to the registry
 #[derive(Accounts)]
 pub struct ManageAttestor<'info> {
@@ -239,3 +286,241 @@ pub fn remove_attestor(ctx: Context<ManageAttestor>, attestor: Pubkey) -> Result
 
     Ok(())
 }
+
+/// Set attestation tiers by claim size
+#[derive(Accounts)]
+pub struct SetAttestationTiers<'info> {
+    #[account(
+        mut,
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        seeds = [AttestorRegistry::SEED_PREFIX],
+        bump = attestor_registry.bump,
+    )]
+    pub attestor_registry: Account<'info, AttestorRegistry>,
+
+    #[account(
+        constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_attestation_tiers(
+    ctx: Context<SetAttestationTiers>,
+    tiers: Vec<AttestationTier>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.claims_config;
+    let clock = Clock::get()?;
+
+    require!(
+        ClaimsConfig::validate_attestation_tiers(
+            &tiers,
+            config.required_attestations,
+            ctx.accounts.attestor_registry.attestor_count,
+        ),
+        ClaimsError::InvalidAttestationTiers
+    );
+
+    config.attestation_tiers = tiers.clone();
+
+    emit!(AttestationTiersUpdated {
+        base_required: config.required_attestations,
+        tiers,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Choose between partial payment and denial for claims over a benefit
+/// limit (DAO authority)
+#[derive(Accounts)]
+pub struct SetLimitPaymentPolicy<'info> {
+    #[account(
+        mut,
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_limit_payment_policy(
+    ctx: Context<SetLimitPaymentPolicy>,
+    allow_partial_limit_payment: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.claims_config;
+    config.allow_partial_limit_payment = allow_partial_limit_payment;
+
+    emit!(LimitPaymentPolicyUpdated {
+        allow_partial_limit_payment,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Change how long paid claims stay open before anyone may close them
+/// (DAO authority)
+#[derive(Accounts)]
+pub struct SetPaidClaimCloseWindow<'info> {
+    #[account(
+        mut,
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_paid_claim_close_window(
+    ctx: Context<SetPaidClaimCloseWindow>,
+    paid_claim_close_window: i64,
+) -> Result<()> {
+    require!(
+        paid_claim_close_window >= 0,
+        ClaimsError::InvalidConfiguration
+    );
+
+    let config = &mut ctx.accounts.claims_config;
+    let old_window = config.paid_claim_close_window;
+    config.paid_claim_close_window = paid_claim_close_window;
+
+    emit!(PaidClaimCloseWindowUpdated {
+        old_window,
+        new_window: paid_claim_close_window,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Designate the specific stop-loss treaty approvals report to (DAO
+/// authority)
+#[derive(Accounts)]
+pub struct SetStopLossTreaty<'info> {
+    #[account(
+        mut,
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = reinsurance_config.bump,
+        seeds::program = apollo_reinsurance::ID,
+    )]
+    pub reinsurance_config: Box<Account<'info, ReinsuranceConfig>>,
+
+    /// Omitted to stop reporting to stop-loss
+    #[account(
+        constraint = treaty.layer_type == ReinsuranceLayerType::SpecificStopLoss
+            @ ClaimsError::StopLossTreatyMismatch
+    )]
+    pub treaty: Option<Box<Account<'info, ReinsuranceTreaty>>>,
+
+    #[account(
+        constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_stop_loss_treaty(ctx: Context<SetStopLossTreaty>) -> Result<()> {
+    let new_treaty = match ctx.accounts.treaty.as_ref() {
+        Some(treaty) => {
+            // Only a treaty created under the protocol's reinsurance config
+            let derived = Pubkey::create_program_address(
+                &[
+                    b"treaty",
+                    ctx.accounts.reinsurance_config.key().as_ref(),
+                    &(treaty.treaty_id as u32).to_le_bytes(),
+                    &[treaty.bump],
+                ],
+                &apollo_reinsurance::ID,
+            )
+            .map_err(|_| error!(ClaimsError::StopLossTreatyMismatch))?;
+            require_keys_eq!(derived, treaty.key(), ClaimsError::StopLossTreatyMismatch);
+            treaty.key()
+        }
+        None => Pubkey::default(),
+    };
+
+    let config = &mut ctx.accounts.claims_config;
+    let old_treaty = config.stop_loss_treaty;
+    config.stop_loss_treaty = new_treaty;
+
+    emit!(StopLossTreatyUpdated {
+        old_treaty,
+        new_treaty,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Change the minimum claim amount (DAO authority)
+#[derive(Accounts)]
+pub struct SetMinClaimAmount<'info> {
+    #[account(
+        mut,
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    /// Reserves phase manager (supplies the phase default)
+    /// CHECK: Address is fixed by seeds; read as Phase 1 until the reserves
+    /// program creates it
+    #[account(
+        seeds = [PhaseManager::SEED_PREFIX],
+        bump,
+        seeds::program = apollo_reserves::ID,
+    )]
+    pub phase_manager: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = authority.key() == claims_config.authority @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Set an explicit minimum (0 disables it) or, with None, reset to the
+/// current phase's default
+pub fn set_min_claim_amount(
+    ctx: Context<SetMinClaimAmount>,
+    min_claim_amount: Option<u64>,
+) -> Result<()> {
+    let phase = current_phase(&ctx.accounts.phase_manager)?;
+    let new_min = min_claim_amount.unwrap_or_else(|| phase.default_min_claim_amount());
+    ClaimsConfig::validate_min_claim_amount(new_min, phase)?;
+
+    let config = &mut ctx.accounts.claims_config;
+    let old_min_claim_amount = config.min_claim_amount;
+    config.min_claim_amount = new_min;
+
+    emit!(MinClaimAmountUpdated {
+        old_min_claim_amount,
+        new_min_claim_amount: new_min,
+        phase,
+        updated_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
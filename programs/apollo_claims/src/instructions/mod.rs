@@ -2,16 +2,22 @@
 
 pub mod ai_processing;
 pub mod attestation;
+pub mod claim_rate;
 pub mod fast_lane;
 pub mod initialize;
 pub mod oracle;
+pub mod provider;
 pub mod resolution;
 pub mod submission;
+pub mod utilization;
 
 pub use attestation::*;
+pub use claim_rate::*;
 pub use initialize::*;
+pub use provider::*;
 pub use resolution::*;
 pub use submission::*;
+pub use utilization::*;
 // Note: fast_lane and oracle exports removed to avoid name collision with ai_processing
 // Use module:: prefix to access specific types (fast_lane::, oracle::)
 pub use ai_processing::*;
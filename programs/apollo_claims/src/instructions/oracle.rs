@@ -9,7 +9,7 @@ use crate::ai_oracle::{
     RegionalPriceFactor, UcrReference,
 };
 use crate::errors::ClaimsError;
-use crate::state::{ClaimAccount, ClaimStatus, ClaimsConfig};
+use crate::state::{ClaimAccount, ClaimStatus, ClaimsConfig, DenialCode};
 use anchor_lang::prelude::*;
 
 // =============================================================================
@@ -185,8 +185,9 @@ pub fn submit_ai_decision(ctx: Context<SubmitAiDecision>, params: AiDecisionPara
             claim.status = ClaimStatus::Denied;
             claim.denial_reason =
                 format!("AI denial: High fraud risk ({}bps)", params.fraud_score_bps);
+            claim.denial_code = DenialCode::Fraud;
             oracle.auto_denied += 1;
-            config.total_claims_denied += 1;
+            config.record_denial(DenialCode::Fraud);
         }
         AiDecisionType::CommitteeReview => {
             claim.status = ClaimStatus::PendingAttestation;
@@ -282,6 +283,7 @@ pub fn overturn_ai_decision(
         AiDecisionType::AutoDeny => {
             claim.status = ClaimStatus::Denied;
             claim.denial_reason = reason.clone();
+            claim.denial_code = DenialCode::Other;
         }
         AiDecisionType::CommitteeReview => {
             claim.status = ClaimStatus::PendingAttestation;
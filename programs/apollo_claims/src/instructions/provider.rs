@@ -0,0 +1,158 @@
+// programs/apollo_claims/src/instructions/provider.rs
+
+use crate::errors::ClaimsError;
+use crate::events::{ProviderCleared, ProviderRegistered, ProviderWatchlisted};
+use crate::state::{ClaimsConfig, ProviderRecord};
+use anchor_lang::prelude::*;
+
+/// Register a provider in the registry
+#[derive(Accounts)]
+#[instruction(provider: Pubkey)]
+pub struct RegisterProvider<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProviderRecord::INIT_SPACE,
+        seeds = [ProviderRecord::SEED_PREFIX, provider.as_ref()],
+        bump
+    )]
+    pub provider_record: Account<'info, ProviderRecord>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == claims_config.authority ||
+                     authority.key() == claims_config.claims_committee @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_provider(ctx: Context<RegisterProvider>, provider: Pubkey) -> Result<()> {
+    let clock = Clock::get()?;
+    let record = &mut ctx.accounts.provider_record;
+
+    record.provider = provider;
+    record.claims_submitted = 0;
+    record.claims_approved = 0;
+    record.claims_denied = 0;
+    record.denial_rate_bps = 0;
+    record.watchlisted = false;
+    record.watchlisted_at = 0;
+    record.registered_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.provider_record;
+
+    emit!(ProviderRegistered {
+        provider,
+        registered_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Manually flag or clear a provider (DAO or Claims Committee)
+#[derive(Accounts)]
+pub struct ManageProvider<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        mut,
+        seeds = [ProviderRecord::SEED_PREFIX, provider_record.provider.as_ref()],
+        bump = provider_record.bump,
+    )]
+    pub provider_record: Account<'info, ProviderRecord>,
+
+    #[account(
+        constraint = authority.key() == claims_config.authority ||
+                     authority.key() == claims_config.claims_committee @ ClaimsError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn flag_provider(ctx: Context<ManageProvider>) -> Result<()> {
+    let clock = Clock::get()?;
+    let record = &mut ctx.accounts.provider_record;
+
+    require!(!record.watchlisted, ClaimsError::ProviderAlreadyWatchlisted);
+
+    record.watchlist(clock.unix_timestamp);
+
+    emit!(ProviderWatchlisted {
+        provider: record.provider,
+        denial_rate_bps: record.denial_rate_bps,
+        automatic: false,
+        flagged_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn clear_provider(ctx: Context<ManageProvider>) -> Result<()> {
+    let clock = Clock::get()?;
+    let record = &mut ctx.accounts.provider_record;
+
+    require!(record.watchlisted, ClaimsError::ProviderNotWatchlisted);
+
+    record.clear();
+
+    emit!(ProviderCleared {
+        provider: record.provider,
+        cleared_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Record a claim outcome against the claim's provider record (required
+/// when the claim names a provider), emitting `ProviderWatchlisted` if the
+/// denial-rate threshold is crossed
+pub(crate) fn record_provider_outcome(
+    record: Option<&mut Account<ProviderRecord>>,
+    claim_provider: Option<Pubkey>,
+    denied: bool,
+    decided_by: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    let Some(record) = record else {
+        require!(
+            claim_provider.is_none(),
+            ClaimsError::ProviderRecordRequired
+        );
+        return Ok(());
+    };
+    require!(
+        claim_provider == Some(record.provider),
+        ClaimsError::ProviderMismatch
+    );
+
+    let newly_watchlisted = if denied {
+        record.record_denial(current_time)
+    } else {
+        record.record_approval(current_time)
+    };
+
+    if newly_watchlisted {
+        emit!(ProviderWatchlisted {
+            provider: record.provider,
+            denial_rate_bps: record.denial_rate_bps,
+            automatic: true,
+            flagged_by: decided_by,
+            timestamp: current_time,
+        });
+    }
+
+    Ok(())
+}
@@ -1,10 +1,25 @@
 // programs/apollo_claims/src/instructions/resolution.rs
 
 use crate::errors::ClaimsError;
-use crate::events::{ClaimAppealed, ClaimApproved, ClaimClosed, ClaimDenied, ClaimPaid};
-use crate::state::{ClaimAccount, ClaimStatus, ClaimsConfig};
+use crate::events::{
+    ClaimAppealed, ClaimApproved, ClaimCappedAtLimit, ClaimClosed, ClaimDenied, ClaimPaid,
+    ClaimReinsuranceRecoverable, ClaimStatusChanged, PaidClaimAutoClosed, PayoutBlockedForFraud,
+    ShockClaimDaoApproved, StaleAiDecision,
+};
+use crate::instructions::ai_processing::{AiDecision, AiOracle};
+use crate::instructions::provider::record_provider_outcome;
+use crate::state::{
+    BenefitSchedule, CategoryStats, ClaimAccount, ClaimStatus, ClaimsConfig, DenialReason,
+    FraudOverride, MemberBenefitAccumulator, PaymentReceipt, ProviderRecord,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Token, TokenAccount};
+use apollo_governance::authorization;
+use apollo_governance::program::ApolloGovernance;
+use apollo_governance::state::{AdminAction, CommitteeType, DaoConfig, Multisig, SignerSet};
+use apollo_membership::state::MemberAccount;
+use apollo_reinsurance::program::ApolloReinsurance;
+use apollo_reinsurance::state::{MemberClaimsAccumulator, ReinsuranceConfig, ReinsuranceTreaty};
 
 /// Approve a claim
 #[derive(Accounts)]
@@ -16,6 +31,15 @@ pub struct ApproveClaim<'info> {
     )]
     pub claims_config: Account<'info, ClaimsConfig>,
 
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
     #[account(
         mut,
         seeds = [ClaimAccount::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
@@ -27,12 +51,115 @@ pub struct ApproveClaim<'info> {
     )]
     pub claim: Account<'info, ClaimAccount>,
 
+    /// Benefit schedule (coinsurance by category)
+    #[account(
+        seeds = [BenefitSchedule::SEED_PREFIX],
+        bump = benefit_schedule.bump,
+    )]
+    pub benefit_schedule: Account<'info, BenefitSchedule>,
+
+    /// Claimant's membership account (plan year and coverage tier)
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, claim.member.as_ref()],
+        bump = member_account.bump,
+        seeds::program = apollo_membership::ID,
+    )]
+    pub member_account: Box<Account<'info, MemberAccount>>,
+
+    /// Claimant's plan-year benefit utilization
+    #[account(
+        init_if_needed,
+        payer = approver,
+        space = 8 + MemberBenefitAccumulator::INIT_SPACE,
+        seeds = [MemberBenefitAccumulator::SEED_PREFIX, claim.member.as_ref()],
+        bump
+    )]
+    pub benefit_accumulator: Box<Account<'info, MemberBenefitAccumulator>>,
+
+    /// Protocol-wide per-category counters
+    #[account(
+        mut,
+        seeds = [CategoryStats::SEED_PREFIX],
+        bump = category_stats.bump,
+    )]
+    pub category_stats: Box<Account<'info, CategoryStats>>,
+
+    /// Registry record for the claim's provider (required if it names one)
+    #[account(
+        mut,
+        seeds = [ProviderRecord::SEED_PREFIX, provider_record.provider.as_ref()],
+        bump = provider_record.bump,
+    )]
+    pub provider_record: Option<Account<'info, ProviderRecord>>,
+
+    /// AI oracle, required when the claim has an AI decision
+    #[account(
+        seeds = [AiOracle::SEED_PREFIX],
+        bump = ai_oracle.bump,
+    )]
+    pub ai_oracle: Option<Account<'info, AiOracle>>,
+
+    /// AI decision the approval relies on, required when the claim has one
+    #[account(
+        seeds = [AiDecision::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+        bump = ai_decision.bump,
+    )]
+    pub ai_decision: Option<Account<'info, AiDecision>>,
+
+    /// Governance claims committee multisig that approved a shock claim
+    /// (shock claims only)
+    #[account(
+        constraint = dao_config.committee(CommitteeType::Claims) == Some(dao_multisig.key()) @ ClaimsError::DaoApprovalRequired
+    )]
+    pub dao_multisig: Option<Account<'info, Multisig>>,
+
+    /// Signer set authorizing approval of this claim (shock claims only)
+    #[account(
+        mut,
+        constraint = dao_signer_set.target == claim.key() @ ClaimsError::DaoApprovalRequired
+    )]
+    pub dao_signer_set: Option<Account<'info, SignerSet>>,
+
+    #[account(
+        constraint = governance_program.key() == claims_config.governance_program @ ClaimsError::Unauthorized
+    )]
+    pub governance_program: Option<Program<'info, ApolloGovernance>>,
+
+    /// Reinsurance config, to report claims past the specific stop-loss
+    /// (the reinsurance accounts are required while a stop-loss treaty is
+    /// designated)
+    #[account(
+        mut,
+        seeds = [b"reinsurance_config"],
+        bump = reinsurance_config.bump,
+        seeds::program = apollo_reinsurance::ID,
+    )]
+    pub reinsurance_config: Option<Box<Account<'info, ReinsuranceConfig>>>,
+
+    /// Specific stop-loss treaty whose attachment the member is checked against
+    #[account(
+        constraint = reinsurance_treaty.key() == claims_config.stop_loss_treaty
+            @ ClaimsError::StopLossTreatyMismatch
+    )]
+    pub reinsurance_treaty: Option<Box<Account<'info, ReinsuranceTreaty>>>,
+
+    /// Member's reinsurance claims accumulator for the policy year
+    /// CHECK: Seeds are validated by the reinsurance program, which creates
+    /// it on the member's first recorded claim
+    #[account(mut)]
+    pub reinsurance_accumulator: Option<UncheckedAccount<'info>>,
+
+    pub reinsurance_program: Option<Program<'info, ApolloReinsurance>>,
+
     /// Must be authorized (committee for regular, DAO for shock claims)
     #[account(
+        mut,
         constraint = approver.key() == claims_config.authority ||
                      approver.key() == claims_config.claims_committee @ ClaimsError::Unauthorized
     )]
     pub approver: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn approve_claim(ctx: Context<ApproveClaim>, approved_amount: u64) -> Result<()> {
@@ -40,14 +167,96 @@ pub fn approve_claim(ctx: Context<ApproveClaim>, approved_amount: u64) -> Result
     let config = &mut ctx.accounts.claims_config;
     let claim = &mut ctx.accounts.claim;
 
+    // A stale AI decision is not applied; the claim goes back for re-evaluation
+    if claim.has_ai_decision {
+        let (Some(decision), Some(oracle)) = (
+            ctx.accounts.ai_decision.as_ref(),
+            ctx.accounts.ai_oracle.as_ref(),
+        ) else {
+            return err!(ClaimsError::AiDecisionRequired);
+        };
+
+        if decision.is_stale(clock.unix_timestamp, oracle.max_decision_age) {
+            let old_status = claim.status;
+            claim.status = ClaimStatus::UnderReview;
+            claim.status_changed_at = clock.unix_timestamp;
+
+            emit!(StaleAiDecision {
+                claim_id: claim.claim_id,
+                decided_at: decision.decided_at,
+                decision_age: decision.age(clock.unix_timestamp),
+                max_decision_age: oracle.max_decision_age,
+                timestamp: clock.unix_timestamp,
+            });
+            emit!(ClaimStatusChanged {
+                claim_id: claim.claim_id,
+                old_status,
+                new_status: ClaimStatus::UnderReview,
+                timestamp: clock.unix_timestamp,
+            });
+
+            return Ok(());
+        }
+    }
+
     // Verify sufficient attestations for non-shock claims
     if !claim.is_shock_claim && claim.status == ClaimStatus::PendingAttestation {
         require!(
-            claim.attestation_count >= config.required_attestations,
+            claim.attestation_count >= config.attestations_required(claim),
             ClaimsError::InsufficientAttestations
         );
     }
 
+    // Shock claims and escalated claims need a DAO signer set that reached threshold
+    if claim.is_shock_claim || claim.status == ClaimStatus::PendingDaoVote {
+        let (Some(multisig), Some(signer_set), Some(governance_program)) = (
+            ctx.accounts.dao_multisig.as_ref(),
+            ctx.accounts.dao_signer_set.as_ref(),
+            ctx.accounts.governance_program.as_ref(),
+        ) else {
+            return err!(ClaimsError::DaoApprovalRequired);
+        };
+
+        apollo_governance::cpi::assert_signed(
+            CpiContext::new(
+                governance_program.to_account_info(),
+                apollo_governance::cpi::accounts::AssertSigned {
+                    multisig: multisig.to_account_info(),
+                    signer_set: signer_set.to_account_info(),
+                },
+            ),
+            AdminAction::ApproveClaim,
+        )?;
+
+        // Consume the signer set so it cannot authorize another approval
+        apollo_governance::cpi::mark_executed(CpiContext::new(
+            governance_program.to_account_info(),
+            apollo_governance::cpi::accounts::MarkExecuted {
+                multisig: multisig.to_account_info(),
+                signer_set: signer_set.to_account_info(),
+                executor: ctx.accounts.approver.to_account_info(),
+            },
+        ))?;
+
+        emit!(ShockClaimDaoApproved {
+            claim_id: claim.claim_id,
+            signer_set: signer_set.key(),
+            approvals: signer_set.approvals.len() as u8,
+            threshold: multisig.threshold,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Services inside the member's waiting periods are not covered
+    require!(
+        !claim.in_waiting_period,
+        ClaimsError::WaitingPeriodNotComplete
+    );
+    require!(
+        !claim.in_preexisting_wait,
+        ClaimsError::PreexistingWaitingPeriod
+    );
+
     // Approved amount can be less than requested but not zero for approval
     require!(approved_amount > 0, ClaimsError::InvalidClaimAmount);
     require!(
@@ -55,17 +264,89 @@ pub fn approve_claim(ctx: Context<ApproveClaim>, approved_amount: u64) -> Result
         ClaimsError::InvalidClaimAmount
     );
 
-    claim.approved_amount = approved_amount;
-    claim.status = ClaimStatus::Approved;
+    // Plan pays the allowed amount net of category coinsurance
+    let coinsurance_bps = ctx
+        .accounts
+        .benefit_schedule
+        .coinsurance_bps_for(claim.category);
+
+    let member_account = &ctx.accounts.member_account;
+    let accumulator = &mut ctx.accounts.benefit_accumulator;
+    if accumulator.member == Pubkey::default() {
+        accumulator.seed(
+            claim.member,
+            member_account.plan_year,
+            ctx.bumps.benefit_accumulator,
+        );
+    }
+
+    let approval = BenefitLedger {
+        config,
+        category_stats: &mut ctx.accounts.category_stats,
+        schedule: &ctx.accounts.benefit_schedule,
+        member_account,
+        accumulator,
+    }
+    .approve(
+        claim,
+        approved_amount,
+        StopLossAccounts {
+            config: ctx.accounts.reinsurance_config.as_deref(),
+            treaty: ctx.accounts.reinsurance_treaty.as_deref(),
+            accumulator: ctx.accounts.reinsurance_accumulator.as_ref(),
+            program: ctx.accounts.reinsurance_program.as_ref(),
+            authority: ctx.accounts.approver.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+        },
+        clock.unix_timestamp,
+    )?;
+
+    // Hard benefit limits: pay up to what is left, or deny outright
+    let Some(covered_amount) = approval else {
+        let reason = DenialReason::ExceedsLimit;
+        claim.status = ClaimStatus::Denied;
+        claim.denial_code = reason.code();
+        claim.denial_note_hash = reason.note_hash();
+        claim.status_changed_at = clock.unix_timestamp;
+        config.record_denial(reason.code());
+
+        record_provider_outcome(
+            ctx.accounts.provider_record.as_mut(),
+            claim.provider,
+            true,
+            ctx.accounts.approver.key(),
+            clock.unix_timestamp,
+        )?;
+
+        emit!(ClaimDenied {
+            claim_id: claim.claim_id,
+            member: claim.member,
+            requested_amount: claim.requested_amount,
+            denial_code: reason.code(),
+            note_hash: reason.note_hash(),
+            denier: ctx.accounts.approver.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    };
+
     claim.status_changed_at = clock.unix_timestamp;
 
-    config.total_claims_approved += 1;
+    record_provider_outcome(
+        ctx.accounts.provider_record.as_mut(),
+        claim.provider,
+        false,
+        ctx.accounts.approver.key(),
+        clock.unix_timestamp,
+    )?;
 
     emit!(ClaimApproved {
         claim_id: claim.claim_id,
         member: claim.member,
         requested_amount: claim.requested_amount,
-        approved_amount,
+        approved_amount: covered_amount,
+        coinsurance_bps,
         approver: ctx.accounts.approver.key(),
         timestamp: clock.unix_timestamp,
     });
@@ -73,6 +354,244 @@ pub fn approve_claim(ctx: Context<ApproveClaim>, approved_amount: u64) -> Result
     Ok(())
 }
 
+/// Benefit utilization and protocol counters every claim approval is
+/// recorded against
+pub(crate) struct BenefitLedger<'a> {
+    pub config: &'a mut ClaimsConfig,
+    pub category_stats: &'a mut CategoryStats,
+    pub schedule: &'a BenefitSchedule,
+    pub member_account: &'a MemberAccount,
+    pub accumulator: &'a mut MemberBenefitAccumulator,
+}
+
+impl BenefitLedger<'_> {
+    /// Approve `claim` for an allowed `approved_amount`: the plan pays it
+    /// net of coinsurance, capped at the member's remaining benefit limits.
+    /// Records the payment on the accumulator and the approval counters and
+    /// reports it to specific stop-loss. Returns the plan payment, or None
+    /// with the claim untouched when the limits deny it.
+    pub(crate) fn approve(
+        self,
+        claim: &mut ClaimAccount,
+        approved_amount: u64,
+        stop_loss: StopLossAccounts,
+        timestamp: i64,
+    ) -> Result<Option<u64>> {
+        let covered_amount = self
+            .schedule
+            .covered_amount(claim.category, approved_amount);
+        let is_family = self.member_account.coverage_tier.is_family();
+        let plan_year = self.member_account.plan_year;
+
+        let Some(payable) = self.accumulator.limit_payable(
+            self.schedule,
+            is_family,
+            plan_year,
+            claim.category,
+            covered_amount,
+            self.config.allow_partial_limit_payment,
+        ) else {
+            return Ok(None);
+        };
+        if payable < covered_amount {
+            emit!(ClaimCappedAtLimit {
+                claim_id: claim.claim_id,
+                member: claim.member,
+                covered_amount,
+                paid_amount: payable,
+                timestamp,
+            });
+        }
+
+        claim.approved_amount = payable;
+        claim.status = ClaimStatus::Approved;
+
+        // The amount cut by the limit is not member cost share, so it stays
+        // out of the allowed amount counted toward deductible and OOP
+        self.accumulator.record_approval(
+            self.schedule,
+            is_family,
+            plan_year,
+            claim.category,
+            approved_amount.saturating_sub(covered_amount - payable),
+            payable,
+        );
+
+        self.config
+            .record_approval(self.category_stats, claim.category);
+
+        // Report to specific stop-loss once the member's plan-paid YTD is
+        // past the attachment
+        stop_loss.report(
+            self.config.stop_loss_treaty,
+            self.accumulator,
+            claim,
+            timestamp,
+        )?;
+
+        Ok(Some(payable))
+    }
+
+    /// Undo `approve` for a claim approved for `approved_amount` that is
+    /// being denied: releases its plan payment and cost share, drops it
+    /// from the approval counters and takes any stop-loss report back.
+    /// `claims_config` is the config PDA, which signs the reversal.
+    pub(crate) fn reverse<'info>(
+        self,
+        claim: &mut ClaimAccount,
+        approved_amount: u64,
+        stop_loss: StopLossAccounts<'_, 'info>,
+        claims_config: AccountInfo<'info>,
+    ) -> Result<()> {
+        let covered_amount = self
+            .schedule
+            .covered_amount(claim.category, approved_amount);
+        let allowed_amount =
+            approved_amount.saturating_sub(covered_amount.saturating_sub(claim.approved_amount));
+        self.accumulator
+            .reverse_approval(claim.category, allowed_amount, claim.approved_amount);
+
+        self.config
+            .reverse_approval(self.category_stats, claim.category);
+
+        stop_loss.reverse(
+            self.config.stop_loss_treaty,
+            claims_config,
+            self.config.bump,
+            self.accumulator,
+            claim,
+        )
+    }
+}
+
+/// Reinsurance accounts an approval reports specific stop-loss claims
+/// through; all are required while a stop-loss treaty is designated
+pub(crate) struct StopLossAccounts<'a, 'info> {
+    pub config: Option<&'a Account<'info, ReinsuranceConfig>>,
+    pub treaty: Option<&'a Account<'info, ReinsuranceTreaty>>,
+    pub accumulator: Option<&'a UncheckedAccount<'info>>,
+    pub program: Option<&'a Program<'info, ApolloReinsurance>>,
+    /// Signs and pays for the member's reinsurance accumulator
+    pub authority: AccountInfo<'info>,
+    pub system_program: AccountInfo<'info>,
+}
+
+impl<'info> StopLossAccounts<'_, 'info> {
+    /// Report the member's plan-paid YTD past the attachment of
+    /// `stop_loss_treaty` and mark the claim recoverable
+    pub(crate) fn report(
+        self,
+        stop_loss_treaty: Pubkey,
+        benefits: &MemberBenefitAccumulator,
+        claim: &mut ClaimAccount,
+        timestamp: i64,
+    ) -> Result<()> {
+        if stop_loss_treaty == Pubkey::default() {
+            return Ok(());
+        }
+        let (Some(re_config), Some(treaty), Some(re_accumulator), Some(re_program)) =
+            (self.config, self.treaty, self.accumulator, self.program)
+        else {
+            return err!(ClaimsError::StopLossAccountsRequired);
+        };
+
+        let reported = reported_stop_loss_claims(re_accumulator)?;
+        let Some(unreported) =
+            benefits.unreported_stop_loss_claims(treaty.attachment_point, reported)
+        else {
+            return Ok(());
+        };
+        if unreported > 0 {
+            apollo_reinsurance::cpi::record_claim_to_accumulator(
+                CpiContext::new(
+                    re_program.to_account_info(),
+                    apollo_reinsurance::cpi::accounts::RecordClaimToAccumulator {
+                        config: re_config.to_account_info(),
+                        treaty: treaty.to_account_info(),
+                        accumulator: re_accumulator.to_account_info(),
+                        authority: self.authority.clone(),
+                        payer: self.authority,
+                        system_program: self.system_program,
+                    },
+                ),
+                claim.member,
+                re_config.policy_year(),
+                unreported,
+                claim.claim_id,
+            )?;
+        }
+        claim.reinsurance_recoverable = true;
+
+        emit!(ClaimReinsuranceRecoverable {
+            claim_id: claim.claim_id,
+            member: claim.member,
+            member_ytd_paid: benefits.annual_paid,
+            attachment_point: treaty.attachment_point,
+            reported_amount: unreported,
+            timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Take back what the member's reinsurance accumulator holds beyond
+    /// the plan-paid YTD once a reported claim is reversed
+    pub(crate) fn reverse(
+        self,
+        stop_loss_treaty: Pubkey,
+        claims_config: AccountInfo<'info>,
+        config_bump: u8,
+        benefits: &MemberBenefitAccumulator,
+        claim: &mut ClaimAccount,
+    ) -> Result<()> {
+        if stop_loss_treaty == Pubkey::default() || !claim.reinsurance_recoverable {
+            return Ok(());
+        }
+        let (Some(re_config), Some(treaty), Some(re_accumulator), Some(re_program)) =
+            (self.config, self.treaty, self.accumulator, self.program)
+        else {
+            return err!(ClaimsError::StopLossAccountsRequired);
+        };
+
+        let reported = reported_stop_loss_claims(re_accumulator)?;
+        let over_reported = reported.saturating_sub(benefits.annual_paid);
+        if over_reported > 0 {
+            apollo_reinsurance::cpi::reverse_claim_from_accumulator(
+                CpiContext::new_with_signer(
+                    re_program.to_account_info(),
+                    apollo_reinsurance::cpi::accounts::ReverseClaimFromAccumulator {
+                        config: re_config.to_account_info(),
+                        treaty: treaty.to_account_info(),
+                        accumulator: re_accumulator.to_account_info(),
+                        claims_config,
+                    },
+                    &[&[ClaimsConfig::SEED_PREFIX, &[config_bump]]],
+                ),
+                over_reported,
+                claim.claim_id,
+            )?;
+        }
+        claim.reinsurance_recoverable = false;
+
+        Ok(())
+    }
+}
+
+/// YTD already recorded on a member's reinsurance accumulator (zero until
+/// the reinsurance program creates it)
+fn reported_stop_loss_claims(accumulator: &UncheckedAccount) -> Result<u64> {
+    if accumulator.data_is_empty() {
+        return Ok(0);
+    }
+    require_keys_eq!(
+        *accumulator.owner,
+        apollo_reinsurance::ID,
+        ClaimsError::InvalidConfiguration
+    );
+    let data = accumulator.try_borrow_data()?;
+    Ok(MemberClaimsAccumulator::try_deserialize(&mut &data[..])?.ytd_claims)
+}
+
 /// Deny a claim
 #[derive(Accounts)]
 pub struct DenyClaim<'info> {
@@ -94,6 +613,14 @@ pub struct DenyClaim<'info> {
     )]
     pub claim: Account<'info, ClaimAccount>,
 
+    /// Registry record for the claim's provider (required if it names one)
+    #[account(
+        mut,
+        seeds = [ProviderRecord::SEED_PREFIX, provider_record.provider.as_ref()],
+        bump = provider_record.bump,
+    )]
+    pub provider_record: Option<Account<'info, ProviderRecord>>,
+
     #[account(
         constraint = denier.key() == claims_config.authority ||
                      denier.key() == claims_config.claims_committee @ ClaimsError::Unauthorized
@@ -101,22 +628,35 @@ pub struct DenyClaim<'info> {
     pub denier: Signer<'info>,
 }
 
-pub fn deny_claim(ctx: Context<DenyClaim>, reason: String) -> Result<()> {
+pub fn deny_claim(ctx: Context<DenyClaim>, reason: DenialReason) -> Result<()> {
     let clock = Clock::get()?;
     let config = &mut ctx.accounts.claims_config;
     let claim = &mut ctx.accounts.claim;
 
+    let denial_code = reason.code();
+    let note_hash = reason.note_hash();
+
     claim.status = ClaimStatus::Denied;
-    claim.denial_reason = reason.clone();
+    claim.denial_code = denial_code;
+    claim.denial_note_hash = note_hash;
     claim.status_changed_at = clock.unix_timestamp;
 
-    config.total_claims_denied += 1;
+    config.record_denial(denial_code);
+
+    record_provider_outcome(
+        ctx.accounts.provider_record.as_mut(),
+        claim.provider,
+        true,
+        ctx.accounts.denier.key(),
+        clock.unix_timestamp,
+    )?;
 
     emit!(ClaimDenied {
         claim_id: claim.claim_id,
         member: claim.member,
         requested_amount: claim.requested_amount,
-        reason,
+        denial_code,
+        note_hash,
         denier: ctx.accounts.denier.key(),
         timestamp: clock.unix_timestamp,
     });
@@ -134,23 +674,67 @@ pub struct PayClaim<'info> {
     )]
     pub claims_config: Account<'info, ClaimsConfig>,
 
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
     #[account(
         mut,
         seeds = [ClaimAccount::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
         bump = claim.bump,
+        constraint = !claim.already_paid @ ClaimsError::AlreadyPaid,
         constraint = claim.status == ClaimStatus::Approved @ ClaimsError::InvalidClaimStatus
     )]
     pub claim: Account<'info, ClaimAccount>,
 
+    /// One receipt per claim - `init` fails if the claim was already paid
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PaymentReceipt::INIT_SPACE,
+        seeds = [PaymentReceipt::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+        bump
+    )]
+    pub payment_receipt: Account<'info, PaymentReceipt>,
+
+    /// AI decision for the claim, required if one was recorded
+    #[account(
+        seeds = [AiDecision::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+        bump = ai_decision.bump,
+    )]
+    pub ai_decision: Option<Account<'info, AiDecision>>,
+
+    /// Committee override releasing a fraud hold, if granted
+    #[account(
+        seeds = [FraudOverride::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+        bump = fraud_override.bump,
+    )]
+    pub fraud_override: Option<Account<'info, FraudOverride>>,
+
+    /// Protocol-wide per-category counters
+    #[account(
+        mut,
+        seeds = [CategoryStats::SEED_PREFIX],
+        bump = category_stats.bump,
+    )]
+    pub category_stats: Account<'info, CategoryStats>,
+
     /// Recipient token account (member or provider)
     #[account(mut)]
     pub recipient: Account<'info, TokenAccount>,
 
     // NOTE: In production, this would include CPI to reserves program
     // For scaffold, we just update state and emit events
+    #[account(mut)]
     pub payer: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 pub fn pay_claim(ctx: Context<PayClaim>) -> Result<()> {
@@ -159,12 +743,24 @@ pub fn pay_claim(ctx: Context<PayClaim>) -> Result<()> {
     let claim = &mut ctx.accounts.claim;
 
     require!(claim.approved_amount > 0, ClaimsError::InvalidClaimAmount);
-    require!(
-        claim.paid_amount < claim.approved_amount,
-        ClaimsError::AlreadyPaid
-    );
 
-    let payment_amount = claim.approved_amount - claim.paid_amount;
+    // Critical fraud flags hold the payout until the committee overrides them
+    if claim.has_ai_decision {
+        let Some(decision) = ctx.accounts.ai_decision.as_ref() else {
+            return err!(ClaimsError::AiDecisionRequired);
+        };
+        if decision.blocks_payout(ctx.accounts.fraud_override.is_some()) {
+            emit!(PayoutBlockedForFraud {
+                claim_id: claim.claim_id,
+                member: claim.member,
+                flags: decision.critical_flags(),
+                timestamp: clock.unix_timestamp,
+            });
+            return err!(ClaimsError::PayoutBlockedForFraud);
+        }
+    }
+
+    let payment_amount = claim.record_payment().ok_or(ClaimsError::AlreadyPaid)?;
 
     // TODO: CPI to apollo_reserves::payout_claim_from_waterfall
     // For scaffold, we mark as paid without actual transfer
@@ -174,11 +770,23 @@ pub fn pay_claim(ctx: Context<PayClaim>) -> Result<()> {
     //     PayoutParams { claim_id: claim.claim_id, amount: payment_amount }
     // )?;
 
-    claim.paid_amount = claim.approved_amount;
     claim.status = ClaimStatus::Paid;
     claim.status_changed_at = clock.unix_timestamp;
 
     config.total_paid_out = config.total_paid_out.saturating_add(payment_amount);
+    ctx.accounts
+        .category_stats
+        .record_payment(claim.category, payment_amount);
+
+    let receipt = &mut ctx.accounts.payment_receipt;
+    receipt.claim_id = claim.claim_id;
+    receipt.claim = claim.key();
+    receipt.member = claim.member;
+    receipt.amount = payment_amount;
+    receipt.recipient = ctx.accounts.recipient.key();
+    receipt.payment_nonce = claim.payment_nonce;
+    receipt.paid_at = clock.unix_timestamp;
+    receipt.bump = ctx.bumps.payment_receipt;
 
     emit!(ClaimPaid {
         claim_id: claim.claim_id,
@@ -226,9 +834,93 @@ pub fn close_claim(ctx: Context<CloseClaim>) -> Result<()> {
     Ok(())
 }
 
+/// Close paid claims past the settlement window (permissionless)
+///
+/// Remaining accounts: `[claim, member]` pairs, each followed by
+/// `[ai_decision, oracle_signer]` when the claim has an AI decision. The
+/// claim's rent goes back to the member who opened it and the decision's to
+/// the oracle signer that recorded it. The payment receipt is kept as the
+/// permanent record of payment. Claims not yet eligible are skipped.
+#[derive(Accounts)]
+pub struct AutoClosePaidClaims<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    pub closer: Signer<'info>,
+}
+
+pub fn auto_close_paid_claims<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AutoClosePaidClaims<'info>>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let close_window = ctx.accounts.claims_config.paid_claim_close_window;
+
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(claim_info) = accounts.next() {
+        let member = accounts.next().ok_or(ClaimsError::InvalidConfiguration)?;
+        let claim: Account<ClaimAccount> = Account::try_from(claim_info)?;
+        require_keys_eq!(claim.member, *member.key, ClaimsError::Unauthorized);
+
+        let decision = if claim.has_ai_decision {
+            let decision_info = accounts.next().ok_or(ClaimsError::InvalidConfiguration)?;
+            let oracle_signer = accounts.next().ok_or(ClaimsError::InvalidConfiguration)?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[AiDecision::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                *decision_info.key,
+                expected,
+                ClaimsError::InvalidConfiguration
+            );
+            let decision: Account<AiDecision> = Account::try_from(decision_info)?;
+            require_keys_eq!(
+                decision.submitted_by,
+                *oracle_signer.key,
+                ClaimsError::Unauthorized
+            );
+            Some((decision, oracle_signer))
+        } else {
+            None
+        };
+
+        if !claim.is_auto_closable(clock.unix_timestamp, close_window) {
+            continue;
+        }
+
+        let rent_refunded = claim_info.lamports();
+        emit!(PaidClaimAutoClosed {
+            claim_id: claim.claim_id,
+            member: claim.member,
+            total_paid: claim.paid_amount,
+            rent_refunded,
+            closed_by: ctx.accounts.closer.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        claim.close(member.clone())?;
+        if let Some((decision, oracle_signer)) = decision {
+            decision.close(oracle_signer.clone())?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Appeal a denied claim
 #[derive(Accounts)]
 pub struct AppealClaim<'info> {
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
     #[account(
         mut,
         seeds = [ClaimAccount::SEED_PREFIX, &claim.claim_id.to_le_bytes()],
@@ -1,9 +1,18 @@
 // programs/apollo_claims/src/instructions/submission.rs
 
 use crate::errors::ClaimsError;
-use crate::events::{ClaimCancelled, ClaimSubmitted};
-use crate::state::{ClaimAccount, ClaimCategory, ClaimStatus, ClaimsConfig};
+use crate::events::{ClaimCancelled, ClaimFrequencyCapReached, ClaimSubmitted};
+use crate::state::{
+    BenefitSchedule, CategoryStats, ClaimAccount, ClaimCategory, ClaimStatus, ClaimsConfig,
+    DenialCode, MemberClaimRate, ProviderRecord,
+};
 use anchor_lang::prelude::*;
+use apollo_core::BootstrapConfig;
+use apollo_governance::authorization;
+use apollo_governance::state::DaoConfig;
+use apollo_membership::membership_helpers;
+use apollo_membership::state::MemberAccount;
+use apollo_reserves::state::ReserveState;
 
 /// Submit a new claim
 #[derive(Accounts)]
@@ -17,6 +26,15 @@ pub struct SubmitClaim<'info> {
     )]
     pub claims_config: Account<'info, ClaimsConfig>,
 
+    /// Governance DAO config (protocol pause flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+        constraint = authorization::is_protocol_active(&dao_config) @ ClaimsError::ProtocolPaused
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
     #[account(
         init,
         payer = member,
@@ -26,6 +44,64 @@ pub struct SubmitClaim<'info> {
     )]
     pub claim: Account<'info, ClaimAccount>,
 
+    #[account(
+        seeds = [BenefitSchedule::SEED_PREFIX],
+        bump = benefit_schedule.bump,
+    )]
+    pub benefit_schedule: Account<'info, BenefitSchedule>,
+
+    /// Member's membership account (enrollment and waiting period)
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, member.key().as_ref()],
+        bump = member_account.bump,
+        seeds::program = apollo_membership::ID,
+    )]
+    pub member_account: Account<'info, MemberAccount>,
+
+    /// Core bootstrap config (bootstrap mode caps the shock threshold)
+    #[account(
+        seeds = [BootstrapConfig::SEED_PREFIX],
+        bump = bootstrap_config.bump,
+        seeds::program = apollo_core::ID,
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+
+    /// Reserve state (total reserves for shock classification)
+    #[account(
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+        seeds::program = apollo_reserves::ID,
+    )]
+    pub reserve_state: Account<'info, ReserveState>,
+
+    /// Registry record for the claim's provider (required if it names one)
+    #[account(
+        mut,
+        seeds = [ProviderRecord::SEED_PREFIX, provider_record.provider.as_ref()],
+        bump = provider_record.bump,
+    )]
+    pub provider_record: Option<Account<'info, ProviderRecord>>,
+
+    /// Member's submission counter for the monthly claim cap
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + MemberClaimRate::INIT_SPACE,
+        seeds = [MemberClaimRate::SEED_PREFIX, member.key().as_ref()],
+        bump
+    )]
+    pub member_claim_rate: Account<'info, MemberClaimRate>,
+
+    /// Protocol-wide per-category counters
+    #[account(
+        init_if_needed,
+        payer = member,
+        space = 8 + CategoryStats::INIT_SPACE,
+        seeds = [CategoryStats::SEED_PREFIX],
+        bump
+    )]
+    pub category_stats: Box<Account<'info, CategoryStats>>,
+
     #[account(mut)]
     pub member: Signer<'info>,
 
@@ -40,19 +116,58 @@ pub struct SubmitClaimParams {
     pub service_date: i64,
     pub description_hash: String,
     pub provider: Option<Pubkey>,
+    /// Member declares the claim relates to a pre-existing condition
+    pub preexisting: bool,
 }
 
 pub fn submit_claim(ctx: Context<SubmitClaim>, params: SubmitClaimParams) -> Result<()> {
     let clock = Clock::get()?;
     let config = &mut ctx.accounts.claims_config;
 
+    require!(
+        params.claim_id == config.next_claim_id(),
+        ClaimsError::InvalidClaimId
+    );
     require!(params.requested_amount > 0, ClaimsError::InvalidClaimAmount);
+    require!(
+        !config.is_below_minimum(params.requested_amount),
+        ClaimsError::ClaimBelowMinimum
+    );
     require!(
         params.service_date <= clock.unix_timestamp,
         ClaimsError::InvalidServiceDate
     );
 
-    let is_shock = params.requested_amount >= config.shock_claim_threshold;
+    // Only members with coverage in force may file
+    membership_helpers::assert_active_coverage(&ctx.accounts.member_account, clock.unix_timestamp)?;
+
+    // ...and only for services rendered while covered
+    require!(
+        ctx.accounts
+            .member_account
+            .covers_service_date(params.service_date),
+        ClaimsError::ServiceDateOutsideCoverage
+    );
+
+    // Monthly claim cap (whitelisted chronic-care members are exempt)
+    let rate = &mut ctx.accounts.member_claim_rate;
+    if rate.member == Pubkey::default() {
+        rate.member = ctx.accounts.member.key();
+        rate.bump = ctx.bumps.member_claim_rate;
+    }
+    let cap = config.max_claims_per_period;
+    require!(
+        !rate.would_exceed_cap(clock.unix_timestamp, cap),
+        ClaimsError::ClaimRateExceeded
+    );
+    let high_frequency = rate.record_submission(clock.unix_timestamp, cap);
+
+    // Shock threshold scales with current reserves, capped in bootstrap mode
+    let shock_threshold = ClaimsConfig::get_effective_shock_threshold(
+        ctx.accounts.bootstrap_config.bootstrap_active,
+        Some(ctx.accounts.reserve_state.total_reserves()),
+    );
+    let is_shock = params.requested_amount >= shock_threshold;
 
     let claim = &mut ctx.accounts.claim;
     claim.claim_id = params.claim_id;
@@ -68,12 +183,48 @@ pub fn submit_claim(ctx: Context<SubmitClaim>, params: SubmitClaimParams) -> Res
     claim.service_date = params.service_date;
     claim.description_hash = params.description_hash;
     claim.attestation_count = 0;
+    claim.required_attestations = 0;
     claim.denial_reason = String::new();
     claim.is_shock_claim = is_shock;
+    let member_account = &ctx.accounts.member_account;
+    claim.in_waiting_period = member_account.is_in_waiting_period(params.service_date);
+    claim.is_preexisting = params.preexisting;
+    claim.in_preexisting_wait = params.preexisting
+        && ctx
+            .accounts
+            .benefit_schedule
+            .is_preexisting_excluded(member_account.enrolled_at, params.service_date);
+    claim.denial_code = DenialCode::None;
+    claim.denial_note_hash = [0u8; 32];
+    claim.already_paid = false;
+    claim.payment_nonce = 0;
+    claim.has_ai_decision = false;
+    claim.timing_anomaly = member_account.is_timing_anomaly(params.service_date);
+    claim.high_frequency = high_frequency;
+    claim.reinsurance_recoverable = false;
     claim.bump = ctx.bumps.claim;
 
+    // A named provider must be registered so its outcomes are tracked
+    match ctx.accounts.provider_record.as_mut() {
+        Some(record) => {
+            require!(
+                params.provider == Some(record.provider),
+                ClaimsError::ProviderMismatch
+            );
+            record.record_submission();
+        }
+        None => require!(
+            params.provider.is_none(),
+            ClaimsError::ProviderRecordRequired
+        ),
+    }
+
     config.total_claims_submitted += 1;
 
+    let stats = &mut ctx.accounts.category_stats;
+    stats.bump = ctx.bumps.category_stats;
+    stats.record_submission(params.category);
+
     emit!(ClaimSubmitted {
         claim_id: params.claim_id,
         member: ctx.accounts.member.key(),
@@ -83,6 +234,16 @@ pub fn submit_claim(ctx: Context<SubmitClaim>, params: SubmitClaimParams) -> Res
         timestamp: clock.unix_timestamp,
     });
 
+    if high_frequency {
+        emit!(ClaimFrequencyCapReached {
+            claim_id: params.claim_id,
+            member: ctx.accounts.member.key(),
+            claims_this_period: ctx.accounts.member_claim_rate.claims_this_period,
+            max_claims_per_period: cap,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     Ok(())
 }
 
@@ -199,6 +360,8 @@ pub fn move_to_pending_attestation(ctx: Context<MoveToPendingAttestation>) -> Re
         claim.status = ClaimStatus::PendingAttestation;
     }
 
+    // Larger claims need more reviewers; fix the count for this claim
+    claim.required_attestations = config.required_attestations_for(claim.requested_amount);
     claim.status_changed_at = clock.unix_timestamp;
 
     Ok(())
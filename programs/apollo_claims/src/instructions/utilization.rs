@@ -0,0 +1,230 @@
+// programs/apollo_claims/src/instructions/utilization.rs
+
+use crate::instructions::ai_processing::FastLaneConfig;
+use crate::state::{
+    BenefitSchedule, CategoryBreakdown, CategoryStats, ClaimCategory, ClaimEligibility,
+    ClaimsConfig, IneligibilityReason, MemberBenefitAccumulator, MemberClaimRate,
+    MemberUtilization,
+};
+use anchor_lang::prelude::*;
+use apollo_core::BootstrapConfig;
+use apollo_membership::state::{MemberAccount, MemberStatus};
+
+/// Read a member's benefit utilization for the current plan year
+#[derive(Accounts)]
+pub struct GetMemberUtilization<'info> {
+    #[account(
+        seeds = [BenefitSchedule::SEED_PREFIX],
+        bump = benefit_schedule.bump,
+    )]
+    pub benefit_schedule: Account<'info, BenefitSchedule>,
+
+    /// Member's membership account (plan year and coverage tier)
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, member_account.member.as_ref()],
+        bump = member_account.bump,
+        seeds::program = apollo_membership::ID,
+    )]
+    pub member_account: Account<'info, MemberAccount>,
+
+    /// Absent until the member's first approved claim
+    #[account(
+        seeds = [MemberBenefitAccumulator::SEED_PREFIX, member_account.member.as_ref()],
+        bump = benefit_accumulator.bump,
+    )]
+    pub benefit_accumulator: Option<Account<'info, MemberBenefitAccumulator>>,
+}
+
+pub fn get_member_utilization(ctx: Context<GetMemberUtilization>) -> Result<MemberUtilization> {
+    let member = &ctx.accounts.member_account;
+    let accumulator = match ctx.accounts.benefit_accumulator.as_ref() {
+        Some(accumulator) => (**accumulator).clone(),
+        None => MemberBenefitAccumulator::empty(member.member, member.plan_year),
+    };
+
+    Ok(accumulator.summarize(
+        &ctx.accounts.benefit_schedule,
+        member.coverage_tier.is_family(),
+        member.plan_year,
+    ))
+}
+
+/// Prospective claim to check with `precheck_claim`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct ClaimPrecheckParams {
+    pub member: Pubkey,
+    pub category: ClaimCategory,
+    pub amount: u64,
+    pub service_date: i64,
+}
+
+/// Check whether a claim would be eligible before submitting it. Reads
+/// only; nothing is created or updated.
+#[derive(Accounts)]
+#[instruction(params: ClaimPrecheckParams)]
+pub struct PrecheckClaim<'info> {
+    #[account(
+        seeds = [ClaimsConfig::SEED_PREFIX],
+        bump = claims_config.bump,
+    )]
+    pub claims_config: Account<'info, ClaimsConfig>,
+
+    #[account(
+        seeds = [BenefitSchedule::SEED_PREFIX],
+        bump = benefit_schedule.bump,
+    )]
+    pub benefit_schedule: Account<'info, BenefitSchedule>,
+
+    /// Member's membership account (status, waiting period, coverage gaps)
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, params.member.as_ref()],
+        bump = member_account.bump,
+        seeds::program = apollo_membership::ID,
+    )]
+    pub member_account: Account<'info, MemberAccount>,
+
+    /// Absent until the member's first approved claim
+    #[account(
+        seeds = [MemberBenefitAccumulator::SEED_PREFIX, params.member.as_ref()],
+        bump = benefit_accumulator.bump,
+    )]
+    pub benefit_accumulator: Option<Account<'info, MemberBenefitAccumulator>>,
+
+    /// Absent until the member's first submission
+    #[account(
+        seeds = [MemberClaimRate::SEED_PREFIX, params.member.as_ref()],
+        bump = member_claim_rate.bump,
+    )]
+    pub member_claim_rate: Option<Account<'info, MemberClaimRate>>,
+
+    /// Absent if the fast lane has not been configured
+    #[account(
+        seeds = [FastLaneConfig::SEED_PREFIX],
+        bump = fast_lane_config.bump,
+    )]
+    pub fast_lane_config: Option<Account<'info, FastLaneConfig>>,
+
+    /// Core bootstrap config (bootstrap mode lowers the fast-lane limit)
+    #[account(
+        seeds = [BootstrapConfig::SEED_PREFIX],
+        bump = bootstrap_config.bump,
+        seeds::program = apollo_core::ID,
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+}
+
+/// Accounts a claim precheck is evaluated against
+pub struct ClaimPrecheck<'a> {
+    pub config: &'a ClaimsConfig,
+    pub schedule: &'a BenefitSchedule,
+    pub member_account: &'a MemberAccount,
+    pub accumulator: &'a MemberBenefitAccumulator,
+    pub claim_rate: Option<&'a MemberClaimRate>,
+    pub fast_lane: Option<&'a FastLaneConfig>,
+    pub bootstrap_active: bool,
+}
+
+impl ClaimPrecheck<'_> {
+    /// Run the submission and approval checks for a prospective claim.
+    /// Fast-lane eligibility does not count the member's monthly
+    /// fast-lane usage.
+    pub fn evaluate(&self, params: &ClaimPrecheckParams, current_time: i64) -> ClaimEligibility {
+        let member = self.member_account;
+        let mut reasons = Vec::new();
+
+        if params.service_date > current_time {
+            reasons.push(IneligibilityReason::FutureServiceDate);
+        }
+        if params.amount == 0 || self.config.is_below_minimum(params.amount) {
+            reasons.push(IneligibilityReason::BelowMinimum);
+        }
+        if member.status != MemberStatus::Active {
+            reasons.push(IneligibilityReason::CoverageNotActive);
+        }
+        if !member.waiting_period_complete(current_time)
+            || member.is_in_waiting_period(params.service_date)
+        {
+            reasons.push(IneligibilityReason::WaitingPeriod);
+        }
+        if !member.covers_service_date(params.service_date) {
+            reasons.push(IneligibilityReason::ServiceDateOutsideCoverage);
+        }
+        if self.claim_rate.is_some_and(|rate| {
+            rate.would_exceed_cap(current_time, self.config.max_claims_per_period)
+        }) {
+            reasons.push(IneligibilityReason::ClaimRateExceeded);
+        }
+
+        // Same plan-payment math as approval: coinsurance, then limits
+        let covered_amount = self.schedule.covered_amount(params.category, params.amount);
+        let estimated_plan_paid = match self.accumulator.limit_payable(
+            self.schedule,
+            member.coverage_tier.is_family(),
+            member.plan_year,
+            params.category,
+            covered_amount,
+            self.config.allow_partial_limit_payment,
+        ) {
+            Some(payable) => payable,
+            None => {
+                reasons.push(IneligibilityReason::ExceedsBenefitLimit);
+                0
+            }
+        };
+
+        let eligible = reasons.is_empty();
+        let fast_lane_eligible = eligible
+            && self.fast_lane.is_some_and(|fast_lane| {
+                fast_lane.is_eligible_category(&params.category)
+                    && params.amount
+                        <= fast_lane.effective_max_amount(params.category, self.bootstrap_active)
+            });
+
+        ClaimEligibility {
+            eligible,
+            reasons,
+            estimated_plan_paid,
+            estimated_member_cost: params.amount.saturating_sub(estimated_plan_paid),
+            fast_lane_eligible,
+        }
+    }
+}
+
+pub fn precheck_claim(
+    ctx: Context<PrecheckClaim>,
+    params: ClaimPrecheckParams,
+) -> Result<ClaimEligibility> {
+    let member = &ctx.accounts.member_account;
+    let accumulator = ctx
+        .accounts
+        .benefit_accumulator
+        .as_ref()
+        .map(|accumulator| (**accumulator).clone())
+        .unwrap_or_else(|| MemberBenefitAccumulator::empty(member.member, member.plan_year));
+
+    let precheck = ClaimPrecheck {
+        config: &ctx.accounts.claims_config,
+        schedule: &ctx.accounts.benefit_schedule,
+        member_account: member,
+        accumulator: &accumulator,
+        claim_rate: ctx.accounts.member_claim_rate.as_deref(),
+        fast_lane: ctx.accounts.fast_lane_config.as_deref(),
+        bootstrap_active: ctx.accounts.bootstrap_config.bootstrap_active,
+    };
+
+    Ok(precheck.evaluate(&params, Clock::get()?.unix_timestamp))
+}
+
+/// Read protocol-wide claim counters by category
+#[derive(Accounts)]
+pub struct GetCategoryStats<'info> {
+    #[account(
+        seeds = [CategoryStats::SEED_PREFIX],
+        bump = category_stats.bump,
+    )]
+    pub category_stats: Account<'info, CategoryStats>,
+}
+
+pub fn get_category_stats(ctx: Context<GetCategoryStats>) -> Result<Vec<CategoryBreakdown>> {
+    Ok(ctx.accounts.category_stats.breakdown())
+}
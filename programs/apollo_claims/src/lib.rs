@@ -55,6 +55,81 @@ pub mod apollo_claims {
         instructions::initialize::remove_attestor(ctx, attestor)
     }
 
+    /// Require more attestations for larger claims
+    pub fn set_attestation_tiers(
+        ctx: Context<SetAttestationTiers>,
+        tiers: Vec<state::AttestationTier>,
+    ) -> Result<()> {
+        instructions::initialize::set_attestation_tiers(ctx, tiers)
+    }
+
+    /// Pay over-limit claims up to the remaining limit, or deny them
+    pub fn set_limit_payment_policy(
+        ctx: Context<SetLimitPaymentPolicy>,
+        allow_partial_limit_payment: bool,
+    ) -> Result<()> {
+        instructions::initialize::set_limit_payment_policy(ctx, allow_partial_limit_payment)
+    }
+
+    /// Set how long paid claims stay open before anyone may close them
+    pub fn set_paid_claim_close_window(
+        ctx: Context<SetPaidClaimCloseWindow>,
+        paid_claim_close_window: i64,
+    ) -> Result<()> {
+        instructions::initialize::set_paid_claim_close_window(ctx, paid_claim_close_window)
+    }
+
+    /// Designate the specific stop-loss treaty approvals report to (no
+    /// treaty stops reporting)
+    pub fn set_stop_loss_treaty(ctx: Context<SetStopLossTreaty>) -> Result<()> {
+        instructions::initialize::set_stop_loss_treaty(ctx)
+    }
+
+    /// Set the minimum claim amount (None resets to the phase default,
+    /// 0 disables)
+    pub fn set_min_claim_amount(
+        ctx: Context<SetMinClaimAmount>,
+        min_claim_amount: Option<u64>,
+    ) -> Result<()> {
+        instructions::initialize::set_min_claim_amount(ctx, min_claim_amount)
+    }
+
+    // ==================== PROVIDER REGISTRY ====================
+
+    /// Register a provider
+    pub fn register_provider(ctx: Context<RegisterProvider>, provider: Pubkey) -> Result<()> {
+        instructions::provider::register_provider(ctx, provider)
+    }
+
+    /// Place a provider on the watchlist
+    pub fn flag_provider(ctx: Context<ManageProvider>) -> Result<()> {
+        instructions::provider::flag_provider(ctx)
+    }
+
+    /// Remove a provider from the watchlist
+    pub fn clear_provider(ctx: Context<ManageProvider>) -> Result<()> {
+        instructions::provider::clear_provider(ctx)
+    }
+
+    // ==================== CLAIM RATE LIMITS ====================
+
+    /// Set the maximum claims per member per period (0 disables the cap)
+    pub fn set_claim_rate_limit(
+        ctx: Context<SetClaimRateLimit>,
+        max_claims_per_period: u16,
+    ) -> Result<()> {
+        instructions::claim_rate::set_claim_rate_limit(ctx, max_claims_per_period)
+    }
+
+    /// Exempt a member from (or return them to) the monthly claim cap
+    pub fn set_claim_rate_whitelist(
+        ctx: Context<SetClaimRateWhitelist>,
+        member: Pubkey,
+        whitelisted: bool,
+    ) -> Result<()> {
+        instructions::claim_rate::set_claim_rate_whitelist(ctx, member, whitelisted)
+    }
+
     // ==================== CLAIM SUBMISSION ====================
 
     /// Submit a new claim
@@ -89,6 +164,24 @@ pub mod apollo_claims {
         instructions::ai_processing::initialize_ai_oracle(ctx, authorized_signers, required_sigs)
     }
 
+    /// Authorize an additional AI oracle signer (DAO)
+    pub fn add_oracle_signer(ctx: Context<ManageOracleSigners>, signer: Pubkey) -> Result<()> {
+        instructions::ai_processing::add_oracle_signer(ctx, signer)
+    }
+
+    /// Revoke an AI oracle signer, e.g. a compromised key (DAO)
+    pub fn remove_oracle_signer(ctx: Context<ManageOracleSigners>, signer: Pubkey) -> Result<()> {
+        instructions::ai_processing::remove_oracle_signer(ctx, signer)
+    }
+
+    /// Set how many oracle signers a decision requires (DAO)
+    pub fn set_oracle_required_sigs(
+        ctx: Context<ManageOracleSigners>,
+        required_sigs: u8,
+    ) -> Result<()> {
+        instructions::ai_processing::set_oracle_required_sigs(ctx, required_sigs)
+    }
+
     /// Submit AI decision for a claim (oracle signer only)
     pub fn submit_ai_decision(
         ctx: Context<SubmitAiDecision>,
@@ -97,6 +190,14 @@ pub mod apollo_claims {
         instructions::ai_processing::submit_ai_decision(ctx, params)
     }
 
+    /// Set fast-lane eligibility criteria (DAO only)
+    pub fn set_fast_lane_config(
+        ctx: Context<SetFastLaneConfig>,
+        params: SetFastLaneConfigParams,
+    ) -> Result<()> {
+        instructions::ai_processing::set_fast_lane_config(ctx, params)
+    }
+
     /// Process fast-lane auto-approval (small routine claims)
     pub fn process_fast_lane(
         ctx: Context<ProcessFastLane>,
@@ -114,6 +215,23 @@ pub mod apollo_claims {
         instructions::ai_processing::mark_decision_overturned(ctx, claim_id)
     }
 
+    /// Full AI scoring for a claim, for audit replay (view)
+    pub fn get_ai_decision(
+        ctx: Context<GetAiDecision>,
+        claim_id: u64,
+    ) -> Result<instructions::ai_processing::AiDecisionAudit> {
+        instructions::ai_processing::get_ai_decision(ctx, claim_id)
+    }
+
+    /// Committee reversal of an AI auto-decision (feeds oracle accuracy)
+    pub fn override_ai_decision(
+        ctx: Context<OverrideAiDecision>,
+        claim_id: u64,
+        outcome: AiOverride,
+    ) -> Result<()> {
+        instructions::ai_processing::override_ai_decision(ctx, claim_id, outcome)
+    }
+
     // ==================== ATTESTATION (Tier 3 Processing) ====================
     // Human review for large/complex claims
 
@@ -122,6 +240,16 @@ pub mod apollo_claims {
         instructions::attestation::attest_claim(ctx, params)
     }
 
+    /// Escalate a claim stuck past the attestation window to a DAO vote
+    pub fn escalate_stale_attestation(ctx: Context<EscalateStaleAttestation>) -> Result<()> {
+        instructions::attestation::escalate_stale_attestation(ctx)
+    }
+
+    /// Release a claim held for critical AI fraud flags
+    pub fn override_fraud_hold(ctx: Context<OverrideFraudHold>, note_hash: [u8; 32]) -> Result<()> {
+        instructions::attestation::override_fraud_hold(ctx, note_hash)
+    }
+
     // ==================== RESOLUTION ====================
 
     /// Approve a claim
@@ -130,7 +258,7 @@ pub mod apollo_claims {
     }
 
     /// Deny a claim
-    pub fn deny_claim(ctx: Context<DenyClaim>, reason: String) -> Result<()> {
+    pub fn deny_claim(ctx: Context<DenyClaim>, reason: state::DenialReason) -> Result<()> {
         instructions::resolution::deny_claim(ctx, reason)
     }
 
@@ -144,8 +272,43 @@ pub mod apollo_claims {
         instructions::resolution::close_claim(ctx)
     }
 
+    /// Close paid claims past the settlement window and refund their rent
+    /// (permissionless)
+    pub fn auto_close_paid_claims<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AutoClosePaidClaims<'info>>,
+    ) -> Result<()> {
+        instructions::resolution::auto_close_paid_claims(ctx)
+    }
+
     /// Appeal a denied claim
     pub fn appeal_claim(ctx: Context<AppealClaim>) -> Result<()> {
         instructions::resolution::appeal_claim(ctx)
     }
+
+    // ==================== MEMBER UTILIZATION ====================
+
+    /// Member's deductible, OOP, annual and category utilization (view)
+    pub fn get_member_utilization(
+        ctx: Context<GetMemberUtilization>,
+    ) -> Result<state::MemberUtilization> {
+        instructions::utilization::get_member_utilization(ctx)
+    }
+
+    /// Whether a prospective claim would be eligible, with estimated
+    /// cost share and fast-lane eligibility (view)
+    pub fn precheck_claim(
+        ctx: Context<PrecheckClaim>,
+        params: ClaimPrecheckParams,
+    ) -> Result<state::ClaimEligibility> {
+        instructions::utilization::precheck_claim(ctx, params)
+    }
+
+    // ==================== CATEGORY ANALYTICS ====================
+
+    /// Submitted, approved and paid counters for every claim category (view)
+    pub fn get_category_stats(
+        ctx: Context<GetCategoryStats>,
+    ) -> Result<Vec<state::CategoryBreakdown>> {
+        instructions::utilization::get_category_stats(ctx)
+    }
 }
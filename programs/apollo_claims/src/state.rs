@@ -1,6 +1,8 @@
 // programs/apollo_claims/src/state.rs
 
+use crate::errors::ClaimsError;
 use anchor_lang::prelude::*;
+use apollo_core::phase::ProtocolPhase;
 
 // =============================================================================
 // AI ORACLE CONFIGURATION
@@ -50,55 +52,10 @@ impl AiOracleConfig {
     pub const DEFAULT_ESCALATION_THRESHOLD: u8 = 70;
 }
 
-/// AI decision for a specific claim
-/// PDA seeds: ["ai_decision", claim_id]
-#[account]
-#[derive(InitSpace)]
-pub struct AiDecision {
-    /// Claim this decision applies to
-    pub claim_id: u64,
-
-    /// Risk score (0-100, higher = riskier)
-    pub risk_score: u8,
-
-    /// Fraud indicators detected
-    pub fraud_flags: FraudFlags,
-
-    /// Price reasonableness score (0-100, higher = more reasonable)
-    pub price_score: u8,
-
-    /// Recommended action
-    pub recommendation: AiRecommendation,
-
-    /// Recommended amount (may differ from requested)
-    pub recommended_amount: u64,
-
-    /// Confidence level (0-100)
-    pub confidence: u8,
-
-    /// Reasoning hash (IPFS link to detailed explanation)
-    #[max_len(64)]
-    pub reasoning_hash: String,
-
-    /// Timestamp of decision
-    pub decided_at: i64,
-
-    /// Oracle that submitted this decision
-    pub oracle: Pubkey,
-
-    /// Was this decision overridden by committee
-    pub was_overridden: bool,
-
-    /// Bump seed
-    pub bump: u8,
-}
-
-impl AiDecision {
-    pub const SEED_PREFIX: &'static [u8] = b"ai_decision";
-}
-
 /// Fraud indicators detected by AI
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
 pub struct FraudFlags {
     /// Duplicate claim detected
     pub duplicate_claim: bool,
@@ -153,6 +110,30 @@ impl FraudFlags {
     pub fn has_critical(&self) -> bool {
         self.duplicate_claim || self.provider_flagged || self.document_issues
     }
+
+    /// Only the critical indicators (see `has_critical`)
+    pub fn critical(&self) -> FraudFlags {
+        FraudFlags {
+            duplicate_claim: self.duplicate_claim,
+            provider_flagged: self.provider_flagged,
+            document_issues: self.document_issues,
+            ..Default::default()
+        }
+    }
+
+    /// Raise the indicator an AI flag corresponds to, if any
+    pub fn raise(&mut self, flag: AiFlag) {
+        match flag {
+            AiFlag::PossibleDuplicate => self.duplicate_claim = true,
+            AiFlag::HighPrice | AiFlag::LowPrice => self.price_anomaly = true,
+            AiFlag::HighFrequency => self.frequency_anomaly = true,
+            AiFlag::IncompleteDoc => self.document_issues = true,
+            AiFlag::ProviderRisk => self.provider_flagged = true,
+            AiFlag::DiagnosisMismatch => self.service_mismatch = true,
+            AiFlag::TimingAnomaly => self.timing_anomaly = true,
+            _ => {}
+        }
+    }
 }
 
 /// AI recommendation for claim processing
@@ -273,6 +254,9 @@ pub struct ClaimsConfig {
     /// Total claims denied
     pub total_claims_denied: u64,
 
+    /// Denials tallied by `DenialCode` (indexed by discriminant)
+    pub denials_by_reason: [u64; DenialCode::COUNT],
+
     /// Total USDC paid out
     pub total_paid_out: u64,
 
@@ -285,12 +269,32 @@ pub struct ClaimsConfig {
     /// Required attestations for approval
     pub required_attestations: u8,
 
+    /// Higher attestation counts for larger claims (ascending thresholds)
+    #[max_len(5)]
+    pub attestation_tiers: Vec<AttestationTier>,
+
     /// Maximum attestation time (seconds)
     pub max_attestation_time: i64,
 
     /// Is claims processing active
     pub is_active: bool,
 
+    /// Maximum claims a member may submit per period (0 = no cap)
+    pub max_claims_per_period: u16,
+
+    /// Pay claims over a benefit limit up to the remaining limit instead
+    /// of denying them
+    pub allow_partial_limit_payment: bool,
+
+    /// Seconds after payment before a paid claim may be closed by anyone
+    pub paid_claim_close_window: i64,
+
+    /// Smallest claim accepted for processing (USDC, 0 = no minimum)
+    pub min_claim_amount: u64,
+
+    /// Specific stop-loss treaty every approval reports to (default = none)
+    pub stop_loss_treaty: Pubkey,
+
     /// Bump seed
     pub bump: u8,
 }
@@ -298,6 +302,58 @@ pub struct ClaimsConfig {
 impl ClaimsConfig {
     pub const SEED_PREFIX: &'static [u8] = b"claims_config";
 
+    /// Claims of any size per member per period before submissions are
+    /// refused (the claim reaching the cap is flagged HighFrequency)
+    pub const DEFAULT_MAX_CLAIMS_PER_PERIOD: u16 = 10;
+
+    /// Settlement window before paid claims can be auto-closed (90 days)
+    pub const DEFAULT_PAID_CLAIM_CLOSE_WINDOW: i64 = 90 * 24 * 60 * 60;
+
+    /// ID the next submitted claim must use. IDs follow the submission
+    /// counter so a closed claim's ID (and its receipt and AI decision
+    /// PDAs) can never be reused.
+    pub fn next_claim_id(&self) -> u64 {
+        self.total_claims_submitted.saturating_add(1)
+    }
+
+    /// Whether `amount` is too small to be worth processing
+    pub fn is_below_minimum(&self, amount: u64) -> bool {
+        amount < self.min_claim_amount
+    }
+
+    /// Validate a governance-set minimum against the phase's claim ceiling
+    pub fn validate_min_claim_amount(min_claim_amount: u64, phase: ProtocolPhase) -> Result<()> {
+        require!(
+            min_claim_amount < phase.max_individual_claim(),
+            ClaimsError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// Count an approval in the total and its category
+    pub fn record_approval(&mut self, stats: &mut CategoryStats, category: ClaimCategory) {
+        self.total_claims_approved = self.total_claims_approved.saturating_add(1);
+        stats.record_approval(category);
+    }
+
+    /// Drop an approval from the total and its category when the claim is
+    /// denied after all
+    pub fn reverse_approval(&mut self, stats: &mut CategoryStats, category: ClaimCategory) {
+        self.total_claims_approved = self.total_claims_approved.saturating_sub(1);
+        stats.reverse_approval(category);
+    }
+
+    /// Count a denial in the total and its per-reason tally
+    pub fn record_denial(&mut self, code: DenialCode) {
+        self.total_claims_denied = self.total_claims_denied.saturating_add(1);
+        let slot = &mut self.denials_by_reason[code as usize];
+        *slot = slot.saturating_add(1);
+    }
+
+    pub fn denials_for(&self, code: DenialCode) -> u64 {
+        self.denials_by_reason[code as usize]
+    }
+
     // =========================================================================
     // AUTO-APPROVE THRESHOLDS (Fast-Lane)
     // =========================================================================
@@ -345,23 +401,69 @@ impl ClaimsConfig {
 
     pub const DEFAULT_REQUIRED_ATTESTATIONS: u8 = 2;
     pub const DEFAULT_MAX_ATTESTATION_TIME: i64 = 48 * 60 * 60; // 48 hours
+    pub const MAX_ATTESTATION_TIERS: usize = 5;
+
+    /// Attestations needed for a claim of `amount`: the highest tier whose
+    /// threshold the amount reaches, else the base requirement
+    pub fn required_attestations_for(&self, amount: u64) -> u8 {
+        self.attestation_tiers
+            .iter()
+            .rev()
+            .find(|tier| amount >= tier.amount_threshold)
+            .map_or(self.required_attestations, |tier| tier.required_count)
+    }
+
+    /// Requirement for a claim, preferring the count fixed when it entered
+    /// attestation (claims moved before tiers existed have 0 recorded)
+    pub fn attestations_required(&self, claim: &ClaimAccount) -> u8 {
+        if claim.required_attestations > 0 {
+            claim.required_attestations
+        } else {
+            self.required_attestations_for(claim.requested_amount)
+        }
+    }
+
+    /// Tiers must ascend by threshold, never lower the count, and be
+    /// satisfiable by the current attestor registry
+    pub fn validate_attestation_tiers(
+        tiers: &[AttestationTier],
+        base_required: u8,
+        attestor_count: u8,
+    ) -> bool {
+        if tiers.len() > Self::MAX_ATTESTATION_TIERS {
+            return false;
+        }
+        let mut prev_threshold = None;
+        let mut prev_count = base_required;
+        for tier in tiers {
+            if prev_threshold.is_some_and(|t| tier.amount_threshold <= t)
+                || tier.required_count < prev_count
+                || tier.required_count > attestor_count
+            {
+                return false;
+            }
+            prev_threshold = Some(tier.amount_threshold);
+            prev_count = tier.required_count;
+        }
+        true
+    }
 
     // =========================================================================
     // HELPER FUNCTIONS
     // =========================================================================
 
-    /// Get auto-approve threshold based on member count
-    pub fn get_auto_approve_threshold(member_count: u32) -> u64 {
-        if member_count < 1000 {
+    /// Get auto-approve threshold for the protocol's bootstrap mode
+    pub fn get_auto_approve_threshold(bootstrap_active: bool) -> u64 {
+        if bootstrap_active {
             Self::BOOTSTRAP_AUTO_APPROVE
         } else {
             Self::DEFAULT_AUTO_APPROVE
         }
     }
 
-    /// Get shock threshold based on member count (simple method)
-    pub fn get_shock_threshold(member_count: u32) -> u64 {
-        if member_count < 1000 {
+    /// Get shock threshold for the protocol's bootstrap mode (simple method)
+    pub fn get_shock_threshold(bootstrap_active: bool) -> u64 {
+        if bootstrap_active {
             Self::BOOTSTRAP_SHOCK_THRESHOLD
         } else {
             Self::DEFAULT_SHOCK_THRESHOLD
@@ -393,13 +495,22 @@ impl ClaimsConfig {
     }
 
     /// Determine the most appropriate shock threshold given all available data
-    /// Prefers reserve-based calculation when reserves are known
-    pub fn get_effective_shock_threshold(member_count: u32, total_reserves: Option<u64>) -> u64 {
-        match total_reserves {
+    /// Prefers reserve-based calculation when reserves are known; bootstrap
+    /// mode never lets it exceed the bootstrap threshold
+    pub fn get_effective_shock_threshold(
+        bootstrap_active: bool,
+        total_reserves: Option<u64>,
+    ) -> u64 {
+        let threshold = match total_reserves {
             Some(reserves) if reserves > 0 => {
                 Self::calculate_shock_threshold_from_reserves(reserves)
             }
-            _ => Self::get_shock_threshold(member_count),
+            _ => Self::get_shock_threshold(bootstrap_active),
+        };
+        if bootstrap_active {
+            threshold.min(Self::BOOTSTRAP_SHOCK_THRESHOLD)
+        } else {
+            threshold
         }
     }
 }
@@ -456,6 +567,127 @@ pub struct BenefitSchedule {
 
 impl BenefitSchedule {
     pub const SEED_PREFIX: &'static [u8] = b"benefit_schedule";
+
+    /// Annual maximum for the member's coverage tier
+    /// (`is_family` mirrors apollo_membership `CoverageTier::is_family`)
+    pub fn annual_max(&self, is_family: bool) -> u64 {
+        if is_family {
+            self.family_annual_max
+        } else {
+            self.individual_annual_max
+        }
+    }
+
+    /// Deductible for the member's coverage tier
+    pub fn deductible(&self, is_family: bool) -> u64 {
+        if is_family {
+            self.family_deductible
+        } else {
+            self.individual_deductible
+        }
+    }
+
+    /// Out-of-pocket maximum for the member's coverage tier
+    pub fn oop_max(&self, is_family: bool) -> u64 {
+        if is_family {
+            self.oop_max_family
+        } else {
+            self.oop_max_individual
+        }
+    }
+
+    /// End of the pre-existing condition waiting period for a member
+    pub fn preexisting_wait_ends(&self, enrolled_at: i64) -> i64 {
+        enrolled_at.saturating_add(self.preexisting_waiting_days as i64 * 24 * 60 * 60)
+    }
+
+    /// Pre-existing condition services before the wait elapses are excluded
+    pub fn is_preexisting_excluded(&self, enrolled_at: i64, service_date: i64) -> bool {
+        service_date < self.preexisting_wait_ends(enrolled_at)
+    }
+
+    /// Default coinsurance and every category override must be a valid
+    /// share (at most 100%) at or above the phase's coverage floor.
+    /// An override of 0 means "use the default" and is not checked.
+    pub fn validate_coinsurance(
+        coinsurance_bps: u16,
+        category_limits: &[CategoryLimit],
+        phase: ProtocolPhase,
+    ) -> Result<()> {
+        let floor = phase.min_coinsurance_bps();
+        let overrides = category_limits
+            .iter()
+            .map(|l| l.coinsurance_override_bps)
+            .filter(|bps| *bps > 0);
+
+        for bps in std::iter::once(coinsurance_bps).chain(overrides) {
+            require!(bps <= 10000, ClaimsError::InvalidBenefitSchedule);
+            require!(bps >= floor, ClaimsError::CoinsuranceBelowFloor);
+        }
+        Ok(())
+    }
+
+    /// Coinsurance for a category: the category override when set,
+    /// otherwise the schedule default
+    pub fn coinsurance_bps_for(&self, category: ClaimCategory) -> u16 {
+        self.category_limits
+            .iter()
+            .find(|l| l.category == category && l.coinsurance_override_bps > 0)
+            .map(|l| l.coinsurance_override_bps)
+            .unwrap_or(self.coinsurance_bps)
+    }
+
+    /// Plan-paid portion of an allowed amount after coinsurance
+    pub fn covered_amount(&self, category: ClaimCategory, allowed_amount: u64) -> u64 {
+        (allowed_amount as u128 * self.coinsurance_bps_for(category) as u128 / 10000) as u64
+    }
+
+    /// Every category carryover must be at most 100% of the unused limit
+    pub fn validate_carryover(category_limits: &[CategoryLimit]) -> Result<()> {
+        require!(
+            category_limits.iter().all(|l| l.carryover_bps <= 10000),
+            ClaimsError::InvalidBenefitSchedule
+        );
+        Ok(())
+    }
+
+    /// Limit carried into the year after one where `paid` was spent in
+    /// `category`. Only the base annual limit is carried; carryover
+    /// received in a year does not itself roll forward again.
+    pub fn carryover_for(&self, category: ClaimCategory, paid: u64) -> u64 {
+        self.category_limits
+            .iter()
+            .find(|l| l.category == category && l.annual_limit > 0)
+            .map(|l| {
+                let unused = l.annual_limit.saturating_sub(paid);
+                (unused as u128 * l.carryover_bps as u128 / 10000) as u64
+            })
+            .unwrap_or(0)
+    }
+
+    /// Per-incident cap for a category: the tighter of the schedule-wide
+    /// and category per-visit limits (0 = uncapped)
+    pub fn per_incident_cap(&self, category: ClaimCategory) -> u64 {
+        let per_visit = self
+            .category_limits
+            .iter()
+            .find(|l| l.category == category)
+            .map(|l| l.per_visit_limit)
+            .unwrap_or(0);
+        match (self.per_incident_max, per_visit) {
+            (0, cap) | (cap, 0) => cap,
+            (a, b) => a.min(b),
+        }
+    }
+}
+
+/// Attestation requirement for claims at or above a requested amount
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct AttestationTier {
+    /// Minimum requested amount (USDC) this tier applies to
+    pub amount_threshold: u64,
+    /// Attestations required at this tier
+    pub required_count: u8,
 }
 
 /// Category-specific benefit limit
@@ -469,6 +701,9 @@ pub struct CategoryLimit {
     pub per_visit_limit: u64,
     /// Coinsurance override (0 = use default)
     pub coinsurance_override_bps: u16,
+    /// Share of the unused annual limit that rolls into the next plan
+    /// year (0 = no carryover)
+    pub carryover_bps: u16,
 }
 
 /// Claim categories
@@ -491,6 +726,35 @@ pub enum ClaimCategory {
     Other,
 }
 
+impl ClaimCategory {
+    /// Number of claim categories
+    pub const COUNT: usize = 15;
+
+    /// Every category, in index order
+    pub const ALL: [ClaimCategory; ClaimCategory::COUNT] = [
+        ClaimCategory::Emergency,
+        ClaimCategory::Hospitalization,
+        ClaimCategory::Surgery,
+        ClaimCategory::OutpatientCare,
+        ClaimCategory::PrimaryCare,
+        ClaimCategory::SpecialistVisit,
+        ClaimCategory::DiagnosticImaging,
+        ClaimCategory::Laboratory,
+        ClaimCategory::Prescription,
+        ClaimCategory::MentalHealth,
+        ClaimCategory::Maternity,
+        ClaimCategory::Preventive,
+        ClaimCategory::Rehabilitation,
+        ClaimCategory::DurableMedicalEquipment,
+        ClaimCategory::Other,
+    ];
+
+    /// Position of the category in per-category arrays
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
 /// Individual claim account
 /// PDA seeds: ["claim", claim_id]
 #[account]
@@ -536,19 +800,91 @@ pub struct ClaimAccount {
     /// Number of attestations received
     pub attestation_count: u8,
 
-    /// Denial reason (if denied)
+    /// Attestations required, fixed when the claim enters attestation
+    pub required_attestations: u8,
+
+    /// Legacy free-text denial reason. Committee denials now record
+    /// `denial_code` + `denial_note_hash`; this remains for claims denied
+    /// before coded reasons and for oracle-generated explanations.
     #[max_len(128)]
     pub denial_reason: String,
 
     /// Is this a shock claim (exceeds threshold)
     pub is_shock_claim: bool,
 
+    /// Service date falls inside the member's waiting period
+    pub in_waiting_period: bool,
+
+    /// Member declared this claim as a pre-existing condition
+    pub is_preexisting: bool,
+
+    /// Pre-existing condition claim inside the pre-existing waiting period
+    pub in_preexisting_wait: bool,
+
+    /// Coded reason for denial (if denied)
+    pub denial_code: DenialCode,
+
+    /// Hash of the denial note (off-chain), zero if none
+    pub denial_note_hash: [u8; 32],
+
+    /// Payment has been recorded (idempotency guard for `pay_claim`)
+    pub already_paid: bool,
+
+    /// Incremented each time a payment is recorded; stamped on the receipt
+    pub payment_nonce: u64,
+
+    /// An AI decision has been recorded; `pay_claim` must then check its flags
+    pub has_ai_decision: bool,
+
+    /// Service date is close to enrollment or reinstatement
+    pub timing_anomaly: bool,
+
+    /// Submission reached the member's monthly claim cap
+    pub high_frequency: bool,
+
+    /// Approval pushed the member's plan-paid YTD past the specific
+    /// stop-loss attachment and was reported to reinsurance
+    pub reinsurance_recoverable: bool,
+
     /// Bump seed
     pub bump: u8,
 }
 
 impl ClaimAccount {
     pub const SEED_PREFIX: &'static [u8] = b"claim";
+
+    /// Record payment of the remaining approved amount. Returns the amount
+    /// newly paid, or None if payment was already recorded.
+    pub fn record_payment(&mut self) -> Option<u64> {
+        if self.already_paid || self.paid_amount >= self.approved_amount {
+            return None;
+        }
+        let amount = self.approved_amount - self.paid_amount;
+        self.paid_amount = self.approved_amount;
+        self.already_paid = true;
+        self.payment_nonce = self.payment_nonce.saturating_add(1);
+        Some(amount)
+    }
+
+    /// Fully paid and untouched for the settlement window. Appealed or
+    /// otherwise reopened claims are never in `Paid` and stay open.
+    pub fn is_auto_closable(&self, current_time: i64, close_window: i64) -> bool {
+        self.status == ClaimStatus::Paid
+            && self.already_paid
+            && current_time.saturating_sub(self.status_changed_at) >= close_window
+    }
+
+    /// Attestation window has run out without enough attestations
+    pub fn is_attestation_stale(
+        &self,
+        current_time: i64,
+        max_attestation_time: i64,
+        required: u8,
+    ) -> bool {
+        self.status == ClaimStatus::PendingAttestation
+            && self.attestation_count < required
+            && current_time.saturating_sub(self.status_changed_at) > max_attestation_time
+    }
 }
 
 /// Claim status state machine
@@ -584,6 +920,136 @@ impl Default for ClaimStatus {
     }
 }
 
+/// Payment receipt - one per paid claim, checked by other programs before
+/// recording a payment into their own accumulators
+/// PDA seeds: ["payment_receipt", claim_id]
+#[account]
+#[derive(InitSpace)]
+pub struct PaymentReceipt {
+    /// Claim ID
+    pub claim_id: u64,
+
+    /// Claim account
+    pub claim: Pubkey,
+
+    /// Member the claim belongs to
+    pub member: Pubkey,
+
+    /// Amount paid (USDC)
+    pub amount: u64,
+
+    /// Recipient token account
+    pub recipient: Pubkey,
+
+    /// Claim's payment nonce at the time of payment
+    pub payment_nonce: u64,
+
+    /// Payment timestamp
+    pub paid_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl PaymentReceipt {
+    pub const SEED_PREFIX: &'static [u8] = b"payment_receipt";
+}
+
+/// Committee override releasing a claim held for critical AI fraud flags
+/// PDA seeds: ["fraud_override", claim_id]
+#[account]
+#[derive(InitSpace)]
+pub struct FraudOverride {
+    /// Claim ID
+    pub claim_id: u64,
+
+    /// Committee member or authority who approved the override
+    pub approved_by: Pubkey,
+
+    /// Hash of the override justification (off-chain)
+    pub note_hash: [u8; 32],
+
+    /// Override timestamp
+    pub overridden_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl FraudOverride {
+    pub const SEED_PREFIX: &'static [u8] = b"fraud_override";
+}
+
+/// Coded denial reason stored on the claim.
+/// New variants are appended so existing accounts keep their discriminants.
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace, Default,
+)]
+pub enum DenialCode {
+    /// Not denied
+    #[default]
+    None,
+    /// Service during the new-member waiting period
+    WaitingPeriod,
+    /// Pre-existing condition inside the pre-existing waiting period
+    PreexistingCondition,
+    /// Any other reason (see `denial_note_hash` / `denial_reason`)
+    Other,
+    /// Service not covered by the benefit schedule
+    NotCovered,
+    /// Exceeds a benefit or category limit
+    ExceedsLimit,
+    /// Duplicate of an earlier claim
+    Duplicate,
+    /// Fraud or abuse
+    Fraud,
+    /// Missing or incomplete documentation
+    IncompleteDoc,
+    /// Provider outside the network
+    OutOfNetwork,
+}
+
+impl DenialCode {
+    pub const COUNT: usize = 10;
+}
+
+/// Denial reason supplied by the committee when denying a claim
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DenialReason {
+    NotCovered,
+    ExceedsLimit,
+    Duplicate,
+    Fraud,
+    WaitingPeriod,
+    PreexistingCondition,
+    IncompleteDoc,
+    OutOfNetwork,
+    Other { note_hash: [u8; 32] },
+}
+
+impl DenialReason {
+    pub fn code(&self) -> DenialCode {
+        match self {
+            DenialReason::NotCovered => DenialCode::NotCovered,
+            DenialReason::ExceedsLimit => DenialCode::ExceedsLimit,
+            DenialReason::Duplicate => DenialCode::Duplicate,
+            DenialReason::Fraud => DenialCode::Fraud,
+            DenialReason::WaitingPeriod => DenialCode::WaitingPeriod,
+            DenialReason::PreexistingCondition => DenialCode::PreexistingCondition,
+            DenialReason::IncompleteDoc => DenialCode::IncompleteDoc,
+            DenialReason::OutOfNetwork => DenialCode::OutOfNetwork,
+            DenialReason::Other { .. } => DenialCode::Other,
+        }
+    }
+
+    pub fn note_hash(&self) -> [u8; 32] {
+        match self {
+            DenialReason::Other { note_hash } => *note_hash,
+            _ => [0u8; 32],
+        }
+    }
+}
+
 /// Attestor registry - tracks authorized claim reviewers
 /// PDA seeds: ["attestor_registry"]
 #[account]
@@ -660,136 +1126,634 @@ pub enum AttestationRecommendation {
 }
 
 // =============================================================================
-// AI/ML CLAIMS ORACLE INFRASTRUCTURE
-// Supports off-chain AI processing with on-chain decision recording
+// PROVIDER REGISTRY
 // =============================================================================
 
-/// Claims Oracle configuration
-/// PDA seeds: ["claims_oracle"]
+/// Provider record - claim history and watchlist status per provider
+/// PDA seeds: ["provider", provider]
 #[account]
 #[derive(InitSpace)]
-pub struct ClaimsOracle {
-    /// Authority for oracle management
-    pub authority: Pubkey,
-
-    /// Authorized oracle signers (AI service endpoints)
-    #[max_len(5)]
-    pub authorized_signers: Vec<Pubkey>,
+pub struct ProviderRecord {
+    /// Provider pubkey
+    pub provider: Pubkey,
 
-    /// Required signatures for AI decision to be valid
-    pub required_sigs: u8,
-
-    /// Total decisions processed
-    pub total_decisions: u64,
-
-    /// Decisions auto-approved by AI
-    pub auto_approved: u64,
+    /// Claims submitted naming this provider
+    pub claims_submitted: u64,
 
-    /// Decisions auto-denied by AI
-    pub auto_denied: u64,
-
-    /// Decisions escalated to committee
-    pub escalated: u64,
-
-    /// Decisions overturned by committee (measures AI accuracy)
-    pub overturned: u64,
+    /// Claims approved
+    pub claims_approved: u64,
 
-    /// Oracle accuracy rate (bps) - (total - overturned) / total
-    pub accuracy_rate_bps: u16,
+    /// Claims denied
+    pub claims_denied: u64,
 
-    /// Is oracle active
-    pub is_active: bool,
+    /// Denial rate over resolved claims (bps)
+    pub denial_rate_bps: u16,
 
-    /// Minimum confidence for auto-approve (bps, e.g., 9500 = 95%)
-    pub auto_approve_confidence_bps: u16,
+    /// Provider is on the watchlist (AI decisions escalate to committee)
+    pub watchlisted: bool,
 
-    /// Maximum fraud score for auto-approve (bps, e.g., 3000 = 30%)
-    pub max_fraud_score_bps: u16,
+    /// When the provider was watchlisted (0 if not)
+    pub watchlisted_at: i64,
 
-    /// Minimum confidence for auto-deny (bps)
-    pub auto_deny_confidence_bps: u16,
+    /// Registration timestamp
+    pub registered_at: i64,
 
     /// Bump seed
     pub bump: u8,
 }
 
-impl ClaimsOracle {
-    pub const SEED_PREFIX: &'static [u8] = b"claims_oracle";
+impl ProviderRecord {
+    pub const SEED_PREFIX: &'static [u8] = b"provider";
 
-    // Default confidence thresholds
-    pub const DEFAULT_AUTO_APPROVE_CONFIDENCE: u16 = 9500; // 95%
-    pub const DEFAULT_MAX_FRAUD_SCORE: u16 = 3000; // 30%
-    pub const DEFAULT_AUTO_DENY_CONFIDENCE: u16 = 9500; // 95% confident it's fraud
+    /// Denial rate at which a provider is auto-watchlisted (30%)
+    pub const WATCHLIST_DENIAL_RATE_BPS: u16 = 3000;
+    /// Minimum resolved claims before the denial rate is acted on
+    pub const WATCHLIST_MIN_RESOLVED: u64 = 10;
 
-    /// Check if a signer is authorized
-    pub fn is_authorized(&self, signer: &Pubkey) -> bool {
-        self.authorized_signers.contains(signer)
+    pub fn resolved_claims(&self) -> u64 {
+        self.claims_approved.saturating_add(self.claims_denied)
     }
 
-    /// Update accuracy rate
-    pub fn update_accuracy(&mut self) {
-        if self.total_decisions == 0 {
-            self.accuracy_rate_bps = 0;
-            return;
+    pub fn record_submission(&mut self) {
+        self.claims_submitted = self.claims_submitted.saturating_add(1);
+    }
+
+    /// Record an approval; returns true if the provider was newly watchlisted
+    pub fn record_approval(&mut self, current_time: i64) -> bool {
+        self.claims_approved = self.claims_approved.saturating_add(1);
+        self.refresh_denial_rate(current_time)
+    }
+
+    /// Record a denial; returns true if the provider was newly watchlisted
+    pub fn record_denial(&mut self, current_time: i64) -> bool {
+        self.claims_denied = self.claims_denied.saturating_add(1);
+        self.refresh_denial_rate(current_time)
+    }
+
+    /// Recompute the denial rate and auto-watchlist once it crosses the
+    /// threshold with enough history
+    fn refresh_denial_rate(&mut self, current_time: i64) -> bool {
+        let resolved = self.resolved_claims();
+        self.denial_rate_bps = (self.claims_denied as u128 * 10000)
+            .checked_div(resolved as u128)
+            .unwrap_or(0) as u16;
+
+        if !self.watchlisted
+            && resolved >= Self::WATCHLIST_MIN_RESOLVED
+            && self.denial_rate_bps >= Self::WATCHLIST_DENIAL_RATE_BPS
+        {
+            self.watchlist(current_time);
+            return true;
         }
+        false
+    }
 
-        let accurate = self.total_decisions.saturating_sub(self.overturned);
-        self.accuracy_rate_bps = (accurate
-            .saturating_mul(10000)
-            .checked_div(self.total_decisions)
-            .unwrap_or(0) as u16)
-            .min(10000);
+    pub fn watchlist(&mut self, current_time: i64) {
+        self.watchlisted = true;
+        self.watchlisted_at = current_time;
+    }
+
+    /// Take the provider off the watchlist with a fresh denial history, so
+    /// the next denial does not immediately re-watchlist it
+    pub fn clear(&mut self) {
+        self.watchlisted = false;
+        self.watchlisted_at = 0;
+        self.claims_approved = 0;
+        self.claims_denied = 0;
+        self.denial_rate_bps = 0;
     }
 }
 
-/// AI decision record for a specific claim
-/// PDA seeds: ["ai_decision", claim_id]
+// =============================================================================
+// MEMBER CLAIM RATE
+// Caps claim submissions of any size per member per period
+// =============================================================================
+
+/// Per-member submission counter for the monthly claim cap
+/// PDA seeds: ["member_claim_rate", member]
 #[account]
 #[derive(InitSpace)]
-pub struct AiDecisionRecord {
-    /// Claim ID this decision applies to
-    pub claim_id: u64,
-
-    /// Decision type
-    pub decision: AiDecisionType,
+pub struct MemberClaimRate {
+    /// Member this tracker belongs to
+    pub member: Pubkey,
 
-    /// Overall confidence score (bps)
-    pub confidence_bps: u16,
+    /// Start of the current period
+    pub period_start: i64,
 
-    /// Price reasonableness score (bps, higher = more reasonable)
-    pub price_score_bps: u16,
+    /// Claims submitted in the current period
+    pub claims_this_period: u16,
 
-    /// Fraud detection score (bps, higher = more suspicious)
-    pub fraud_score_bps: u16,
+    /// Total claims submitted all-time
+    pub total_claims: u64,
 
-    /// Consistency score (diagnosis-procedure match)
-    pub consistency_score_bps: u16,
+    /// Exempt from the cap (chronic-care members with legitimate volume)
+    pub whitelisted: bool,
 
-    /// Flags raised during analysis
-    #[max_len(5)]
-    pub flags: Vec<AiFlag>,
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl MemberClaimRate {
+    pub const SEED_PREFIX: &'static [u8] = b"member_claim_rate";
+
+    /// Period length (30 days in seconds)
+    pub const PERIOD_LENGTH: i64 = 30 * 24 * 60 * 60;
+
+    fn is_new_period(&self, current_time: i64) -> bool {
+        current_time - self.period_start >= Self::PERIOD_LENGTH
+    }
+
+    fn is_capped(&self, cap: u16) -> bool {
+        cap > 0 && !self.whitelisted
+    }
+
+    /// Whether another submission now would go over the cap
+    pub fn would_exceed_cap(&self, current_time: i64, cap: u16) -> bool {
+        self.is_capped(cap) && !self.is_new_period(current_time) && self.claims_this_period >= cap
+    }
+
+    /// Count a submission; returns true if it reaches the cap and should be
+    /// flagged as high frequency
+    pub fn record_submission(&mut self, current_time: i64, cap: u16) -> bool {
+        if self.is_new_period(current_time) {
+            self.period_start = current_time;
+            self.claims_this_period = 0;
+        }
+
+        self.claims_this_period = self.claims_this_period.saturating_add(1);
+        self.total_claims = self.total_claims.saturating_add(1);
+
+        self.is_capped(cap) && self.claims_this_period >= cap
+    }
+}
+
+/// Per-member benefit utilization for the current plan year
+/// PDA seeds: ["benefit_accumulator", member]
+#[account]
+#[derive(InitSpace)]
+pub struct MemberBenefitAccumulator {
+    /// Member this accumulator belongs to
+    pub member: Pubkey,
+
+    /// Membership plan year the totals below belong to
+    pub plan_year: u16,
+
+    /// Member cost share applied toward the deductible
+    pub deductible_met: u64,
+
+    /// Member out-of-pocket spend (deductible and coinsurance)
+    pub oop_met: u64,
+
+    /// Plan-paid benefits across all categories
+    pub annual_paid: u64,
+
+    /// Plan-paid benefits by category (indexed by `ClaimCategory::index`)
+    pub category_paid: [u64; ClaimCategory::COUNT],
+
+    /// Unused prior-year limit added to each category's annual limit this
+    /// plan year (indexed by `ClaimCategory::index`)
+    pub category_carryover: [u64; ClaimCategory::COUNT],
+
+    /// Claims approved this plan year
+    pub claims_approved: u32,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl MemberBenefitAccumulator {
+    pub const SEED_PREFIX: &'static [u8] = b"benefit_accumulator";
+
+    /// Zero totals for a member with no approved claims yet, as of the
+    /// year before `plan_year` (see `seed_year`)
+    pub fn empty(member: Pubkey, plan_year: u16) -> Self {
+        Self {
+            member,
+            plan_year: Self::seed_year(plan_year),
+            deductible_met: 0,
+            oop_met: 0,
+            annual_paid: 0,
+            category_paid: [0; ClaimCategory::COUNT],
+            category_carryover: [0; ClaimCategory::COUNT],
+            claims_approved: 0,
+            bump: 0,
+        }
+    }
+
+    /// Plan year a new accumulator starts in. A member first approved in
+    /// `plan_year` was covered the year before with nothing paid, so the
+    /// accumulator starts there and rolls that fully unused year forward.
+    /// Plan years start at 1.
+    pub fn seed_year(plan_year: u16) -> u16 {
+        plan_year.saturating_sub(1).max(1)
+    }
+
+    /// Set up a newly created accumulator account (see `seed_year`)
+    pub fn seed(&mut self, member: Pubkey, plan_year: u16, bump: u8) {
+        self.member = member;
+        self.plan_year = Self::seed_year(plan_year);
+        self.bump = bump;
+    }
+
+    /// Start `plan_year` from zero if the totals belong to an earlier year,
+    /// carrying the schedule's share of each unused category limit forward.
+    /// Years skipped without claims left their whole limit unused.
+    fn roll_to(&mut self, schedule: &BenefitSchedule, plan_year: u16) {
+        if self.plan_year == plan_year {
+            return;
+        }
+
+        let mut carryover = [0; ClaimCategory::COUNT];
+        if plan_year > self.plan_year {
+            let renewed = plan_year == self.plan_year.saturating_add(1);
+            for limit in &schedule.category_limits {
+                let i = limit.category.index();
+                let paid = if renewed { self.category_paid[i] } else { 0 };
+                carryover[i] = schedule.carryover_for(limit.category, paid);
+            }
+        }
+
+        self.plan_year = plan_year;
+        self.deductible_met = 0;
+        self.oop_met = 0;
+        self.annual_paid = 0;
+        self.category_paid = [0; ClaimCategory::COUNT];
+        self.category_carryover = carryover;
+        self.claims_approved = 0;
+    }
+
+    /// Record an approved claim. The plan pays `plan_paid` of the allowed
+    /// amount; the member's share counts toward the deductible and OOP max.
+    pub fn record_approval(
+        &mut self,
+        schedule: &BenefitSchedule,
+        is_family: bool,
+        plan_year: u16,
+        category: ClaimCategory,
+        allowed_amount: u64,
+        plan_paid: u64,
+    ) {
+        self.roll_to(schedule, plan_year);
+
+        let member_share = allowed_amount.saturating_sub(plan_paid);
+        self.deductible_met = self
+            .deductible_met
+            .saturating_add(member_share)
+            .min(schedule.deductible(is_family));
+        self.oop_met = self
+            .oop_met
+            .saturating_add(member_share)
+            .min(schedule.oop_max(is_family));
+
+        self.annual_paid = self.annual_paid.saturating_add(plan_paid);
+        let paid = &mut self.category_paid[category.index()];
+        *paid = paid.saturating_add(plan_paid);
+        self.claims_approved = self.claims_approved.saturating_add(1);
+    }
+
+    /// Take back a claim recorded by `record_approval` when it is denied
+    /// after approval, releasing its plan payment and member cost share in
+    /// the current totals. A cost share that was cut by the deductible or
+    /// OOP cap is released in full.
+    pub fn reverse_approval(
+        &mut self,
+        category: ClaimCategory,
+        allowed_amount: u64,
+        plan_paid: u64,
+    ) {
+        let member_share = allowed_amount.saturating_sub(plan_paid);
+        self.deductible_met = self.deductible_met.saturating_sub(member_share);
+        self.oop_met = self.oop_met.saturating_sub(member_share);
+
+        self.annual_paid = self.annual_paid.saturating_sub(plan_paid);
+        let paid = &mut self.category_paid[category.index()];
+        *paid = paid.saturating_sub(plan_paid);
+        self.claims_approved = self.claims_approved.saturating_sub(1);
+    }
+
+    /// Plan-paid headroom for a claim in `category`: the tightest of the
+    /// per-incident, category annual and plan annual caps (limits of 0 are
+    /// uncapped; u64::MAX if nothing applies)
+    pub fn remaining_limit(
+        &self,
+        schedule: &BenefitSchedule,
+        is_family: bool,
+        plan_year: u16,
+        category: ClaimCategory,
+    ) -> u64 {
+        let mut current = self.clone();
+        current.roll_to(schedule, plan_year);
+
+        let uncapped = |cap: u64| if cap == 0 { u64::MAX } else { cap };
+        let category_remaining = schedule
+            .category_limits
+            .iter()
+            .find(|l| l.category == category && l.annual_limit > 0)
+            .map(|l| {
+                l.annual_limit
+                    .saturating_add(current.category_carryover[category.index()])
+                    .saturating_sub(current.category_paid[category.index()])
+            })
+            .unwrap_or(u64::MAX);
+        let annual_remaining = match schedule.annual_max(is_family) {
+            0 => u64::MAX,
+            max => max.saturating_sub(current.annual_paid),
+        };
+
+        uncapped(schedule.per_incident_cap(category))
+            .min(category_remaining)
+            .min(annual_remaining)
+    }
+
+    /// Plan payment for a claim of `plan_paid` against the remaining
+    /// limits: the full amount when within them, the remaining limit when
+    /// `allow_partial`, otherwise None (deny as `ExceedsLimit`)
+    pub fn limit_payable(
+        &self,
+        schedule: &BenefitSchedule,
+        is_family: bool,
+        plan_year: u16,
+        category: ClaimCategory,
+        plan_paid: u64,
+        allow_partial: bool,
+    ) -> Option<u64> {
+        let remaining = self.remaining_limit(schedule, is_family, plan_year, category);
+        if plan_paid <= remaining {
+            Some(plan_paid)
+        } else if allow_partial && remaining > 0 {
+            Some(remaining)
+        } else {
+            None
+        }
+    }
+
+    /// Plan-paid YTD not yet reported to reinsurance, once it exceeds the
+    /// specific stop-loss `attachment`. `reported` is the YTD already on the
+    /// member's reinsurance accumulator. None while under the attachment.
+    pub fn unreported_stop_loss_claims(&self, attachment: u64, reported: u64) -> Option<u64> {
+        if self.annual_paid <= attachment {
+            return None;
+        }
+        Some(self.annual_paid.saturating_sub(reported))
+    }
+
+    /// Utilization against the schedule for the member's current plan year
+    pub fn summarize(
+        &self,
+        schedule: &BenefitSchedule,
+        is_family: bool,
+        plan_year: u16,
+    ) -> MemberUtilization {
+        let mut current = self.clone();
+        current.roll_to(schedule, plan_year);
+
+        let categories = schedule
+            .category_limits
+            .iter()
+            .filter(|l| l.annual_limit > 0)
+            .map(|l| {
+                let i = l.category.index();
+                let paid = current.category_paid[i];
+                CategoryUtilization {
+                    category: l.category,
+                    paid,
+                    remaining: l
+                        .annual_limit
+                        .saturating_add(current.category_carryover[i])
+                        .saturating_sub(paid),
+                }
+            })
+            .collect();
+
+        MemberUtilization {
+            member: current.member,
+            plan_year,
+            deductible_met: current.deductible_met,
+            remaining_deductible: schedule
+                .deductible(is_family)
+                .saturating_sub(current.deductible_met),
+            oop_met: current.oop_met,
+            remaining_oop: schedule.oop_max(is_family).saturating_sub(current.oop_met),
+            annual_paid: current.annual_paid,
+            remaining_annual_max: schedule
+                .annual_max(is_family)
+                .saturating_sub(current.annual_paid),
+            claims_approved: current.claims_approved,
+            categories,
+        }
+    }
+}
+
+/// Member benefit utilization summary (view return)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct MemberUtilization {
+    pub member: Pubkey,
+    pub plan_year: u16,
+    pub deductible_met: u64,
+    pub remaining_deductible: u64,
+    pub oop_met: u64,
+    pub remaining_oop: u64,
+    pub annual_paid: u64,
+    pub remaining_annual_max: u64,
+    pub claims_approved: u32,
+    /// Categories with an annual limit in the benefit schedule
+    pub categories: Vec<CategoryUtilization>,
+}
+
+/// Plan-paid and remaining benefit for one limited category
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CategoryUtilization {
+    pub category: ClaimCategory,
+    pub paid: u64,
+    pub remaining: u64,
+}
+
+/// Why a prospective claim would be rejected or denied
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IneligibilityReason {
+    /// Service date is in the future
+    FutureServiceDate,
+    /// Amount is below the protocol minimum claim amount
+    BelowMinimum,
+    /// Member is not active (suspended, lapsed, terminated, pending)
+    CoverageNotActive,
+    /// Service falls inside the new-member waiting period
+    WaitingPeriod,
+    /// Service date is before enrollment or inside a coverage gap
+    ServiceDateOutsideCoverage,
+    /// Member has reached the monthly claim cap
+    ClaimRateExceeded,
+    /// Benefit limits are exhausted for this category
+    ExceedsBenefitLimit,
+}
+
+/// Claim eligibility precheck result (view return)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ClaimEligibility {
+    pub eligible: bool,
+    /// Every check the claim fails (empty when eligible)
+    pub reasons: Vec<IneligibilityReason>,
+    /// Plan payment after coinsurance and remaining benefit limits
+    pub estimated_plan_paid: u64,
+    /// Amount left for the member to pay
+    pub estimated_member_cost: u64,
+    /// Eligible claim that the fast lane could auto-approve
+    pub fast_lane_eligible: bool,
+}
+
+// =============================================================================
+// CATEGORY ANALYTICS
+// =============================================================================
+
+/// Protocol-wide claim counters by category
+/// PDA seeds: ["category_stats"]
+#[account]
+#[derive(InitSpace)]
+pub struct CategoryStats {
+    /// Claims submitted (indexed by `ClaimCategory::index`)
+    pub submitted: [u32; ClaimCategory::COUNT],
+
+    /// Claims approved
+    pub approved: [u32; ClaimCategory::COUNT],
+
+    /// USDC paid out
+    pub total_paid: [u64; ClaimCategory::COUNT],
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl CategoryStats {
+    pub const SEED_PREFIX: &'static [u8] = b"category_stats";
+
+    pub fn record_submission(&mut self, category: ClaimCategory) {
+        let i = category.index();
+        self.submitted[i] = self.submitted[i].saturating_add(1);
+    }
+
+    pub fn record_approval(&mut self, category: ClaimCategory) {
+        let i = category.index();
+        self.approved[i] = self.approved[i].saturating_add(1);
+    }
+
+    /// Drop an approval when the claim is denied after all
+    pub fn reverse_approval(&mut self, category: ClaimCategory) {
+        let i = category.index();
+        self.approved[i] = self.approved[i].saturating_sub(1);
+    }
+
+    pub fn record_payment(&mut self, category: ClaimCategory, amount: u64) {
+        let i = category.index();
+        self.total_paid[i] = self.total_paid[i].saturating_add(amount);
+    }
+
+    /// Counters for every category, in index order
+    pub fn breakdown(&self) -> Vec<CategoryBreakdown> {
+        ClaimCategory::ALL
+            .iter()
+            .map(|&category| {
+                let i = category.index();
+                CategoryBreakdown {
+                    category,
+                    submitted: self.submitted[i],
+                    approved: self.approved[i],
+                    total_paid: self.total_paid[i],
+                }
+            })
+            .collect()
+    }
+}
+
+/// Submitted/approved/paid counters for one category (view return)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CategoryBreakdown {
+    pub category: ClaimCategory,
+    pub submitted: u32,
+    pub approved: u32,
+    pub total_paid: u64,
+}
+
+// =============================================================================
+// AI/ML CLAIMS ORACLE INFRASTRUCTURE
+// Supports off-chain AI processing with on-chain decision recording
+// =============================================================================
+
+/// Claims Oracle configuration
+/// PDA seeds: ["claims_oracle"]
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimsOracle {
+    /// Authority for oracle management
+    pub authority: Pubkey,
+
+    /// Authorized oracle signers (AI service endpoints)
+    #[max_len(5)]
+    pub authorized_signers: Vec<Pubkey>,
+
+    /// Required signatures for AI decision to be valid
+    pub required_sigs: u8,
 
-    /// Suggested approved amount (may differ from requested)
-    pub suggested_amount: u64,
+    /// Total decisions processed
+    pub total_decisions: u64,
+
+    /// Decisions auto-approved by AI
+    pub auto_approved: u64,
+
+    /// Decisions auto-denied by AI
+    pub auto_denied: u64,
+
+    /// Decisions escalated to committee
+    pub escalated: u64,
+
+    /// Decisions overturned by committee (measures AI accuracy)
+    pub overturned: u64,
+
+    /// Oracle accuracy rate (bps) - (total - overturned) / total
+    pub accuracy_rate_bps: u16,
 
-    /// Reference price from UCR database
-    pub reference_price: u64,
+    /// Is oracle active
+    pub is_active: bool,
 
-    /// Oracle signer who submitted this decision
-    pub submitted_by: Pubkey,
+    /// Minimum confidence for auto-approve (bps, e.g., 9500 = 95%)
+    pub auto_approve_confidence_bps: u16,
 
-    /// Timestamp of decision
-    pub decided_at: i64,
+    /// Maximum fraud score for auto-approve (bps, e.g., 3000 = 30%)
+    pub max_fraud_score_bps: u16,
 
-    /// Was this decision overturned by committee
-    pub overturned: bool,
+    /// Minimum confidence for auto-deny (bps)
+    pub auto_deny_confidence_bps: u16,
 
     /// Bump seed
     pub bump: u8,
 }
 
-impl AiDecisionRecord {
-    pub const SEED_PREFIX: &'static [u8] = b"ai_decision";
+impl ClaimsOracle {
+    pub const SEED_PREFIX: &'static [u8] = b"claims_oracle";
+
+    // Default confidence thresholds
+    pub const DEFAULT_AUTO_APPROVE_CONFIDENCE: u16 = 9500; // 95%
+    pub const DEFAULT_MAX_FRAUD_SCORE: u16 = 3000; // 30%
+    pub const DEFAULT_AUTO_DENY_CONFIDENCE: u16 = 9500; // 95% confident it's fraud
+
+    /// Check if a signer is authorized
+    pub fn is_authorized(&self, signer: &Pubkey) -> bool {
+        self.authorized_signers.contains(signer)
+    }
+
+    /// Update accuracy rate
+    pub fn update_accuracy(&mut self) {
+        if self.total_decisions == 0 {
+            self.accuracy_rate_bps = 0;
+            return;
+        }
+
+        let accurate = self.total_decisions.saturating_sub(self.overturned);
+        self.accuracy_rate_bps = (accurate
+            .saturating_mul(10000)
+            .checked_div(self.total_decisions)
+            .unwrap_or(0) as u16)
+            .min(10000);
+    }
 }
 
 /// AI decision type
@@ -832,6 +1796,10 @@ pub enum AiFlag {
     Experimental,
     /// Out-of-network provider
     OutOfNetwork,
+    /// Pre-existing condition during the pre-existing waiting period
+    PreexistingCondition,
+    /// Service dated shortly after enrollment or reinstatement
+    TimingAnomaly,
 }
 
 // =============================================================================
@@ -1054,17 +2022,17 @@ mod tests {
     }
 
     #[test]
-    fn test_shock_threshold_member_based_small() {
-        // Small pool (< 1000 members)
-        let threshold = ClaimsConfig::get_shock_threshold(500);
+    fn test_shock_threshold_bootstrap_active() {
+        // Small pool in bootstrap mode
+        let threshold = ClaimsConfig::get_shock_threshold(true);
         assert_eq!(threshold, ClaimsConfig::BOOTSTRAP_SHOCK_THRESHOLD);
         assert_eq!(threshold, 25_000_000_000); // $25k
     }
 
     #[test]
-    fn test_shock_threshold_member_based_large() {
-        // Large pool (>= 1000 members)
-        let threshold = ClaimsConfig::get_shock_threshold(2000);
+    fn test_shock_threshold_after_bootstrap_exit() {
+        // Scaled pool after leaving bootstrap mode
+        let threshold = ClaimsConfig::get_shock_threshold(false);
         assert_eq!(threshold, ClaimsConfig::DEFAULT_SHOCK_THRESHOLD);
         assert_eq!(threshold, 100_000_000_000); // $100k
     }
@@ -1073,7 +2041,7 @@ mod tests {
     fn test_effective_shock_threshold_prefers_reserves() {
         // When reserves are known, prefer reserve-based calculation
         let threshold = ClaimsConfig::get_effective_shock_threshold(
-            500,                     // member count
+            false,                   // bootstrap exited
             Some(1_500_000_000_000), // $1.5M reserves
         );
 
@@ -1081,11 +2049,24 @@ mod tests {
         assert_eq!(threshold, 75_000_000_000);
     }
 
+    #[test]
+    fn test_60k_claim_is_shock_at_1m_reserves() {
+        // $1M reserves → 5% = $50k, so a $60k claim needs a DAO vote
+        let threshold = ClaimsConfig::get_effective_shock_threshold(
+            false,
+            Some(1_000_000_000_000), // $1M reserves
+        );
+
+        assert_eq!(threshold, 50_000_000_000);
+        assert!(60_000_000_000 >= threshold);
+        assert!(40_000_000_000 < threshold);
+    }
+
     #[test]
     fn test_effective_shock_threshold_fallback_to_members() {
-        // When reserves unknown, use member-based calculation
+        // When reserves unknown, use the bootstrap-mode threshold
         let threshold = ClaimsConfig::get_effective_shock_threshold(
-            500,  // member count
+            true, // bootstrap active
             None, // no reserves known
         );
 
@@ -1098,24 +2079,37 @@ mod tests {
     // =========================================================================
 
     #[test]
-    fn test_auto_approve_small_pool() {
-        let threshold = ClaimsConfig::get_auto_approve_threshold(500);
+    fn test_auto_approve_bootstrap_active() {
+        let threshold = ClaimsConfig::get_auto_approve_threshold(true);
         assert_eq!(threshold, ClaimsConfig::BOOTSTRAP_AUTO_APPROVE);
         assert_eq!(threshold, 500_000_000); // $500
     }
 
     #[test]
-    fn test_auto_approve_large_pool() {
-        let threshold = ClaimsConfig::get_auto_approve_threshold(2000);
+    fn test_auto_approve_after_bootstrap_exit() {
+        let threshold = ClaimsConfig::get_auto_approve_threshold(false);
         assert_eq!(threshold, ClaimsConfig::DEFAULT_AUTO_APPROVE);
         assert_eq!(threshold, 1_000_000_000); // $1,000
     }
 
     #[test]
-    fn test_auto_approve_at_threshold() {
-        // At exactly 1000 members, should use large pool threshold
-        let threshold = ClaimsConfig::get_auto_approve_threshold(1000);
-        assert_eq!(threshold, ClaimsConfig::DEFAULT_AUTO_APPROVE);
+    fn test_bootstrap_caps_reserve_based_shock_threshold() {
+        // $1.5M reserves → $75k scaled, but bootstrap holds it at $25k
+        let reserves = Some(1_500_000_000_000);
+        assert_eq!(
+            ClaimsConfig::get_effective_shock_threshold(true, reserves),
+            ClaimsConfig::BOOTSTRAP_SHOCK_THRESHOLD
+        );
+        assert_eq!(
+            ClaimsConfig::get_effective_shock_threshold(false, reserves),
+            75_000_000_000
+        );
+
+        // Below the bootstrap threshold the reserve-based value still applies
+        assert_eq!(
+            ClaimsConfig::get_effective_shock_threshold(true, Some(200_000_000_000)),
+            ClaimsConfig::SHOCK_THRESHOLD_MIN
+        );
     }
 
     // =========================================================================
@@ -1147,19 +2141,1516 @@ mod tests {
     }
 
     // =========================================================================
-    // CLAIM STATUS TESTS
+    // BENEFIT SCHEDULE TESTS
     // =========================================================================
 
+    fn create_test_benefit_schedule() -> BenefitSchedule {
+        BenefitSchedule {
+            name: String::from("standard"),
+            individual_annual_max: 1_000_000_000_000, // $1M
+            family_annual_max: 2_000_000_000_000,     // $2M
+            per_incident_max: 500_000_000_000,
+            individual_deductible: 1_000_000_000, // $1,000
+            family_deductible: 2_000_000_000,     // $2,000
+            coinsurance_bps: 8000,
+            oop_max_individual: 5_000_000_000,
+            oop_max_family: 10_000_000_000,
+            preexisting_waiting_days: 180,
+            is_active: true,
+            category_limits: vec![],
+            last_updated: 0,
+            bump: 255,
+        }
+    }
+
+    fn coinsurance_override(category: ClaimCategory, bps: u16) -> CategoryLimit {
+        CategoryLimit {
+            category,
+            annual_limit: 0,
+            per_visit_limit: 0,
+            coinsurance_override_bps: bps,
+            carryover_bps: 0,
+        }
+    }
+
     #[test]
-    fn test_claim_status_default() {
-        assert_eq!(ClaimStatus::default(), ClaimStatus::Submitted);
+    fn test_coinsurance_over_100_percent_rejected() {
+        let phase = ProtocolPhase::Phase1Hcsm;
+        assert!(BenefitSchedule::validate_coinsurance(10000, &[], phase).is_ok());
+        assert!(BenefitSchedule::validate_coinsurance(10001, &[], phase).is_err());
+
+        let limits = [coinsurance_override(ClaimCategory::Preventive, 12000)];
+        assert!(BenefitSchedule::validate_coinsurance(8000, &limits, phase).is_err());
     }
 
     #[test]
-    fn test_claim_category_coverage() {
-        // Ensure all categories are distinct
-        assert_ne!(ClaimCategory::Emergency, ClaimCategory::Hospitalization);
-        assert_ne!(ClaimCategory::PrimaryCare, ClaimCategory::SpecialistVisit);
+    fn test_coinsurance_below_phase3_floor_rejected() {
+        let phase = ProtocolPhase::Phase3Licensed;
+        assert!(BenefitSchedule::validate_coinsurance(6000, &[], phase).is_ok());
+        assert!(BenefitSchedule::validate_coinsurance(5000, &[], phase).is_err());
+
+        // A low category override breaches the floor too; 0 defers to the default
+        let low = [coinsurance_override(ClaimCategory::SpecialistVisit, 4000)];
+        assert!(BenefitSchedule::validate_coinsurance(8000, &low, phase).is_err());
+        let unset = [coinsurance_override(ClaimCategory::SpecialistVisit, 0)];
+        assert!(BenefitSchedule::validate_coinsurance(8000, &unset, phase).is_ok());
+
+        // The same schedule is fine for a Phase 1 sharing ministry
+        assert!(
+            BenefitSchedule::validate_coinsurance(5000, &low, ProtocolPhase::Phase1Hcsm).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_category_coinsurance_override() {
+        let mut schedule = create_test_benefit_schedule();
+        schedule.category_limits = vec![CategoryLimit {
+            category: ClaimCategory::Preventive,
+            annual_limit: 0,
+            per_visit_limit: 0,
+            coinsurance_override_bps: 10000,
+            carryover_bps: 0,
+        }];
+
+        // Preventive is covered in full
+        assert_eq!(
+            schedule.coinsurance_bps_for(ClaimCategory::Preventive),
+            10000
+        );
+        assert_eq!(
+            schedule.covered_amount(ClaimCategory::Preventive, 250_000_000),
+            250_000_000
+        );
+
+        // Surgery has no override and falls back to the 80% default
+        assert_eq!(schedule.coinsurance_bps_for(ClaimCategory::Surgery), 8000);
+        assert_eq!(
+            schedule.covered_amount(ClaimCategory::Surgery, 40_000_000_000),
+            32_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_benefit_schedule_family_limits() {
+        let schedule = create_test_benefit_schedule();
+
+        assert_eq!(schedule.annual_max(false), 1_000_000_000_000);
+        assert_eq!(schedule.annual_max(true), 2_000_000_000_000);
+        assert_eq!(schedule.deductible(false), 1_000_000_000);
+        assert_eq!(schedule.deductible(true), 2_000_000_000);
+        assert_eq!(schedule.oop_max(true), 10_000_000_000);
+    }
+
+    #[test]
+    fn test_preexisting_claim_denied_at_day_90() {
+        let schedule = create_test_benefit_schedule();
+        let day = 24 * 60 * 60;
+        let enrolled_at = 1_000 * day;
+
+        assert!(schedule.is_preexisting_excluded(enrolled_at, enrolled_at + 90 * day));
+        assert!(schedule.is_preexisting_excluded(enrolled_at, enrolled_at + 179 * day));
+    }
+
+    #[test]
+    fn test_preexisting_claim_allowed_at_day_200() {
+        let schedule = create_test_benefit_schedule();
+        let day = 24 * 60 * 60;
+        let enrolled_at = 1_000 * day;
+
+        assert_eq!(
+            schedule.preexisting_wait_ends(enrolled_at),
+            enrolled_at + 180 * day
+        );
+        assert!(!schedule.is_preexisting_excluded(enrolled_at, enrolled_at + 180 * day));
+        assert!(!schedule.is_preexisting_excluded(enrolled_at, enrolled_at + 200 * day));
+    }
+
+    fn create_test_config() -> ClaimsConfig {
+        ClaimsConfig {
+            authority: Pubkey::new_unique(),
+            governance_program: Pubkey::new_unique(),
+            reserves_program: Pubkey::new_unique(),
+            claims_committee: Pubkey::new_unique(),
+            total_claims_submitted: 0,
+            total_claims_approved: 0,
+            total_claims_denied: 0,
+            denials_by_reason: [0; DenialCode::COUNT],
+            total_paid_out: 0,
+            auto_approve_threshold: ClaimsConfig::DEFAULT_AUTO_APPROVE,
+            shock_claim_threshold: 0,
+            required_attestations: 2,
+            attestation_tiers: vec![],
+            max_attestation_time: 0,
+            is_active: true,
+            max_claims_per_period: ClaimsConfig::DEFAULT_MAX_CLAIMS_PER_PERIOD,
+            allow_partial_limit_payment: true,
+            paid_claim_close_window: ClaimsConfig::DEFAULT_PAID_CLAIM_CLOSE_WINDOW,
+            min_claim_amount: ProtocolPhase::Phase1Hcsm.default_min_claim_amount(),
+            stop_loss_treaty: Pubkey::default(),
+            bump: 255,
+        }
+    }
+
+    fn create_tiered_config() -> ClaimsConfig {
+        let mut config = create_test_config();
+        config.attestation_tiers = vec![
+            AttestationTier {
+                amount_threshold: 25_000_000_000, // $25k
+                required_count: 3,
+            },
+            AttestationTier {
+                amount_threshold: 50_000_000_000, // $50k
+                required_count: 4,
+            },
+        ];
+        config
+    }
+
+    #[test]
+    fn test_attestations_scale_with_claim_size() {
+        let config = create_tiered_config();
+
+        assert_eq!(config.required_attestations_for(5_000_000_000), 2);
+        assert_eq!(config.required_attestations_for(25_000_000_000), 3);
+        assert_eq!(config.required_attestations_for(80_000_000_000), 4);
+    }
+
+    #[test]
+    fn test_under_attested_large_claim_cannot_be_approved() {
+        let config = create_tiered_config();
+        let mut claim = create_test_claim(80_000_000_000);
+        claim.requested_amount = 80_000_000_000;
+        claim.attestation_count = 2;
+
+        // Legacy claim with no recorded requirement falls back to the tier
+        assert_eq!(config.attestations_required(&claim), 4);
+        assert!(claim.attestation_count < config.attestations_required(&claim));
+
+        claim.required_attestations = config.required_attestations_for(claim.requested_amount);
+        claim.attestation_count = 4;
+        assert!(claim.attestation_count >= config.attestations_required(&claim));
+    }
+
+    #[test]
+    fn test_escalation_fires_only_after_timeout() {
+        let config = create_test_config();
+        let mut claim = create_test_claim(0);
+        claim.status = ClaimStatus::PendingAttestation;
+        claim.status_changed_at = 1_000;
+        claim.attestation_count = 1;
+        let required = config.attestations_required(&claim);
+        let deadline = 1_000 + config.max_attestation_time;
+
+        assert!(!claim.is_attestation_stale(deadline - 1, config.max_attestation_time, required));
+        assert!(!claim.is_attestation_stale(deadline, config.max_attestation_time, required));
+        assert!(claim.is_attestation_stale(deadline + 1, config.max_attestation_time, required));
+
+        // Fully attested claims are left for the committee to resolve
+        claim.attestation_count = required;
+        assert!(!claim.is_attestation_stale(deadline + 1, config.max_attestation_time, required));
+    }
+
+    #[test]
+    fn test_attestation_tier_validation() {
+        let tiers = create_tiered_config().attestation_tiers;
+        assert!(ClaimsConfig::validate_attestation_tiers(&tiers, 2, 5));
+
+        // More attestations than registered attestors
+        assert!(!ClaimsConfig::validate_attestation_tiers(&tiers, 2, 3));
+
+        // Unsorted thresholds
+        let unsorted = vec![tiers[1], tiers[0]];
+        assert!(!ClaimsConfig::validate_attestation_tiers(&unsorted, 2, 5));
+
+        // A tier may not require fewer than the base count
+        let lower = vec![AttestationTier {
+            amount_threshold: 10_000_000_000,
+            required_count: 1,
+        }];
+        assert!(!ClaimsConfig::validate_attestation_tiers(&lower, 2, 5));
+    }
+
+    #[test]
+    fn test_denial_counters_by_reason() {
+        let mut config = create_test_config();
+
+        for reason in [
+            DenialReason::NotCovered,
+            DenialReason::NotCovered,
+            DenialReason::Duplicate,
+            DenialReason::Other {
+                note_hash: [7u8; 32],
+            },
+        ] {
+            config.record_denial(reason.code());
+        }
+
+        assert_eq!(config.total_claims_denied, 4);
+        assert_eq!(config.denials_for(DenialCode::NotCovered), 2);
+        assert_eq!(config.denials_for(DenialCode::Duplicate), 1);
+        assert_eq!(config.denials_for(DenialCode::Other), 1);
+        assert_eq!(config.denials_for(DenialCode::Fraud), 0);
+    }
+
+    #[test]
+    fn test_denial_reason_maps_to_code() {
+        let other = DenialReason::Other {
+            note_hash: [9u8; 32],
+        };
+        assert_eq!(other.code(), DenialCode::Other);
+        assert_eq!(other.note_hash(), [9u8; 32]);
+        assert_eq!(DenialReason::Fraud.code(), DenialCode::Fraud);
+        assert_eq!(DenialReason::Fraud.note_hash(), [0u8; 32]);
+        // Every code has a tally slot
+        assert_eq!(DenialCode::OutOfNetwork as usize, DenialCode::COUNT - 1);
+    }
+
+    #[test]
+    fn test_denial_code_default() {
+        assert_eq!(DenialCode::default(), DenialCode::None);
+    }
+
+    // =========================================================================
+    // PROVIDER REGISTRY TESTS
+    // =========================================================================
+
+    fn create_test_provider() -> ProviderRecord {
+        ProviderRecord {
+            provider: Pubkey::new_unique(),
+            claims_submitted: 0,
+            claims_approved: 0,
+            claims_denied: 0,
+            denial_rate_bps: 0,
+            watchlisted: false,
+            watchlisted_at: 0,
+            registered_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_provider_auto_watchlisted_on_denial_rate() {
+        let mut provider = create_test_provider();
+
+        // 7 approvals, 2 denials: below minimum history
+        for _ in 0..7 {
+            assert!(!provider.record_approval(100));
+        }
+        for _ in 0..2 {
+            assert!(!provider.record_denial(100));
+        }
+        assert!(!provider.watchlisted);
+
+        // 10th resolved claim is a denial: 3/10 = 30% crosses threshold
+        assert!(provider.record_denial(200));
+        assert_eq!(provider.denial_rate_bps, 3000);
+        assert!(provider.watchlisted);
+        assert_eq!(provider.watchlisted_at, 200);
+
+        // Already watchlisted: not reported again
+        assert!(!provider.record_denial(300));
+        assert_eq!(provider.watchlisted_at, 200);
+    }
+
+    #[test]
+    fn test_provider_below_threshold_not_watchlisted() {
+        let mut provider = create_test_provider();
+
+        for _ in 0..8 {
+            provider.record_approval(100);
+        }
+        for _ in 0..2 {
+            provider.record_denial(100);
+        }
+
+        // 2/10 = 20%
+        assert_eq!(provider.denial_rate_bps, 2000);
+        assert!(!provider.watchlisted);
+    }
+
+    #[test]
+    fn test_provider_high_rate_needs_minimum_history() {
+        let mut provider = create_test_provider();
+
+        // 100% denial rate but only 3 resolved claims
+        for _ in 0..3 {
+            assert!(!provider.record_denial(100));
+        }
+        assert_eq!(provider.denial_rate_bps, 10000);
+        assert!(!provider.watchlisted);
+
+        provider.watchlist(100);
+        provider.clear();
+        assert!(!provider.watchlisted);
+        assert_eq!(provider.watchlisted_at, 0);
+    }
+
+    #[test]
+    fn test_cleared_provider_starts_fresh_history() {
+        let mut provider = create_test_provider();
+        for _ in 0..10 {
+            provider.record_denial(100);
+        }
+        assert!(provider.watchlisted);
+
+        provider.clear();
+        assert_eq!(provider.resolved_claims(), 0);
+        assert_eq!(provider.denial_rate_bps, 0);
+
+        // One more denial is not enough history to re-watchlist
+        assert!(!provider.record_denial(200));
+        assert!(!provider.watchlisted);
+    }
+
+    // =========================================================================
+    // MEMBER CLAIM RATE TESTS
+    // =========================================================================
+
+    fn create_test_claim_rate() -> MemberClaimRate {
+        MemberClaimRate {
+            member: Pubkey::new_unique(),
+            period_start: 0,
+            claims_this_period: 0,
+            total_claims: 0,
+            whitelisted: false,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_claim_rate_flags_nth_and_rejects_next() {
+        let mut rate = create_test_claim_rate();
+        let cap = ClaimsConfig::DEFAULT_MAX_CLAIMS_PER_PERIOD;
+        let start = 1_000_000;
+
+        for i in 1..cap {
+            assert!(!rate.would_exceed_cap(start + i as i64, cap));
+            assert!(!rate.record_submission(start + i as i64, cap));
+        }
+
+        // Nth claim is accepted but flagged HighFrequency
+        assert!(!rate.would_exceed_cap(start + 100, cap));
+        assert!(rate.record_submission(start + 100, cap));
+        assert_eq!(rate.claims_this_period, cap);
+
+        // N+1th is rejected
+        assert!(rate.would_exceed_cap(start + 200, cap));
+
+        // A new period resets the count
+        let next_period = rate.period_start + MemberClaimRate::PERIOD_LENGTH;
+        assert!(!rate.would_exceed_cap(next_period, cap));
+        assert!(!rate.record_submission(next_period, cap));
+        assert_eq!(rate.claims_this_period, 1);
+        assert_eq!(rate.total_claims, cap as u64 + 1);
+    }
+
+    #[test]
+    fn test_claim_rate_whitelist_overrides_cap() {
+        let mut rate = create_test_claim_rate();
+        rate.whitelisted = true;
+
+        for i in 0..20 {
+            assert!(!rate.would_exceed_cap(1_000 + i, 3));
+            assert!(!rate.record_submission(1_000 + i, 3));
+        }
+        assert_eq!(rate.claims_this_period, 20);
+
+        // Zero disables the cap for everyone
+        rate.whitelisted = false;
+        assert!(!rate.would_exceed_cap(1_100, 0));
+        assert!(!rate.record_submission(1_100, 0));
+    }
+
+    // =========================================================================
+    // BENEFIT UTILIZATION TESTS
+    // =========================================================================
+
+    fn create_test_accumulator() -> MemberBenefitAccumulator {
+        MemberBenefitAccumulator {
+            member: Pubkey::new_unique(),
+            plan_year: 1,
+            deductible_met: 0,
+            oop_met: 0,
+            annual_paid: 0,
+            category_paid: [0; ClaimCategory::COUNT],
+            category_carryover: [0; ClaimCategory::COUNT],
+            claims_approved: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_utilization_reflects_approved_claims() {
+        let mut schedule = create_test_benefit_schedule();
+        schedule.category_limits = vec![CategoryLimit {
+            category: ClaimCategory::MentalHealth,
+            annual_limit: 10_000_000_000, // $10k
+            per_visit_limit: 0,
+            coinsurance_override_bps: 0,
+            carryover_bps: 0,
+        }];
+        let mut acc = create_test_accumulator();
+
+        // $2,000 therapy claim at 80%: plan pays $1,600, member owes $400
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::MentalHealth,
+            2_000_000_000,
+            1_600_000_000,
+        );
+        // $5,000 surgery at 80%: plan pays $4,000, member owes $1,000
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::Surgery,
+            5_000_000_000,
+            4_000_000_000,
+        );
+
+        let view = acc.summarize(&schedule, false, 1);
+        assert_eq!(view.claims_approved, 2);
+        assert_eq!(view.annual_paid, 5_600_000_000);
+        assert_eq!(view.remaining_annual_max, 1_000_000_000_000 - 5_600_000_000);
+        // $1,400 of cost share: deductible ($1,000) met, OOP $1,400 of $5,000
+        assert_eq!(view.deductible_met, 1_000_000_000);
+        assert_eq!(view.remaining_deductible, 0);
+        assert_eq!(view.oop_met, 1_400_000_000);
+        assert_eq!(view.remaining_oop, 3_600_000_000);
+        // Only limited categories are reported
+        assert_eq!(
+            view.categories,
+            vec![CategoryUtilization {
+                category: ClaimCategory::MentalHealth,
+                paid: 1_600_000_000,
+                remaining: 8_400_000_000,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_stop_loss_reported_once_ytd_crosses_attachment() {
+        let schedule = create_test_benefit_schedule();
+        let mut acc = create_test_accumulator();
+        let attachment = 100_000_000_000; // $100k
+
+        // $60k paid: under the attachment, nothing to report
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::Surgery,
+            60_000_000_000,
+            60_000_000_000,
+        );
+        assert_eq!(acc.unreported_stop_loss_claims(attachment, 0), None);
+
+        // $120k more: the whole $180k YTD goes to the accumulator
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::Hospitalization,
+            120_000_000_000,
+            120_000_000_000,
+        );
+        assert_eq!(
+            acc.unreported_stop_loss_claims(attachment, 0),
+            Some(180_000_000_000)
+        );
+
+        // Once reported, only later claims are sent
+        assert_eq!(
+            acc.unreported_stop_loss_claims(attachment, 180_000_000_000),
+            Some(0)
+        );
+    }
+
+    fn create_limited_schedule() -> BenefitSchedule {
+        let mut schedule = create_test_benefit_schedule();
+        schedule.category_limits = vec![CategoryLimit {
+            category: ClaimCategory::Rehabilitation,
+            annual_limit: 10_000_000_000, // $10k a year
+            per_visit_limit: 0,
+            coinsurance_override_bps: 0,
+            carryover_bps: 0,
+        }];
+        schedule
+    }
+
+    #[test]
+    fn test_limit_exceeded_claim_paid_up_to_cap() {
+        let schedule = create_limited_schedule();
+        let mut acc = create_test_accumulator();
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::Rehabilitation,
+            7_500_000_000,
+            6_000_000_000,
+        );
+
+        let category = ClaimCategory::Rehabilitation;
+        assert_eq!(
+            acc.remaining_limit(&schedule, false, 1, category),
+            4_000_000_000
+        );
+        // Within the limit: paid in full
+        assert_eq!(
+            acc.limit_payable(&schedule, false, 1, category, 3_000_000_000, true),
+            Some(3_000_000_000)
+        );
+        // Over the limit: only the remaining $4k is paid
+        assert_eq!(
+            acc.limit_payable(&schedule, false, 1, category, 6_000_000_000, true),
+            Some(4_000_000_000)
+        );
+        // A new plan year restores the full limit
+        assert_eq!(
+            acc.limit_payable(&schedule, false, 2, category, 6_000_000_000, true),
+            Some(6_000_000_000)
+        );
+        // The per-incident max caps uncapped categories
+        assert_eq!(
+            acc.limit_payable(
+                &schedule,
+                false,
+                1,
+                ClaimCategory::Surgery,
+                600_000_000_000,
+                true
+            ),
+            Some(500_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_limit_exceeded_claim_denied_without_partial() {
+        let schedule = create_limited_schedule();
+        let mut acc = create_test_accumulator();
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::Rehabilitation,
+            12_500_000_000,
+            10_000_000_000,
+        );
+
+        let category = ClaimCategory::Rehabilitation;
+        assert_eq!(
+            acc.limit_payable(&schedule, false, 1, category, 1_000_000_000, false),
+            None
+        );
+        // An exhausted limit is denied even when partial payment is allowed
+        assert_eq!(
+            acc.limit_payable(&schedule, false, 1, category, 1_000_000_000, true),
+            None
+        );
+
+        let acc = create_test_accumulator();
+        assert_eq!(
+            acc.limit_payable(&schedule, false, 1, category, 12_000_000_000, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_reversed_approval_restores_limit() {
+        let schedule = create_limited_schedule();
+        let category = ClaimCategory::Rehabilitation;
+        let mut acc = create_test_accumulator();
+        acc.record_approval(&schedule, false, 1, category, 2_500_000_000, 2_000_000_000);
+        let before = acc.clone();
+
+        acc.record_approval(&schedule, false, 1, category, 6_200_000_000, 6_000_000_000);
+        assert_eq!(
+            acc.remaining_limit(&schedule, false, 1, category),
+            2_000_000_000
+        );
+
+        acc.reverse_approval(category, 6_200_000_000, 6_000_000_000);
+        assert_eq!(acc.annual_paid, before.annual_paid);
+        assert_eq!(acc.category_paid, before.category_paid);
+        assert_eq!(acc.deductible_met, before.deductible_met);
+        assert_eq!(acc.oop_met, before.oop_met);
+        assert_eq!(acc.claims_approved, before.claims_approved);
+        assert_eq!(
+            acc.remaining_limit(&schedule, false, 1, category),
+            8_000_000_000
+        );
+
+        let mut stats = CategoryStats {
+            submitted: [0; ClaimCategory::COUNT],
+            approved: [0; ClaimCategory::COUNT],
+            total_paid: [0; ClaimCategory::COUNT],
+            bump: 255,
+        };
+        stats.record_approval(category);
+        stats.reverse_approval(category);
+        assert_eq!(stats.approved[category.index()], 0);
+    }
+
+    #[test]
+    fn test_approvals_count_in_config_and_category() {
+        let mut config = create_test_config();
+        let mut stats = CategoryStats {
+            submitted: [0; ClaimCategory::COUNT],
+            approved: [0; ClaimCategory::COUNT],
+            total_paid: [0; ClaimCategory::COUNT],
+            bump: 255,
+        };
+
+        // AI auto-approvals and committee approvals count alike
+        config.record_approval(&mut stats, ClaimCategory::Prescription);
+        config.record_approval(&mut stats, ClaimCategory::Prescription);
+        config.record_approval(&mut stats, ClaimCategory::Emergency);
+        assert_eq!(config.total_claims_approved, 3);
+        assert_eq!(stats.approved[ClaimCategory::Prescription.index()], 2);
+        assert_eq!(stats.approved[ClaimCategory::Emergency.index()], 1);
+
+        // An overturned approval comes back off both
+        config.reverse_approval(&mut stats, ClaimCategory::Prescription);
+        assert_eq!(config.total_claims_approved, 2);
+        assert_eq!(stats.approved[ClaimCategory::Prescription.index()], 1);
+    }
+
+    #[test]
+    fn test_category_index_matches_all() {
+        for (i, category) in ClaimCategory::ALL.iter().enumerate() {
+            assert_eq!(category.index(), i);
+        }
+    }
+
+    #[test]
+    fn test_category_stats_track_emergency_and_prescription() {
+        let mut stats = CategoryStats {
+            submitted: [0; ClaimCategory::COUNT],
+            approved: [0; ClaimCategory::COUNT],
+            total_paid: [0; ClaimCategory::COUNT],
+            bump: 255,
+        };
+
+        stats.record_submission(ClaimCategory::Emergency);
+        stats.record_submission(ClaimCategory::Prescription);
+        stats.record_approval(ClaimCategory::Emergency);
+        stats.record_approval(ClaimCategory::Prescription);
+        stats.record_payment(ClaimCategory::Emergency, 8_000_000_000);
+        stats.record_payment(ClaimCategory::Prescription, 120_000_000);
+
+        let breakdown = stats.breakdown();
+        assert_eq!(breakdown.len(), ClaimCategory::COUNT);
+        assert_eq!(
+            breakdown[ClaimCategory::Emergency.index()],
+            CategoryBreakdown {
+                category: ClaimCategory::Emergency,
+                submitted: 1,
+                approved: 1,
+                total_paid: 8_000_000_000,
+            }
+        );
+        assert_eq!(
+            breakdown[ClaimCategory::Prescription.index()],
+            CategoryBreakdown {
+                category: ClaimCategory::Prescription,
+                submitted: 1,
+                approved: 1,
+                total_paid: 120_000_000,
+            }
+        );
+        // Nothing leaks into other categories
+        let others: u32 = breakdown
+            .iter()
+            .filter(|b| {
+                b.category != ClaimCategory::Emergency && b.category != ClaimCategory::Prescription
+            })
+            .map(|b| b.submitted + b.approved)
+            .sum();
+        assert_eq!(others, 0);
+    }
+
+    #[test]
+    fn test_utilization_resets_on_new_plan_year() {
+        let schedule = create_test_benefit_schedule();
+        let mut acc = create_test_accumulator();
+        acc.record_approval(
+            &schedule,
+            true,
+            1,
+            ClaimCategory::Emergency,
+            40_000_000_000,
+            32_000_000_000,
+        );
+
+        // OOP is capped at the family maximum
+        assert_eq!(acc.oop_met, 8_000_000_000);
+
+        // The view for plan year 2 starts clean without mutating the account
+        let view = acc.summarize(&schedule, true, 2);
+        assert_eq!(view.annual_paid, 0);
+        assert_eq!(view.remaining_deductible, 2_000_000_000);
+        assert_eq!(view.remaining_oop, 10_000_000_000);
+        assert_eq!(acc.annual_paid, 32_000_000_000);
+
+        // The first plan-year-2 approval rolls the totals over
+        acc.record_approval(
+            &schedule,
+            true,
+            2,
+            ClaimCategory::Emergency,
+            1_000_000_000,
+            800_000_000,
+        );
+        assert_eq!(acc.plan_year, 2);
+        assert_eq!(acc.annual_paid, 800_000_000);
+        assert_eq!(
+            acc.category_paid[ClaimCategory::Emergency.index()],
+            800_000_000
+        );
+        assert_eq!(acc.claims_approved, 1);
+    }
+
+    fn carryover_schedule(carryover_bps: u16) -> BenefitSchedule {
+        let mut schedule = create_test_benefit_schedule();
+        schedule.category_limits = vec![CategoryLimit {
+            category: ClaimCategory::MentalHealth,
+            annual_limit: 2_000_000_000, // $2k a year
+            per_visit_limit: 0,
+            coinsurance_override_bps: 0,
+            carryover_bps,
+        }];
+        schedule
+    }
+
+    fn mental_health_remaining(view: &MemberUtilization) -> u64 {
+        view.categories
+            .iter()
+            .find(|c| c.category == ClaimCategory::MentalHealth)
+            .unwrap()
+            .remaining
+    }
+
+    #[test]
+    fn test_half_of_unused_category_limit_carries_over() {
+        let schedule = carryover_schedule(5000);
+        let mut acc = create_test_accumulator();
+
+        // Plan pays $1,000 of the $2,000 limit in year 1
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::MentalHealth,
+            1_250_000_000,
+            1_000_000_000,
+        );
+
+        // Half the unused $1,000 is added to year 2's limit
+        let view = acc.summarize(&schedule, false, 2);
+        assert_eq!(mental_health_remaining(&view), 2_500_000_000);
+
+        acc.record_approval(
+            &schedule,
+            false,
+            2,
+            ClaimCategory::MentalHealth,
+            125_000_000,
+            100_000_000,
+        );
+        assert_eq!(
+            acc.category_carryover[ClaimCategory::MentalHealth.index()],
+            500_000_000
+        );
+        assert_eq!(
+            acc.remaining_limit(&schedule, false, 2, ClaimCategory::MentalHealth),
+            2_400_000_000
+        );
+
+        // Carryover does not compound: year 3 carries half of the unused
+        // base limit only
+        let view = acc.summarize(&schedule, false, 3);
+        assert_eq!(mental_health_remaining(&view), 2_950_000_000);
+    }
+
+    #[test]
+    fn test_member_without_prior_claims_carries_full_unused_limit() {
+        let schedule = carryover_schedule(5000);
+
+        // No approved claims in year 1: the year-2 view carries half of the
+        // whole unused $2,000
+        let view =
+            MemberBenefitAccumulator::empty(Pubkey::new_unique(), 2).summarize(&schedule, false, 2);
+        assert_eq!(mental_health_remaining(&view), 3_000_000_000);
+
+        // The first approval in year 2 creates the accumulator with the same
+        // carryover
+        let mut acc = create_test_accumulator();
+        acc.plan_year = 0;
+        acc.seed(acc.member, 2, 255);
+        acc.record_approval(
+            &schedule,
+            false,
+            2,
+            ClaimCategory::MentalHealth,
+            125_000_000,
+            100_000_000,
+        );
+        assert_eq!(acc.plan_year, 2);
+        assert_eq!(
+            acc.category_carryover[ClaimCategory::MentalHealth.index()],
+            1_000_000_000
+        );
+        assert_eq!(
+            acc.remaining_limit(&schedule, false, 2, ClaimCategory::MentalHealth),
+            2_900_000_000
+        );
+
+        // A first-year member has nothing to carry
+        let view =
+            MemberBenefitAccumulator::empty(Pubkey::new_unique(), 1).summarize(&schedule, false, 1);
+        assert_eq!(mental_health_remaining(&view), 2_000_000_000);
+    }
+
+    #[test]
+    fn test_no_carryover_by_default() {
+        let schedule = carryover_schedule(0);
+        let mut acc = create_test_accumulator();
+        acc.record_approval(
+            &schedule,
+            false,
+            1,
+            ClaimCategory::MentalHealth,
+            1_250_000_000,
+            1_000_000_000,
+        );
+
+        let view = acc.summarize(&schedule, false, 2);
+        assert_eq!(mental_health_remaining(&view), 2_000_000_000);
+        assert!(BenefitSchedule::validate_carryover(&schedule.category_limits).is_ok());
+
+        let invalid = carryover_schedule(10001);
+        assert!(BenefitSchedule::validate_carryover(&invalid.category_limits).is_err());
+    }
+
+    // =========================================================================
+    // CLAIM PRECHECK TESTS
+    // =========================================================================
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn create_test_member_account(enrolled_at: i64) -> apollo_membership::state::MemberAccount {
+        use apollo_membership::state::{CoverageTier, MemberAccount, MemberStatus};
+        MemberAccount {
+            member: Pubkey::new_unique(),
+            member_id: 1,
+            age: 35,
+            region_code: 1,
+            is_tobacco_user: false,
+            num_children: 0,
+            num_additional_adults: 0,
+            coverage_tier: CoverageTier::Individual,
+            next_dependent_index: 0,
+            enrolled_at,
+            waiting_period_ends: enrolled_at + 30 * DAY,
+            coverage_activated_at: enrolled_at + 30 * DAY,
+            status: MemberStatus::Active,
+            monthly_contribution: 450_000_000,
+            total_contributions_paid: 0,
+            last_contribution_at: 0,
+            past_due_since: 0,
+            consecutive_months: 0,
+            has_qualifying_event: false,
+            qualifying_event_at: 0,
+            persistency_discount_bps: 0,
+            plan_year: 1,
+            plan_year_start: enrolled_at,
+            last_gap_start: 0,
+            last_gap_end: 0,
+            continuous_months: 0,
+            benefit_schedule: String::from("standard"),
+            bump: 255,
+        }
+    }
+
+    fn precheck_params(
+        member: Pubkey,
+        amount: u64,
+        service_date: i64,
+    ) -> crate::instructions::utilization::ClaimPrecheckParams {
+        crate::instructions::utilization::ClaimPrecheckParams {
+            member,
+            category: ClaimCategory::PrimaryCare,
+            amount,
+            service_date,
+        }
+    }
+
+    #[test]
+    fn test_precheck_eligible_fast_lane_claim() {
+        use crate::instructions::ai_processing::FastLaneConfig;
+        use crate::instructions::utilization::ClaimPrecheck;
+
+        let config = create_test_config();
+        let schedule = create_test_benefit_schedule();
+        let member = create_test_member_account(1_000 * DAY);
+        let accumulator = MemberBenefitAccumulator::empty(member.member, member.plan_year);
+        let fast_lane = FastLaneConfig::default();
+        let precheck = ClaimPrecheck {
+            config: &config,
+            schedule: &schedule,
+            member_account: &member,
+            accumulator: &accumulator,
+            claim_rate: None,
+            fast_lane: Some(&fast_lane),
+            bootstrap_active: true,
+        };
+
+        // $100 office visit two months after enrollment
+        let now = 1_060 * DAY;
+        let result =
+            precheck.evaluate(&precheck_params(member.member, 100_000_000, now - DAY), now);
+
+        assert!(result.eligible);
+        assert!(result.reasons.is_empty());
+        assert_eq!(result.estimated_plan_paid, 80_000_000); // 80% coinsurance
+        assert_eq!(result.estimated_member_cost, 20_000_000);
+        assert!(result.fast_lane_eligible);
+
+        // Same claim over the fast-lane cap is eligible but not fast-lane
+        let result =
+            precheck.evaluate(&precheck_params(member.member, 900_000_000, now - DAY), now);
+        assert!(result.eligible);
+        assert!(!result.fast_lane_eligible);
+    }
+
+    #[test]
+    fn test_precheck_rejects_waiting_period_claim() {
+        use crate::instructions::ai_processing::FastLaneConfig;
+        use crate::instructions::utilization::ClaimPrecheck;
+
+        let config = create_test_config();
+        let schedule = create_test_benefit_schedule();
+        let member = create_test_member_account(1_000 * DAY);
+        let accumulator = MemberBenefitAccumulator::empty(member.member, member.plan_year);
+        let fast_lane = FastLaneConfig::default();
+        let precheck = ClaimPrecheck {
+            config: &config,
+            schedule: &schedule,
+            member_account: &member,
+            accumulator: &accumulator,
+            claim_rate: None,
+            fast_lane: Some(&fast_lane),
+            bootstrap_active: true,
+        };
+
+        // Ten days after enrollment, still inside the 30-day waiting period
+        let now = 1_010 * DAY;
+        let result =
+            precheck.evaluate(&precheck_params(member.member, 100_000_000, now - DAY), now);
+
+        assert!(!result.eligible);
+        assert_eq!(result.reasons, vec![IneligibilityReason::WaitingPeriod]);
+        assert!(!result.fast_lane_eligible);
+
+        // Service during the waiting period stays ineligible after it ends
+        let later = 1_060 * DAY;
+        let result = precheck.evaluate(
+            &precheck_params(member.member, 100_000_000, 1_005 * DAY),
+            later,
+        );
+        assert_eq!(result.reasons, vec![IneligibilityReason::WaitingPeriod]);
+    }
+
+    // =========================================================================
+    // CLAIM STATUS TESTS
+    // =========================================================================
+
+    // =========================================================================
+    // PAYMENT IDEMPOTENCY TESTS
+    // =========================================================================
+
+    fn create_test_claim(approved_amount: u64) -> ClaimAccount {
+        ClaimAccount {
+            claim_id: 1,
+            member: Pubkey::new_unique(),
+            provider: None,
+            category: ClaimCategory::PrimaryCare,
+            requested_amount: approved_amount,
+            approved_amount,
+            paid_amount: 0,
+            status: ClaimStatus::Approved,
+            submitted_at: 0,
+            status_changed_at: 0,
+            service_date: 0,
+            description_hash: String::new(),
+            attestation_count: 0,
+            required_attestations: 0,
+            denial_reason: String::new(),
+            is_shock_claim: false,
+            in_waiting_period: false,
+            is_preexisting: false,
+            in_preexisting_wait: false,
+            denial_code: DenialCode::None,
+            denial_note_hash: [0u8; 32],
+            already_paid: false,
+            payment_nonce: 0,
+            has_ai_decision: false,
+            timing_anomaly: false,
+            high_frequency: false,
+            reinsurance_recoverable: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_claim_ids_follow_submission_counter() {
+        let mut config = create_test_config();
+        assert_eq!(config.next_claim_id(), 1);
+
+        // IDs keep advancing after claims are closed, so none is reused
+        config.total_claims_submitted = 41;
+        assert_eq!(config.next_claim_id(), 42);
+    }
+
+    #[test]
+    fn test_min_claim_amount_rejects_dust() {
+        let mut config = create_test_config();
+        config.min_claim_amount = 5_000_000; // $5
+
+        assert!(config.is_below_minimum(500_000)); // $0.50
+        assert!(!config.is_below_minimum(10_000_000)); // $10
+        assert!(!config.is_below_minimum(5_000_000));
+    }
+
+    #[test]
+    fn test_zero_min_claim_amount_disables_check() {
+        let mut config = create_test_config();
+        config.min_claim_amount = 0;
+        assert!(!config.is_below_minimum(1));
+        assert!(!config.is_below_minimum(500_000));
+
+        assert!(ClaimsConfig::validate_min_claim_amount(0, ProtocolPhase::Phase1Hcsm).is_ok());
+        // A minimum at the phase's claim ceiling would block every claim
+        let ceiling = ProtocolPhase::Phase1Hcsm.max_individual_claim();
+        assert!(
+            ClaimsConfig::validate_min_claim_amount(ceiling, ProtocolPhase::Phase1Hcsm).is_err()
+        );
+    }
+
+    #[test]
+    fn test_paid_claim_auto_closes_after_window() {
+        let window = ClaimsConfig::DEFAULT_PAID_CLAIM_CLOSE_WINDOW;
+        let mut claim = create_test_claim(2_000_000_000);
+        claim.record_payment();
+        claim.status = ClaimStatus::Paid;
+        claim.status_changed_at = 1_000;
+
+        assert!(!claim.is_auto_closable(1_000 + window - 1, window));
+        assert!(claim.is_auto_closable(1_000 + window, window));
+    }
+
+    #[test]
+    fn test_appealed_claim_never_auto_closes() {
+        let window = ClaimsConfig::DEFAULT_PAID_CLAIM_CLOSE_WINDOW;
+        let mut claim = create_test_claim(2_000_000_000);
+        claim.status = ClaimStatus::Appealed;
+        claim.status_changed_at = 1_000;
+        assert!(!claim.is_auto_closable(1_000 + 10 * window, window));
+
+        // Approved but not yet paid stays open as well
+        claim.status = ClaimStatus::Approved;
+        assert!(!claim.is_auto_closable(1_000 + 10 * window, window));
+    }
+
+    #[test]
+    fn test_second_payment_is_rejected() {
+        let mut claim = create_test_claim(2_000_000_000);
+
+        assert_eq!(claim.record_payment(), Some(2_000_000_000));
+        assert!(claim.already_paid);
+        assert_eq!(claim.payment_nonce, 1);
+
+        assert_eq!(claim.record_payment(), None);
+        assert_eq!(claim.payment_nonce, 1);
+        assert_eq!(claim.paid_amount, 2_000_000_000);
+    }
+
+    #[test]
+    fn test_retried_payment_counts_once_downstream() {
+        let mut claim = create_test_claim(5_000_000_000);
+        let mut total_paid_out = 0u64;
+
+        // A retried transaction replays the same payment
+        for _ in 0..3 {
+            if let Some(amount) = claim.record_payment() {
+                total_paid_out += amount;
+            }
+        }
+
+        assert_eq!(total_paid_out, 5_000_000_000);
+    }
+
+    #[test]
+    fn test_claim_status_default() {
+        assert_eq!(ClaimStatus::default(), ClaimStatus::Submitted);
+    }
+
+    #[test]
+    fn test_claim_category_coverage() {
+        // Ensure all categories are distinct
+        assert_ne!(ClaimCategory::Emergency, ClaimCategory::Hospitalization);
+        assert_ne!(ClaimCategory::PrimaryCare, ClaimCategory::SpecialistVisit);
+    }
+
+    // =========================================================================
+    // AI DECISION STALENESS TESTS
+    // =========================================================================
+
+    #[test]
+    fn test_ai_decision_just_within_window() {
+        use crate::instructions::ai_processing::AiDecision;
+
+        let decision = AiDecision {
+            decided_at: 1_000_000,
+            ..Default::default()
+        };
+        let max_age = 3600;
+
+        assert!(!decision.is_stale(1_000_000, max_age));
+        assert!(!decision.is_stale(1_000_000 + max_age, max_age));
+    }
+
+    #[test]
+    fn test_ai_decision_just_beyond_window() {
+        use crate::instructions::ai_processing::AiDecision;
+
+        let decision = AiDecision {
+            decided_at: 1_000_000,
+            ..Default::default()
+        };
+        let max_age = 3600;
+
+        assert!(decision.is_stale(1_000_000 + max_age + 1, max_age));
+        assert_eq!(decision.age(1_000_000 + max_age + 1), max_age + 1);
+    }
+
+    #[test]
+    fn test_ai_decision_audit_matches_submission() {
+        use crate::instructions::ai_processing::{AiDecision, AiDecisionParams, AiDecisionType};
+
+        let params = AiDecisionParams {
+            claim_id: 42,
+            confidence_bps: 9200,
+            price_score_bps: 8100,
+            fraud_score_bps: 450,
+            consistency_score_bps: 9700,
+            suggested_amount: 1_150_000_000,
+            reference_price: 1_200_000_000,
+            flags: vec![],
+            fraud_flags: FraudFlags::default(),
+        };
+        let oracle = Pubkey::new_unique();
+        let flags = vec!["HighPrice".to_string(), "PossibleDuplicate".to_string()];
+        let fraud_flags = FraudFlags {
+            price_anomaly: true,
+            duplicate_claim: true,
+            ..Default::default()
+        };
+
+        let mut decision = AiDecision {
+            overturned: true,
+            ..Default::default()
+        };
+        decision.record(
+            &params,
+            AiDecisionType::CommitteeReview,
+            flags.clone(),
+            fraud_flags,
+            oracle,
+            1_700_000_000,
+        );
+
+        let audit = decision.audit();
+        assert_eq!(audit.claim_id, 42);
+        assert_eq!(audit.decision, AiDecisionType::CommitteeReview);
+        assert_eq!(audit.confidence_bps, 9200);
+        assert_eq!(audit.price_score_bps, 8100);
+        assert_eq!(audit.fraud_score_bps, 450);
+        assert_eq!(audit.consistency_score_bps, 9700);
+        assert_eq!(audit.suggested_amount, 1_150_000_000);
+        assert_eq!(audit.reference_price, 1_200_000_000);
+        assert_eq!(audit.flags, flags);
+        assert_eq!(
+            audit.critical_flags,
+            FraudFlags {
+                duplicate_claim: true,
+                ..Default::default()
+            }
+        );
+        assert_eq!(audit.submitted_by, oracle);
+        assert_eq!(audit.decided_at, 1_700_000_000);
+        // Resubmission clears a stale overturn
+        assert!(!audit.overturned);
+
+        decision.overturned = true;
+        assert!(decision.audit().overturned);
+    }
+
+    // =========================================================================
+    // AI OVERRIDE ACCURACY TESTS
+    // =========================================================================
+
+    fn create_test_oracle(decisions: u64) -> crate::instructions::ai_processing::AiOracle {
+        use crate::instructions::ai_processing::{AiDecisionType, AiOracle};
+
+        let mut oracle = AiOracle {
+            authority: Pubkey::new_unique(),
+            authorized_signers: vec![],
+            required_sigs: 1,
+            total_decisions: 0,
+            auto_approved: 0,
+            auto_denied: 0,
+            escalated_to_committee: 0,
+            decisions_overturned: 0,
+            accuracy_rate_bps: 10000,
+            min_auto_approve_confidence_bps: AiOracle::DEFAULT_MIN_AUTO_APPROVE_CONFIDENCE,
+            max_fraud_score_for_approval_bps: AiOracle::DEFAULT_MAX_FRAUD_SCORE,
+            min_confidence_threshold_bps: AiOracle::DEFAULT_MIN_CONFIDENCE,
+            is_active: true,
+            max_decision_age: AiOracle::DEFAULT_MAX_DECISION_AGE,
+            bump: 0,
+        };
+        for _ in 0..decisions {
+            oracle.record_decision(&AiDecisionType::AutoApprove);
+        }
+        oracle
+    }
+
+    #[test]
+    fn test_oracle_signer_removal_respects_threshold() {
+        let mut oracle = create_test_oracle(0);
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        oracle.add_signer(a).unwrap();
+        oracle.add_signer(b).unwrap();
+        oracle.set_required_sigs(2).unwrap();
+
+        // 2-of-2: removing either signer would leave the threshold unreachable
+        assert!(oracle.remove_signer(&a).is_err());
+        assert_eq!(oracle.authorized_signers.len(), 2);
+
+        // Threshold can't exceed the signer count either
+        assert!(oracle.set_required_sigs(3).is_err());
+        assert!(oracle.set_required_sigs(0).is_err());
+        assert!(oracle.add_signer(a).is_err());
+    }
+
+    #[test]
+    fn test_rotated_out_oracle_signer_is_unauthorized() {
+        let mut oracle = create_test_oracle(0);
+        let (compromised, replacement) = (Pubkey::new_unique(), Pubkey::new_unique());
+        oracle.add_signer(compromised).unwrap();
+        assert!(oracle.is_authorized_signer(&compromised));
+
+        oracle.add_signer(replacement).unwrap();
+        oracle.remove_signer(&compromised).unwrap();
+
+        assert!(!oracle.is_authorized_signer(&compromised));
+        assert!(oracle.is_authorized_signer(&replacement));
+        assert!(oracle.remove_signer(&compromised).is_err());
+    }
+
+    #[test]
+    fn test_override_lowers_accuracy() {
+        let mut oracle = create_test_oracle(10);
+        assert_eq!(oracle.total_decisions, 10);
+        assert_eq!(oracle.auto_approved, 10);
+        assert_eq!(oracle.accuracy_rate_bps, 10000);
+
+        oracle.record_overturn();
+        assert_eq!(oracle.decisions_overturned, 1);
+        assert_eq!(oracle.accuracy_rate_bps, 9000);
+
+        oracle.record_overturn();
+        assert_eq!(oracle.accuracy_rate_bps, 8000);
+    }
+
+    #[test]
+    fn test_new_decisions_recover_accuracy() {
+        use crate::instructions::ai_processing::AiDecisionType;
+
+        let mut oracle = create_test_oracle(4);
+        oracle.record_overturn();
+        assert_eq!(oracle.accuracy_rate_bps, 7500);
+
+        oracle.record_decision(&AiDecisionType::AutoDeny {
+            reason: String::new(),
+        });
+        assert_eq!(oracle.auto_denied, 1);
+        assert_eq!(oracle.accuracy_rate_bps, 8000);
+    }
+
+    #[test]
+    fn test_replaced_stale_decision_counted_once() {
+        use crate::instructions::ai_processing::AiDecisionType;
+
+        let mut oracle = create_test_oracle(3);
+        oracle.retract_decision(&AiDecisionType::AutoApprove);
+        oracle.record_decision(&AiDecisionType::CommitteeReview);
+
+        assert_eq!(oracle.total_decisions, 3);
+        assert_eq!(oracle.auto_approved, 2);
+        assert_eq!(oracle.escalated_to_committee, 1);
+    }
+
+    // =========================================================================
+    // FRAUD HOLD TESTS
+    // =========================================================================
+
+    #[test]
+    fn test_flagged_claim_blocked_without_override() {
+        use crate::instructions::ai_processing::AiDecision;
+
+        let mut fraud_flags = FraudFlags::default();
+        fraud_flags.raise(AiFlag::HighPrice);
+        fraud_flags.raise(AiFlag::PossibleDuplicate);
+        let decision = AiDecision {
+            fraud_flags,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            decision.critical_flags(),
+            FraudFlags {
+                duplicate_claim: true,
+                ..Default::default()
+            }
+        );
+        assert!(decision.blocks_payout(false));
+    }
+
+    #[test]
+    fn test_flagged_claim_paid_once_overridden() {
+        use crate::instructions::ai_processing::AiDecision;
+
+        let flagged = AiDecision {
+            fraud_flags: FraudFlags {
+                provider_flagged: true,
+                document_issues: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        assert!(!flagged.blocks_payout(true));
+
+        // Non-critical flags never hold payout
+        let mut fraud_flags = FraudFlags::default();
+        fraud_flags.raise(AiFlag::HighPrice);
+        fraud_flags.raise(AiFlag::NewMember);
+        let minor = AiDecision {
+            fraud_flags,
+            ..Default::default()
+        };
+        assert!(!minor.blocks_payout(false));
+    }
+
+    // =========================================================================
+    // FAST-LANE CONFIG TESTS
+    // =========================================================================
+
+    #[test]
+    fn test_removing_prescription_blocks_fast_lane() {
+        use crate::instructions::ai_processing::FastLaneConfig;
+
+        let mut config = FastLaneConfig::default();
+        assert!(config.is_eligible_category(&ClaimCategory::Prescription));
+
+        config
+            .eligible_categories
+            .retain(|c| *c != ClaimCategory::Prescription);
+        assert!(FastLaneConfig::validate(
+            config.max_amount,
+            &config.eligible_categories,
+            config.max_per_member_per_month,
+        ));
+
+        assert!(!config.is_eligible_category(&ClaimCategory::Prescription));
+        assert!(config.is_eligible_category(&ClaimCategory::PrimaryCare));
+    }
+
+    #[test]
+    fn test_fast_lane_limit_follows_bootstrap_mode() {
+        use crate::instructions::ai_processing::FastLaneConfig;
+
+        let config = FastLaneConfig {
+            max_amount: 2_000_000_000, // $2,000 configured
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.effective_max_amount(ClaimCategory::PrimaryCare, true),
+            ClaimsConfig::BOOTSTRAP_AUTO_APPROVE
+        );
+        assert_eq!(
+            config.effective_max_amount(ClaimCategory::PrimaryCare, false),
+            ClaimsConfig::DEFAULT_AUTO_APPROVE
+        );
+    }
+
+    #[test]
+    fn test_fast_lane_cap_is_per_category() {
+        use crate::instructions::ai_processing::FastLaneConfig;
+
+        let config = FastLaneConfig::default();
+        let amount = 400_000_000; // $400
+
+        // Prescriptions are capped at $250, primary care uses the $500 default
+        assert_eq!(
+            config.effective_max_amount(ClaimCategory::Prescription, false),
+            250_000_000
+        );
+        assert!(amount > config.effective_max_amount(ClaimCategory::Prescription, false));
+        assert!(amount <= config.effective_max_amount(ClaimCategory::PrimaryCare, false));
+    }
+
+    #[test]
+    fn test_fast_lane_category_cap_still_bounded_by_auto_approve() {
+        use crate::instructions::ai_processing::FastLaneConfig;
+
+        let mut config = FastLaneConfig::default();
+        config.category_max_amounts[ClaimCategory::DiagnosticImaging.index()] = 5_000_000_000;
+
+        assert_eq!(
+            config.max_amount_for(ClaimCategory::DiagnosticImaging),
+            5_000_000_000
+        );
+        assert_eq!(
+            config.effective_max_amount(ClaimCategory::DiagnosticImaging, true),
+            ClaimsConfig::BOOTSTRAP_AUTO_APPROVE
+        );
+        // Categories without an override fall back to the default cap
+        assert_eq!(
+            config.max_amount_for(ClaimCategory::Preventive),
+            500_000_000
+        );
+    }
+
+    #[test]
+    fn test_fast_lane_config_validation() {
+        use crate::instructions::ai_processing::FastLaneConfig;
+
+        let categories = [ClaimCategory::PrimaryCare, ClaimCategory::Laboratory];
+        assert!(FastLaneConfig::validate(500_000_000, &categories, 5));
+        assert!(!FastLaneConfig::validate(0, &categories, 5));
+        assert!(!FastLaneConfig::validate(500_000_000, &categories, 0));
+
+        let duplicated = [ClaimCategory::Laboratory, ClaimCategory::Laboratory];
+        assert!(!FastLaneConfig::validate(500_000_000, &duplicated, 5));
+
+        // An empty list is allowed and disables fast-lane for every category
+        assert!(FastLaneConfig::validate(500_000_000, &[], 5));
     }
 
     #[test]
@@ -0,0 +1,75 @@
+// programs/apollo_core/src/doc_ref.rs
+//
+// Off-chain Document References
+// =============================
+//
+// Accounts keep a 32-byte content hash for supporting documents (treaty
+// notes, recovery documentation, dispute resolutions). A bare hash does not
+// say where the document lives, so `DocRef` tags it with the storage system
+// and a content type that off-chain retrievers use to fetch and decode it.
+
+use anchor_lang::prelude::*;
+
+/// Storage system a document reference points into
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace,
+)]
+#[repr(u8)]
+pub enum StorageKind {
+    /// Hash recorded before storage was tracked; location unknown
+    #[default]
+    Legacy = 0,
+    /// IPFS (hash is the CID's sha2-256 multihash digest)
+    Ipfs = 1,
+    /// Arweave (hash is the transaction ID)
+    Arweave = 2,
+}
+
+/// Content type tags for `DocRef::content_type`
+pub mod content_type {
+    pub const UNSPECIFIED: u8 = 0;
+    pub const JSON: u8 = 1;
+    pub const PDF: u8 = 2;
+    pub const IMAGE: u8 = 3;
+    /// HL7 FHIR bundle (claims, EOBs)
+    pub const FHIR: u8 = 4;
+}
+
+/// Hash of an off-chain document plus where and how to fetch it
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default, InitSpace,
+)]
+pub struct DocRef {
+    pub hash: [u8; 32],
+    pub storage: StorageKind,
+    /// One of the `content_type` tags
+    pub content_type: u8,
+}
+
+impl DocRef {
+    pub const SIZE: usize = 32 + 1 + 1;
+
+    pub fn new(hash: [u8; 32], storage: StorageKind, content_type: u8) -> Self {
+        Self {
+            hash,
+            storage,
+            content_type,
+        }
+    }
+
+    /// Wrap a bare hash from before storage was tracked
+    pub fn legacy(hash: [u8; 32]) -> Self {
+        Self::new(hash, StorageKind::Legacy, content_type::UNSPECIFIED)
+    }
+
+    /// No document attached
+    pub fn is_empty(&self) -> bool {
+        self.hash == [0u8; 32]
+    }
+}
+
+impl From<[u8; 32]> for DocRef {
+    fn from(hash: [u8; 32]) -> Self {
+        Self::legacy(hash)
+    }
+}
@@ -7,7 +7,7 @@
 // - APH Token-2022 mint address and configuration
 // - Token allocation constants per tokenomics
 // - Transfer fee handling utilities
-// - Cross-program shared types
+// - Cross-program shared types (incl. off-chain document references)
 // - Protocol phase tracking (Phase 1/2/3 transitions)
 //
 // APH Token: 6S3T6f1mmhxQWKR1gMiZR1SZLpu396jnnRMGqAZUj3Qj (Token-2022)
@@ -24,12 +24,17 @@ declare_id!("DHGQUHAXRvEiHA4J3JhxKdSLkqvyKPyZYaciLfMA5yok");
 /// Protocol phase tracking (Phase 1→2→3 transitions)
 pub mod phase;
 
+/// Off-chain document references (hash + storage system)
+pub mod doc_ref;
+
 // Re-export phase types for convenience
 pub use phase::{
     Phase1To2Requirements, Phase2To3Requirements, PhaseComplianceFlags, ProtocolPhase,
     ProtocolPhaseState,
 };
 
+pub use doc_ref::{DocRef, StorageKind};
+
 // =============================================================================
 // APH TOKEN-2022 CONFIGURATION
 // =============================================================================
@@ -345,6 +350,101 @@ impl AphTokenConfig {
     }
 }
 
+/// Protocol-wide bootstrap mode flag, read by claims, reserves and membership
+/// PDA seeds: ["bootstrap_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct BootstrapConfig {
+    /// Authority that can exit bootstrap mode (DAO)
+    pub authority: Pubkey,
+
+    /// Conservative bootstrap thresholds are in force
+    pub bootstrap_active: bool,
+
+    /// Timestamp bootstrap mode was entered
+    pub activated_at: i64,
+
+    /// Timestamp bootstrap mode was exited (0 while active)
+    pub exited_at: i64,
+
+    /// Bump for PDA
+    pub bump: u8,
+}
+
+impl BootstrapConfig {
+    pub const SEED_PREFIX: &'static [u8] = b"bootstrap_config";
+
+    /// Whether one more enrollment this month fits the bootstrap cap, given
+    /// the month's count including that enrollment
+    pub fn permits_monthly_enrollments(&self, month_enrollments: u32) -> bool {
+        !self.bootstrap_active || month_enrollments <= bootstrap::BOOTSTRAP_ENROLLMENT_CAP
+    }
+}
+
+/// Reserves program ID. Not a crate dependency (reserves depends on core),
+/// so reserve state is read through `ReserveCapital`.
+pub const RESERVES_PROGRAM_ID: Pubkey = pubkey!("4kZvvmDi1d9xKFzB7i7qK2psgFnbpmNfNTPJEnyAsUhh");
+
+/// Membership program ID. Not a crate dependency (membership depends on
+/// core), so the global config is read through `MembershipCount`.
+pub const MEMBERSHIP_PROGRAM_ID: Pubkey = pubkey!("CHJ4Bdc9wqKy6pjSiC3URjs53iDQpn58MPeAgLQVqRW1");
+
+/// Claims-paying capital view of the reserves program's `ReserveState`
+/// PDA seeds (reserves program): ["reserve_state"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReserveCapital {
+    pub tier0_balance: u64,
+    pub tier1_balance: u64,
+    pub tier2_balance: u64,
+}
+
+impl ReserveCapital {
+    pub const SEED_PREFIX: &'static [u8] = b"reserve_state";
+
+    /// Read the tier balances from account data. The layout is the
+    /// `ReserveState` prefix: discriminator, tier0, tier1, tier2.
+    pub fn from_account_data(data: &[u8]) -> Option<Self> {
+        let read_u64 = |at: usize| -> Option<u64> {
+            Some(u64::from_le_bytes(data.get(at..at + 8)?.try_into().ok()?))
+        };
+
+        Some(Self {
+            tier0_balance: read_u64(8)?,
+            tier1_balance: read_u64(16)?,
+            tier2_balance: read_u64(24)?,
+        })
+    }
+
+    /// Tier 0-2 balances (run-off reserve excluded)
+    pub fn total(&self) -> u64 {
+        self.tier0_balance
+            .saturating_add(self.tier1_balance)
+            .saturating_add(self.tier2_balance)
+    }
+}
+
+/// Member count view of the membership program's `GlobalConfig`
+/// PDA seeds (membership program): ["global_config"]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MembershipCount {
+    /// Members enrolled to date
+    pub total_members: u64,
+}
+
+impl MembershipCount {
+    pub const SEED_PREFIX: &'static [u8] = b"global_config";
+
+    /// Read the member count from account data. The layout is the
+    /// `GlobalConfig` prefix: discriminator, five program/mint keys,
+    /// total_members.
+    pub fn from_account_data(data: &[u8]) -> Option<Self> {
+        let at = 8 + 5 * 32;
+        Some(Self {
+            total_members: u64::from_le_bytes(data.get(at..at + 8)?.try_into().ok()?),
+        })
+    }
+}
+
 // =============================================================================
 // VESTING SCHEDULE STRUCTURES
 // =============================================================================
@@ -794,6 +894,11 @@ pub mod bootstrap {
     /// Minimum members to exit bootstrap mode
     pub const BOOTSTRAP_EXIT_MEMBERS: u32 = 500;
 
+    /// Whether capital and membership are large enough to leave bootstrap mode
+    pub fn meets_exit_criteria(total_capital: u64, total_members: u32) -> bool {
+        total_capital >= BOOTSTRAP_EXIT_CAPITAL && total_members >= BOOTSTRAP_EXIT_MEMBERS
+    }
+
     /// Calculate enrollment capacity based on reserve coverage
     /// Returns maximum new members per month
     pub fn calculate_enrollment_capacity(
@@ -836,6 +941,17 @@ pub mod protocol_constants {
 
     /// Re-export BPS_DENOMINATOR for convenience
     pub const BPS_DENOMINATOR: u64 = super::actuarial::BPS_DENOMINATOR;
+
+    /// `numerator / denominator` in basis points, widened to u128 so the
+    /// multiply cannot overflow. A zero denominator yields 0 and results
+    /// above u16::MAX (655.35%) are capped.
+    pub fn ratio_bps(numerator: u64, denominator: u64) -> u16 {
+        if denominator == 0 {
+            return 0;
+        }
+        (numerator as u128 * BPS_DENOMINATOR as u128 / denominator as u128).min(u16::MAX as u128)
+            as u16
+    }
 }
 
 // =============================================================================
@@ -878,6 +994,21 @@ pub struct VestingClaimed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BootstrapConfigInitialized {
+    pub authority: Pubkey,
+    pub bootstrap_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BootstrapExited {
+    pub total_capital: u64,
+    pub total_members: u32,
+    pub exited_by: Pubkey,
+    pub timestamp: i64,
+}
+
 // =============================================================================
 // ERRORS
 // =============================================================================
@@ -907,6 +1038,15 @@ pub enum ApolloError {
 
     #[msg("Token-2022 operation failed")]
     Token2022Error,
+
+    #[msg("Bootstrap mode is not active")]
+    BootstrapNotActive,
+
+    #[msg("Capital or membership below bootstrap exit requirements")]
+    BootstrapExitCriteriaNotMet,
+
+    #[msg("Account is not readable as the expected protocol state")]
+    InvalidProtocolState,
 }
 
 // =============================================================================
@@ -971,6 +1111,61 @@ pub mod apollo_core {
 
         Ok(())
     }
+
+    /// Initialize the bootstrap config (protocol starts in bootstrap mode)
+    pub fn initialize_bootstrap_config(ctx: Context<InitializeBootstrapConfig>) -> Result<()> {
+        let config = &mut ctx.accounts.bootstrap_config;
+        let clock = Clock::get()?;
+
+        config.authority = ctx.accounts.authority.key();
+        config.bootstrap_active = true;
+        config.activated_at = clock.unix_timestamp;
+        config.exited_at = 0;
+        config.bump = ctx.bumps.bootstrap_config;
+
+        emit!(BootstrapConfigInitialized {
+            authority: config.authority,
+            bootstrap_active: true,
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    /// Leave bootstrap mode once reserve capital and membership reach exit
+    /// levels, read from the reserves and membership program state
+    pub fn exit_bootstrap(ctx: Context<ExitBootstrap>) -> Result<()> {
+        let total_capital =
+            ReserveCapital::from_account_data(&ctx.accounts.reserve_state.try_borrow_data()?)
+                .ok_or(ApolloError::InvalidProtocolState)?
+                .total();
+        let total_members =
+            MembershipCount::from_account_data(&ctx.accounts.global_config.try_borrow_data()?)
+                .ok_or(ApolloError::InvalidProtocolState)?
+                .total_members
+                .min(u32::MAX as u64) as u32;
+
+        let config = &mut ctx.accounts.bootstrap_config;
+        let clock = Clock::get()?;
+
+        require!(config.bootstrap_active, ApolloError::BootstrapNotActive);
+        require!(
+            bootstrap::meets_exit_criteria(total_capital, total_members),
+            ApolloError::BootstrapExitCriteriaNotMet
+        );
+
+        config.bootstrap_active = false;
+        config.exited_at = clock.unix_timestamp;
+
+        emit!(BootstrapExited {
+            total_capital,
+            total_members,
+            exited_by: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        Ok(())
+    }
 }
 
 // =============================================================================
@@ -1014,6 +1209,65 @@ pub struct UpdateTransferFeeStatus<'info> {
     pub authority: Signer<'info>,
 }
 
+#[derive(Accounts)]
+pub struct InitializeBootstrapConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BootstrapConfig::INIT_SPACE,
+        seeds = [BootstrapConfig::SEED_PREFIX],
+        bump
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ ApolloError::Unauthorized
+    )]
+    pub program: Program<'info, crate::program::ApolloCore>,
+
+    /// Only the program's upgrade authority may start bootstrap mode
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ApolloError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExitBootstrap<'info> {
+    #[account(
+        mut,
+        seeds = [BootstrapConfig::SEED_PREFIX],
+        bump = bootstrap_config.bump,
+        has_one = authority @ ApolloError::Unauthorized
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+
+    /// CHECK: reserves-program reserve state; read via `ReserveCapital`
+    #[account(
+        seeds = [ReserveCapital::SEED_PREFIX],
+        bump,
+        seeds::program = RESERVES_PROGRAM_ID,
+        owner = RESERVES_PROGRAM_ID @ ApolloError::InvalidProtocolState,
+    )]
+    pub reserve_state: UncheckedAccount<'info>,
+
+    /// CHECK: membership-program global config; read via `MembershipCount`
+    #[account(
+        seeds = [MembershipCount::SEED_PREFIX],
+        bump,
+        seeds::program = MEMBERSHIP_PROGRAM_ID,
+        owner = MEMBERSHIP_PROGRAM_ID @ ApolloError::InvalidProtocolState,
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+}
+
 // =============================================================================
 // CPI HELPERS
 // =============================================================================
@@ -1057,3 +1311,95 @@ pub mod cpi_helpers {
         &[AphTokenConfig::SEED_PREFIX]
     }
 }
+
+// =============================================================================
+// UNIT TESTS
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratio_bps_zero_denominator() {
+        assert_eq!(protocol_constants::ratio_bps(0, 0), 0);
+        assert_eq!(protocol_constants::ratio_bps(1_000_000, 0), 0);
+    }
+
+    #[test]
+    fn test_ratio_bps_exact_and_fractional() {
+        assert_eq!(protocol_constants::ratio_bps(5_000_000, 5_000_000), 10_000);
+        assert_eq!(protocol_constants::ratio_bps(1_125, 1_000), 11_250);
+        assert_eq!(protocol_constants::ratio_bps(1, 3), 3_333); // truncates
+    }
+
+    #[test]
+    fn test_ratio_bps_clamps_overflow() {
+        // 7x is 70,000 bps, above u16::MAX
+        assert_eq!(protocol_constants::ratio_bps(7_000, 1_000), u16::MAX);
+        // numerator * 10_000 would overflow u64
+        assert_eq!(protocol_constants::ratio_bps(u64::MAX, u64::MAX), 10_000);
+        assert_eq!(protocol_constants::ratio_bps(u64::MAX, 1), u16::MAX);
+    }
+
+    fn create_bootstrap_config(bootstrap_active: bool) -> BootstrapConfig {
+        BootstrapConfig {
+            authority: Pubkey::new_unique(),
+            bootstrap_active,
+            activated_at: 0,
+            exited_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_enrollment_cap_while_active() {
+        let config = create_bootstrap_config(true);
+        assert!(config.permits_monthly_enrollments(bootstrap::BOOTSTRAP_ENROLLMENT_CAP));
+        assert!(!config.permits_monthly_enrollments(bootstrap::BOOTSTRAP_ENROLLMENT_CAP + 1));
+    }
+
+    #[test]
+    fn test_no_bootstrap_enrollment_cap_after_exit() {
+        let config = create_bootstrap_config(false);
+        assert!(config.permits_monthly_enrollments(bootstrap::BOOTSTRAP_ENROLLMENT_CAP + 1));
+    }
+
+    #[test]
+    fn test_bootstrap_exit_requires_capital_and_members() {
+        use bootstrap::{meets_exit_criteria, BOOTSTRAP_EXIT_CAPITAL, BOOTSTRAP_EXIT_MEMBERS};
+
+        assert!(meets_exit_criteria(
+            BOOTSTRAP_EXIT_CAPITAL,
+            BOOTSTRAP_EXIT_MEMBERS
+        ));
+        assert!(!meets_exit_criteria(
+            BOOTSTRAP_EXIT_CAPITAL - 1,
+            BOOTSTRAP_EXIT_MEMBERS
+        ));
+        assert!(!meets_exit_criteria(
+            BOOTSTRAP_EXIT_CAPITAL,
+            BOOTSTRAP_EXIT_MEMBERS - 1
+        ));
+    }
+
+    #[test]
+    fn test_exit_criteria_read_from_program_state() {
+        let mut reserve_state = vec![0u8; 8];
+        for balance in [500_000_000_000u64, 500_000_000_000, 1_000_000_000_000] {
+            reserve_state.extend_from_slice(&balance.to_le_bytes());
+        }
+        reserve_state.extend_from_slice(&7_000_000_000_000u64.to_le_bytes()); // run-off
+        let capital = ReserveCapital::from_account_data(&reserve_state).unwrap();
+        assert_eq!(capital.total(), bootstrap::BOOTSTRAP_EXIT_CAPITAL);
+
+        let mut global_config = vec![0u8; 8 + 5 * 32];
+        global_config.extend_from_slice(&500u64.to_le_bytes());
+        let members = MembershipCount::from_account_data(&global_config).unwrap();
+        assert_eq!(members.total_members, 500);
+
+        // Truncated accounts are unreadable
+        assert!(ReserveCapital::from_account_data(&reserve_state[..24]).is_none());
+        assert!(MembershipCount::from_account_data(&global_config[..8]).is_none());
+    }
+}
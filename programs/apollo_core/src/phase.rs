@@ -103,6 +103,24 @@ impl ProtocolPhase {
         }
     }
 
+    /// Minimum plan-paid share a benefit schedule may set (bps)
+    pub fn min_coinsurance_bps(&self) -> u16 {
+        match self {
+            ProtocolPhase::Phase1Hcsm => 0,        // Voluntary sharing, no floor
+            ProtocolPhase::Phase2Hybrid => 5000,   // 50% in the sandbox pilot
+            ProtocolPhase::Phase3Licensed => 6000, // 60% (bronze actuarial value)
+        }
+    }
+
+    /// Default smallest claim worth processing (USDC, 6 decimals)
+    pub fn default_min_claim_amount(&self) -> u64 {
+        match self {
+            ProtocolPhase::Phase1Hcsm => 1_000_000,     // $1
+            ProtocolPhase::Phase2Hybrid => 5_000_000,   // $5
+            ProtocolPhase::Phase3Licensed => 5_000_000, // $5
+        }
+    }
+
     /// Whether pre-existing condition exclusions are allowed
     pub fn preexisting_exclusions_allowed(&self) -> bool {
         matches!(self, ProtocolPhase::Phase1Hcsm) // HCSMs can exclude; ACA-compliant phases cannot
@@ -426,6 +444,29 @@ mod tests {
         assert_eq!(ProtocolPhase::Phase3Licensed.min_car_bps(), 20000);
     }
 
+    #[test]
+    fn test_phase_default_min_claim_amount() {
+        assert_eq!(
+            ProtocolPhase::Phase1Hcsm.default_min_claim_amount(),
+            1_000_000
+        );
+        assert_eq!(
+            ProtocolPhase::Phase2Hybrid.default_min_claim_amount(),
+            5_000_000
+        );
+        assert_eq!(
+            ProtocolPhase::Phase3Licensed.default_min_claim_amount(),
+            5_000_000
+        );
+    }
+
+    #[test]
+    fn test_phase_min_coinsurance() {
+        assert_eq!(ProtocolPhase::Phase1Hcsm.min_coinsurance_bps(), 0);
+        assert_eq!(ProtocolPhase::Phase2Hybrid.min_coinsurance_bps(), 5000);
+        assert_eq!(ProtocolPhase::Phase3Licensed.min_coinsurance_bps(), 6000);
+    }
+
     #[test]
     fn test_phase_transitions() {
         let phase1 = ProtocolPhase::Phase1Hcsm;
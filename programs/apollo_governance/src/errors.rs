@@ -84,4 +84,10 @@ pub enum GovernanceError {
 
     #[msg("Invalid expiration time")]
     InvalidExpiration,
+
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthorityTransfer,
+
+    #[msg("Pending authority must be a new, non-default key")]
+    InvalidPendingAuthority,
 }
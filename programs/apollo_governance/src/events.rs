@@ -109,6 +109,30 @@ pub struct CommitteeUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when the DAO authority proposes a successor
+#[event]
+pub struct AuthorityTransferProposed {
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the pending authority accepts and takes over
+#[event]
+pub struct AuthorityTransferAccepted {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a pending authority transfer is withdrawn
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub cancelled_authority: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when a proposal is created (stub)
 #[event]
 pub struct ProposalCreated {
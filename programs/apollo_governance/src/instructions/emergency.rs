@@ -2,6 +2,7 @@
 
 use crate::errors::GovernanceError;
 use crate::events::{
+    AuthorityTransferAccepted, AuthorityTransferCancelled, AuthorityTransferProposed,
     CommitteeUpdated, EmergencyActivated, EmergencyDeactivated, ProtocolPaused, ProtocolUnpaused,
 };
 use crate::state::{CommitteeType, DaoConfig, Multisig};
@@ -244,9 +245,9 @@ pub fn update_committee(
     Ok(())
 }
 
-/// Update DAO authority (transfer ownership)
+/// Propose a new DAO authority (step one of the ownership transfer)
 #[derive(Accounts)]
-pub struct UpdateAuthority<'info> {
+pub struct ProposeAuthorityTransfer<'info> {
     #[account(
         mut,
         seeds = [DaoConfig::SEED_PREFIX],
@@ -259,13 +260,81 @@ pub struct UpdateAuthority<'info> {
     )]
     pub current_authority: Signer<'info>,
 
-    /// CHECK: New authority doesn't need to sign
+    /// CHECK: Proposed authority doesn't sign until it accepts
     pub new_authority: UncheckedAccount<'info>,
 }
 
-pub fn update_authority(ctx: Context<UpdateAuthority>) -> Result<()> {
+pub fn propose_authority_transfer(ctx: Context<ProposeAuthorityTransfer>) -> Result<()> {
     let dao_config = &mut ctx.accounts.dao_config;
-    dao_config.authority = ctx.accounts.new_authority.key();
+    let clock = Clock::get()?;
+
+    dao_config.propose_authority(ctx.accounts.new_authority.key())?;
+
+    emit!(AuthorityTransferProposed {
+        current_authority: dao_config.authority,
+        pending_authority: dao_config.pending_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Accept a pending DAO authority transfer (step two, signed by the new key)
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    pub pending_authority: Signer<'info>,
+}
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let dao_config = &mut ctx.accounts.dao_config;
+    let clock = Clock::get()?;
+
+    let old_authority = dao_config.accept_authority(&ctx.accounts.pending_authority.key())?;
+
+    emit!(AuthorityTransferAccepted {
+        old_authority,
+        new_authority: dao_config.authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Withdraw a pending DAO authority transfer
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    #[account(
+        mut,
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        constraint = authority.key() == dao_config.authority @ GovernanceError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let dao_config = &mut ctx.accounts.dao_config;
+    let clock = Clock::get()?;
+
+    let cancelled_authority = dao_config.cancel_authority_transfer()?;
+
+    emit!(AuthorityTransferCancelled {
+        authority: dao_config.authority,
+        cancelled_authority,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -50,6 +50,7 @@ pub fn handler(ctx: Context<InitializeDao>, params: InitializeDaoParams) -> Resu
     dao_config.proposal_count = 0;
     dao_config.protocol_paused = false;
     dao_config.bump = ctx.bumps.dao_config;
+    dao_config.pending_authority = Pubkey::default();
     dao_config.reserved = vec![];
 
     emit!(DaoInitialized {
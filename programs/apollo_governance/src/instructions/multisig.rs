@@ -389,7 +389,7 @@ pub fn assert_signed(ctx: Context<AssertSigned>, expected_action: AdminAction) -
         GovernanceError::ActionTypeMismatch
     );
     require!(
-        signer_set.approval_count() >= multisig.threshold as usize,
+        signer_set.has_quorum(multisig),
         GovernanceError::InsufficientSignatures
     );
 
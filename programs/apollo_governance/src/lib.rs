@@ -114,9 +114,19 @@ pub mod apollo_governance {
         instructions::emergency::update_committee(ctx, committee_type, new_address)
     }
 
-    /// Transfer DAO authority to a new address
-    pub fn update_authority(ctx: Context<UpdateAuthority>) -> Result<()> {
-        instructions::emergency::update_authority(ctx)
+    /// Propose a new DAO authority (takes effect once it accepts)
+    pub fn propose_authority_transfer(ctx: Context<ProposeAuthorityTransfer>) -> Result<()> {
+        instructions::emergency::propose_authority_transfer(ctx)
+    }
+
+    /// Accept a pending DAO authority transfer (signed by the pending key)
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::emergency::accept_authority(ctx)
+    }
+
+    /// Cancel a pending DAO authority transfer
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::emergency::cancel_authority_transfer(ctx)
     }
 
     /// Update the maximum emergency duration
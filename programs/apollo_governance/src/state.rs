@@ -1,5 +1,6 @@
 // programs/apollo_governance/src/state.rs
 
+use crate::errors::GovernanceError;
 use anchor_lang::prelude::*;
 
 /// Global DAO configuration account
@@ -40,8 +41,13 @@ pub struct DaoConfig {
     /// Bump seed for PDA
     pub bump: u8,
 
+    /// Proposed new authority awaiting acceptance (default if none).
+    /// Carved from the front of `reserved`: existing accounts hold zeroes
+    /// here, which read back as no pending authority.
+    pub pending_authority: Pubkey,
+
     /// Reserved space for future upgrades
-    #[max_len(64)]
+    #[max_len(32)]
     pub reserved: Vec<u8>,
 }
 
@@ -54,6 +60,60 @@ impl DaoConfig {
         }
         current_time > self.emergency_activated_at + self.max_emergency_duration
     }
+
+    /// Multisig address registered for a committee (None for the DAO itself)
+    pub fn committee(&self, committee_type: CommitteeType) -> Option<Pubkey> {
+        match committee_type {
+            CommitteeType::Risk => Some(self.risk_committee),
+            CommitteeType::Actuarial => Some(self.actuarial_committee),
+            CommitteeType::Claims => Some(self.claims_committee),
+            CommitteeType::Treasury => Some(self.treasury_committee),
+            CommitteeType::Dao => None,
+        }
+    }
+
+    pub fn has_pending_authority(&self) -> bool {
+        self.pending_authority != Pubkey::default()
+    }
+
+    /// Stage `new_authority`; it only takes effect once that key accepts
+    pub fn propose_authority(&mut self, new_authority: Pubkey) -> Result<()> {
+        require!(
+            new_authority != Pubkey::default() && new_authority != self.authority,
+            GovernanceError::InvalidPendingAuthority
+        );
+        self.pending_authority = new_authority;
+        Ok(())
+    }
+
+    /// Finalize the transfer if `signer` is the pending authority.
+    /// Returns the previous authority.
+    pub fn accept_authority(&mut self, signer: &Pubkey) -> Result<Pubkey> {
+        require!(
+            self.has_pending_authority(),
+            GovernanceError::NoPendingAuthorityTransfer
+        );
+        require_keys_eq!(
+            *signer,
+            self.pending_authority,
+            GovernanceError::Unauthorized
+        );
+        let old_authority = self.authority;
+        self.authority = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+        Ok(old_authority)
+    }
+
+    /// Drop the pending transfer. Returns the key that was pending.
+    pub fn cancel_authority_transfer(&mut self) -> Result<Pubkey> {
+        require!(
+            self.has_pending_authority(),
+            GovernanceError::NoPendingAuthorityTransfer
+        );
+        let cancelled = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+        Ok(cancelled)
+    }
 }
 
 /// Multisig account for committee-based authorization
@@ -163,6 +223,12 @@ impl SignerSet {
     pub fn approval_count(&self) -> usize {
         self.approvals.len()
     }
+
+    /// Whether approvals from current members of `multisig` reach its
+    /// threshold. Approvals from since-removed signers do not count.
+    pub fn has_quorum(&self, multisig: &Multisig) -> bool {
+        multisig.verify_signatures(&self.approvals)
+    }
 }
 
 /// Stub proposal account for future full governance
@@ -258,6 +324,14 @@ pub enum AdminAction {
     OpenEnrollmentWindow,
     CloseEnrollmentWindow,
     SetQualifyingEvent,
+
+    // Reserve treasury actions
+    RefillReserveTier,
+    SweepAdminVault,
+    RequestEmergencyCapital,
+
+    // Reserve routing actions
+    SetRoutingRatios,
 }
 
 /// Proposal types for future governance
@@ -292,3 +366,153 @@ pub enum CommitteeType {
     Treasury,
     Dao,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_dao_config(authority: Pubkey) -> DaoConfig {
+        DaoConfig {
+            authority,
+            risk_committee: Pubkey::new_unique(),
+            actuarial_committee: Pubkey::new_unique(),
+            claims_committee: Pubkey::new_unique(),
+            treasury_committee: Pubkey::new_unique(),
+            emergency_active: false,
+            emergency_activated_at: 0,
+            max_emergency_duration: 48 * 3600,
+            proposal_count: 0,
+            protocol_paused: false,
+            bump: 255,
+            pending_authority: Pubkey::default(),
+            reserved: vec![],
+        }
+    }
+
+    fn create_test_multisig(signers: Vec<Pubkey>, threshold: u8) -> Multisig {
+        Multisig {
+            name: "actuarial_committee".to_string(),
+            threshold,
+            signer_count: signers.len() as u8,
+            max_signers: Multisig::MAX_SIGNERS,
+            signers,
+            transaction_count: 0,
+            owner: Pubkey::new_unique(),
+            created_at: 0,
+            is_active: true,
+            bump: 255,
+        }
+    }
+
+    fn create_test_signer_set(multisig: Pubkey, approvals: Vec<Pubkey>) -> SignerSet {
+        SignerSet {
+            multisig,
+            action_id: 1,
+            action_type: AdminAction::SetReserveTargets,
+            target: Pubkey::new_unique(),
+            approvals,
+            executed: false,
+            created_at: 0,
+            expires_at: SignerSet::DEFAULT_EXPIRY,
+            action_data: vec![],
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_committee_quorum_requires_threshold() {
+        let members: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let committee = create_test_multisig(members.clone(), 2);
+
+        let one = create_test_signer_set(Pubkey::new_unique(), vec![members[0]]);
+        assert!(!one.has_quorum(&committee));
+
+        let two = create_test_signer_set(Pubkey::new_unique(), vec![members[0], members[2]]);
+        assert!(two.has_quorum(&committee));
+    }
+
+    #[test]
+    fn test_committee_quorum_ignores_non_members() {
+        let members: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let mut committee = create_test_multisig(members.clone(), 2);
+
+        let set =
+            create_test_signer_set(Pubkey::new_unique(), vec![members[0], Pubkey::new_unique()]);
+        assert!(!set.has_quorum(&committee));
+
+        // A signer removed after approving no longer counts toward quorum
+        let set = create_test_signer_set(Pubkey::new_unique(), vec![members[0], members[1]]);
+        assert!(set.has_quorum(&committee));
+        committee.signers.retain(|s| s != &members[1]);
+        assert!(!set.has_quorum(&committee));
+    }
+
+    #[test]
+    fn test_committee_lookup_by_type() {
+        let config = create_test_dao_config(Pubkey::new_unique());
+        assert_eq!(
+            config.committee(CommitteeType::Actuarial),
+            Some(config.actuarial_committee)
+        );
+        assert_eq!(
+            config.committee(CommitteeType::Risk),
+            Some(config.risk_committee)
+        );
+        assert_eq!(config.committee(CommitteeType::Dao), None);
+    }
+
+    #[test]
+    fn test_authority_changes_only_after_acceptance() {
+        let current = Pubkey::new_unique();
+        let successor = Pubkey::new_unique();
+        let mut config = create_test_dao_config(current);
+
+        config.propose_authority(successor).unwrap();
+        assert_eq!(config.authority, current);
+        assert_eq!(config.pending_authority, successor);
+
+        assert_eq!(config.accept_authority(&successor).unwrap(), current);
+        assert_eq!(config.authority, successor);
+        assert!(!config.has_pending_authority());
+    }
+
+    #[test]
+    fn test_pending_authority_reads_from_legacy_reserved() {
+        let config = create_test_dao_config(Pubkey::new_unique());
+        let serialized = config.try_to_vec().unwrap();
+
+        // Legacy layout: bump, then an empty 64-byte reserved vec
+        let head = serialized.len() - 32 - 4;
+        let mut legacy = serialized[..head].to_vec();
+        legacy.extend_from_slice(&0u32.to_le_bytes());
+        legacy.extend_from_slice(&[0u8; 64]);
+
+        let decoded = DaoConfig::deserialize(&mut &legacy[..]).unwrap();
+        assert_eq!(decoded.authority, config.authority);
+        assert_eq!(decoded.bump, 255);
+        assert!(!decoded.has_pending_authority());
+        assert!(decoded.reserved.is_empty());
+        // Account size is unchanged
+        assert_eq!(DaoConfig::INIT_SPACE, legacy.len());
+    }
+
+    #[test]
+    fn test_wrong_key_cannot_accept_authority() {
+        let current = Pubkey::new_unique();
+        let successor = Pubkey::new_unique();
+        let mut config = create_test_dao_config(current);
+
+        // Nothing to accept before a proposal
+        assert!(config.accept_authority(&successor).is_err());
+
+        config.propose_authority(successor).unwrap();
+        assert!(config.accept_authority(&Pubkey::new_unique()).is_err());
+        assert!(config.accept_authority(&current).is_err());
+        assert_eq!(config.authority, current);
+
+        // Once cancelled, the proposed key can no longer accept
+        assert_eq!(config.cancel_authority_transfer().unwrap(), successor);
+        assert!(config.accept_authority(&successor).is_err());
+        assert_eq!(config.authority, current);
+    }
+}
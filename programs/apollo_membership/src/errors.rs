@@ -84,4 +84,46 @@ pub enum MembershipError {
 
     #[msg("Maximum persistency discount reached")]
     MaxPersistencyReached,
+
+    #[msg("Maximum number of dependents reached")]
+    MaxDependentsReached,
+
+    #[msg("A spouse or partner is already covered")]
+    SpouseAlreadyCovered,
+
+    #[msg("Dependent is not covered")]
+    DependentNotCovered,
+
+    #[msg("Payment is not overdue")]
+    PaymentNotOverdue,
+
+    #[msg("Member is within the payment grace period")]
+    GracePeriodActive,
+
+    #[msg("Plan year renewal is not due yet")]
+    RenewalNotDue,
+
+    #[msg("Protocol is in run-off; new enrollment is closed")]
+    ProtocolInRunoff,
+
+    #[msg("Persistency discount budget exhausted for this year")]
+    PersistencyBudgetExhausted,
+
+    #[msg("Protocol member cap reached")]
+    MemberCapReached,
+
+    #[msg("Enrollment window overlaps an existing window of the same type")]
+    EnrollmentWindowOverlap,
+
+    #[msg("Qualifying event date cannot be in the future")]
+    InvalidQualifyingEventDate,
+
+    #[msg("Coverage has not been terminated")]
+    CoverageNotTerminated,
+
+    #[msg("No refund is pending")]
+    NoRefundPending,
+
+    #[msg("The covered spouse or partner's account is required")]
+    SpouseAccountRequired,
 }
@@ -1,6 +1,6 @@
 // programs/apollo_membership/src/events.rs
 
-use crate::state::{MemberStatus, QualifyingEvent};
+use crate::state::{CoverageTier, DependentRelationship, MemberStatus, QualifyingEvent};
 use anchor_lang::prelude::*;
 
 /// Emitted when global config is initialized
@@ -19,6 +19,10 @@ pub struct EnrollmentWindowOpened {
     pub end_time: i64,
     pub max_enrollments: u32,
     pub is_special: bool,
+    /// Requested capacity before the zone cap
+    pub requested_enrollments: u32,
+    /// Zone monthly enrollment cap when the window opened
+    pub monthly_zone_cap: u32,
     pub timestamp: i64,
 }
 
@@ -39,6 +43,28 @@ pub struct MemberEnrolled {
     pub region_code: u8,
     pub monthly_contribution: u64,
     pub waiting_period_ends: i64,
+    pub remaining_monthly_capacity: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when a dependent is added under a subscriber
+#[event]
+pub struct DependentAdded {
+    pub subscriber: Pubkey,
+    pub index: u8,
+    pub relationship: DependentRelationship,
+    pub age: u8,
+    pub coverage_tier: CoverageTier,
+    pub timestamp: i64,
+}
+
+/// Emitted when a dependent is removed from coverage
+#[event]
+pub struct DependentRemoved {
+    pub subscriber: Pubkey,
+    pub index: u8,
+    pub relationship: DependentRelationship,
+    pub coverage_tier: CoverageTier,
     pub timestamp: i64,
 }
 
@@ -50,13 +76,17 @@ pub struct CoverageActivated {
     pub timestamp: i64,
 }
 
-/// Emitted when a contribution is deposited
+/// Emitted when a contribution is deposited and routed to the reserve vaults
 #[event]
 pub struct ContributionDeposited {
     pub member: Pubkey,
     pub amount: u64,
     pub total_contributions: u64,
     pub next_payment_due: i64,
+    pub to_tier0: u64,
+    pub to_tier1: u64,
+    pub to_tier2: u64,
+    pub to_admin: u64,
     pub timestamp: i64,
 }
 
@@ -78,13 +108,49 @@ pub struct QualifyingEventSet {
     pub timestamp: i64,
 }
 
+/// Emitted when the authority records a prospect's qualifying life event
+#[event]
+pub struct QualifyingEventRecorded {
+    pub prospect: Pubkey,
+    pub event_type: QualifyingEvent,
+    pub event_at: i64,
+    pub recorded_by: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when persistency discount is applied
 #[event]
 pub struct PersistencyDiscountApplied {
     pub member: Pubkey,
     pub consecutive_months: u16,
     pub discount_bps: u16,
+    /// Monthly discount off the undiscounted contribution
+    pub discount_amount: u64,
     pub new_contribution: u64,
+    /// Annualized cost of the newly granted discount
+    pub annual_cost: u64,
+    /// Discount budget left this budget year (None if unbudgeted)
+    pub budget_remaining: Option<u64>,
+    pub timestamp: i64,
+    /// Continuous months of paid coverage the discount was priced on
+    pub continuous_months: u16,
+}
+
+/// Emitted when the scaled member cap changes
+#[event]
+pub struct MaxMembersUpdated {
+    pub old_max: u32,
+    pub new_max: u32,
+    pub updater: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the annual persistency discount budget changes
+#[event]
+pub struct PersistencyBudgetUpdated {
+    pub old_budget: u64,
+    pub new_budget: u64,
+    pub updater: Pubkey,
     pub timestamp: i64,
 }
 
@@ -104,6 +170,24 @@ pub struct MemberTerminated {
     pub timestamp: i64,
 }
 
+/// Emitted when coverage is terminated, with any refund queued for payout
+#[event]
+pub struct CoverageTerminatedWithRefund {
+    pub member: Pubkey,
+    pub refund_amount: u64,
+    pub pending_refund: u64,
+    pub refunds_enabled: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a terminated member's pending refund is paid out
+#[event]
+pub struct RefundPaid {
+    pub member: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when member info is updated
 #[event]
 pub struct MemberInfoUpdated {
@@ -111,3 +195,16 @@ pub struct MemberInfoUpdated {
     pub field: String,
     pub timestamp: i64,
 }
+
+/// Emitted when a member renews into a new plan year
+#[event]
+pub struct MemberRenewed {
+    pub member: Pubkey,
+    pub plan_year: u16,
+    pub plan_year_start: i64,
+    pub previous_contribution: u64,
+    pub quoted_contribution: u64,
+    pub persistency_discount_bps: u16,
+    pub new_contribution: u64,
+    pub timestamp: i64,
+}
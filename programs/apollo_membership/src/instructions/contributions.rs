@@ -2,9 +2,10 @@
 
 use crate::errors::MembershipError;
 use crate::events::{ContributionDeposited, PersistencyDiscountApplied};
-use crate::state::{ContributionLedger, GlobalConfig, MemberAccount};
+use crate::state::{ContributionLedger, GlobalConfig, MemberAccount, PaymentState};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
+use anchor_spl::token::{Token, TokenAccount};
+use apollo_reserves::program::ApolloReserves;
 
 /// Deposit a contribution
 #[derive(Accounts)]
@@ -39,36 +40,72 @@ pub struct DepositContribution<'info> {
     )]
     pub member_token_account: Account<'info, TokenAccount>,
 
-    /// Protocol's contribution receiving account
-    /// NOTE: In production, this routes to reserves via CPI
+    /// CHECK: Validated by the reserves program
+    pub reserve_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    #[account(mut)]
+    pub reserve_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    #[account(mut)]
+    pub tier0_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    #[account(mut)]
+    pub tier1_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    #[account(mut)]
+    pub tier2_vault: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
     #[account(mut)]
-    pub protocol_token_account: Account<'info, TokenAccount>,
+    pub admin_vault: UncheckedAccount<'info>,
 
     pub member: Signer<'info>,
 
+    #[account(
+        constraint = reserves_program.key() == global_config.reserves_program @ MembershipError::Unauthorized
+    )]
+    pub reserves_program: Program<'info, ApolloReserves>,
+
     pub token_program: Program<'info, Token>,
 }
 
 pub fn deposit_contribution(ctx: Context<DepositContribution>, amount: u64) -> Result<()> {
     let clock = Clock::get()?;
-    let config = &mut ctx.accounts.global_config;
-    let member_account = &mut ctx.accounts.member_account;
-    let ledger = &mut ctx.accounts.contribution_ledger;
 
     require!(amount > 0, MembershipError::InsufficientContribution);
 
-    // Transfer USDC from member to protocol
-    token::transfer(
+    // Route USDC from member straight into the reserve vaults
+    // (admin load, reserve margin and tier targeting applied by reserves)
+    let routing = apollo_reserves::cpi::route_contribution_to_vaults(
         CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            token::Transfer {
-                from: ctx.accounts.member_token_account.to_account_info(),
-                to: ctx.accounts.protocol_token_account.to_account_info(),
-                authority: ctx.accounts.member.to_account_info(),
+            ctx.accounts.reserves_program.to_account_info(),
+            apollo_reserves::cpi::accounts::RouteContribution {
+                reserve_config: ctx.accounts.reserve_config.to_account_info(),
+                reserve_state: ctx.accounts.reserve_state.to_account_info(),
+                vault_authority: ctx.accounts.vault_authority.to_account_info(),
+                source: ctx.accounts.member_token_account.to_account_info(),
+                tier0_vault: ctx.accounts.tier0_vault.to_account_info(),
+                tier1_vault: ctx.accounts.tier1_vault.to_account_info(),
+                tier2_vault: ctx.accounts.tier2_vault.to_account_info(),
+                admin_vault: ctx.accounts.admin_vault.to_account_info(),
+                contributor: ctx.accounts.member.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
             },
         ),
         amount,
-    )?;
+    )?
+    .get();
+
+    let config = &mut ctx.accounts.global_config;
+    let member_account = &mut ctx.accounts.member_account;
+    let ledger = &mut ctx.accounts.contribution_ledger;
 
     // Update ledger
     ledger.total_deposits = ledger.total_deposits.saturating_add(amount);
@@ -76,12 +113,15 @@ pub fn deposit_contribution(ctx: Context<DepositContribution>, amount: u64) -> R
 
     // Check if this covers the amount due
     let is_on_time = clock.unix_timestamp <= ledger.next_payment_due + (7 * 24 * 60 * 60); // 7 day grace
-
-    if amount >= ledger.amount_due {
-        // Full payment
-        ledger.balance = ledger.balance.saturating_add(amount - ledger.amount_due);
-        ledger.total_applied = ledger.total_applied.saturating_add(ledger.amount_due);
-
+    let payment_state = ledger.payment_state(clock.unix_timestamp, config.grace_period_seconds());
+
+    // Partial deposits reduce the balance due; paid_through only advances
+    // once a full period is covered
+    let next_amount_due = member_account.monthly_contribution;
+    if ledger
+        .apply_deposit(amount, next_amount_due, clock.unix_timestamp)
+        .is_some()
+    {
         if is_on_time {
             ledger.on_time_payments += 1;
             member_account.consecutive_months += 1;
@@ -90,12 +130,11 @@ pub fn deposit_contribution(ctx: Context<DepositContribution>, amount: u64) -> R
             // Don't reset consecutive months for slightly late payment
         }
 
-        // Set next payment due (monthly)
-        ledger.next_payment_due = clock.unix_timestamp + (30 * 24 * 60 * 60);
-        ledger.amount_due = member_account.monthly_contribution;
-    } else {
-        // Partial payment - add to balance
-        ledger.balance = ledger.balance.saturating_add(amount);
+        // Late within the grace period keeps the streak; a lapse resets it
+        member_account.record_paid_month(payment_state);
+        member_account.past_due_since = 0;
+    } else if member_account.past_due_since == 0 && clock.unix_timestamp > ledger.next_payment_due {
+        member_account.past_due_since = ledger.next_payment_due;
     }
 
     // Update member totals
@@ -112,6 +151,10 @@ pub fn deposit_contribution(ctx: Context<DepositContribution>, amount: u64) -> R
         amount,
         total_contributions: member_account.total_contributions_paid,
         next_payment_due: ledger.next_payment_due,
+        to_tier0: routing.to_tier0,
+        to_tier1: routing.to_tier1,
+        to_tier2: routing.to_tier2,
+        to_admin: routing.to_admin,
         timestamp: clock.unix_timestamp,
     });
 
@@ -122,6 +165,7 @@ pub fn deposit_contribution(ctx: Context<DepositContribution>, amount: u64) -> R
 #[derive(Accounts)]
 pub struct ApplyPersistencyDiscount<'info> {
     #[account(
+        mut,
         seeds = [GlobalConfig::SEED_PREFIX],
         bump = global_config.bump,
     )]
@@ -144,20 +188,18 @@ pub struct ApplyPersistencyDiscount<'info> {
 
 pub fn apply_persistency_discount(ctx: Context<ApplyPersistencyDiscount>) -> Result<()> {
     let clock = Clock::get()?;
-    let config = &ctx.accounts.global_config;
+    let config = &mut ctx.accounts.global_config;
     let member = &mut ctx.accounts.member_account;
     let ledger = &mut ctx.accounts.contribution_ledger;
 
     // Check eligibility
     require!(
-        member.consecutive_months >= config.persistency_discount_start_months as u16,
+        member.continuous_months >= config.persistency_eligibility_months(),
         MembershipError::PersistencyNotAvailable
     );
 
-    // Calculate discount based on years of continuous coverage
-    let years = member.consecutive_months / 12;
-    let discount_bps =
-        (years as u16 * config.persistency_discount_bps).min(config.max_persistency_discount_bps);
+    // Calculate discount based on tenure of continuous coverage
+    let discount_bps = config.persistency_discount_for(member.continuous_months);
 
     // Already at max?
     require!(
@@ -165,25 +207,39 @@ pub fn apply_persistency_discount(ctx: Context<ApplyPersistencyDiscount>) -> Res
         MembershipError::MaxPersistencyReached
     );
 
-    member.persistency_discount_bps = discount_bps;
-
-    // Calculate new contribution
-    let base_contribution = member.monthly_contribution;
+    // Calculate new contribution from the undiscounted rate so tenure
+    // increases replace (rather than compound) the earlier discount
+    let base_contribution = member.undiscounted_contribution();
     let discount_amount = base_contribution
         .saturating_mul(discount_bps as u64)
         .checked_div(10000)
         .unwrap_or(0);
     let new_contribution = base_contribution.saturating_sub(discount_amount);
 
+    // Only the increase over the current discount is newly granted; it
+    // runs for a year, so charge the annualized amount against the budget
+    let added_monthly = member.monthly_contribution.saturating_sub(new_contribution);
+    let annual_cost = added_monthly.saturating_mul(12);
+    require!(
+        config.charge_persistency_discount(annual_cost, clock.unix_timestamp),
+        MembershipError::PersistencyBudgetExhausted
+    );
+
+    member.persistency_discount_bps = discount_bps;
+
     member.monthly_contribution = new_contribution;
-    ledger.amount_due = new_contribution;
+    ledger.set_amount_due(new_contribution);
 
     emit!(PersistencyDiscountApplied {
         member: member.member,
         consecutive_months: member.consecutive_months,
         discount_bps,
+        discount_amount,
         new_contribution,
+        annual_cost,
+        budget_remaining: config.persistency_budget_remaining(clock.unix_timestamp),
         timestamp: clock.unix_timestamp,
+        continuous_months: member.continuous_months,
     });
 
     Ok(())
@@ -192,6 +248,12 @@ pub fn apply_persistency_discount(ctx: Context<ApplyPersistencyDiscount>) -> Res
 /// Check payment status
 #[derive(Accounts)]
 pub struct CheckPaymentStatus<'info> {
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
     #[account(
         seeds = [MemberAccount::SEED_PREFIX, member_account.member.as_ref()],
         bump = member_account.bump,
@@ -208,26 +270,41 @@ pub struct CheckPaymentStatus<'info> {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct PaymentStatus {
     pub is_current: bool,
+    pub state: PaymentState,
+    pub grace_period_ends: i64,
     pub days_until_due: i64,
     pub amount_due: u64,
+    pub member_balance_due: u64,
+    pub partial_paid: bool,
     pub balance: u64,
     pub on_time_payments: u32,
     pub late_payments: u32,
+    pub paid_through: u32,
+    pub missed_periods: u32,
 }
 
 pub fn check_payment_status(ctx: Context<CheckPaymentStatus>) -> Result<PaymentStatus> {
     let clock = Clock::get()?;
     let ledger = &ctx.accounts.contribution_ledger;
+    let grace_period = ctx.accounts.global_config.grace_period_seconds();
 
-    let days_until_due = (ledger.next_payment_due - clock.unix_timestamp) / (24 * 60 * 60);
-    let is_current = clock.unix_timestamp <= ledger.next_payment_due + (7 * 24 * 60 * 60);
+    // Derived from paid_through rather than the cached next_payment_due
+    let due_date = ledger.due_date();
+    let days_until_due = (due_date - clock.unix_timestamp) / (24 * 60 * 60);
+    let is_current = clock.unix_timestamp <= due_date + (7 * 24 * 60 * 60);
 
     Ok(PaymentStatus {
         is_current,
+        state: ledger.payment_state(clock.unix_timestamp, grace_period),
+        grace_period_ends: due_date.saturating_add(grace_period),
         days_until_due,
         amount_due: ledger.amount_due,
+        member_balance_due: ledger.member_balance_due,
+        partial_paid: ledger.partial_paid() > 0,
         balance: ledger.balance,
         on_time_payments: ledger.on_time_payments,
         late_payments: ledger.late_payments,
+        paid_through: ledger.paid_through,
+        missed_periods: ledger.missed_periods().len() as u32,
     })
 }
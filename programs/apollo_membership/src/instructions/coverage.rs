@@ -1,9 +1,16 @@
 // programs/apollo_membership/src/instructions/coverage.rs
 
 use crate::errors::MembershipError;
-use crate::events::{CoverageActivated, MemberStatusChanged, MemberSuspended, MemberTerminated};
-use crate::state::{ContributionLedger, GlobalConfig, MemberAccount, MemberStatus};
+use crate::events::{
+    CoverageActivated, CoverageTerminatedWithRefund, MemberStatusChanged, MemberSuspended,
+    MemberTerminated, RefundPaid,
+};
+use crate::state::{
+    ContributionLedger, GlobalConfig, MemberAccount, MemberRegistry, MemberStatus, PaymentState,
+};
 use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use apollo_reserves::program::ApolloReserves;
 
 /// Activate coverage after waiting period
 #[derive(Accounts)]
@@ -37,11 +44,8 @@ pub fn activate_coverage_if_eligible(ctx: Context<ActivateCoverageIfEligible>) -
     let ledger = &ctx.accounts.contribution_ledger;
 
     // Check waiting period has passed
-    let waiting_days = config.default_waiting_period_days as i64;
-    let waiting_period_end = member.enrolled_at + (waiting_days * 24 * 60 * 60);
-
     require!(
-        clock.unix_timestamp >= waiting_period_end,
+        member.waiting_period_complete(clock.unix_timestamp),
         MembershipError::WaitingPeriodNotComplete
     );
 
@@ -108,16 +112,25 @@ pub fn suspend_coverage(ctx: Context<SuspendCoverage>, reason: String) -> Result
     let member = &mut ctx.accounts.member_account;
     let ledger = &ctx.accounts.contribution_ledger;
 
-    // Verify payment is overdue (more than 30 days past due)
-    let grace_period = 30 * 24 * 60 * 60; // 30 days
-    require!(
-        clock.unix_timestamp > ledger.next_payment_due + grace_period,
-        MembershipError::PaymentOverdue
-    );
+    // Coverage stays active through the grace period
+    let grace_period = config.grace_period_seconds();
+    match ledger.payment_state(clock.unix_timestamp, grace_period) {
+        PaymentState::Current => return Err(MembershipError::PaymentNotOverdue.into()),
+        PaymentState::InGracePeriod => return Err(MembershipError::GracePeriodActive.into()),
+        PaymentState::PastDue => {}
+    }
+
+    if member.past_due_since == 0 {
+        member.past_due_since = ledger.next_payment_due;
+    }
+    // Services from the past-due date until reinstatement are not covered
+    let gap_start = member.past_due_since;
+    member.open_coverage_gap(gap_start);
 
     let old_status = member.status;
     member.status = MemberStatus::Suspended;
     member.consecutive_months = 0; // Reset streak
+    member.continuous_months = 0;
 
     config.active_members = config.active_members.saturating_sub(1);
 
@@ -180,6 +193,7 @@ pub fn reinstate_coverage(ctx: Context<ReinstateCoverage>) -> Result<()> {
 
     let old_status = member.status;
     member.status = MemberStatus::Active;
+    member.close_coverage_gap(clock.unix_timestamp);
 
     config.active_members += 1;
 
@@ -210,7 +224,26 @@ pub struct TerminateCoverage<'info> {
     )]
     pub member_account: Account<'info, MemberAccount>,
 
+    #[account(
+        mut,
+        seeds = [ContributionLedger::SEED_PREFIX, member_account.member.as_ref()],
+        bump = contribution_ledger.bump,
+    )]
+    pub contribution_ledger: Account<'info, ContributionLedger>,
+
+    /// Protocol-wide member registry (termination frees a slot)
+    #[account(
+        mut,
+        seeds = [MemberRegistry::SEED_PREFIX],
+        bump = member_registry.bump,
+    )]
+    pub member_registry: Account<'info, MemberRegistry>,
+
     /// Must be authority or member
+    #[account(
+        constraint = terminator.key() == global_config.authority
+            || terminator.key() == member_account.member @ MembershipError::Unauthorized
+    )]
     pub terminator: Signer<'info>,
 }
 
@@ -218,6 +251,7 @@ pub fn terminate_coverage(ctx: Context<TerminateCoverage>, reason: String) -> Re
     let clock = Clock::get()?;
     let config = &mut ctx.accounts.global_config;
     let member = &mut ctx.accounts.member_account;
+    let ledger = &mut ctx.accounts.contribution_ledger;
 
     // Cannot terminate already terminated
     require!(
@@ -233,6 +267,20 @@ pub fn terminate_coverage(ctx: Context<TerminateCoverage>, reason: String) -> Re
     }
 
     member.status = MemberStatus::Terminated;
+    ctx.accounts.member_registry.release();
+
+    // Queue a refund of the unused prepaid contribution (paid out by
+    // `pay_refund`); the credit balance is folded into it
+    let refund_amount =
+        ledger.book_termination_refund(clock.unix_timestamp, config.refunds_enabled);
+
+    emit!(CoverageTerminatedWithRefund {
+        member: member.member,
+        refund_amount,
+        pending_refund: ledger.pending_refund,
+        refunds_enabled: config.refunds_enabled,
+        timestamp: clock.unix_timestamp,
+    });
 
     emit!(MemberTerminated {
         member: member.member,
@@ -250,6 +298,102 @@ pub fn terminate_coverage(ctx: Context<TerminateCoverage>, reason: String) -> Re
     Ok(())
 }
 
+/// Pay a terminated member's pending refund from the reserves
+#[derive(Accounts)]
+pub struct PayRefund<'info> {
+    /// Signs the reserves payout
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        seeds = [MemberAccount::SEED_PREFIX, member.key().as_ref()],
+        bump = member_account.bump,
+        constraint = member_account.status == MemberStatus::Terminated @ MembershipError::CoverageNotTerminated
+    )]
+    pub member_account: Account<'info, MemberAccount>,
+
+    #[account(
+        mut,
+        seeds = [ContributionLedger::SEED_PREFIX, member.key().as_ref()],
+        bump = contribution_ledger.bump,
+    )]
+    pub contribution_ledger: Account<'info, ContributionLedger>,
+
+    /// Member's USDC token account
+    #[account(
+        mut,
+        constraint = member_token_account.mint == global_config.usdc_mint @ MembershipError::InvalidBenefitSchedule,
+        constraint = member_token_account.owner == member.key() @ MembershipError::Unauthorized
+    )]
+    pub member_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Validated by the reserves program
+    pub reserve_config: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    #[account(mut)]
+    pub reserve_state: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    pub vault_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by the reserves program
+    #[account(mut)]
+    pub tier0_vault: UncheckedAccount<'info>,
+
+    pub member: Signer<'info>,
+
+    #[account(
+        constraint = reserves_program.key() == global_config.reserves_program @ MembershipError::Unauthorized
+    )]
+    pub reserves_program: Program<'info, ApolloReserves>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn pay_refund(ctx: Context<PayRefund>) -> Result<()> {
+    let clock = Clock::get()?;
+    let ledger = &mut ctx.accounts.contribution_ledger;
+
+    let amount = ledger.pending_refund;
+    require!(amount > 0, MembershipError::NoRefundPending);
+    ledger.pending_refund = 0;
+
+    let config_seeds = &[
+        GlobalConfig::SEED_PREFIX,
+        &[ctx.accounts.global_config.bump],
+    ];
+    let signer_seeds = &[&config_seeds[..]];
+
+    apollo_reserves::cpi::pay_member_refund(
+        CpiContext::new_with_signer(
+            ctx.accounts.reserves_program.to_account_info(),
+            apollo_reserves::cpi::accounts::PayMemberRefund {
+                reserve_config: ctx.accounts.reserve_config.to_account_info(),
+                reserve_state: ctx.accounts.reserve_state.to_account_info(),
+                vault_authority: ctx.accounts.vault_authority.to_account_info(),
+                tier0_vault: ctx.accounts.tier0_vault.to_account_info(),
+                recipient: ctx.accounts.member_token_account.to_account_info(),
+                membership_config: ctx.accounts.global_config.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    emit!(RefundPaid {
+        member: ctx.accounts.member.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 /// Get member coverage status
 #[derive(Accounts)]
 pub struct GetMemberStatus<'info> {
@@ -269,6 +413,8 @@ pub struct MemberCoverageStatus {
     pub monthly_contribution: u64,
     pub persistency_discount_bps: u16,
     pub has_qualifying_event: bool,
+    pub past_due_since: i64,
+    pub continuous_months: u16,
 }
 
 pub fn get_member_status(ctx: Context<GetMemberStatus>) -> Result<MemberCoverageStatus> {
@@ -282,5 +428,7 @@ pub fn get_member_status(ctx: Context<GetMemberStatus>) -> Result<MemberCoverage
         monthly_contribution: member.monthly_contribution,
         persistency_discount_bps: member.persistency_discount_bps,
         has_qualifying_event: member.has_qualifying_event,
+        past_due_since: member.past_due_since,
+        continuous_months: member.continuous_months,
     })
 }
@@ -0,0 +1,147 @@
+// programs/apollo_membership/src/instructions/dependents.rs
+
+use crate::errors::MembershipError;
+use crate::events::{DependentAdded, DependentRemoved};
+use crate::state::{Dependent, DependentRelationship, MemberAccount, MemberStatus};
+use anchor_lang::prelude::*;
+
+/// Add a dependent under a subscriber
+#[derive(Accounts)]
+pub struct AddDependent<'info> {
+    #[account(
+        mut,
+        seeds = [MemberAccount::SEED_PREFIX, subscriber.key().as_ref()],
+        bump = member_account.bump,
+        constraint = member_account.member == subscriber.key() @ MembershipError::Unauthorized,
+        constraint = member_account.status != MemberStatus::Terminated @ MembershipError::CoverageNotActive
+    )]
+    pub member_account: Account<'info, MemberAccount>,
+
+    #[account(
+        init,
+        payer = subscriber,
+        space = 8 + Dependent::INIT_SPACE,
+        seeds = [
+            Dependent::SEED_PREFIX,
+            subscriber.key().as_ref(),
+            &[member_account.next_dependent_index]
+        ],
+        bump
+    )]
+    pub dependent: Account<'info, Dependent>,
+
+    #[account(mut)]
+    pub subscriber: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddDependentParams {
+    pub relationship: DependentRelationship,
+    pub date_of_birth: i64,
+    pub age: u8,
+}
+
+pub fn add_dependent(ctx: Context<AddDependent>, params: AddDependentParams) -> Result<()> {
+    let clock = Clock::get()?;
+    let member = &mut ctx.accounts.member_account;
+
+    require!(
+        member.dependent_count() < MemberAccount::MAX_DEPENDENTS,
+        MembershipError::MaxDependentsReached
+    );
+    require!(
+        params.date_of_birth <= clock.unix_timestamp,
+        MembershipError::InvalidAge
+    );
+
+    if params.relationship.is_adult() {
+        // One spouse/partner per subscriber
+        require!(
+            member.num_additional_adults == 0,
+            MembershipError::SpouseAlreadyCovered
+        );
+        require!(
+            params.age >= 18 && params.age <= 64,
+            MembershipError::InvalidAge
+        );
+    } else {
+        require!(
+            params.age <= Dependent::MAX_CHILD_AGE,
+            MembershipError::InvalidAge
+        );
+    }
+
+    let index = member.next_dependent_index;
+
+    let dependent = &mut ctx.accounts.dependent;
+    dependent.subscriber = member.member;
+    dependent.index = index;
+    dependent.relationship = params.relationship;
+    dependent.date_of_birth = params.date_of_birth;
+    dependent.age = params.age;
+    dependent.status = member.status;
+    dependent.added_at = clock.unix_timestamp;
+    dependent.removed_at = 0;
+    dependent.bump = ctx.bumps.dependent;
+
+    member.next_dependent_index = index
+        .checked_add(1)
+        .ok_or(MembershipError::MaxDependentsReached)?;
+    member.add_dependent(params.relationship);
+
+    emit!(DependentAdded {
+        subscriber: member.member,
+        index,
+        relationship: params.relationship,
+        age: params.age,
+        coverage_tier: member.coverage_tier,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Remove a dependent from a subscriber's coverage
+#[derive(Accounts)]
+pub struct RemoveDependent<'info> {
+    #[account(
+        mut,
+        seeds = [MemberAccount::SEED_PREFIX, subscriber.key().as_ref()],
+        bump = member_account.bump,
+        constraint = member_account.member == subscriber.key() @ MembershipError::Unauthorized
+    )]
+    pub member_account: Account<'info, MemberAccount>,
+
+    #[account(
+        mut,
+        seeds = [Dependent::SEED_PREFIX, subscriber.key().as_ref(), &[dependent.index]],
+        bump = dependent.bump,
+        constraint = dependent.status != MemberStatus::Terminated @ MembershipError::DependentNotCovered
+    )]
+    pub dependent: Account<'info, Dependent>,
+
+    pub subscriber: Signer<'info>,
+}
+
+pub fn remove_dependent(ctx: Context<RemoveDependent>) -> Result<()> {
+    let clock = Clock::get()?;
+    let member = &mut ctx.accounts.member_account;
+    let dependent = &mut ctx.accounts.dependent;
+
+    dependent.status = MemberStatus::Terminated;
+    dependent.removed_at = clock.unix_timestamp;
+
+    member.remove_dependent(dependent.relationship);
+
+    emit!(DependentRemoved {
+        subscriber: member.member,
+        index: dependent.index,
+        relationship: dependent.relationship,
+        coverage_tier: member.coverage_tier,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
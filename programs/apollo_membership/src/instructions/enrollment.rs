@@ -2,13 +2,18 @@
 
 use crate::errors::MembershipError;
 use crate::events::{
-    EnrollmentWindowClosed, EnrollmentWindowOpened, MemberEnrolled, QualifyingEventSet,
+    EnrollmentWindowClosed, EnrollmentWindowOpened, MemberEnrolled, QualifyingEventRecorded,
+    QualifyingEventSet,
 };
 use crate::state::{
-    ContributionLedger, EnrollmentWindow, GlobalConfig, MemberAccount, MemberStatus,
-    QualifyingEvent,
+    ContributionLedger, CoverageTier, EnrollmentWindow, GlobalConfig, MemberAccount,
+    MemberRegistry, MemberStatus, QualifyingEvent, QualifyingEventRecord,
 };
 use anchor_lang::prelude::*;
+use apollo_core::BootstrapConfig;
+use apollo_reserves::state::RunoffState;
+use apollo_risk_engine::program::ApolloRiskEngine;
+use apollo_risk_engine::state::{CarState, ZoneState};
 
 /// Open an enrollment window
 #[derive(Accounts)]
@@ -30,12 +35,33 @@ pub struct OpenEnrollmentWindow<'info> {
     )]
     pub enrollment_window: Account<'info, EnrollmentWindow>,
 
+    /// Risk engine CAR state (read by the zone status CPI)
+    #[account(
+        seeds = [CarState::SEED_PREFIX],
+        bump = car_state.bump,
+        seeds::program = apollo_risk_engine::ID,
+    )]
+    pub car_state: Account<'info, CarState>,
+
+    /// Risk engine zone state (monthly enrollment caps)
+    #[account(
+        seeds = [ZoneState::SEED_PREFIX],
+        bump = zone_state.bump,
+        seeds::program = apollo_risk_engine::ID,
+    )]
+    pub zone_state: Account<'info, ZoneState>,
+
     #[account(
         mut,
         constraint = authority.key() == global_config.authority @ MembershipError::Unauthorized
     )]
     pub authority: Signer<'info>,
 
+    #[account(
+        constraint = risk_engine_program.key() == global_config.risk_engine_program @ MembershipError::Unauthorized
+    )]
+    pub risk_engine_program: Program<'info, ApolloRiskEngine>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -64,25 +90,49 @@ pub fn open_enrollment_window(
         MembershipError::InvalidWindowConfig
     );
 
+    // Current zone cap (0 when enrollment is frozen)
+    let car_status = apollo_risk_engine::cpi::get_car_status(CpiContext::new(
+        ctx.accounts.risk_engine_program.to_account_info(),
+        apollo_risk_engine::cpi::accounts::GetCarStatus {
+            car_state: ctx.accounts.car_state.to_account_info(),
+            zone_state: ctx.accounts.zone_state.to_account_info(),
+        },
+    ))?
+    .get();
+    let monthly_zone_cap = if car_status.enrollment_frozen {
+        0
+    } else {
+        car_status.enrollment_cap
+    };
+
     let window = &mut ctx.accounts.enrollment_window;
     window.window_id = params.window_id;
     window.start_time = params.start_time;
     window.end_time = params.end_time;
-    window.max_enrollments = params.max_enrollments;
     window.enrollment_count = 0;
     window.is_active = true;
     window.is_special_enrollment = params.is_special_enrollment;
     window.description = params.description;
     window.bump = ctx.bumps.enrollment_window;
 
-    ctx.accounts.global_config.enrollment_open = true;
+    window.max_enrollments = window.capacity_within(params.max_enrollments, monthly_zone_cap);
+    require!(
+        window.max_enrollments > 0,
+        MembershipError::ZoneEnrollmentBlocked
+    );
+
+    let config = &mut ctx.accounts.global_config;
+    config.schedule_window(window)?;
+    config.enrollment_open = true;
 
     emit!(EnrollmentWindowOpened {
         window_id: params.window_id,
         start_time: params.start_time,
         end_time: params.end_time,
-        max_enrollments: params.max_enrollments,
+        max_enrollments: window.max_enrollments,
         is_special: params.is_special_enrollment,
+        requested_enrollments: params.max_enrollments,
+        monthly_zone_cap,
         timestamp: clock.unix_timestamp,
     });
 
@@ -117,6 +167,9 @@ pub fn close_enrollment_window(ctx: Context<CloseEnrollmentWindow>) -> Result<()
     let window = &mut ctx.accounts.enrollment_window;
 
     window.is_active = false;
+    ctx.accounts
+        .global_config
+        .release_window(window, clock.unix_timestamp);
 
     emit!(EnrollmentWindowClosed {
         window_id: window.window_id,
@@ -134,10 +187,11 @@ pub struct EnrollMember<'info> {
         mut,
         seeds = [GlobalConfig::SEED_PREFIX],
         bump = global_config.bump,
-        constraint = global_config.enrollment_open @ MembershipError::EnrollmentClosed
     )]
     pub global_config: Account<'info, GlobalConfig>,
 
+    /// Enrollment window (outside an open window, a recent qualifying
+    /// life event is required instead)
     #[account(
         mut,
         seeds = [EnrollmentWindow::SEED_PREFIX, &enrollment_window.window_id.to_le_bytes()],
@@ -163,9 +217,56 @@ pub struct EnrollMember<'info> {
     )]
     pub contribution_ledger: Account<'info, ContributionLedger>,
 
+    /// Protocol-wide member registry (member cap)
+    #[account(
+        mut,
+        seeds = [MemberRegistry::SEED_PREFIX],
+        bump = member_registry.bump,
+    )]
+    pub member_registry: Account<'info, MemberRegistry>,
+
+    /// Reserves run-off state (no new enrollment once run-off is active)
+    #[account(
+        seeds = [RunoffState::SEED_PREFIX],
+        bump = runoff_state.bump,
+        seeds::program = apollo_reserves::ID,
+        constraint = !runoff_state.runoff_active @ MembershipError::ProtocolInRunoff
+    )]
+    pub runoff_state: Account<'info, RunoffState>,
+
+    /// Risk engine zone state (monthly enrollment caps)
+    #[account(
+        mut,
+        seeds = [ZoneState::SEED_PREFIX],
+        bump = zone_state.bump,
+        seeds::program = apollo_risk_engine::ID,
+    )]
+    pub zone_state: Account<'info, ZoneState>,
+
+    /// Core bootstrap config (bootstrap mode caps monthly enrollment)
+    #[account(
+        seeds = [BootstrapConfig::SEED_PREFIX],
+        bump = bootstrap_config.bump,
+        seeds::program = apollo_core::ID,
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+
+    /// Authority-recorded qualifying life event (required outside an open
+    /// window)
+    #[account(
+        seeds = [QualifyingEventRecord::SEED_PREFIX, member.key().as_ref()],
+        bump = qualifying_event.bump,
+    )]
+    pub qualifying_event: Option<Account<'info, QualifyingEventRecord>>,
+
     #[account(mut)]
     pub member: Signer<'info>,
 
+    #[account(
+        constraint = risk_engine_program.key() == global_config.risk_engine_program @ MembershipError::Unauthorized
+    )]
+    pub risk_engine_program: Program<'info, ApolloRiskEngine>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -174,8 +275,6 @@ pub struct EnrollMemberParams {
     pub age: u8,
     pub region_code: u8,
     pub is_tobacco_user: bool,
-    pub num_children: u8,
-    pub num_additional_adults: u8,
     pub benefit_schedule: String,
     pub quoted_contribution: u64,
 }
@@ -185,24 +284,68 @@ pub fn enroll_member(ctx: Context<EnrollMember>, params: EnrollMemberParams) ->
     let config = &mut ctx.accounts.global_config;
     let window = &mut ctx.accounts.enrollment_window;
 
-    // Validate window is open
-    require!(
-        window.is_open(clock.unix_timestamp),
-        MembershipError::WindowNotActive
-    );
+    // Outside an open window, only special enrollment after a recent QLE
+    // the authority has verified
+    let window_open = config.enrollment_open && window.is_open(clock.unix_timestamp);
+    let qualifying_event_at = ctx
+        .accounts
+        .qualifying_event
+        .as_ref()
+        .map(|record| record.event_at)
+        .unwrap_or(0);
+    if !window_open {
+        require!(
+            MemberAccount::qualifying_event_permits_enrollment(
+                qualifying_event_at,
+                clock.unix_timestamp
+            ),
+            MembershipError::NoQualifyingEvent
+        );
+    }
     require!(
         params.age > 0 && params.age <= 64,
         MembershipError::InvalidAge
     );
 
+    // Count against the current zone's monthly cap (rejects when the cap
+    // is reached or enrollment is frozen)
+    let config_seeds = &[GlobalConfig::SEED_PREFIX, &[config.bump]];
+    let signer_seeds = &[&config_seeds[..]];
+    let remaining_monthly_capacity =
+        apollo_risk_engine::cpi::record_enrollment(CpiContext::new_with_signer(
+            ctx.accounts.risk_engine_program.to_account_info(),
+            apollo_risk_engine::cpi::accounts::RecordEnrollment {
+                zone_state: ctx.accounts.zone_state.to_account_info(),
+                member: ctx.accounts.member.to_account_info(),
+                membership_config: config.to_account_info(),
+            },
+            signer_seeds,
+        ))?
+        .get();
+
+    // Bootstrap mode holds monthly enrollment below the zone cap
+    ctx.accounts.zone_state.reload()?;
+    require!(
+        ctx.accounts
+            .bootstrap_config
+            .permits_monthly_enrollments(ctx.accounts.zone_state.current_month_enrollments),
+        MembershipError::EnrollmentCapReached
+    );
+
+    // Protocol-wide member cap (bootstrap maximum or scaled maximum)
+    ctx.accounts
+        .member_registry
+        .register(ctx.accounts.bootstrap_config.bootstrap_active)?;
+
     // Increment counts
-    window.enrollment_count += 1;
+    if window_open {
+        window.enrollment_count += 1;
+    }
     config.total_members += 1;
     let member_id = config.total_members;
 
     // Calculate waiting period end
-    let waiting_days = config.default_waiting_period_days as i64;
-    let waiting_period_ends = clock.unix_timestamp + (waiting_days * 24 * 60 * 60);
+    let waiting_period_ends = clock.unix_timestamp + config.waiting_period_seconds();
 
     // Initialize member account
     let member_account = &mut ctx.accounts.member_account;
@@ -211,18 +354,29 @@ pub fn enroll_member(ctx: Context<EnrollMember>, params: EnrollMemberParams) ->
     member_account.age = params.age;
     member_account.region_code = params.region_code;
     member_account.is_tobacco_user = params.is_tobacco_user;
-    member_account.num_children = params.num_children;
-    member_account.num_additional_adults = params.num_additional_adults;
+    // Dependents are covered through `add_dependent`, which creates the
+    // record and updates these counts together
+    member_account.num_children = 0;
+    member_account.num_additional_adults = 0;
+    member_account.coverage_tier = CoverageTier::Individual;
+    member_account.next_dependent_index = 0;
     member_account.enrolled_at = clock.unix_timestamp;
+    member_account.waiting_period_ends = waiting_period_ends;
     member_account.coverage_activated_at = 0;
     member_account.status = MemberStatus::PendingActivation;
     member_account.monthly_contribution = params.quoted_contribution;
     member_account.total_contributions_paid = 0;
     member_account.last_contribution_at = 0;
+    member_account.past_due_since = 0;
     member_account.consecutive_months = 0;
-    member_account.has_qualifying_event = false;
-    member_account.qualifying_event_at = 0;
+    member_account.has_qualifying_event = qualifying_event_at > 0;
+    member_account.qualifying_event_at = qualifying_event_at;
     member_account.persistency_discount_bps = 0;
+    member_account.plan_year = 1;
+    member_account.plan_year_start = clock.unix_timestamp;
+    member_account.last_gap_start = 0;
+    member_account.last_gap_end = 0;
+    member_account.continuous_months = 0;
     member_account.benefit_schedule = params.benefit_schedule;
     member_account.bump = ctx.bumps.member_account;
 
@@ -235,8 +389,12 @@ pub fn enroll_member(ctx: Context<EnrollMember>, params: EnrollMemberParams) ->
     ledger.last_deposit_at = 0;
     ledger.next_payment_due = clock.unix_timestamp; // First payment due now
     ledger.amount_due = params.quoted_contribution;
+    ledger.member_balance_due = params.quoted_contribution;
     ledger.on_time_payments = 0;
     ledger.late_payments = 0;
+    ledger.period_anchor = clock.unix_timestamp;
+    ledger.paid_through = 0;
+    ledger.history = vec![];
     ledger.bump = ctx.bumps.contribution_ledger;
 
     emit!(MemberEnrolled {
@@ -246,6 +404,71 @@ pub fn enroll_member(ctx: Context<EnrollMember>, params: EnrollMemberParams) ->
         region_code: params.region_code,
         monthly_contribution: params.quoted_contribution,
         waiting_period_ends,
+        remaining_monthly_capacity,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Record a verified qualifying life event for a prospective member
+#[derive(Accounts)]
+#[instruction(params: RecordQualifyingEventParams)]
+pub struct RecordQualifyingEvent<'info> {
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + QualifyingEventRecord::INIT_SPACE,
+        seeds = [QualifyingEventRecord::SEED_PREFIX, params.prospect.as_ref()],
+        bump
+    )]
+    pub qualifying_event: Account<'info, QualifyingEventRecord>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == global_config.authority @ MembershipError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RecordQualifyingEventParams {
+    pub prospect: Pubkey,
+    pub event_type: QualifyingEvent,
+    pub event_at: i64,
+}
+
+pub fn record_qualifying_event(
+    ctx: Context<RecordQualifyingEvent>,
+    params: RecordQualifyingEventParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    require!(
+        params.event_at > 0 && params.event_at <= clock.unix_timestamp,
+        MembershipError::InvalidQualifyingEventDate
+    );
+
+    let record = &mut ctx.accounts.qualifying_event;
+    record.prospect = params.prospect;
+    record.event_type = params.event_type;
+    record.event_at = params.event_at;
+    record.recorded_by = ctx.accounts.authority.key();
+    record.recorded_at = clock.unix_timestamp;
+    record.bump = ctx.bumps.qualifying_event;
+
+    emit!(QualifyingEventRecorded {
+        prospect: params.prospect,
+        event_type: params.event_type,
+        event_at: params.event_at,
+        recorded_by: record.recorded_by,
         timestamp: clock.unix_timestamp,
     });
 
@@ -279,8 +502,8 @@ pub fn set_member_qualifying_event(
     let clock = Clock::get()?;
     let member = &mut ctx.accounts.member_account;
 
-    // Qualifying events typically valid for 60 days
-    let expires_at = clock.unix_timestamp + (60 * 24 * 60 * 60);
+    // Qualifying events are valid for special enrollment for 60 days
+    let expires_at = clock.unix_timestamp + (MemberAccount::QLE_ENROLLMENT_DAYS * 24 * 60 * 60);
 
     member.has_qualifying_event = true;
     member.qualifying_event_at = clock.unix_timestamp;
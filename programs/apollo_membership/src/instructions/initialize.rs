@@ -1,7 +1,8 @@
 // programs/apollo_membership/src/instructions/initialize.rs
 
-use crate::events::GlobalConfigInitialized;
-use crate::state::GlobalConfig;
+use crate::errors::MembershipError;
+use crate::events::{GlobalConfigInitialized, MaxMembersUpdated, PersistencyBudgetUpdated};
+use crate::state::{GlobalConfig, MemberRegistry};
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 
@@ -31,6 +32,11 @@ pub struct InitializeGlobalConfigParams {
     pub reserves_program: Pubkey,
     pub default_waiting_period_days: Option<u16>,
     pub preexisting_waiting_days: Option<u16>,
+    pub grace_period_days: Option<u16>,
+    /// Refund unused prepaid contributions on termination (default true)
+    pub refunds_enabled: Option<bool>,
+    /// Annual persistency discount budget (default 0 = unbudgeted)
+    pub persistency_discount_budget: Option<u64>,
 }
 
 pub fn handler(
@@ -57,7 +63,19 @@ pub fn handler(
     config.persistency_discount_start_months = GlobalConfig::DEFAULT_PERSISTENCY_START;
     config.persistency_discount_bps = GlobalConfig::DEFAULT_PERSISTENCY_BPS;
     config.max_persistency_discount_bps = GlobalConfig::MAX_PERSISTENCY_BPS;
+    config.grace_period_days = params
+        .grace_period_days
+        .unwrap_or(GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS);
     config.enrollment_open = false;
+    config.refunds_enabled = params.refunds_enabled.unwrap_or(true);
+    config.persistency_discount_budget = params.persistency_discount_budget.unwrap_or(0);
+    config.persistency_budget_used = 0;
+    config.persistency_budget_period_start = clock.unix_timestamp;
+    config.persistency_discounts_granted = 0;
+    config.open_window_id = 0;
+    config.open_window_end = 0;
+    config.special_window_id = 0;
+    config.special_window_end = 0;
     config.bump = ctx.bumps.global_config;
 
     emit!(GlobalConfigInitialized {
@@ -68,3 +86,118 @@ pub fn handler(
 
     Ok(())
 }
+
+/// Update the annual persistency discount budget (DAO)
+#[derive(Accounts)]
+pub struct SetPersistencyDiscountBudget<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalConfig::SEED_PREFIX],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        constraint = authority.key() == global_config.authority @ MembershipError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_persistency_discount_budget(
+    ctx: Context<SetPersistencyDiscountBudget>,
+    budget: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    let old_budget = config.persistency_discount_budget;
+    config.persistency_discount_budget = budget;
+
+    emit!(PersistencyBudgetUpdated {
+        old_budget,
+        new_budget: budget,
+        updater: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Create the protocol-wide member registry
+#[derive(Accounts)]
+pub struct InitializeMemberRegistry<'info> {
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + MemberRegistry::INIT_SPACE,
+        seeds = [MemberRegistry::SEED_PREFIX],
+        bump
+    )]
+    pub member_registry: Account<'info, MemberRegistry>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == global_config.authority @ MembershipError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_member_registry(
+    ctx: Context<InitializeMemberRegistry>,
+    scaled_max_members: u32,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.member_registry;
+    // Seed from members enrolled before the registry existed (conservative:
+    // includes any since terminated)
+    registry.enrolled_members =
+        u32::try_from(ctx.accounts.global_config.total_members).unwrap_or(u32::MAX);
+    registry.scaled_max_members = scaled_max_members;
+    registry.total_registered = ctx.accounts.global_config.total_members;
+    registry.total_released = 0;
+    registry.bump = ctx.bumps.member_registry;
+
+    Ok(())
+}
+
+/// Update the member cap that applies after bootstrap mode (DAO)
+#[derive(Accounts)]
+pub struct SetMaxMembers<'info> {
+    #[account(
+        seeds = [GlobalConfig::SEED_PREFIX],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [MemberRegistry::SEED_PREFIX],
+        bump = member_registry.bump,
+    )]
+    pub member_registry: Account<'info, MemberRegistry>,
+
+    #[account(
+        constraint = authority.key() == global_config.authority @ MembershipError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_max_members(ctx: Context<SetMaxMembers>, scaled_max_members: u32) -> Result<()> {
+    let registry = &mut ctx.accounts.member_registry;
+    let old_max = registry.scaled_max_members;
+    registry.scaled_max_members = scaled_max_members;
+
+    emit!(MaxMembersUpdated {
+        old_max,
+        new_max: scaled_max_members,
+        updater: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -2,10 +2,14 @@
 
 pub mod contributions;
 pub mod coverage;
+pub mod dependents;
 pub mod enrollment;
 pub mod initialize;
+pub mod renewal;
 
 pub use contributions::*;
 pub use coverage::*;
+pub use dependents::*;
 pub use enrollment::*;
 pub use initialize::*;
+pub use renewal::*;
@@ -0,0 +1,129 @@
+// programs/apollo_membership/src/instructions/renewal.rs
+
+use crate::errors::MembershipError;
+use crate::events::MemberRenewed;
+use crate::state::{ContributionLedger, Dependent, GlobalConfig, MemberAccount, MemberStatus};
+use anchor_lang::prelude::*;
+use apollo_risk_engine::instructions::QuoteContributionParams;
+use apollo_risk_engine::program::ApolloRiskEngine;
+
+/// Renew a member into the next plan year (permissionless crank)
+#[derive(Accounts)]
+pub struct RenewMember<'info> {
+    #[account(
+        mut,
+        seeds = [GlobalConfig::SEED_PREFIX],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [MemberAccount::SEED_PREFIX, member_account.member.as_ref()],
+        bump = member_account.bump,
+        constraint = member_account.status == MemberStatus::Active @ MembershipError::CoverageNotActive
+    )]
+    pub member_account: Account<'info, MemberAccount>,
+
+    #[account(
+        mut,
+        seeds = [ContributionLedger::SEED_PREFIX, member_account.member.as_ref()],
+        bump = contribution_ledger.bump,
+    )]
+    pub contribution_ledger: Account<'info, ContributionLedger>,
+
+    /// Covered spouse or partner, priced at their own age (required while
+    /// the member covers one)
+    #[account(
+        seeds = [Dependent::SEED_PREFIX, member_account.member.as_ref(), &[spouse.index]],
+        bump = spouse.bump,
+        constraint = spouse.relationship.is_adult() @ MembershipError::DependentNotCovered,
+        constraint = spouse.status != MemberStatus::Terminated @ MembershipError::DependentNotCovered,
+    )]
+    pub spouse: Option<Account<'info, Dependent>>,
+
+    /// Risk engine config
+    /// CHECK: Validated by the risk engine program
+    pub risk_config: UncheckedAccount<'info>,
+
+    /// Risk engine rating table
+    /// CHECK: Validated by the risk engine program
+    pub rating_table: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = risk_engine_program.key() == global_config.risk_engine_program @ MembershipError::Unauthorized
+    )]
+    pub risk_engine_program: Program<'info, ApolloRiskEngine>,
+}
+
+pub fn renew_member(ctx: Context<RenewMember>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        ctx.accounts
+            .member_account
+            .is_renewal_due(clock.unix_timestamp),
+        MembershipError::RenewalNotDue
+    );
+
+    // Fresh quote at current rates
+    let member = &ctx.accounts.member_account;
+    let additional_adult_ages = member.additional_adult_ages(ctx.accounts.spouse.as_deref())?;
+    let quote = apollo_risk_engine::cpi::quote_contribution(
+        CpiContext::new(
+            ctx.accounts.risk_engine_program.to_account_info(),
+            apollo_risk_engine::cpi::accounts::QuoteContribution {
+                risk_config: ctx.accounts.risk_config.to_account_info(),
+                rating_table: ctx.accounts.rating_table.to_account_info(),
+            },
+        ),
+        QuoteContributionParams {
+            age: member.age,
+            is_tobacco_user: member.is_tobacco_user,
+            region_code: member.region_code,
+            num_children: member.num_children,
+            num_additional_adults: member.num_additional_adults,
+            additional_adult_ages,
+        },
+    )?
+    .get();
+
+    let config = &mut ctx.accounts.global_config;
+    let member = &mut ctx.accounts.member_account;
+    let ledger = &mut ctx.accounts.contribution_ledger;
+
+    member.start_new_plan_year(clock.unix_timestamp);
+
+    // Apply earned persistency discount to the fresh quote, if the year's
+    // discount budget can fund it
+    let mut discount_bps = config.persistency_discount_for(member.continuous_months);
+    let mut discount_amount = quote
+        .final_contribution
+        .saturating_mul(discount_bps as u64)
+        .checked_div(10000)
+        .unwrap_or(0);
+    if !config.charge_persistency_discount(discount_amount.saturating_mul(12), clock.unix_timestamp)
+    {
+        discount_bps = 0;
+        discount_amount = 0;
+    }
+    let new_contribution = quote.final_contribution.saturating_sub(discount_amount);
+
+    let previous_contribution = member.monthly_contribution;
+    member.persistency_discount_bps = discount_bps;
+    member.monthly_contribution = new_contribution;
+    ledger.set_amount_due(new_contribution);
+
+    emit!(MemberRenewed {
+        member: member.member,
+        plan_year: member.plan_year,
+        plan_year_start: member.plan_year_start,
+        previous_contribution,
+        quoted_contribution: quote.final_contribution,
+        persistency_discount_bps: discount_bps,
+        new_contribution,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -5,9 +5,11 @@
 // Manages member lifecycle:
 // - Enrollment windows (open/special enrollment)
 // - Member registration and profiles
+// - Dependent (spouse/children) coverage under a subscriber
 // - Contribution collection and routing
 // - Coverage activation and management
 // - Persistency discounts for loyal members
+// - Plan-year renewal with fresh contribution quotes
 
 use anchor_lang::prelude::*;
 
@@ -35,6 +37,27 @@ pub mod apollo_membership {
         instructions::initialize::handler(ctx, params)
     }
 
+    /// Set the annual persistency discount budget (0 = unbudgeted)
+    pub fn set_persistency_discount_budget(
+        ctx: Context<SetPersistencyDiscountBudget>,
+        budget: u64,
+    ) -> Result<()> {
+        instructions::initialize::set_persistency_discount_budget(ctx, budget)
+    }
+
+    /// Create the member registry that enforces the protocol member cap
+    pub fn initialize_member_registry(
+        ctx: Context<InitializeMemberRegistry>,
+        scaled_max_members: u32,
+    ) -> Result<()> {
+        instructions::initialize::initialize_member_registry(ctx, scaled_max_members)
+    }
+
+    /// Set the member cap that applies once bootstrap mode ends (0 = uncapped)
+    pub fn set_max_members(ctx: Context<SetMaxMembers>, scaled_max_members: u32) -> Result<()> {
+        instructions::initialize::set_max_members(ctx, scaled_max_members)
+    }
+
     // ==================== ENROLLMENT WINDOWS ====================
 
     /// Open an enrollment window
@@ -55,6 +78,14 @@ pub mod apollo_membership {
         instructions::enrollment::enroll_member(ctx, params)
     }
 
+    /// Record a verified qualifying life event for a prospective member
+    pub fn record_qualifying_event(
+        ctx: Context<RecordQualifyingEvent>,
+        params: RecordQualifyingEventParams,
+    ) -> Result<()> {
+        instructions::enrollment::record_qualifying_event(ctx, params)
+    }
+
     /// Set a qualifying life event
     pub fn set_member_qualifying_event(
         ctx: Context<SetMemberQualifyingEvent>,
@@ -63,6 +94,18 @@ pub mod apollo_membership {
         instructions::enrollment::set_member_qualifying_event(ctx, event_type)
     }
 
+    // ==================== DEPENDENTS ====================
+
+    /// Add a dependent (spouse/partner or child) under a subscriber
+    pub fn add_dependent(ctx: Context<AddDependent>, params: AddDependentParams) -> Result<()> {
+        instructions::dependents::add_dependent(ctx, params)
+    }
+
+    /// Remove a dependent from a subscriber's coverage
+    pub fn remove_dependent(ctx: Context<RemoveDependent>) -> Result<()> {
+        instructions::dependents::remove_dependent(ctx)
+    }
+
     // ==================== CONTRIBUTIONS ====================
 
     /// Deposit a contribution
@@ -80,6 +123,13 @@ pub mod apollo_membership {
         instructions::contributions::check_payment_status(ctx)
     }
 
+    // ==================== RENEWAL ====================
+
+    /// Renew a member into the next plan year
+    pub fn renew_member(ctx: Context<RenewMember>) -> Result<()> {
+        instructions::renewal::renew_member(ctx)
+    }
+
     // ==================== COVERAGE MANAGEMENT ====================
 
     /// Activate coverage after waiting period
@@ -102,8 +152,34 @@ pub mod apollo_membership {
         instructions::coverage::terminate_coverage(ctx, reason)
     }
 
+    /// Pay a terminated member's pending refund
+    pub fn pay_refund(ctx: Context<PayRefund>) -> Result<()> {
+        instructions::coverage::pay_refund(ctx)
+    }
+
     /// Get member coverage status
     pub fn get_member_status(ctx: Context<GetMemberStatus>) -> Result<MemberCoverageStatus> {
         instructions::coverage::get_member_status(ctx)
     }
 }
+
+/// Public helpers for CPI callers
+pub mod membership_helpers {
+    use super::*;
+    use crate::errors::MembershipError;
+    use crate::state::{MemberAccount, MemberStatus};
+
+    /// Reject members who are suspended, terminated, or still in the
+    /// waiting period. Used by the claims program before accepting a claim.
+    pub fn assert_active_coverage(member: &MemberAccount, current_time: i64) -> Result<()> {
+        require!(
+            member.waiting_period_complete(current_time),
+            MembershipError::WaitingPeriodNotComplete
+        );
+        require!(
+            member.status == MemberStatus::Active,
+            MembershipError::CoverageNotActive
+        );
+        Ok(())
+    }
+}
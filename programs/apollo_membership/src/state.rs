@@ -1,6 +1,9 @@
 // programs/apollo_membership/src/state.rs
 
+use crate::errors::MembershipError;
 use anchor_lang::prelude::*;
+use apollo_core::{actuarial, bootstrap, protocol_constants};
+use apollo_risk_engine::state::ZoneState;
 
 /// Global membership configuration
 /// PDA seeds: ["global_config"]
@@ -46,9 +49,36 @@ pub struct GlobalConfig {
     /// Maximum persistency discount (basis points)
     pub max_persistency_discount_bps: u16,
 
+    /// Grace period (days) past due before coverage can be suspended
+    pub grace_period_days: u16,
+
     /// Is enrollment open
     pub enrollment_open: bool,
 
+    /// Whether unused prepaid contributions are refunded on termination
+    pub refunds_enabled: bool,
+
+    /// Annual budget for persistency discounts, in annualized USDC cost
+    /// (0 = unbudgeted)
+    pub persistency_discount_budget: u64,
+
+    /// Discount cost charged against the budget in the current budget year
+    pub persistency_budget_used: u64,
+
+    /// Start of the current discount budget year
+    pub persistency_budget_period_start: i64,
+
+    /// Annualized persistency discounts granted (all-time)
+    pub persistency_discounts_granted: u64,
+
+    /// Latest scheduled open-enrollment window and when it ends (0 = none)
+    pub open_window_id: u64,
+    pub open_window_end: i64,
+
+    /// Latest scheduled special-enrollment window and when it ends (0 = none)
+    pub special_window_id: u64,
+    pub special_window_end: i64,
+
     /// Bump seed
     pub bump: u8,
 }
@@ -56,11 +86,175 @@ pub struct GlobalConfig {
 impl GlobalConfig {
     pub const SEED_PREFIX: &'static [u8] = b"global_config";
 
-    pub const DEFAULT_WAITING_PERIOD: u16 = 30; // 30 days
+    /// Windows of one type are scheduled back to back: a new window must
+    /// start after the latest one of its type ends
+    pub fn schedule_window(&mut self, window: &EnrollmentWindow) -> Result<()> {
+        let (latest_id, latest_end) = if window.is_special_enrollment {
+            (&mut self.special_window_id, &mut self.special_window_end)
+        } else {
+            (&mut self.open_window_id, &mut self.open_window_end)
+        };
+        require!(
+            window.start_time > *latest_end,
+            MembershipError::EnrollmentWindowOverlap
+        );
+
+        *latest_id = window.window_id;
+        *latest_end = window.end_time;
+        Ok(())
+    }
+
+    /// Closing the latest window frees its remaining schedule. Earlier
+    /// windows of the type end before it starts, so the freed range never
+    /// reaches back into one of them.
+    pub fn release_window(&mut self, window: &EnrollmentWindow, current_time: i64) {
+        let (latest_id, latest_end) = if window.is_special_enrollment {
+            (self.special_window_id, &mut self.special_window_end)
+        } else {
+            (self.open_window_id, &mut self.open_window_end)
+        };
+        if latest_id == window.window_id {
+            *latest_end = window.end_time.min(current_time.max(window.start_time - 1));
+        }
+    }
+
+    pub const DEFAULT_WAITING_PERIOD: u16 = actuarial::WAITING_PERIOD_DAYS as u16; // 30 days
     pub const DEFAULT_PREEXISTING_WAIT: u16 = 180; // 6 months
-    pub const DEFAULT_PERSISTENCY_START: u8 = 12; // After 1 year
-    pub const DEFAULT_PERSISTENCY_BPS: u16 = 500; // 5% per year
-    pub const MAX_PERSISTENCY_BPS: u16 = 1000; // Max 10%
+    pub const DEFAULT_PERSISTENCY_START: u8 = actuarial::PERSISTENCY_DISCOUNT_MIN_MONTHS; // After 1 year
+    pub const DEFAULT_PERSISTENCY_BPS: u16 = actuarial::PERSISTENCY_DISCOUNT_MIN_BPS; // 5% at eligibility
+    pub const MAX_PERSISTENCY_BPS: u16 = actuarial::PERSISTENCY_DISCOUNT_MAX_BPS; // Max 10%
+
+    /// Months of tenure over which the discount ramps from the starting
+    /// rate to the maximum
+    pub const PERSISTENCY_RAMP_MONTHS: u16 = 12;
+    pub const DEFAULT_GRACE_PERIOD_DAYS: u16 = 30;
+
+    pub fn waiting_period_seconds(&self) -> i64 {
+        self.default_waiting_period_days as i64 * protocol_constants::SECONDS_PER_DAY
+    }
+
+    pub fn grace_period_seconds(&self) -> i64 {
+        self.grace_period_days as i64 * 24 * 60 * 60
+    }
+
+    /// Months of continuous coverage required before any discount applies
+    /// (never below the protocol minimum)
+    pub fn persistency_eligibility_months(&self) -> u16 {
+        self.persistency_discount_start_months
+            .max(actuarial::PERSISTENCY_DISCOUNT_MIN_MONTHS) as u16
+    }
+
+    /// Persistency discount earned for a run of continuous coverage.
+    /// Starts at `persistency_discount_bps` on eligibility and scales
+    /// linearly by tenure up to `max_persistency_discount_bps`.
+    pub fn persistency_discount_for(&self, continuous_months: u16) -> u16 {
+        let start = self.persistency_eligibility_months();
+        if continuous_months < start {
+            return 0;
+        }
+        let min_bps = self
+            .persistency_discount_bps
+            .min(self.max_persistency_discount_bps);
+        let span = (self.max_persistency_discount_bps - min_bps) as u32;
+        let tenure = (continuous_months - start).min(Self::PERSISTENCY_RAMP_MONTHS) as u32;
+        min_bps + (span * tenure / Self::PERSISTENCY_RAMP_MONTHS as u32) as u16
+    }
+
+    fn persistency_budget_rolled_over(&self, current_time: i64) -> bool {
+        current_time
+            >= self
+                .persistency_budget_period_start
+                .saturating_add(protocol_constants::SECONDS_PER_YEAR)
+    }
+
+    /// Persistency discount budget left this budget year (None if unbudgeted)
+    pub fn persistency_budget_remaining(&self, current_time: i64) -> Option<u64> {
+        if self.persistency_discount_budget == 0 {
+            return None;
+        }
+        let used = if self.persistency_budget_rolled_over(current_time) {
+            0
+        } else {
+            self.persistency_budget_used
+        };
+        Some(self.persistency_discount_budget.saturating_sub(used))
+    }
+
+    /// Charge the annualized cost of a discount against the budget, starting
+    /// a new budget year if the last one has ended. Returns false, charging
+    /// nothing, if the cost would exceed what is left.
+    pub fn charge_persistency_discount(&mut self, annual_cost: u64, current_time: i64) -> bool {
+        if self.persistency_budget_rolled_over(current_time) {
+            self.persistency_budget_used = 0;
+            self.persistency_budget_period_start = current_time;
+        }
+        if let Some(remaining) = self.persistency_budget_remaining(current_time) {
+            if annual_cost > remaining {
+                return false;
+            }
+        }
+        self.persistency_budget_used = self.persistency_budget_used.saturating_add(annual_cost);
+        self.persistency_discounts_granted = self
+            .persistency_discounts_granted
+            .saturating_add(annual_cost);
+        true
+    }
+}
+
+/// Protocol-wide registry of enrolled members, enforcing the member cap
+/// PDA seeds: ["member_registry"]
+#[account]
+#[derive(InitSpace)]
+pub struct MemberRegistry {
+    /// Members currently holding a slot (enrolled and not terminated)
+    pub enrolled_members: u32,
+
+    /// Member cap once bootstrap mode has ended (0 = uncapped)
+    pub scaled_max_members: u32,
+
+    /// Slots taken (all-time)
+    pub total_registered: u64,
+
+    /// Slots freed by termination (all-time)
+    pub total_released: u64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl MemberRegistry {
+    pub const SEED_PREFIX: &'static [u8] = b"member_registry";
+
+    /// Member cap in force: the bootstrap maximum while bootstrap mode is
+    /// active, otherwise the configured scaled maximum
+    pub fn member_cap(&self, bootstrap_active: bool) -> Option<u32> {
+        if bootstrap_active {
+            Some(bootstrap::BOOTSTRAP_MAX_MEMBERS)
+        } else if self.scaled_max_members > 0 {
+            Some(self.scaled_max_members)
+        } else {
+            None
+        }
+    }
+
+    /// Take a slot for a new member, failing once the cap is reached
+    pub fn register(&mut self, bootstrap_active: bool) -> Result<()> {
+        if let Some(cap) = self.member_cap(bootstrap_active) {
+            require!(
+                self.enrolled_members < cap,
+                MembershipError::MemberCapReached
+            );
+        }
+        self.enrolled_members += 1;
+        self.total_registered = self.total_registered.saturating_add(1);
+        Ok(())
+    }
+
+    /// Free a slot when a member terminates
+    pub fn release(&mut self) {
+        self.enrolled_members = self.enrolled_members.saturating_sub(1);
+        self.total_released = self.total_released.saturating_add(1);
+    }
 }
 
 /// Individual member account
@@ -83,15 +277,24 @@ pub struct MemberAccount {
     /// Is tobacco user
     pub is_tobacco_user: bool,
 
-    /// Number of dependents (children)
+    /// Number of covered child `Dependent` records
     pub num_children: u8,
 
-    /// Number of additional adults
+    /// Number of covered adult `Dependent` records
     pub num_additional_adults: u8,
 
+    /// Coverage tier derived from covered dependents
+    pub coverage_tier: CoverageTier,
+
+    /// Next dependent index (sequential, never reused)
+    pub next_dependent_index: u8,
+
     /// Enrollment timestamp
     pub enrolled_at: i64,
 
+    /// End of the adverse-selection waiting period (no coverage before)
+    pub waiting_period_ends: i64,
+
     /// Coverage activation timestamp (0 if not yet active)
     pub coverage_activated_at: i64,
 
@@ -107,6 +310,9 @@ pub struct MemberAccount {
     /// Last contribution timestamp
     pub last_contribution_at: i64,
 
+    /// When the member first went past due (0 if current)
+    pub past_due_since: i64,
+
     /// Consecutive months of coverage
     pub consecutive_months: u16,
 
@@ -119,6 +325,22 @@ pub struct MemberAccount {
     /// Applied persistency discount (basis points)
     pub persistency_discount_bps: u16,
 
+    /// Current plan year (1 = enrollment year)
+    pub plan_year: u16,
+
+    /// Start of the current plan year
+    pub plan_year_start: i64,
+
+    /// Start of the most recent coverage gap (0 if never suspended)
+    pub last_gap_start: i64,
+
+    /// End of the most recent coverage gap (0 while still suspended)
+    pub last_gap_end: i64,
+
+    /// Continuous months of paid coverage (reset by a lapse in payment).
+    /// Drives persistency discount eligibility and tenure.
+    pub continuous_months: u16,
+
     /// Benefit schedule key
     #[max_len(32)]
     pub benefit_schedule: String,
@@ -129,6 +351,191 @@ pub struct MemberAccount {
 
 impl MemberAccount {
     pub const SEED_PREFIX: &'static [u8] = b"member";
+
+    /// Maximum covered dependents under one subscriber
+    pub const MAX_DEPENDENTS: u8 = 8;
+
+    /// Days after a qualifying life event during which special enrollment is allowed
+    pub const QLE_ENROLLMENT_DAYS: i64 = 60;
+
+    /// Plan year length
+    pub const PLAN_YEAR_SECONDS: i64 = 365 * 24 * 60 * 60;
+
+    /// Timestamp at which the current plan year ends
+    pub fn plan_year_end(&self) -> i64 {
+        self.plan_year_start.saturating_add(Self::PLAN_YEAR_SECONDS)
+    }
+
+    /// Renewal is due once the current plan year has ended
+    pub fn is_renewal_due(&self, current_time: i64) -> bool {
+        current_time >= self.plan_year_end()
+    }
+
+    /// Roll into the plan year containing `current_time`. Skipped years (no
+    /// renewal run) are rolled past. The claims program's benefit accumulator
+    /// keys its annual totals on `plan_year`, so advancing it is what resets
+    /// the member's deductible and out-of-pocket progress.
+    pub fn start_new_plan_year(&mut self, current_time: i64) {
+        let elapsed_years = (current_time - self.plan_year_start) / Self::PLAN_YEAR_SECONDS;
+        if elapsed_years <= 0 {
+            return;
+        }
+        self.plan_year = self.plan_year.saturating_add(elapsed_years as u16);
+        self.plan_year_start = self
+            .plan_year_start
+            .saturating_add(elapsed_years * Self::PLAN_YEAR_SECONDS);
+    }
+
+    /// Whether a qualifying life event at `event_at` still permits
+    /// enrollment outside an open window
+    pub fn qualifying_event_permits_enrollment(event_at: i64, current_time: i64) -> bool {
+        event_at > 0
+            && event_at <= current_time
+            && current_time - event_at <= Self::QLE_ENROLLMENT_DAYS * 24 * 60 * 60
+    }
+
+    /// Services shortly after enrollment or reinstatement look suspicious
+    pub const TIMING_ANOMALY_DAYS: i64 = 30;
+
+    /// Whether `service_date` falls inside the coverage window: on or after
+    /// enrollment and outside the most recent suspension gap
+    pub fn covers_service_date(&self, service_date: i64) -> bool {
+        if service_date < self.enrolled_at {
+            return false;
+        }
+        let in_gap = self.last_gap_start > 0
+            && service_date >= self.last_gap_start
+            && (self.last_gap_end == 0 || service_date < self.last_gap_end);
+        !in_gap
+    }
+
+    /// Covered service dated within `TIMING_ANOMALY_DAYS` of enrollment or
+    /// of the end of the last coverage gap
+    pub fn is_timing_anomaly(&self, service_date: i64) -> bool {
+        let window = Self::TIMING_ANOMALY_DAYS * 24 * 60 * 60;
+        let near = |start: i64| start > 0 && service_date >= start && service_date - start < window;
+        near(self.enrolled_at) || near(self.last_gap_end)
+    }
+
+    /// Open a coverage gap at `gap_start` (suspension)
+    pub fn open_coverage_gap(&mut self, gap_start: i64) {
+        self.last_gap_start = gap_start;
+        self.last_gap_end = 0;
+    }
+
+    /// Close the open coverage gap at `gap_end` (reinstatement)
+    pub fn close_coverage_gap(&mut self, gap_end: i64) {
+        if self.last_gap_start > 0 && self.last_gap_end == 0 {
+            self.last_gap_end = gap_end;
+        }
+    }
+
+    /// Coverage can activate once the waiting period has fully elapsed
+    pub fn waiting_period_complete(&self, current_time: i64) -> bool {
+        current_time >= self.waiting_period_ends
+    }
+
+    /// Coverage is in force: activated, not suspended/terminated, and past
+    /// the waiting period
+    pub fn has_active_coverage(&self, current_time: i64) -> bool {
+        self.status == MemberStatus::Active && self.waiting_period_complete(current_time)
+    }
+
+    /// Services rendered before the waiting period ends are not covered
+    pub fn is_in_waiting_period(&self, service_date: i64) -> bool {
+        service_date < self.waiting_period_ends
+    }
+
+    /// Count a paid month toward continuous coverage. A payment made after
+    /// the grace period lapsed starts a new run.
+    pub fn record_paid_month(&mut self, payment_state: PaymentState) {
+        match payment_state {
+            PaymentState::Current | PaymentState::InGracePeriod => {
+                self.continuous_months = self.continuous_months.saturating_add(1);
+            }
+            PaymentState::PastDue => self.continuous_months = 1,
+        }
+    }
+
+    /// Contribution before the currently applied persistency discount
+    pub fn undiscounted_contribution(&self) -> u64 {
+        let retained_bps = 10000u64.saturating_sub(self.persistency_discount_bps as u64);
+        if retained_bps == 0 {
+            return self.monthly_contribution;
+        }
+        (self.monthly_contribution as u128 * 10000 / retained_bps as u128) as u64
+    }
+
+    /// Number of currently covered dependents
+    pub fn dependent_count(&self) -> u8 {
+        self.num_children.saturating_add(self.num_additional_adults)
+    }
+
+    /// Record a newly covered dependent and re-derive the coverage tier
+    pub fn add_dependent(&mut self, relationship: DependentRelationship) {
+        if relationship.is_adult() {
+            self.num_additional_adults = self.num_additional_adults.saturating_add(1);
+        } else {
+            self.num_children = self.num_children.saturating_add(1);
+        }
+        self.coverage_tier =
+            CoverageTier::from_dependents(self.num_additional_adults, self.num_children);
+    }
+
+    /// Ages to price the covered additional adults at: the covered
+    /// spouse or partner's own age, which must be supplied while one is
+    /// covered
+    pub fn additional_adult_ages(&self, spouse: Option<&Dependent>) -> Result<Vec<u8>> {
+        if self.num_additional_adults == 0 {
+            return Ok(Vec::new());
+        }
+        let spouse = spouse.ok_or(MembershipError::SpouseAccountRequired)?;
+        Ok(vec![spouse.age; self.num_additional_adults as usize])
+    }
+
+    /// Remove a covered dependent and re-derive the coverage tier
+    pub fn remove_dependent(&mut self, relationship: DependentRelationship) {
+        if relationship.is_adult() {
+            self.num_additional_adults = self.num_additional_adults.saturating_sub(1);
+        } else {
+            self.num_children = self.num_children.saturating_sub(1);
+        }
+        self.coverage_tier =
+            CoverageTier::from_dependents(self.num_additional_adults, self.num_children);
+    }
+}
+
+/// Coverage tier (who is covered under the subscriber)
+/// Anything above `Individual` uses family maximums and deductibles
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace, Default,
+)]
+pub enum CoverageTier {
+    /// Subscriber only
+    #[default]
+    Individual,
+    /// Subscriber + spouse/partner
+    MemberSpouse,
+    /// Subscriber + child(ren)
+    MemberChildren,
+    /// Subscriber + spouse/partner + child(ren)
+    Family,
+}
+
+impl CoverageTier {
+    pub fn from_dependents(adults: u8, children: u8) -> Self {
+        match (adults > 0, children > 0) {
+            (false, false) => CoverageTier::Individual,
+            (true, false) => CoverageTier::MemberSpouse,
+            (false, true) => CoverageTier::MemberChildren,
+            (true, true) => CoverageTier::Family,
+        }
+    }
+
+    /// Whether family annual maximums and deductibles apply
+    pub fn is_family(&self) -> bool {
+        *self != CoverageTier::Individual
+    }
 }
 
 /// Member status
@@ -152,6 +559,63 @@ impl Default for MemberStatus {
     }
 }
 
+/// Dependent covered under a subscriber
+/// PDA seeds: ["dependent", subscriber, index]
+#[account]
+#[derive(InitSpace)]
+pub struct Dependent {
+    /// Subscriber (primary member) this dependent is covered under
+    pub subscriber: Pubkey,
+
+    /// Dependent index under the subscriber
+    pub index: u8,
+
+    /// Relationship to the subscriber
+    pub relationship: DependentRelationship,
+
+    /// Date of birth (Unix timestamp)
+    pub date_of_birth: i64,
+
+    /// Age at time of enrollment
+    pub age: u8,
+
+    /// Coverage status
+    pub status: MemberStatus,
+
+    /// Added timestamp
+    pub added_at: i64,
+
+    /// Removed timestamp (0 if still covered)
+    pub removed_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl Dependent {
+    pub const SEED_PREFIX: &'static [u8] = b"dependent";
+
+    /// Children may be covered until age 26
+    pub const MAX_CHILD_AGE: u8 = 25;
+}
+
+/// Dependent relationship to the subscriber
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum DependentRelationship {
+    Spouse,
+    DomesticPartner,
+    Child,
+}
+
+impl DependentRelationship {
+    pub fn is_adult(&self) -> bool {
+        matches!(
+            self,
+            DependentRelationship::Spouse | DependentRelationship::DomesticPartner
+        )
+    }
+}
+
 /// Enrollment window configuration
 /// PDA seeds: ["enrollment_window", window_id]
 #[account]
@@ -195,6 +659,45 @@ impl EnrollmentWindow {
             && current_time <= self.end_time
             && self.enrollment_count < self.max_enrollments
     }
+
+    /// `requested` capped at the zone's monthly cap for every enrollment
+    /// month the window touches
+    pub fn capacity_within(&self, requested: u32, monthly_cap: u32) -> u32 {
+        let months = (ZoneState::month_start(self.end_time)
+            - ZoneState::month_start(self.start_time))
+            / ZoneState::MONTH_SECONDS
+            + 1;
+        requested.min(monthly_cap.saturating_mul(months.min(u32::MAX as i64) as u32))
+    }
+}
+
+/// Qualifying life event verified and recorded by the membership authority
+/// for a prospective member (special enrollment outside an open window)
+/// PDA seeds: ["qualifying_event", prospect]
+#[account]
+#[derive(InitSpace)]
+pub struct QualifyingEventRecord {
+    /// Wallet that may enroll on the strength of this event
+    pub prospect: Pubkey,
+
+    /// Kind of life event
+    pub event_type: QualifyingEvent,
+
+    /// When the event occurred
+    pub event_at: i64,
+
+    /// Authority that verified the documentation
+    pub recorded_by: Pubkey,
+
+    /// When it was recorded
+    pub recorded_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl QualifyingEventRecord {
+    pub const SEED_PREFIX: &'static [u8] = b"qualifying_event";
 }
 
 /// Contribution ledger for tracking payments
@@ -223,18 +726,198 @@ pub struct ContributionLedger {
     /// Amount due for next period
     pub amount_due: u64,
 
+    /// Still owed toward the next unpaid period after partial deposits
+    pub member_balance_due: u64,
+
     /// Number of on-time payments
     pub on_time_payments: u32,
 
     /// Number of late payments
     pub late_payments: u32,
 
+    /// Start of billing period 1 (first payment due date)
+    pub period_anchor: i64,
+
+    /// Last billing period paid in full (0 = nothing paid yet)
+    pub paid_through: u32,
+
+    /// Most recent payments, oldest first (bounded to `MAX_HISTORY`)
+    #[max_len(12)]
+    pub history: Vec<PaymentRecord>,
+
+    /// Refund owed to the member after termination, awaiting payout
+    pub pending_refund: u64,
+
     /// Bump seed
     pub bump: u8,
 }
 
 impl ContributionLedger {
     pub const SEED_PREFIX: &'static [u8] = b"contribution_ledger";
+
+    /// Billing period length (30 days)
+    pub const PERIOD_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// Payments retained in `history`
+    pub const MAX_HISTORY: usize = 12;
+
+    /// Billing period containing `timestamp` (1-based; 0 before the anchor)
+    pub fn period_at(&self, timestamp: i64) -> u32 {
+        if timestamp < self.period_anchor {
+            return 0;
+        }
+        ((timestamp - self.period_anchor) / Self::PERIOD_SECONDS + 1) as u32
+    }
+
+    /// Due date of the first unpaid period
+    pub fn due_date(&self) -> i64 {
+        self.period_anchor
+            .saturating_add(self.paid_through as i64 * Self::PERIOD_SECONDS)
+    }
+
+    /// Amount deposited so far toward the next unpaid period
+    pub fn partial_paid(&self) -> u64 {
+        self.amount_due.saturating_sub(self.member_balance_due)
+    }
+
+    /// Change the per-period amount, keeping any partial payment already
+    /// made toward the outstanding period
+    pub fn set_amount_due(&mut self, new_amount_due: u64) {
+        let partial_paid = self.partial_paid();
+        self.amount_due = new_amount_due;
+        self.member_balance_due = new_amount_due.saturating_sub(partial_paid);
+    }
+
+    /// Apply a deposit against `member_balance_due`. A deposit that covers
+    /// the outstanding balance pays the period (excess is credited to
+    /// `balance`) and resets the balance due to `next_amount_due`; anything
+    /// less just reduces it. Returns the period paid, if one was.
+    pub fn apply_deposit(
+        &mut self,
+        amount: u64,
+        next_amount_due: u64,
+        current_time: i64,
+    ) -> Option<u32> {
+        if amount < self.member_balance_due {
+            self.member_balance_due -= amount;
+            self.total_applied = self.total_applied.saturating_add(amount);
+            return None;
+        }
+
+        let applied = self.member_balance_due;
+        self.balance = self.balance.saturating_add(amount - applied);
+        self.total_applied = self.total_applied.saturating_add(applied);
+
+        let period = self.record_payment(self.amount_due, current_time);
+        self.amount_due = next_amount_due;
+        self.member_balance_due = next_amount_due;
+        Some(period)
+    }
+
+    /// Record a full payment: prepays the next period, or pays the current
+    /// one if earlier periods were skipped. Returns the period paid.
+    pub fn record_payment(&mut self, amount: u64, current_time: i64) -> u32 {
+        let period = self
+            .paid_through
+            .saturating_add(1)
+            .max(self.period_at(current_time));
+
+        if self.history.len() >= Self::MAX_HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(PaymentRecord {
+            period,
+            amount,
+            timestamp: current_time,
+        });
+
+        self.paid_through = period;
+        self.next_payment_due = self.due_date();
+        period
+    }
+
+    /// Unused portion of prepaid contributions at `current_time`: the
+    /// unelapsed fraction of each paid period plus any credit balance
+    pub fn unused_prepaid(&self, current_time: i64) -> u64 {
+        let unused_periods: u64 = self
+            .history
+            .iter()
+            .map(|record| {
+                let start = self
+                    .period_anchor
+                    .saturating_add((record.period as i64 - 1) * Self::PERIOD_SECONDS);
+                let end = start.saturating_add(Self::PERIOD_SECONDS);
+                let unused = (end - current_time.max(start)).clamp(0, Self::PERIOD_SECONDS);
+                (record.amount as u128 * unused as u128 / Self::PERIOD_SECONDS as u128) as u64
+            })
+            .sum();
+        unused_periods.saturating_add(self.balance)
+    }
+
+    /// Refund owed on termination at `current_time` (zero for
+    /// non-refundable plans)
+    pub fn termination_refund(&self, current_time: i64, refunds_enabled: bool) -> u64 {
+        if !refunds_enabled {
+            return 0;
+        }
+        self.unused_prepaid(current_time)
+    }
+
+    /// Queue the termination refund in `pending_refund`, folding the credit
+    /// balance into it. With no refund booked the balance stays on the
+    /// ledger. Returns the refund booked.
+    pub fn book_termination_refund(&mut self, current_time: i64, refunds_enabled: bool) -> u64 {
+        let refund = self.termination_refund(current_time, refunds_enabled);
+        if refund > 0 {
+            self.pending_refund = self.pending_refund.saturating_add(refund);
+            self.balance = 0;
+        }
+        refund
+    }
+
+    /// Periods within the retained history that were never paid
+    pub fn missed_periods(&self) -> Vec<u32> {
+        let Some(first) = self.history.first() else {
+            return vec![];
+        };
+        (first.period..=self.paid_through)
+            .filter(|period| !self.history.iter().any(|p| p.period == *period))
+            .collect()
+    }
+
+    /// Payment state relative to `paid_through` and the grace period
+    pub fn payment_state(&self, current_time: i64, grace_period_seconds: i64) -> PaymentState {
+        let due = self.due_date();
+        if current_time <= due {
+            PaymentState::Current
+        } else if current_time < due.saturating_add(grace_period_seconds) {
+            PaymentState::InGracePeriod
+        } else {
+            PaymentState::PastDue
+        }
+    }
+}
+
+/// One entry in a member's payment history
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct PaymentRecord {
+    /// Billing period paid
+    pub period: u32,
+    /// Amount deposited
+    pub amount: u64,
+    /// Deposit timestamp
+    pub timestamp: i64,
+}
+
+/// Payment state reported by `check_payment_status`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum PaymentState {
+    /// Paid through the current due date
+    Current,
+    /// Past due but coverage remains active
+    InGracePeriod,
+    /// Grace period lapsed - coverage can be suspended
+    PastDue,
 }
 
 /// Qualifying life events for special enrollment
@@ -271,6 +954,7 @@ mod tests {
         assert_eq!(GlobalConfig::DEFAULT_PERSISTENCY_START, 12);
         assert_eq!(GlobalConfig::DEFAULT_PERSISTENCY_BPS, 500); // 5%
         assert_eq!(GlobalConfig::MAX_PERSISTENCY_BPS, 1000); // 10%
+        assert_eq!(GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS, 30);
     }
 
     #[test]
@@ -285,6 +969,385 @@ mod tests {
         assert_eq!(MemberAccount::SEED_PREFIX, b"member");
     }
 
+    fn create_test_member() -> MemberAccount {
+        MemberAccount {
+            member: Pubkey::default(),
+            member_id: 1,
+            age: 35,
+            region_code: 1,
+            is_tobacco_user: false,
+            num_children: 0,
+            num_additional_adults: 0,
+            coverage_tier: CoverageTier::Individual,
+            next_dependent_index: 0,
+            enrolled_at: 0,
+            waiting_period_ends: 0,
+            coverage_activated_at: 0,
+            status: MemberStatus::Active,
+            monthly_contribution: 450_000_000, // $450
+            total_contributions_paid: 0,
+            last_contribution_at: 0,
+            past_due_since: 0,
+            consecutive_months: 0,
+            has_qualifying_event: false,
+            qualifying_event_at: 0,
+            persistency_discount_bps: 0,
+            plan_year: 1,
+            plan_year_start: 0,
+            last_gap_start: 0,
+            last_gap_end: 0,
+            continuous_months: 0,
+            benefit_schedule: String::from("standard"),
+            bump: 255,
+        }
+    }
+
+    // ==================== WAITING PERIOD TESTS ====================
+
+    fn enroll_test_member(config: &GlobalConfig, enrolled_at: i64) -> MemberAccount {
+        let mut member = create_test_member();
+        member.status = MemberStatus::PendingActivation;
+        member.enrolled_at = enrolled_at;
+        member.waiting_period_ends = enrolled_at + config.waiting_period_seconds();
+        member
+    }
+
+    #[test]
+    fn test_activation_rejected_at_day_29() {
+        let config = create_test_config();
+        let member = enroll_test_member(&config, 1_000 * DAY);
+
+        assert!(!member.waiting_period_complete(1_029 * DAY));
+        assert!(member.is_in_waiting_period(1_029 * DAY));
+    }
+
+    #[test]
+    fn test_activation_allowed_at_day_30() {
+        let config = create_test_config();
+        let member = enroll_test_member(&config, 1_000 * DAY);
+
+        assert!(member.waiting_period_complete(1_030 * DAY));
+        assert!(!member.is_in_waiting_period(1_030 * DAY));
+    }
+
+    #[test]
+    fn test_waiting_period_configurable() {
+        let mut config = create_test_config();
+        config.default_waiting_period_days = 60;
+        let member = enroll_test_member(&config, 0);
+
+        assert!(!member.waiting_period_complete(59 * DAY));
+        assert!(member.waiting_period_complete(60 * DAY));
+    }
+
+    // ==================== SPECIAL ENROLLMENT TESTS ====================
+
+    #[test]
+    fn test_enrollment_allowed_ten_days_after_qle() {
+        let qle_at = 1_000 * DAY;
+        assert!(MemberAccount::qualifying_event_permits_enrollment(
+            qle_at,
+            qle_at + 10 * DAY
+        ));
+        assert!(MemberAccount::qualifying_event_permits_enrollment(
+            qle_at,
+            qle_at + 60 * DAY
+        ));
+    }
+
+    #[test]
+    fn test_enrollment_rejected_ninety_days_after_qle() {
+        let qle_at = 1_000 * DAY;
+        assert!(!MemberAccount::qualifying_event_permits_enrollment(
+            qle_at,
+            qle_at + 90 * DAY
+        ));
+        // No event, or an event dated in the future
+        assert!(!MemberAccount::qualifying_event_permits_enrollment(
+            0, qle_at
+        ));
+        assert!(!MemberAccount::qualifying_event_permits_enrollment(
+            qle_at + DAY,
+            qle_at
+        ));
+    }
+
+    // ==================== RENEWAL TESTS ====================
+
+    fn create_test_config() -> GlobalConfig {
+        GlobalConfig {
+            authority: Pubkey::default(),
+            governance_program: Pubkey::default(),
+            risk_engine_program: Pubkey::default(),
+            reserves_program: Pubkey::default(),
+            usdc_mint: Pubkey::default(),
+            total_members: 0,
+            active_members: 0,
+            total_contributions: 0,
+            default_waiting_period_days: GlobalConfig::DEFAULT_WAITING_PERIOD,
+            preexisting_waiting_days: GlobalConfig::DEFAULT_PREEXISTING_WAIT,
+            persistency_discount_start_months: GlobalConfig::DEFAULT_PERSISTENCY_START,
+            persistency_discount_bps: GlobalConfig::DEFAULT_PERSISTENCY_BPS,
+            max_persistency_discount_bps: GlobalConfig::MAX_PERSISTENCY_BPS,
+            grace_period_days: GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS,
+            enrollment_open: true,
+            refunds_enabled: true,
+            persistency_discount_budget: 0,
+            persistency_budget_used: 0,
+            persistency_budget_period_start: 0,
+            persistency_discounts_granted: 0,
+            open_window_id: 0,
+            open_window_end: 0,
+            special_window_id: 0,
+            special_window_end: 0,
+            bump: 255,
+        }
+    }
+
+    fn create_test_registry() -> MemberRegistry {
+        MemberRegistry {
+            enrolled_members: 0,
+            scaled_max_members: 0,
+            total_registered: 0,
+            total_released: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_bootstrap_rejects_member_past_cap() {
+        let mut registry = create_test_registry();
+        for _ in 0..bootstrap::BOOTSTRAP_MAX_MEMBERS {
+            registry.register(true).unwrap();
+        }
+        assert_eq!(registry.enrolled_members, 200);
+
+        // The 201st enrollment is rejected in bootstrap mode
+        assert!(registry.register(true).is_err());
+        assert_eq!(registry.enrolled_members, 200);
+
+        // A termination frees a slot
+        registry.release();
+        registry.register(true).unwrap();
+        assert_eq!(registry.enrolled_members, 200);
+        assert_eq!(registry.total_registered, 201);
+        assert_eq!(registry.total_released, 1);
+    }
+
+    #[test]
+    fn test_scaled_member_cap() {
+        let mut registry = create_test_registry();
+        registry.enrolled_members = bootstrap::BOOTSTRAP_MAX_MEMBERS;
+
+        // Uncapped once bootstrap ends without a scaled maximum
+        assert_eq!(registry.member_cap(false), None);
+        registry.register(false).unwrap();
+
+        registry.scaled_max_members = 202;
+        registry.register(false).unwrap();
+        assert!(registry.register(false).is_err());
+    }
+
+    #[test]
+    fn test_persistency_discounts_stop_when_budget_exhausted() {
+        let mut config = create_test_config();
+        config.persistency_discount_budget = 1_000_000_000; // $1,000 per year
+        let now = 10 * protocol_constants::SECONDS_PER_YEAR;
+
+        // $50/month discount = $600 a year
+        assert!(config.charge_persistency_discount(600_000_000, now));
+        assert_eq!(config.persistency_budget_remaining(now), Some(400_000_000));
+
+        // A second $600 discount would overrun the budget and is not granted
+        assert!(!config.charge_persistency_discount(600_000_000, now + 1));
+        assert_eq!(config.persistency_budget_used, 600_000_000);
+
+        assert!(config.charge_persistency_discount(400_000_000, now + 2));
+        assert_eq!(config.persistency_budget_remaining(now + 2), Some(0));
+        assert!(!config.charge_persistency_discount(1, now + 3));
+        assert_eq!(config.persistency_discounts_granted, 1_000_000_000);
+
+        // The budget refreshes a year after the budget period started
+        let next_year = now + protocol_constants::SECONDS_PER_YEAR;
+        assert_eq!(
+            config.persistency_budget_remaining(next_year),
+            Some(1_000_000_000)
+        );
+        assert!(config.charge_persistency_discount(600_000_000, next_year));
+        assert_eq!(config.persistency_discounts_granted, 1_600_000_000);
+    }
+
+    #[test]
+    fn test_unbudgeted_persistency_discounts_are_tracked() {
+        let mut config = create_test_config();
+        assert_eq!(config.persistency_budget_remaining(0), None);
+
+        assert!(config.charge_persistency_discount(u64::MAX / 2, 0));
+        assert!(config.charge_persistency_discount(600_000_000, 1));
+        assert_eq!(
+            config.persistency_discounts_granted,
+            u64::MAX / 2 + 600_000_000
+        );
+    }
+
+    #[test]
+    fn test_renewal_advances_plan_year() {
+        let mut member = create_test_member();
+
+        let year_end = member.plan_year_end();
+        assert!(!member.is_renewal_due(year_end - 1));
+        assert!(member.is_renewal_due(year_end));
+
+        member.start_new_plan_year(year_end + 10);
+        assert_eq!(member.plan_year, 2);
+        assert_eq!(member.plan_year_start, year_end);
+
+        // Only once per plan year
+        assert!(!member.is_renewal_due(year_end + 10));
+    }
+
+    #[test]
+    fn test_renewal_prices_spouse_at_own_age() {
+        let mut member = create_test_member();
+        assert_eq!(
+            member.additional_adult_ages(None).unwrap(),
+            Vec::<u8>::new()
+        );
+
+        member.add_dependent(DependentRelationship::Spouse);
+        let spouse = Dependent {
+            subscriber: member.member,
+            index: 0,
+            relationship: DependentRelationship::Spouse,
+            date_of_birth: 0,
+            age: 52,
+            status: MemberStatus::Active,
+            added_at: 0,
+            removed_at: 0,
+            bump: 255,
+        };
+        assert_ne!(spouse.age, member.age);
+        assert_eq!(
+            member.additional_adult_ages(Some(&spouse)).unwrap(),
+            vec![52]
+        );
+
+        // A covered spouse cannot be left out of the quote
+        assert!(member.additional_adult_ages(None).is_err());
+    }
+
+    #[test]
+    fn test_renewal_rolls_past_skipped_years() {
+        let mut member = create_test_member();
+        let now = 2 * MemberAccount::PLAN_YEAR_SECONDS + 100;
+
+        member.start_new_plan_year(now);
+        assert_eq!(member.plan_year, 3);
+        assert_eq!(member.plan_year_start, 2 * MemberAccount::PLAN_YEAR_SECONDS);
+        assert!(!member.is_renewal_due(now));
+    }
+
+    #[test]
+    fn test_persistency_discount_at_twelve_months() {
+        let config = create_test_config();
+
+        assert_eq!(config.persistency_discount_for(0), 0);
+        assert_eq!(config.persistency_discount_for(11), 0);
+        assert_eq!(config.persistency_discount_for(12), 500);
+    }
+
+    #[test]
+    fn test_persistency_discount_scales_by_tenure() {
+        let config = create_test_config();
+
+        assert_eq!(config.persistency_discount_for(18), 750);
+        assert_eq!(config.persistency_discount_for(24), 1000);
+        assert_eq!(config.persistency_discount_for(60), 1000);
+    }
+
+    #[test]
+    fn test_persistency_eligibility_floor() {
+        let mut config = create_test_config();
+        config.persistency_discount_start_months = 6;
+
+        // Configured start can't undercut the protocol minimum
+        assert_eq!(config.persistency_eligibility_months(), 12);
+        assert_eq!(config.persistency_discount_for(11), 0);
+    }
+
+    #[test]
+    fn test_continuous_months_reset_after_gap() {
+        let mut member = create_test_member();
+
+        for _ in 0..11 {
+            member.record_paid_month(PaymentState::Current);
+        }
+        member.record_paid_month(PaymentState::InGracePeriod);
+        assert_eq!(member.continuous_months, 12);
+
+        // Paying after the grace period lapsed starts a new run
+        member.record_paid_month(PaymentState::PastDue);
+        assert_eq!(member.continuous_months, 1);
+    }
+
+    #[test]
+    fn test_undiscounted_contribution() {
+        let mut member = create_test_member();
+        member.persistency_discount_bps = 500;
+        member.monthly_contribution = 427_500_000; // $450 less 5%
+
+        assert_eq!(member.undiscounted_contribution(), 450_000_000);
+    }
+
+    // ==================== DEPENDENT TESTS ====================
+
+    #[test]
+    fn test_add_spouse_and_two_children() {
+        let mut member = create_test_member();
+
+        member.add_dependent(DependentRelationship::Spouse);
+        assert_eq!(member.coverage_tier, CoverageTier::MemberSpouse);
+
+        member.add_dependent(DependentRelationship::Child);
+        member.add_dependent(DependentRelationship::Child);
+        assert_eq!(member.coverage_tier, CoverageTier::Family);
+        assert_eq!(member.num_additional_adults, 1);
+        assert_eq!(member.num_children, 2);
+        assert_eq!(member.dependent_count(), 3);
+        assert!(member.coverage_tier.is_family());
+    }
+
+    #[test]
+    fn test_remove_dependents_reverts_tier() {
+        let mut member = create_test_member();
+        member.add_dependent(DependentRelationship::Spouse);
+        member.add_dependent(DependentRelationship::Child);
+        member.add_dependent(DependentRelationship::Child);
+
+        member.remove_dependent(DependentRelationship::Spouse);
+        assert_eq!(member.coverage_tier, CoverageTier::MemberChildren);
+
+        member.remove_dependent(DependentRelationship::Child);
+        assert_eq!(member.coverage_tier, CoverageTier::MemberChildren);
+
+        member.remove_dependent(DependentRelationship::Child);
+        assert_eq!(member.coverage_tier, CoverageTier::Individual);
+        assert_eq!(member.dependent_count(), 0);
+        assert!(!member.coverage_tier.is_family());
+    }
+
+    #[test]
+    fn test_dependent_relationship_is_adult() {
+        assert!(DependentRelationship::Spouse.is_adult());
+        assert!(DependentRelationship::DomesticPartner.is_adult());
+        assert!(!DependentRelationship::Child.is_adult());
+    }
+
+    #[test]
+    fn test_dependent_seed_prefix() {
+        assert_eq!(Dependent::SEED_PREFIX, b"dependent");
+    }
+
     // ==================== MEMBER STATUS TESTS ====================
 
     #[test]
@@ -369,6 +1432,58 @@ mod tests {
         assert!(window.is_open(200)); // At end
     }
 
+    #[test]
+    fn test_overlapping_window_rejected() {
+        let mut config = create_test_config();
+        let q1 = create_test_enrollment_window(100, 200, true, 0, 1000);
+        config.schedule_window(&q1).unwrap();
+
+        let overlapping = EnrollmentWindow {
+            window_id: 2,
+            ..create_test_enrollment_window(150, 300, true, 0, 1000)
+        };
+        assert!(config.schedule_window(&overlapping).is_err());
+
+        // A special enrollment period runs independently
+        let special = EnrollmentWindow {
+            window_id: 3,
+            is_special_enrollment: true,
+            ..create_test_enrollment_window(150, 300, true, 0, 100)
+        };
+        config.schedule_window(&special).unwrap();
+
+        // Closing Q1 early frees the rest of its range
+        config.release_window(&q1, 160);
+        assert!(config.schedule_window(&overlapping).is_err());
+        let follow_on = EnrollmentWindow {
+            window_id: 4,
+            ..create_test_enrollment_window(161, 300, true, 0, 1000)
+        };
+        config.schedule_window(&follow_on).unwrap();
+        assert_eq!(config.open_window_id, 4);
+        assert_eq!(config.open_window_end, 300);
+    }
+
+    #[test]
+    fn test_window_capacity_capped_in_yellow_zone() {
+        let month = ZoneState::MONTH_SECONDS;
+        let yellow_cap = ZoneState::YELLOW_CAP;
+
+        // Inside one enrollment month: at most one month of the Yellow cap
+        let window = create_test_enrollment_window(month, month + 20 * 86400, true, 0, 2000);
+        assert_eq!(window.capacity_within(2000, yellow_cap), 500);
+
+        // Spanning two enrollment months
+        let window =
+            create_test_enrollment_window(month + 20 * 86400, 2 * month + 10, true, 0, 2000);
+        assert_eq!(window.capacity_within(2000, yellow_cap), 1000);
+
+        // Requests under the cap are kept; Green is unlimited
+        assert_eq!(window.capacity_within(300, yellow_cap), 300);
+        assert_eq!(window.capacity_within(2000, ZoneState::GREEN_CAP), 2000);
+        assert_eq!(window.capacity_within(2000, 0), 0);
+    }
+
     #[test]
     fn test_enrollment_window_seed_prefix() {
         assert_eq!(EnrollmentWindow::SEED_PREFIX, b"enrollment_window");
@@ -376,6 +1491,307 @@ mod tests {
 
     // ==================== CONTRIBUTION LEDGER TESTS ====================
 
+    fn create_test_ledger(next_payment_due: i64) -> ContributionLedger {
+        ContributionLedger {
+            member: Pubkey::default(),
+            balance: 0,
+            total_deposits: 450_000_000,
+            total_applied: 450_000_000,
+            last_deposit_at: 0,
+            next_payment_due,
+            amount_due: 450_000_000,
+            member_balance_due: 450_000_000,
+            on_time_payments: 1,
+            late_payments: 0,
+            period_anchor: next_payment_due,
+            paid_through: 0,
+            history: vec![],
+            pending_refund: 0,
+            bump: 255,
+        }
+    }
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    #[test]
+    fn test_suspension_blocked_within_grace() {
+        let due = 1_000 * DAY;
+        let ledger = create_test_ledger(due);
+        let grace = GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS as i64 * DAY;
+
+        assert_eq!(ledger.payment_state(due, grace), PaymentState::Current);
+        assert_eq!(
+            ledger.payment_state(due + DAY, grace),
+            PaymentState::InGracePeriod
+        );
+        assert_eq!(
+            ledger.payment_state(due + 29 * DAY, grace),
+            PaymentState::InGracePeriod
+        );
+    }
+
+    #[test]
+    fn test_suspension_allowed_after_grace_lapses() {
+        let due = 1_000 * DAY;
+        let ledger = create_test_ledger(due);
+        let grace = GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS as i64 * DAY;
+
+        assert_eq!(
+            ledger.payment_state(due + 30 * DAY, grace),
+            PaymentState::PastDue
+        );
+        assert_eq!(
+            ledger.payment_state(due + 45 * DAY, grace),
+            PaymentState::PastDue
+        );
+    }
+
+    #[test]
+    fn test_grace_claims_retro_denied() {
+        let mut member = create_test_member();
+        member.past_due_since = 1_000 * DAY;
+
+        // Still in grace: coverage active, grace-period services covered
+        assert!(member.covers_service_date(1_010 * DAY));
+
+        // Grace lapsed unpaid: suspension backdates the gap to the past-due date
+        member.status = MemberStatus::Suspended;
+        member.open_coverage_gap(member.past_due_since);
+        assert!(!member.covers_service_date(1_010 * DAY));
+        assert!(member.covers_service_date(990 * DAY));
+    }
+
+    #[test]
+    fn test_claims_eligibility_follows_suspension_and_reinstatement() {
+        use crate::membership_helpers::assert_active_coverage;
+
+        let mut member = create_test_member();
+        member.waiting_period_ends = 100 * DAY;
+        let now = 1_000 * DAY;
+        assert!(assert_active_coverage(&member, now).is_ok());
+
+        member.status = MemberStatus::Suspended;
+        assert!(!member.has_active_coverage(now));
+        assert!(assert_active_coverage(&member, now).is_err());
+
+        // Reinstated after catching up on contributions
+        member.status = MemberStatus::Active;
+        assert!(assert_active_coverage(&member, now).is_ok());
+
+        member.status = MemberStatus::Terminated;
+        assert!(assert_active_coverage(&member, now).is_err());
+    }
+
+    #[test]
+    fn test_service_before_enrollment_outside_coverage() {
+        let config = create_test_config();
+        let member = enroll_test_member(&config, 1_000 * DAY);
+
+        assert!(!member.covers_service_date(999 * DAY));
+        assert!(member.covers_service_date(1_000 * DAY));
+        assert!(member.covers_service_date(1_200 * DAY));
+    }
+
+    #[test]
+    fn test_service_during_coverage_gap_outside_coverage() {
+        let mut member = create_test_member();
+        member.enrolled_at = 100 * DAY;
+
+        // Suspended from day 500, reinstated on day 560
+        member.open_coverage_gap(500 * DAY);
+        assert!(!member.covers_service_date(600 * DAY)); // Gap still open
+        member.close_coverage_gap(560 * DAY);
+
+        assert!(member.covers_service_date(499 * DAY));
+        assert!(!member.covers_service_date(500 * DAY));
+        assert!(!member.covers_service_date(559 * DAY));
+        assert!(member.covers_service_date(560 * DAY));
+
+        // Right after reinstatement is covered but suspicious
+        assert!(member.is_timing_anomaly(565 * DAY));
+        assert!(!member.is_timing_anomaly(600 * DAY));
+    }
+
+    #[test]
+    fn test_claims_rejected_within_waiting_period() {
+        let config = create_test_config();
+        let member = enroll_test_member(&config, 1_000 * DAY);
+        let now = 1_010 * DAY;
+
+        assert!(!member.has_active_coverage(now));
+        assert!(crate::membership_helpers::assert_active_coverage(&member, now).is_err());
+    }
+
+    #[test]
+    fn test_monthly_deposits_advance_paid_through() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        let grace = GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS as i64 * DAY;
+
+        for month in 0..3 {
+            let paid_at = anchor + month * ContributionLedger::PERIOD_SECONDS + DAY;
+            let period = ledger.record_payment(450_000_000, paid_at);
+            assert_eq!(period, month as u32 + 1);
+        }
+
+        assert_eq!(ledger.paid_through, 3);
+        assert_eq!(ledger.history.len(), 3);
+        assert!(ledger.missed_periods().is_empty());
+        assert_eq!(
+            ledger.due_date(),
+            anchor + 3 * ContributionLedger::PERIOD_SECONDS
+        );
+        assert_eq!(ledger.next_payment_due, ledger.due_date());
+        assert_eq!(
+            ledger.payment_state(ledger.due_date() - DAY, grace),
+            PaymentState::Current
+        );
+    }
+
+    #[test]
+    fn test_skipped_month_shows_gap() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        let grace = GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS as i64 * DAY;
+
+        ledger.record_payment(450_000_000, anchor + DAY);
+
+        // Nothing paid in period 2: past due once its grace lapses
+        let period3 = anchor + 2 * ContributionLedger::PERIOD_SECONDS + DAY;
+        assert_eq!(ledger.payment_state(period3, grace), PaymentState::PastDue);
+
+        assert_eq!(ledger.record_payment(450_000_000, period3), 3);
+        assert_eq!(ledger.paid_through, 3);
+        assert_eq!(ledger.missed_periods(), vec![2]);
+    }
+
+    #[test]
+    fn test_payment_history_is_bounded() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+
+        for _ in 0..(ContributionLedger::MAX_HISTORY + 3) {
+            ledger.record_payment(450_000_000, anchor);
+        }
+
+        assert_eq!(ledger.history.len(), ContributionLedger::MAX_HISTORY);
+        assert_eq!(ledger.history[0].period, 4);
+        assert_eq!(ledger.paid_through, 15);
+        assert!(ledger.missed_periods().is_empty());
+    }
+
+    #[test]
+    fn test_two_half_payments_pay_one_period() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        let grace = GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS as i64 * DAY;
+
+        assert_eq!(ledger.apply_deposit(225_000_000, 450_000_000, anchor), None);
+        assert_eq!(ledger.paid_through, 0);
+        assert_eq!(ledger.member_balance_due, 225_000_000);
+        assert_eq!(ledger.partial_paid(), 225_000_000);
+
+        assert_eq!(
+            ledger.apply_deposit(225_000_000, 450_000_000, anchor + DAY),
+            Some(1)
+        );
+        assert_eq!(ledger.paid_through, 1);
+        assert_eq!(ledger.member_balance_due, 450_000_000);
+        assert_eq!(ledger.partial_paid(), 0);
+        assert_eq!(ledger.balance, 0);
+        assert_eq!(
+            ledger.payment_state(anchor + 10 * DAY, grace),
+            PaymentState::Current
+        );
+    }
+
+    #[test]
+    fn test_single_half_payment_leaves_member_past_due() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        let grace = GlobalConfig::DEFAULT_GRACE_PERIOD_DAYS as i64 * DAY;
+
+        assert_eq!(ledger.apply_deposit(225_000_000, 450_000_000, anchor), None);
+        assert_eq!(ledger.paid_through, 0);
+        assert_eq!(
+            ledger.payment_state(anchor + grace, grace),
+            PaymentState::PastDue
+        );
+
+        // A rate change keeps the partial payment already made
+        ledger.set_amount_due(400_000_000);
+        assert_eq!(ledger.member_balance_due, 175_000_000);
+        assert_eq!(ledger.partial_paid(), 225_000_000);
+    }
+
+    #[test]
+    fn test_overpayment_credits_balance() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+
+        assert_eq!(
+            ledger.apply_deposit(500_000_000, 450_000_000, anchor),
+            Some(1)
+        );
+        assert_eq!(ledger.balance, 50_000_000);
+        assert_eq!(ledger.history[0].amount, 450_000_000);
+    }
+
+    #[test]
+    fn test_mid_period_termination_refunds_unused_fraction() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        ledger.apply_deposit(450_000_000, 450_000_000, anchor);
+
+        // 12 of 30 days used: 18/30 of the period is refunded
+        assert_eq!(
+            ledger.termination_refund(anchor + 12 * DAY, true),
+            270_000_000
+        );
+        // Nothing left once the paid period has elapsed
+        assert_eq!(ledger.termination_refund(anchor + 30 * DAY, true), 0);
+
+        // Prepaid future periods and credit are refunded in full
+        ledger.apply_deposit(500_000_000, 450_000_000, anchor + 5 * DAY);
+        assert_eq!(
+            ledger.termination_refund(anchor + 15 * DAY, true),
+            225_000_000 + 450_000_000 + 50_000_000
+        );
+    }
+
+    #[test]
+    fn test_non_refundable_plan_refunds_zero() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        ledger.apply_deposit(500_000_000, 450_000_000, anchor);
+
+        assert!(ledger.unused_prepaid(anchor + DAY) > 0);
+        assert_eq!(ledger.termination_refund(anchor + DAY, false), 0);
+    }
+
+    #[test]
+    fn test_booked_refund_absorbs_credit_balance() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        ledger.apply_deposit(500_000_000, 450_000_000, anchor);
+
+        let refund = ledger.book_termination_refund(anchor + 12 * DAY, true);
+        assert_eq!(refund, 270_000_000 + 50_000_000);
+        assert_eq!(ledger.pending_refund, refund);
+        assert_eq!(ledger.balance, 0);
+    }
+
+    #[test]
+    fn test_unbooked_refund_keeps_credit_balance() {
+        let anchor = 1_000 * DAY;
+        let mut ledger = create_test_ledger(anchor);
+        ledger.apply_deposit(500_000_000, 450_000_000, anchor);
+
+        assert_eq!(ledger.book_termination_refund(anchor + DAY, false), 0);
+        assert_eq!(ledger.pending_refund, 0);
+        assert_eq!(ledger.balance, 50_000_000);
+    }
+
     #[test]
     fn test_contribution_ledger_seed_prefix() {
         assert_eq!(ContributionLedger::SEED_PREFIX, b"contribution_ledger");
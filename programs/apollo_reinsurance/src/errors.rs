@@ -151,4 +151,73 @@ pub enum ReinsuranceError {
 
     #[msg("Cross-program invocation failed")]
     CpiError,
+
+    #[msg("Prior month's aggregate is required to compute YTD")]
+    MissingPriorMonthAggregate,
+
+    #[msg("Recovery claim does not belong to a reconciled treaty")]
+    RecoveryClaimTreatyMismatch,
+
+    #[msg("Account passed more than once")]
+    DuplicateAccount,
+
+    #[msg("Invalid premium installment schedule")]
+    InvalidPremiumSchedule,
+
+    #[msg("Premium schedule does not belong to this treaty")]
+    PremiumScheduleMismatch,
+
+    #[msg("Accumulator belongs to the current policy year")]
+    AccumulatorStillActive,
+
+    #[msg("Policy year does not match the current reinsurance policy year")]
+    PolicyYearMismatch,
+
+    #[msg("Premium payment would exceed the annual premium budget")]
+    PremiumBudgetExceeded,
+
+    #[msg("Treaty cannot be activated before its effective date")]
+    TreatyNotYetEffective,
+
+    #[msg("Panel shares must be nonzero and sum to 10000 bps")]
+    InvalidPanelShares,
+
+    #[msg("Panel treaties must cover the same layer and attachment")]
+    PanelLayerMismatch,
+
+    #[msg("Treaty is not a member of this panel")]
+    TreatyNotInPanel,
+
+    #[msg("A dispute resolution method must be specified")]
+    InvalidDisputeResolution,
+
+    #[msg("Minimum activation premium must be between 0 and 10000 bps")]
+    InvalidActivationPremium,
+
+    #[msg("Triggering event falls outside the treaty coverage period")]
+    EventOutsideCoverage,
+
+    #[msg("Treaty reporting window has closed")]
+    ReportingWindowClosed,
+
+    #[msg("Settlement reference has already been recorded")]
+    DuplicateSettlementReference,
+
+    #[msg("Maximum settlement payments recorded for this claim")]
+    TooManySettlements,
+
+    #[msg("Treaty coverage limit is exhausted")]
+    CoverageLimitExhausted,
+
+    #[msg("Panel treaties file through the panel's lead treaty with every panelist")]
+    PanelRequired,
+
+    #[msg("Treaty already belongs to a panel")]
+    TreatyAlreadyInPanel,
+
+    #[msg("Accumulator still has unfiled or unresolved recoveries")]
+    AccumulatorRecoveriesOutstanding,
+
+    #[msg("Recovery claim was filed against a different member accumulator")]
+    AccumulatorMismatch,
 }
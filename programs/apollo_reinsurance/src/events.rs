@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use apollo_core::DocRef;
 
-use crate::state::{ReinsuranceLayerType, TreatyStatus};
+use crate::state::{DisputeResolutionMethod, ReinsuranceLayerType, TreatyStatus};
 
 // ============================================================================
 // CONFIGURATION EVENTS
@@ -36,6 +37,26 @@ pub struct ExpectedClaimsUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when trigger state is re-evaluated after expected claims change
+#[event]
+pub struct TriggerStateRecomputed {
+    pub old_ratio_bps: u16,
+    pub new_ratio_bps: u16,
+    pub aggregate_triggered: bool,
+    pub catastrophic_triggered: bool,
+    pub aggregate_newly_triggered: bool,
+    pub catastrophic_newly_triggered: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BudgetEnforcementUpdated {
+    pub strict_budget: bool,
+    pub premium_budget: u64,
+    pub updater: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TriggerRatiosUpdated {
     pub old_aggregate_bps: u16,
@@ -47,6 +68,19 @@ pub struct TriggerRatiosUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ScalableParamsApplied {
+    pub member_count: u64,
+    pub pool_category: String,
+    pub aggregate_trigger_bps: u16,
+    pub catastrophic_trigger_bps: u16,
+    pub catastrophic_ceiling_bps: u16,
+    pub suggested_attachment: u64,
+    pub budget_bps: u16,
+    pub applied_by: Pubkey,
+    pub timestamp: i64,
+}
+
 // ============================================================================
 // TREATY EVENTS
 // ============================================================================
@@ -61,6 +95,7 @@ pub struct TreatyCreated {
     pub coinsurance_rate_bps: u16,
     pub coverage_limit: u64,
     pub annual_premium: u64,
+    pub min_activation_premium_bps: u16,
     pub effective_date: i64,
     pub expiration_date: i64,
     pub timestamp: i64,
@@ -86,6 +121,19 @@ pub struct TreatyStatusChanged {
     pub timestamp: i64,
 }
 
+/// Unearned premium recorded as receivable on early cancellation
+#[event]
+pub struct UnearnedPremiumRefund {
+    pub treaty_id: u64,
+    pub treaty_pubkey: Pubkey,
+    pub premium_paid: u64,
+    pub refund_amount: u64,
+    pub cancelled_at: i64,
+    pub expiration_date: i64,
+    /// Refunds receivable across all cancelled treaties
+    pub total_receivable: u64,
+}
+
 #[event]
 pub struct TreatyExpired {
     pub treaty_id: u64,
@@ -96,6 +144,30 @@ pub struct TreatyExpired {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct TreatyUtilizationReported {
+    pub treaty_id: u64,
+    pub treaty_pubkey: Pubkey,
+    pub layer_type: ReinsuranceLayerType,
+    pub annual_premium: u64,
+    pub coverage_limit: u64,
+    pub total_recoveries_received: u64,
+    pub loss_ratio_bps: u32,
+    pub limit_utilization_bps: u16,
+    pub remaining_coverage: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreatyPremiumEarningsReported {
+    pub treaty_id: u64,
+    pub treaty_pubkey: Pubkey,
+    pub premium_paid: u64,
+    pub earned: u64,
+    pub unearned: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct TreatyRenewed {
     pub old_treaty_id: u64,
@@ -119,6 +191,33 @@ pub struct PremiumPaid {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct PremiumScheduleSet {
+    pub treaty_id: u64,
+    pub treaty_pubkey: Pubkey,
+    pub installment_count: u8,
+    pub total_scheduled: u64,
+    pub grace_period_days: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreatyPremiumLapsed {
+    pub treaty_id: u64,
+    pub treaty_pubkey: Pubkey,
+    pub premium_paid: u64,
+    pub amount_overdue: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreatyPremiumReinstated {
+    pub treaty_id: u64,
+    pub treaty_pubkey: Pubkey,
+    pub premium_paid: u64,
+    pub timestamp: i64,
+}
+
 // ============================================================================
 // STOP-LOSS TRIGGER EVENTS
 // ============================================================================
@@ -175,12 +274,46 @@ pub struct RecoveryClaimFiled {
     pub timestamp: i64,
 }
 
+/// Recoveries under a treaty have reached its coverage limit
+#[event]
+pub struct CoverageLimitReached {
+    pub treaty_id: u64,
+    pub claim_id: u64,
+    pub coverage_limit: u64,
+    pub cumulative_reinsurer_paid: u64,
+    /// Reinsurer portion cut from this recovery by the limit
+    pub clamped_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreatyPanelCreated {
+    pub panel_id: u32,
+    pub layer_type: ReinsuranceLayerType,
+    pub attachment_point: u64,
+    pub treaties: Vec<Pubkey>,
+    pub shares_bps: Vec<u16>,
+    pub created_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PanelRecoveryAllocated {
+    pub panel_id: u32,
+    pub treaty_id: u64,
+    pub claim_id: u64,
+    pub share_bps: u16,
+    pub total_excess: u64,
+    pub allocated_excess: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct RecoveryClaimSubmitted {
     pub claim_id: u64,
     pub treaty_id: u64,
     pub claimed_amount: u64,
-    pub documentation_hash: [u8; 32],
+    pub documentation: DocRef,
     pub submitted_by: Pubkey,
     pub timestamp: i64,
 }
@@ -214,6 +347,18 @@ pub struct RecoveryClaimDisputed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct RecoveryDisputeResolved {
+    pub claim_id: u64,
+    pub treaty_id: u64,
+    pub claimed_amount: u64,
+    pub final_amount: u64,
+    pub method: DisputeResolutionMethod,
+    pub denied: bool,
+    pub resolution_notes: DocRef,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct RecoverySettled {
     pub claim_id: u64,
@@ -222,6 +367,8 @@ pub struct RecoverySettled {
     pub approved_amount: u64,
     pub received_amount: u64,
     pub is_partial: bool,
+    /// External payment reference credited by this settlement
+    pub settlement_reference: [u8; 32],
     pub timestamp: i64,
 }
 
@@ -255,6 +402,16 @@ pub struct MemberStopLossBreached {
     pub breach_timestamp: i64,
 }
 
+#[event]
+pub struct MemberClaimReversed {
+    pub member: Pubkey,
+    pub claim_amount: u64,
+    pub ytd_total: u64,
+    pub stop_loss_triggered: bool,
+    pub original_claim_id: u64,
+    pub timestamp: i64,
+}
+
 // ============================================================================
 // AGGREGATE TRACKING EVENTS
 // ============================================================================
@@ -301,6 +458,47 @@ pub struct YearEndReconciliation {
     pub timestamp: i64,
 }
 
+/// Emitted when recoveries for a policy year are reconciled
+#[event]
+pub struct PolicyYearReconciled {
+    pub policy_year: u16,
+    pub treaties_reconciled: u32,
+    pub recovery_claims_reconciled: u32,
+    pub total_claims_submitted: u64,
+    pub total_recoveries_received: u64,
+    pub outstanding_approved: u64,
+    pub partial_settlement_shortfall: u64,
+    pub denied_amount: u64,
+    pub net_recovery_shortfall: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted by `report_recovery_aging` with outstanding recoveries by age
+#[event]
+pub struct RecoveryAgingReported {
+    pub claims_0_30: u32,
+    pub claims_31_60: u32,
+    pub claims_61_90: u32,
+    pub claims_over_90: u32,
+    pub amount_0_30: u64,
+    pub amount_31_60: u64,
+    pub amount_61_90: u64,
+    pub amount_over_90: u64,
+    pub total_outstanding: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MemberAccumulatorArchived {
+    pub member: Pubkey,
+    pub policy_year: u16,
+    pub ytd_claims: u64,
+    pub excess_claimed: u64,
+    pub recovered_amount: u64,
+    pub stop_loss_triggered: bool,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AccumulatorsReset {
     pub policy_year: u16,
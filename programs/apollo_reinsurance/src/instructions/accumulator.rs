@@ -3,8 +3,8 @@ use anchor_lang::prelude::*;
 use crate::errors::ReinsuranceError;
 use crate::events::*;
 use crate::state::{
-    MemberClaimsAccumulator, MonthlyAggregate, ReinsuranceConfig, ReinsuranceLayerType,
-    ReinsuranceTreaty,
+    AccumulatorArchive, MemberClaimsAccumulator, MonthlyAggregate, ReinsuranceConfig,
+    ReinsuranceLayerType, ReinsuranceTreaty, CLAIMS_CONFIG_SEED, CLAIMS_PROGRAM_ID,
 };
 
 // ============================================================================
@@ -47,17 +47,7 @@ pub fn create_member_accumulator(
     let accumulator = &mut ctx.accounts.accumulator;
     let clock = Clock::get()?;
 
-    accumulator.member = member;
-    accumulator.policy_year = policy_year;
-    accumulator.ytd_claims = 0;
-    accumulator.claims_count = 0;
-    accumulator.excess_claimed = 0;
-    accumulator.recovered_amount = 0;
-    accumulator.max_single_claim = 0;
-    accumulator.stop_loss_triggered = false;
-    accumulator.first_trigger_timestamp = 0;
-    accumulator.last_claim_timestamp = 0;
-    accumulator.bump = ctx.bumps.accumulator;
+    accumulator.initialize(member, policy_year, ctx.bumps.accumulator);
 
     emit!(MemberAccumulatorCreated {
         member,
@@ -75,6 +65,7 @@ pub fn create_member_accumulator(
 // ============================================================================
 
 #[derive(Accounts)]
+#[instruction(member: Pubkey, policy_year: u16)]
 pub struct RecordClaimToAccumulator<'info> {
     #[account(
         mut,
@@ -90,15 +81,33 @@ pub struct RecordClaimToAccumulator<'info> {
     )]
     pub treaty: Account<'info, ReinsuranceTreaty>,
 
-    #[account(mut)]
+    /// Created on the member's first recorded claim of the policy year
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = MemberClaimsAccumulator::SIZE,
+        seeds = [
+            b"member_accumulator",
+            member.as_ref(),
+            &policy_year.to_le_bytes()
+        ],
+        bump
+    )]
     pub accumulator: Account<'info, MemberClaimsAccumulator>,
 
     /// Authority must be claims program or authorized caller
     pub authority: Signer<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn record_claim_to_accumulator(
     ctx: Context<RecordClaimToAccumulator>,
+    member: Pubkey,
+    policy_year: u16,
     claim_amount: u64,
     original_claim_id: u64,
 ) -> Result<()> {
@@ -108,6 +117,20 @@ pub fn record_claim_to_accumulator(
     let clock = Clock::get()?;
 
     require!(claim_amount > 0, ReinsuranceError::ZeroAmount);
+    require!(
+        policy_year == config.policy_year(),
+        ReinsuranceError::PolicyYearMismatch
+    );
+
+    if !accumulator.is_initialized() {
+        accumulator.initialize(member, policy_year, ctx.bumps.accumulator);
+
+        emit!(MemberAccumulatorCreated {
+            member,
+            policy_year,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     // Check if this triggers stop-loss
     let was_triggered = accumulator.stop_loss_triggered;
@@ -173,6 +196,75 @@ pub fn record_claim_to_accumulator(
     Ok(())
 }
 
+// ============================================================================
+// REVERSE CLAIM FROM ACCUMULATOR
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ReverseClaimFromAccumulator<'info> {
+    #[account(
+        mut,
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+
+    /// Specific stop-loss treaty the claim was recorded against
+    #[account(
+        constraint = treaty.layer_type == ReinsuranceLayerType::SpecificStopLoss
+            @ ReinsuranceError::TreatyTypeMismatch,
+    )]
+    pub treaty: Account<'info, ReinsuranceTreaty>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"member_accumulator",
+            accumulator.member.as_ref(),
+            &accumulator.policy_year.to_le_bytes()
+        ],
+        bump = accumulator.bump,
+        constraint = accumulator.policy_year == config.policy_year()
+            @ ReinsuranceError::PolicyYearMismatch,
+    )]
+    pub accumulator: Account<'info, MemberClaimsAccumulator>,
+
+    /// CHECK: claims-program config PDA, signing via CPI
+    #[account(
+        signer @ ReinsuranceError::Unauthorized,
+        seeds = [CLAIMS_CONFIG_SEED],
+        bump,
+        seeds::program = CLAIMS_PROGRAM_ID,
+    )]
+    pub claims_config: UncheckedAccount<'info>,
+}
+
+pub fn reverse_claim_from_accumulator(
+    ctx: Context<ReverseClaimFromAccumulator>,
+    claim_amount: u64,
+    original_claim_id: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let accumulator = &mut ctx.accounts.accumulator;
+    let clock = Clock::get()?;
+
+    require!(claim_amount > 0, ReinsuranceError::ZeroAmount);
+
+    accumulator.reverse_claim(claim_amount, ctx.accounts.treaty.attachment_point)?;
+    config.ytd_claims_paid = config.ytd_claims_paid.saturating_sub(claim_amount);
+
+    emit!(MemberClaimReversed {
+        member: accumulator.member,
+        claim_amount,
+        ytd_total: accumulator.ytd_claims,
+        stop_loss_triggered: accumulator.stop_loss_triggered,
+        original_claim_id,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 // ============================================================================
 // UPDATE RECOVERY AMOUNT
 // ============================================================================
@@ -288,14 +380,35 @@ pub fn initialize_monthly_aggregate(
 #[derive(Accounts)]
 pub struct UpdateMonthlyAggregate<'info> {
     #[account(
+        mut,
         seeds = [b"reinsurance_config"],
         bump = config.bump,
+        has_one = authority @ ReinsuranceError::Unauthorized,
     )]
     pub config: Account<'info, ReinsuranceConfig>,
 
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [
+            b"monthly_aggregate",
+            monthly.policy_year.to_le_bytes().as_ref(),
+            &[monthly.month]
+        ],
+        bump = monthly.bump,
+    )]
     pub monthly: Account<'info, MonthlyAggregate>,
 
+    /// Prior month's aggregate (required for months 2-12, source of YTD)
+    #[account(
+        seeds = [
+            b"monthly_aggregate",
+            monthly.policy_year.to_le_bytes().as_ref(),
+            &[monthly.month.saturating_sub(1)]
+        ],
+        bump = prior_month.bump,
+    )]
+    pub prior_month: Option<Account<'info, MonthlyAggregate>>,
+
     pub authority: Signer<'info>,
 }
 
@@ -303,43 +416,23 @@ pub fn update_monthly_aggregate(
     ctx: Context<UpdateMonthlyAggregate>,
     claim_amount: u64,
     is_shock_claim: bool,
-    ytd_total: u64,
 ) -> Result<()> {
-    let monthly = &mut ctx.accounts.monthly;
     let clock = Clock::get()?;
 
-    monthly.total_claims = monthly
-        .total_claims
-        .checked_add(claim_amount)
+    // YTD is derived on-chain from the prior month rather than trusted input
+    let prior_ytd = match (ctx.accounts.monthly.month, &ctx.accounts.prior_month) {
+        (1, _) => 0,
+        (_, Some(prior)) => prior.ytd_through_month,
+        (_, None) => return err!(ReinsuranceError::MissingPriorMonthAggregate),
+    };
+
+    let monthly = &mut ctx.accounts.monthly;
+    monthly
+        .record_claim(claim_amount, is_shock_claim)
         .ok_or(ReinsuranceError::Overflow)?;
-    monthly.claims_count = monthly
-        .claims_count
-        .checked_add(1)
+    monthly
+        .roll_up_ytd(prior_ytd)
         .ok_or(ReinsuranceError::Overflow)?;
-
-    if monthly.claims_count > 0 {
-        monthly.avg_claim_amount = monthly.total_claims / monthly.claims_count as u64;
-    }
-
-    if claim_amount > monthly.max_claim {
-        monthly.max_claim = claim_amount;
-    }
-
-    if is_shock_claim {
-        monthly.shock_claims_count = monthly
-            .shock_claims_count
-            .checked_add(1)
-            .ok_or(ReinsuranceError::Overflow)?;
-    }
-
-    monthly.ytd_through_month = ytd_total;
-
-    // Calculate ratio
-    if monthly.expected_claims > 0 {
-        monthly.ratio_bps =
-            ((monthly.total_claims as u128 * 10_000) / monthly.expected_claims as u128) as u16;
-    }
-
     monthly.last_updated = clock.unix_timestamp;
 
     emit!(MonthlyAggregateUpdated {
@@ -353,6 +446,12 @@ pub fn update_monthly_aggregate(
         timestamp: clock.unix_timestamp,
     });
 
+    // Fire aggregate/catastrophic triggers in the same instruction that
+    // crosses them, so there is no untriggered window
+    let config = &mut ctx.accounts.config;
+    config.sync_ytd_claims(monthly.ytd_through_month);
+    fire_threshold_triggers(config, clock.unix_timestamp);
+
     Ok(())
 }
 
@@ -397,6 +496,93 @@ pub fn mark_accumulators_for_reset(ctx: Context<ResetAccumulators>) -> Result<()
     Ok(())
 }
 
+// ============================================================================
+// ARCHIVE MEMBER ACCUMULATOR
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ArchiveMemberAccumulator<'info> {
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+
+    /// Prior-year accumulator, closed with rent refunded to the authority
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"member_accumulator",
+            accumulator.member.as_ref(),
+            &accumulator.policy_year.to_le_bytes()
+        ],
+        bump = accumulator.bump,
+    )]
+    pub accumulator: Account<'info, MemberClaimsAccumulator>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = AccumulatorArchive::SIZE,
+        seeds = [
+            b"accumulator_archive",
+            accumulator.member.as_ref(),
+            &accumulator.policy_year.to_le_bytes()
+        ],
+        bump
+    )]
+    pub archive: Account<'info, AccumulatorArchive>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Snapshot a prior-year accumulator into a compact archive and close it
+pub fn archive_member_accumulator(ctx: Context<ArchiveMemberAccumulator>) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let accumulator = &ctx.accounts.accumulator;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    let is_authorized = ctx.accounts.authority.key() == config.authority
+        || ctx.accounts.authority.key() == config.reinsurance_committee;
+    require!(is_authorized, ReinsuranceError::Unauthorized);
+
+    // Only accumulators for a policy year that has already ended
+    require!(
+        accumulator.is_prior_year(config.policy_year()),
+        ReinsuranceError::AccumulatorStillActive
+    );
+    require!(
+        !accumulator.has_outstanding_recoveries(),
+        ReinsuranceError::AccumulatorRecoveriesOutstanding
+    );
+
+    let archive = &mut ctx.accounts.archive;
+    archive.snapshot(accumulator, clock.unix_timestamp);
+    archive.bump = ctx.bumps.archive;
+
+    emit!(MemberAccumulatorArchived {
+        member: archive.member,
+        policy_year: archive.policy_year,
+        ytd_claims: archive.ytd_claims,
+        excess_claimed: archive.excess_claimed,
+        recovered_amount: archive.recovered_amount,
+        stop_loss_triggered: archive.stop_loss_triggered,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Member accumulator for year {} archived",
+        archive.policy_year
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // CHECK AGGREGATE THRESHOLDS
 // ============================================================================
@@ -416,7 +602,7 @@ pub fn check_aggregate_thresholds(ctx: Context<CheckAggregateThresholds>) -> Res
     let config = &mut ctx.accounts.config;
     let clock = Clock::get()?;
 
-    let current_ratio = config.current_claims_ratio_bps();
+    let current_ratio = config.current_claims_ratio_bps() as u64;
 
     // Check warning threshold (e.g., 100% of expected)
     if current_ratio >= 10_000 && !config.aggregate_triggered {
@@ -445,18 +631,26 @@ pub fn check_aggregate_thresholds(ctx: Context<CheckAggregateThresholds>) -> Res
         msg!("Warning: Claims at {}% of expected", current_ratio / 100);
     }
 
-    // Check if aggregate should trigger
-    if config.should_trigger_aggregate() {
-        config.aggregate_triggered = true;
+    // Backstop for thresholds crossed outside monthly updates
+    fire_threshold_triggers(config, clock.unix_timestamp);
+
+    Ok(())
+}
+
+/// Set crossed threshold flags and emit the corresponding trigger events
+pub(crate) fn fire_threshold_triggers(config: &mut ReinsuranceConfig, timestamp: i64) {
+    let (aggregate, catastrophic) = config.apply_threshold_triggers();
+    let current_ratio = config.current_claims_ratio_bps() as u64;
 
+    if aggregate {
         emit!(AggregateStopLossTriggered {
             treaty_id: 0, // Will be filled when filing recovery
             ytd_claims: config.ytd_claims_paid,
             expected_claims: config.expected_annual_claims,
             trigger_ratio_bps: config.aggregate_trigger_ratio_bps,
             actual_ratio_bps: current_ratio,
-            excess_amount: config.calculate_aggregate_recoverable(),
-            timestamp: clock.unix_timestamp,
+            excess_amount: config.aggregate_layer_recoverable(),
+            timestamp,
         });
 
         msg!(
@@ -465,17 +659,14 @@ pub fn check_aggregate_thresholds(ctx: Context<CheckAggregateThresholds>) -> Res
         );
     }
 
-    // Check if catastrophic should trigger
-    if config.should_trigger_catastrophic() {
-        config.catastrophic_triggered = true;
-
+    if catastrophic {
         emit!(CatastrophicLayerTriggered {
             treaty_id: 0,
             ytd_claims: config.ytd_claims_paid,
             expected_claims: config.expected_annual_claims,
             trigger_ratio_bps: config.catastrophic_trigger_ratio_bps,
             actual_ratio_bps: current_ratio,
-            timestamp: clock.unix_timestamp,
+            timestamp,
         });
 
         msg!(
@@ -483,6 +674,4 @@ pub fn check_aggregate_thresholds(ctx: Context<CheckAggregateThresholds>) -> Res
             current_ratio / 100
         );
     }
-
-    Ok(())
 }
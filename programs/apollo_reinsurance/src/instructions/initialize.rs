@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
 
 use crate::errors::ReinsuranceError;
-use crate::events::ReinsuranceConfigInitialized;
-use crate::state::ReinsuranceConfig;
+use crate::events::{ReinsuranceConfigInitialized, TriggerStateRecomputed};
+use crate::instructions::accumulator::fire_threshold_triggers;
+use crate::state::{ReinsuranceConfig, ReinsuranceStatus, ScalableReinsuranceParams};
 
 /// Initialize the global reinsurance configuration
 #[derive(Accounts)]
@@ -163,6 +164,10 @@ pub fn update_expected_claims(
     let clock = Clock::get()?;
 
     let old_expected = config.expected_annual_claims;
+    let old_ratio_bps = config.current_claims_ratio_bps();
+    let was_aggregate = config.aggregate_triggered;
+    let was_catastrophic = config.catastrophic_triggered;
+
     config.expected_annual_claims = new_expected_claims;
 
     emit!(crate::events::ExpectedClaimsUpdated {
@@ -173,6 +178,19 @@ pub fn update_expected_claims(
         timestamp: clock.unix_timestamp,
     });
 
+    // A new denominator moves the claims ratio; fire any trigger it crosses
+    fire_threshold_triggers(config, clock.unix_timestamp);
+
+    emit!(TriggerStateRecomputed {
+        old_ratio_bps,
+        new_ratio_bps: config.current_claims_ratio_bps(),
+        aggregate_triggered: config.aggregate_triggered,
+        catastrophic_triggered: config.catastrophic_triggered,
+        aggregate_newly_triggered: config.aggregate_triggered && !was_aggregate,
+        catastrophic_newly_triggered: config.catastrophic_triggered && !was_catastrophic,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!(
         "Expected claims updated: {} -> {} USDC",
         old_expected / 1_000_000,
@@ -228,6 +246,53 @@ pub fn update_trigger_ratios(
     Ok(())
 }
 
+/// Switch premium budget enforcement between strict (reject) and warn-only
+pub fn set_strict_budget(ctx: Context<UpdateReinsuranceConfig>, strict_budget: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.strict_budget = strict_budget;
+
+    emit!(crate::events::BudgetEnforcementUpdated {
+        strict_budget,
+        premium_budget: config.premium_budget,
+        updater: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Apply pool-size-appropriate trigger ratios and suggested attachment
+pub fn apply_scalable_params(
+    ctx: Context<UpdateReinsuranceConfig>,
+    member_count: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    let params = ScalableReinsuranceParams::default();
+
+    params.apply_to(config, member_count);
+
+    emit!(crate::events::ScalableParamsApplied {
+        member_count,
+        pool_category: params.get_pool_category(member_count).to_string(),
+        aggregate_trigger_bps: config.aggregate_trigger_ratio_bps,
+        catastrophic_trigger_bps: config.catastrophic_trigger_ratio_bps,
+        catastrophic_ceiling_bps: config.catastrophic_ceiling_ratio_bps,
+        suggested_attachment: config.suggested_specific_attachment,
+        budget_bps: params.get_budget_bps(member_count),
+        applied_by: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Applied {} pool params for {} members",
+        params.get_pool_category(member_count),
+        member_count
+    );
+
+    Ok(())
+}
+
 /// Start new policy year
 pub fn start_new_policy_year(
     ctx: Context<UpdateReinsuranceConfig>,
@@ -295,3 +360,22 @@ pub fn start_new_policy_year(
 
     Ok(())
 }
+
+// ============================================================================
+// STATUS VIEW
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ReinsuranceStatusView<'info> {
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+}
+
+/// Permissionless view - trigger flags, claims ratio, pending recoveries
+/// and premium budget usage in one read
+pub fn get_reinsurance_status(ctx: Context<ReinsuranceStatusView>) -> Result<ReinsuranceStatus> {
+    Ok(ctx.accounts.config.status())
+}
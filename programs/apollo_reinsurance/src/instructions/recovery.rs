@@ -1,17 +1,358 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 use anchor_spl::token::TokenAccount;
+use apollo_core::DocRef;
 
 use crate::errors::ReinsuranceError;
 use crate::events::*;
 use crate::state::{
-    MemberClaimsAccumulator, RecoveryClaim, RecoveryStatus, ReinsuranceConfig,
-    ReinsuranceLayerType, ReinsuranceTreaty, TreatyStatus,
+    DisputeResolutionMethod, MemberClaimsAccumulator, PolicyYearReconciliation,
+    RecoveryAgingReport, RecoveryClaim, RecoveryStatus, ReinsuranceConfig, ReinsuranceLayerType,
+    ReinsuranceTreaty, TreatyPanel, TreatyStatus,
 };
 
+/// Layer-wide facts shared by every claim booked from one filing
+struct RecoveryFiling {
+    layer_type: ReinsuranceLayerType,
+    member_hash: [u8; 32],
+    original_claim_ids: Vec<u64>,
+    total_claims_amount: u64,
+    attachment_point: u64,
+    /// Excess across the whole layer, before any panel split
+    layer_excess: u64,
+    /// Triggering event; specific stop-loss treaties must cover it
+    event_timestamp: Option<i64>,
+    documentation: DocRef,
+    filed_by: Pubkey,
+    timestamp: i64,
+    /// Member accumulator a specific stop-loss filing draws on
+    accumulator: Option<Pubkey>,
+}
+
+/// A panel treaty must file through its panel's lead treaty, with the panel
+/// account; a standalone treaty files without one
+fn filing_panel<'a, 'info>(
+    panel: Option<&'a Account<'info, TreatyPanel>>,
+    treaty: &Account<ReinsuranceTreaty>,
+) -> Result<Option<&'a Account<'info, TreatyPanel>>> {
+    let Some(panel) = panel else {
+        require!(treaty.panel_id == 0, ReinsuranceError::PanelRequired);
+        return Ok(None);
+    };
+    require!(
+        treaty.panel_id == panel.panel_id,
+        ReinsuranceError::TreatyNotInPanel
+    );
+    require!(
+        panel.treaties[0] == treaty.key(),
+        ReinsuranceError::PanelRequired
+    );
+    Ok(Some(panel))
+}
+
+/// The treaty may still take this filing
+fn check_filing_window(treaty: &ReinsuranceTreaty, filing: &RecoveryFiling) -> Result<()> {
+    // The triggering event must fall in the coverage period; filing may
+    // continue through the reporting tail after expiration
+    if let Some(event_timestamp) = filing.event_timestamp {
+        require!(
+            treaty.covers_event(event_timestamp),
+            ReinsuranceError::EventOutsideCoverage
+        );
+    }
+    require!(
+        treaty.within_reporting_window(filing.timestamp),
+        ReinsuranceError::ReportingWindowClosed
+    );
+    require!(
+        !treaty.is_limit_exhausted(),
+        ReinsuranceError::CoverageLimitExhausted
+    );
+    Ok(())
+}
+
+/// Count a filed recovery's reinsurer portion against the treaty's coverage
+/// limit, announcing when the limit is used up
+fn record_against_limit(
+    treaty: &mut ReinsuranceTreaty,
+    claim_id: u64,
+    reinsurer_portion: u64,
+    clamped_amount: u64,
+    timestamp: i64,
+) {
+    treaty.record_reinsurer_claim(reinsurer_portion);
+
+    if treaty.is_limit_exhausted() {
+        emit!(CoverageLimitReached {
+            treaty_id: treaty.treaty_id,
+            claim_id,
+            coverage_limit: treaty.coverage_limit,
+            cumulative_reinsurer_paid: treaty.cumulative_reinsurer_paid,
+            clamped_amount,
+            timestamp,
+        });
+    }
+}
+
+/// A specific stop-loss claim that was denied or finally settled no longer
+/// holds its member accumulator open
+fn close_accumulator_recovery(
+    claim: &RecoveryClaim,
+    accumulator: Option<&mut Account<MemberClaimsAccumulator>>,
+) -> Result<()> {
+    if claim.accumulator == Pubkey::default() {
+        return Ok(());
+    }
+    let accumulator = accumulator.ok_or(ReinsuranceError::AccumulatorNotFound)?;
+    accumulator.open_recovery_claims = accumulator.open_recovery_claims.saturating_sub(1);
+    Ok(())
+}
+
+/// Book one treaty's recovery claim for `excess_amount` of the layer.
+/// Returns the (apollo, reinsurer) split.
+fn book_recovery_claim(
+    config: &mut ReinsuranceConfig,
+    treaty: &mut Account<ReinsuranceTreaty>,
+    claim: &mut Account<RecoveryClaim>,
+    bump: u8,
+    filing: &RecoveryFiling,
+    excess_amount: u64,
+    panel_share_bps: u16,
+) -> Result<(u64, u64)> {
+    let (apollo_portion, reinsurer_portion) = treaty.calculate_coverage(excess_amount);
+
+    // Increment counters
+    config.total_recovery_claims = config
+        .total_recovery_claims
+        .checked_add(1)
+        .ok_or(ReinsuranceError::Overflow)?;
+
+    treaty.recovery_claims_count = treaty
+        .recovery_claims_count
+        .checked_add(1)
+        .ok_or(ReinsuranceError::Overflow)?;
+    treaty.claims_pending_count = treaty
+        .claims_pending_count
+        .checked_add(1)
+        .ok_or(ReinsuranceError::Overflow)?;
+    treaty.last_updated = filing.timestamp;
+
+    // Track pending recoveries
+    config.pending_recoveries = config
+        .pending_recoveries
+        .checked_add(reinsurer_portion)
+        .ok_or(ReinsuranceError::Overflow)?;
+
+    // Initialize claim
+    claim.claim_id = config.total_recovery_claims;
+    claim.treaty = treaty.key();
+    claim.layer_type = filing.layer_type;
+    claim.status = RecoveryStatus::Pending;
+    claim.member_hash = filing.member_hash;
+
+    // Copy claim IDs (up to 8)
+    let count = filing.original_claim_ids.len().min(8);
+    claim.original_claim_ids[..count].copy_from_slice(&filing.original_claim_ids[..count]);
+    claim.original_claims_count = count as u8;
+
+    claim.total_claims_amount = filing.total_claims_amount;
+    claim.attachment_point = filing.attachment_point;
+    claim.excess_amount = excess_amount;
+    claim.apollo_portion = apollo_portion;
+    claim.claimed_amount = reinsurer_portion;
+    record_against_limit(
+        treaty,
+        claim.claim_id,
+        reinsurer_portion,
+        excess_amount - apollo_portion - reinsurer_portion,
+        filing.timestamp,
+    );
+
+    claim.event_timestamp = filing.event_timestamp.unwrap_or(0);
+    claim.filed_timestamp = filing.timestamp;
+    claim.filed_by = filing.filed_by;
+    claim.documentation = filing.documentation;
+    claim.panel_id = treaty.panel_id;
+    claim.panel_share_bps = panel_share_bps;
+    claim.accumulator = filing.accumulator.unwrap_or_default();
+    claim.bump = bump;
+
+    if panel_share_bps > 0 {
+        emit!(PanelRecoveryAllocated {
+            panel_id: treaty.panel_id,
+            treaty_id: treaty.treaty_id,
+            claim_id: claim.claim_id,
+            share_bps: panel_share_bps,
+            total_excess: filing.layer_excess,
+            allocated_excess: excess_amount,
+            timestamp: filing.timestamp,
+        });
+    }
+
+    emit!(RecoveryClaimFiled {
+        claim_id: claim.claim_id,
+        claim_pubkey: claim.key(),
+        treaty_id: treaty.treaty_id,
+        layer_type: claim.layer_type,
+        total_claims_amount: claim.total_claims_amount,
+        excess_amount: claim.excess_amount,
+        claimed_amount: claim.claimed_amount,
+        filed_by: filing.filed_by,
+        timestamp: filing.timestamp,
+    });
+
+    Ok((apollo_portion, reinsurer_portion))
+}
+
+/// Book the child claims of the panelists after the lead.
+///
+/// `remaining_accounts` holds a (treaty, recovery_claim) pair per follower,
+/// in panel order; each recovery claim is created at the follower treaty's
+/// next `recovery_claim` PDA.
+fn file_panel_followers<'info>(
+    config: &mut ReinsuranceConfig,
+    panel: &TreatyPanel,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    filing: &RecoveryFiling,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let count = panel.member_count as usize;
+    require!(
+        remaining_accounts.len() == (count - 1) * 2,
+        ReinsuranceError::PanelRequired
+    );
+    let allocations = panel.allocate(filing.layer_excess);
+
+    for (i, pair) in (1..count).zip(remaining_accounts.chunks(2)) {
+        let (treaty_info, claim_info) = (&pair[0], &pair[1]);
+        require_keys_eq!(
+            *treaty_info.key,
+            panel.treaties[i],
+            ReinsuranceError::TreatyNotInPanel
+        );
+        let mut treaty: Account<ReinsuranceTreaty> = Account::try_from(treaty_info)?;
+        require!(
+            treaty.layer_type == filing.layer_type,
+            ReinsuranceError::TreatyTypeMismatch
+        );
+        require!(
+            matches!(treaty.status, TreatyStatus::Active | TreatyStatus::Expired),
+            ReinsuranceError::TreatyNotActive
+        );
+        check_filing_window(&treaty, filing)?;
+
+        let claim_number = (treaty.recovery_claims_count + 1).to_le_bytes();
+        let (address, bump) = Pubkey::find_program_address(
+            &[b"recovery_claim", treaty_info.key.as_ref(), &claim_number],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            address,
+            *claim_info.key,
+            ReinsuranceError::InvalidConfiguration
+        );
+        create_program_account(
+            payer,
+            claim_info,
+            system_program,
+            RecoveryClaim::SIZE,
+            &[
+                b"recovery_claim",
+                treaty_info.key.as_ref(),
+                &claim_number,
+                &[bump],
+            ],
+        )?;
+        let mut claim: Account<RecoveryClaim> = Account::try_from_unchecked(claim_info)?;
+
+        book_recovery_claim(
+            config,
+            &mut treaty,
+            &mut claim,
+            bump,
+            filing,
+            allocations[i],
+            panel.shares_bps[i],
+        )?;
+        treaty.exit(&crate::ID)?;
+        claim.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Create a program-owned PDA of `space` bytes the way Anchor's `init`
+/// does: an address someone already sent lamports to is topped up to rent
+/// exemption, allocated and assigned instead of failing `create_account`.
+fn create_program_account<'info>(
+    payer: &Signer<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = target.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    let shortfall = rent.saturating_sub(current_lamports);
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Allocate {
+                account_to_allocate: target.clone(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            system_program::Assign {
+                account_to_assign: target.clone(),
+            },
+            &[seeds],
+        ),
+        &crate::ID,
+    )
+}
+
 // ============================================================================
 // FILE RECOVERY CLAIM (SPECIFIC STOP-LOSS)
 // ============================================================================
 
+/// File a specific stop-loss recovery. A panel's lead treaty files for the
+/// whole layer: its own claim here, and a child claim per follower panelist
+/// from `remaining_accounts` (see `file_panel_followers`).
 #[derive(Accounts)]
 #[instruction(params: FileSpecificRecoveryParams)]
 pub struct FileSpecificRecovery<'info> {
@@ -26,7 +367,8 @@ pub struct FileSpecificRecovery<'info> {
         mut,
         constraint = treaty.layer_type == ReinsuranceLayerType::SpecificStopLoss
             @ ReinsuranceError::TreatyTypeMismatch,
-        constraint = treaty.status == TreatyStatus::Active @ ReinsuranceError::TreatyNotActive,
+        constraint = matches!(treaty.status, TreatyStatus::Active | TreatyStatus::Expired)
+            @ ReinsuranceError::TreatyNotActive,
     )]
     pub treaty: Account<'info, ReinsuranceTreaty>,
 
@@ -36,6 +378,13 @@ pub struct FileSpecificRecovery<'info> {
     )]
     pub accumulator: Account<'info, MemberClaimsAccumulator>,
 
+    /// Panel the treaty shares this layer with (required if it has one)
+    #[account(
+        seeds = [b"treaty_panel", config.key().as_ref(), &panel.panel_id.to_le_bytes()],
+        bump = panel.bump,
+    )]
+    pub panel: Option<Account<'info, TreatyPanel>>,
+
     #[account(
         init,
         payer = authority,
@@ -63,112 +412,90 @@ pub struct FileSpecificRecoveryParams {
     /// Original claim IDs that triggered this recovery
     pub original_claim_ids: Vec<u64>,
 
-    /// Supporting documentation hash
-    pub documentation_hash: [u8; 32],
+    /// Supporting documentation
+    pub documentation: DocRef,
 }
 
-pub fn file_specific_recovery(
-    ctx: Context<FileSpecificRecovery>,
+pub fn file_specific_recovery<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FileSpecificRecovery<'info>>,
     params: FileSpecificRecoveryParams,
 ) -> Result<()> {
-    let config = &mut ctx.accounts.config;
-    let treaty = &mut ctx.accounts.treaty;
-    let accumulator = &ctx.accounts.accumulator;
-    let claim = &mut ctx.accounts.recovery_claim;
     let clock = Clock::get()?;
-
-    // Verify treaty is within its effective period
-    require!(
-        treaty.is_active(clock.unix_timestamp),
-        ReinsuranceError::TreatyNotActive
-    );
+    let accumulator = &ctx.accounts.accumulator;
+    let panel = filing_panel(ctx.accounts.panel.as_ref(), &ctx.accounts.treaty)?;
 
     // Calculate excess amount (claims above attachment)
-    let excess_amount = accumulator
-        .ytd_claims
-        .saturating_sub(treaty.attachment_point);
-
-    require!(excess_amount > 0, ReinsuranceError::NoExcessAmount);
-
-    // Calculate coverage split
-    let (apollo_portion, reinsurer_portion) = treaty.calculate_coverage(excess_amount);
-
-    // Increment counters
-    config.total_recovery_claims = config
-        .total_recovery_claims
-        .checked_add(1)
-        .ok_or(ReinsuranceError::Overflow)?;
-
-    treaty.recovery_claims_count = treaty
-        .recovery_claims_count
-        .checked_add(1)
-        .ok_or(ReinsuranceError::Overflow)?;
-    treaty.claims_pending_count = treaty
-        .claims_pending_count
-        .checked_add(1)
-        .ok_or(ReinsuranceError::Overflow)?;
-    treaty.last_updated = clock.unix_timestamp;
-
-    // Track pending recoveries
-    config.pending_recoveries = config
-        .pending_recoveries
-        .checked_add(reinsurer_portion)
-        .ok_or(ReinsuranceError::Overflow)?;
-
-    // Initialize claim
-    claim.claim_id = config.total_recovery_claims;
-    claim.treaty = treaty.key();
-    claim.layer_type = ReinsuranceLayerType::SpecificStopLoss;
-    claim.status = RecoveryStatus::Pending;
-    claim.member_hash = params.member_hash;
+    let attachment_point = ctx.accounts.treaty.attachment_point;
+    let layer_excess = accumulator.ytd_claims.saturating_sub(attachment_point);
+    require!(layer_excess > 0, ReinsuranceError::NoExcessAmount);
 
-    // Copy claim IDs (up to 8)
-    let count = params.original_claim_ids.len().min(8);
-    for i in 0..count {
-        claim.original_claim_ids[i] = params.original_claim_ids[i];
-    }
-    claim.original_claims_count = count as u8;
-
-    claim.total_claims_amount = accumulator.ytd_claims;
-    claim.attachment_point = treaty.attachment_point;
-    claim.excess_amount = excess_amount;
-    claim.apollo_portion = apollo_portion;
-    claim.claimed_amount = reinsurer_portion;
-
-    claim.event_timestamp = accumulator.first_trigger_timestamp;
-    claim.filed_timestamp = clock.unix_timestamp;
-    claim.filed_by = ctx.accounts.authority.key();
-    claim.documentation_hash = params.documentation_hash;
-    claim.bump = ctx.bumps.recovery_claim;
-
-    emit!(RecoveryClaimFiled {
-        claim_id: claim.claim_id,
-        claim_pubkey: claim.key(),
-        treaty_id: treaty.treaty_id,
-        layer_type: claim.layer_type,
-        total_claims_amount: claim.total_claims_amount,
-        excess_amount: claim.excess_amount,
-        claimed_amount: claim.claimed_amount,
+    let filing = RecoveryFiling {
+        layer_type: ReinsuranceLayerType::SpecificStopLoss,
+        member_hash: params.member_hash,
+        original_claim_ids: params.original_claim_ids,
+        total_claims_amount: accumulator.ytd_claims,
+        attachment_point,
+        layer_excess,
+        event_timestamp: Some(accumulator.first_trigger_timestamp),
+        documentation: params.documentation,
         filed_by: ctx.accounts.authority.key(),
         timestamp: clock.unix_timestamp,
-    });
+        accumulator: Some(ctx.accounts.accumulator.key()),
+    };
+    check_filing_window(&ctx.accounts.treaty, &filing)?;
+
+    // Panelists each claim their share of the layer
+    let (excess_amount, panel_share_bps) = match panel {
+        Some(panel) => (panel.allocate(layer_excess)[0], panel.shares_bps[0]),
+        None => (layer_excess, 0),
+    };
+    let (apollo_portion, reinsurer_portion) = book_recovery_claim(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.treaty,
+        &mut ctx.accounts.recovery_claim,
+        ctx.bumps.recovery_claim,
+        &filing,
+        excess_amount,
+        panel_share_bps,
+    )?;
+    if let Some(panel) = panel {
+        file_panel_followers(
+            &mut ctx.accounts.config,
+            panel,
+            ctx.remaining_accounts,
+            &filing,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
+    }
 
+    // Every panelist's claim holds the accumulator open until it resolves
+    let claims_filed = panel.map_or(1, |panel| panel.member_count as u16);
+    let accumulator = &mut ctx.accounts.accumulator;
+    accumulator.recovery_claims_filed = accumulator
+        .recovery_claims_filed
+        .saturating_add(claims_filed);
+    accumulator.open_recovery_claims = accumulator
+        .open_recovery_claims
+        .saturating_add(claims_filed);
+
+    let treaty = &ctx.accounts.treaty;
     emit!(SpecificStopLossTriggered {
-        member: accumulator.member,
-        member_hash: params.member_hash,
+        member: ctx.accounts.accumulator.member,
+        member_hash: filing.member_hash,
         treaty_id: treaty.treaty_id,
-        total_claims: accumulator.ytd_claims,
-        attachment_point: treaty.attachment_point,
+        total_claims: filing.total_claims_amount,
+        attachment_point,
         excess_amount,
         apollo_portion,
         reinsurer_portion,
-        triggering_claim_id: params.original_claim_ids.first().copied().unwrap_or(0),
+        triggering_claim_id: filing.original_claim_ids.first().copied().unwrap_or(0),
         timestamp: clock.unix_timestamp,
     });
 
     msg!(
         "Recovery claim {} filed: {} USDC recoverable",
-        claim.claim_id,
+        ctx.accounts.recovery_claim.claim_id,
         reinsurer_portion / 1_000_000
     );
 
@@ -179,6 +506,8 @@ pub fn file_specific_recovery(
 // FILE AGGREGATE RECOVERY
 // ============================================================================
 
+/// File an aggregate stop-loss recovery; panels file as in
+/// `FileSpecificRecovery`
 #[derive(Accounts)]
 pub struct FileAggregateRecovery<'info> {
     #[account(
@@ -193,10 +522,18 @@ pub struct FileAggregateRecovery<'info> {
         mut,
         constraint = treaty.layer_type == ReinsuranceLayerType::AggregateStopLoss
             @ ReinsuranceError::TreatyTypeMismatch,
-        constraint = treaty.status == TreatyStatus::Active @ ReinsuranceError::TreatyNotActive,
+        constraint = matches!(treaty.status, TreatyStatus::Active | TreatyStatus::Expired)
+            @ ReinsuranceError::TreatyNotActive,
     )]
     pub treaty: Account<'info, ReinsuranceTreaty>,
 
+    /// Panel the treaty shares this layer with (required if it has one)
+    #[account(
+        seeds = [b"treaty_panel", config.key().as_ref(), &panel.panel_id.to_le_bytes()],
+        bump = panel.bump,
+    )]
+    pub panel: Option<Account<'info, TreatyPanel>>,
+
     #[account(
         init,
         payer = authority,
@@ -216,83 +553,70 @@ pub struct FileAggregateRecovery<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn file_aggregate_recovery(
-    ctx: Context<FileAggregateRecovery>,
-    documentation_hash: [u8; 32],
+pub fn file_aggregate_recovery<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FileAggregateRecovery<'info>>,
+    documentation: DocRef,
 ) -> Result<()> {
-    let config = &mut ctx.accounts.config;
-    let treaty = &mut ctx.accounts.treaty;
-    let claim = &mut ctx.accounts.recovery_claim;
     let clock = Clock::get()?;
+    let panel = filing_panel(ctx.accounts.panel.as_ref(), &ctx.accounts.treaty)?;
 
     // Calculate recoverable amount
-    let recoverable = config.calculate_aggregate_recoverable();
-    require!(recoverable > 0, ReinsuranceError::NoExcessAmount);
+    let config = &ctx.accounts.config;
+    let layer_recoverable = config.aggregate_layer_recoverable();
+    require!(layer_recoverable > 0, ReinsuranceError::NoExcessAmount);
 
     // Calculate trigger threshold in USDC
     let trigger_amount = config
         .expected_annual_claims
-        .checked_mul(treaty.trigger_ratio_bps as u64)
+        .checked_mul(ctx.accounts.treaty.trigger_ratio_bps as u64)
         .ok_or(ReinsuranceError::Overflow)?
         .checked_div(10_000)
         .ok_or(ReinsuranceError::DivisionByZero)?;
 
-    // For aggregate, typically 100% coverage (0 coinsurance)
-    let (apollo_portion, reinsurer_portion) = treaty.calculate_coverage(recoverable);
-
-    // Update counters
-    config.total_recovery_claims = config
-        .total_recovery_claims
-        .checked_add(1)
-        .ok_or(ReinsuranceError::Overflow)?;
-
-    treaty.recovery_claims_count = treaty
-        .recovery_claims_count
-        .checked_add(1)
-        .ok_or(ReinsuranceError::Overflow)?;
-    treaty.claims_pending_count = treaty
-        .claims_pending_count
-        .checked_add(1)
-        .ok_or(ReinsuranceError::Overflow)?;
-    treaty.last_updated = clock.unix_timestamp;
-
-    config.pending_recoveries = config
-        .pending_recoveries
-        .checked_add(reinsurer_portion)
-        .ok_or(ReinsuranceError::Overflow)?;
-
-    // Initialize claim
-    claim.claim_id = config.total_recovery_claims;
-    claim.treaty = treaty.key();
-    claim.layer_type = ReinsuranceLayerType::AggregateStopLoss;
-    claim.status = RecoveryStatus::Pending;
-
-    claim.total_claims_amount = config.ytd_claims_paid;
-    claim.attachment_point = trigger_amount;
-    claim.excess_amount = recoverable;
-    claim.apollo_portion = apollo_portion;
-    claim.claimed_amount = reinsurer_portion;
-
-    claim.filed_timestamp = clock.unix_timestamp;
-    claim.filed_by = ctx.accounts.authority.key();
-    claim.documentation_hash = documentation_hash;
-    claim.bump = ctx.bumps.recovery_claim;
-
-    emit!(RecoveryClaimFiled {
-        claim_id: claim.claim_id,
-        claim_pubkey: claim.key(),
-        treaty_id: treaty.treaty_id,
-        layer_type: claim.layer_type,
-        total_claims_amount: claim.total_claims_amount,
-        excess_amount: claim.excess_amount,
-        claimed_amount: claim.claimed_amount,
+    let filing = RecoveryFiling {
+        layer_type: ReinsuranceLayerType::AggregateStopLoss,
+        member_hash: [0u8; 32],
+        original_claim_ids: Vec::new(),
+        total_claims_amount: config.ytd_claims_paid,
+        attachment_point: trigger_amount,
+        layer_excess: layer_recoverable,
+        event_timestamp: None,
+        documentation,
         filed_by: ctx.accounts.authority.key(),
         timestamp: clock.unix_timestamp,
-    });
+        accumulator: None,
+    };
+    check_filing_window(&ctx.accounts.treaty, &filing)?;
+
+    // Panelists each claim their share of the layer; for aggregate,
+    // typically 100% coverage (0 coinsurance)
+    let (recoverable, panel_share_bps) = match panel {
+        Some(panel) => (panel.allocate(layer_recoverable)[0], panel.shares_bps[0]),
+        None => (layer_recoverable, 0),
+    };
+    let (_, reinsurer_portion) = book_recovery_claim(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.treaty,
+        &mut ctx.accounts.recovery_claim,
+        ctx.bumps.recovery_claim,
+        &filing,
+        recoverable,
+        panel_share_bps,
+    )?;
+    if let Some(panel) = panel {
+        file_panel_followers(
+            &mut ctx.accounts.config,
+            panel,
+            ctx.remaining_accounts,
+            &filing,
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
+    }
 
     msg!(
         "Aggregate recovery claim {} filed: {} USDC recoverable",
-        claim.claim_id,
+        ctx.accounts.recovery_claim.claim_id,
         reinsurer_portion / 1_000_000
     );
 
@@ -327,7 +651,7 @@ pub struct SubmitRecoveryToReinsurer<'info> {
 
 pub fn submit_recovery_to_reinsurer(
     ctx: Context<SubmitRecoveryToReinsurer>,
-    documentation_hash: [u8; 32],
+    documentation: DocRef,
 ) -> Result<()> {
     let config = &ctx.accounts.config;
     let treaty = &mut ctx.accounts.treaty;
@@ -341,7 +665,7 @@ pub fn submit_recovery_to_reinsurer(
 
     claim.status = RecoveryStatus::Submitted;
     claim.submitted_timestamp = clock.unix_timestamp;
-    claim.documentation_hash = documentation_hash;
+    claim.documentation = documentation;
 
     treaty.total_claims_submitted = treaty
         .total_claims_submitted
@@ -353,7 +677,7 @@ pub fn submit_recovery_to_reinsurer(
         claim_id: claim.claim_id,
         treaty_id: treaty.treaty_id,
         claimed_amount: claim.claimed_amount,
-        documentation_hash,
+        documentation,
         submitted_by: ctx.accounts.authority.key(),
         timestamp: clock.unix_timestamp,
     });
@@ -384,9 +708,19 @@ pub struct RecordReinsurerDecision<'info> {
         constraint = recovery_claim.status == RecoveryStatus::Submitted
             || recovery_claim.status == RecoveryStatus::UnderReview
             @ ReinsuranceError::InvalidRecoveryStatus,
+        constraint = recovery_claim.treaty == treaty.key() @ ReinsuranceError::TreatyTypeMismatch,
     )]
     pub recovery_claim: Account<'info, RecoveryClaim>,
 
+    /// Member accumulator a specific stop-loss claim was filed against
+    /// (required once the claim is denied or finally settled)
+    #[account(
+        mut,
+        constraint = accumulator.key() == recovery_claim.accumulator
+            @ ReinsuranceError::AccumulatorMismatch,
+    )]
+    pub accumulator: Option<Account<'info, MemberClaimsAccumulator>>,
+
     pub authority: Signer<'info>,
 }
 
@@ -434,6 +768,11 @@ pub fn record_reinsurer_decision(
             claim.reinsurer_reference = reference;
             claim.resolution_timestamp = clock.unix_timestamp;
 
+            // The unapproved remainder of a partial approval will never be received
+            let released = claim.claimed_amount - amount;
+            config.pending_recoveries = config.pending_recoveries.saturating_sub(released);
+            treaty.release_reinsurer_claim(released);
+
             emit!(RecoveryClaimApproved {
                 claim_id: claim.claim_id,
                 treaty_id: treaty.treaty_id,
@@ -453,7 +792,7 @@ pub fn record_reinsurer_decision(
         ReinsurerDecision::Denied { reason_hash } => {
             claim.status = RecoveryStatus::Denied;
             claim.approved_amount = 0;
-            claim.resolution_notes_hash = reason_hash;
+            claim.resolution_notes = DocRef::legacy(reason_hash);
             claim.resolution_timestamp = clock.unix_timestamp;
 
             // Update pending recoveries
@@ -462,6 +801,8 @@ pub fn record_reinsurer_decision(
                 .saturating_sub(claim.claimed_amount);
 
             treaty.claims_pending_count = treaty.claims_pending_count.saturating_sub(1);
+            treaty.release_reinsurer_claim(claim.claimed_amount);
+            close_accumulator_recovery(claim, ctx.accounts.accumulator.as_mut())?;
 
             emit!(RecoveryClaimDenied {
                 claim_id: claim.claim_id,
@@ -479,7 +820,7 @@ pub fn record_reinsurer_decision(
             reason_hash,
         } => {
             claim.status = RecoveryStatus::Disputed;
-            claim.resolution_notes_hash = reason_hash;
+            claim.resolution_notes = DocRef::legacy(reason_hash);
 
             emit!(RecoveryClaimDisputed {
                 claim_id: claim.claim_id,
@@ -508,6 +849,99 @@ pub fn record_reinsurer_decision(
     Ok(())
 }
 
+// ============================================================================
+// RESOLVE DISPUTE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+
+    #[account(mut)]
+    pub treaty: Account<'info, ReinsuranceTreaty>,
+
+    #[account(
+        mut,
+        constraint = recovery_claim.status == RecoveryStatus::Disputed
+            @ ReinsuranceError::InvalidRecoveryStatus,
+        constraint = recovery_claim.treaty == treaty.key() @ ReinsuranceError::TreatyTypeMismatch,
+    )]
+    pub recovery_claim: Account<'info, RecoveryClaim>,
+
+    /// Member accumulator a specific stop-loss claim was filed against
+    /// (required once the claim is denied or finally settled)
+    #[account(
+        mut,
+        constraint = accumulator.key() == recovery_claim.accumulator
+            @ ReinsuranceError::AccumulatorMismatch,
+    )]
+    pub accumulator: Option<Account<'info, MemberClaimsAccumulator>>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Close out a disputed recovery at the negotiated `final_amount`.
+/// A nonzero amount approves the claim at that amount; zero denies it.
+pub fn resolve_dispute(
+    ctx: Context<ResolveDispute>,
+    final_amount: u64,
+    method: DisputeResolutionMethod,
+    resolution_notes: DocRef,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let treaty = &mut ctx.accounts.treaty;
+    let claim = &mut ctx.accounts.recovery_claim;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    let is_authorized = ctx.accounts.authority.key() == config.authority
+        || ctx.accounts.authority.key() == config.reinsurance_committee;
+    require!(is_authorized, ReinsuranceError::Unauthorized);
+    require!(
+        method != DisputeResolutionMethod::None,
+        ReinsuranceError::InvalidDisputeResolution
+    );
+
+    let released = claim
+        .resolve_dispute(final_amount, method, clock.unix_timestamp)
+        .ok_or(ReinsuranceError::RecoveryExceedsClaim)?;
+    claim.resolution_notes = resolution_notes;
+
+    // The conceded portion will never be received
+    config.pending_recoveries = config.pending_recoveries.saturating_sub(released);
+    treaty.release_reinsurer_claim(released);
+
+    if claim.status == RecoveryStatus::Denied {
+        treaty.claims_pending_count = treaty.claims_pending_count.saturating_sub(1);
+        close_accumulator_recovery(claim, ctx.accounts.accumulator.as_mut())?;
+    }
+    treaty.last_updated = clock.unix_timestamp;
+
+    emit!(RecoveryDisputeResolved {
+        claim_id: claim.claim_id,
+        treaty_id: treaty.treaty_id,
+        claimed_amount: claim.claimed_amount,
+        final_amount,
+        method,
+        denied: claim.status == RecoveryStatus::Denied,
+        resolution_notes,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Recovery claim {} dispute resolved: {} USDC",
+        claim.claim_id,
+        final_amount / 1_000_000
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // RECORD SETTLEMENT (PAYMENT RECEIVED)
 // ============================================================================
@@ -532,6 +966,15 @@ pub struct RecordSettlement<'info> {
     )]
     pub recovery_claim: Account<'info, RecoveryClaim>,
 
+    /// Member accumulator a specific stop-loss claim was filed against
+    /// (required once the claim is denied or finally settled)
+    #[account(
+        mut,
+        constraint = accumulator.key() == recovery_claim.accumulator
+            @ ReinsuranceError::AccumulatorMismatch,
+    )]
+    pub accumulator: Option<Account<'info, MemberClaimsAccumulator>>,
+
     /// Where the reinsurer payment was received
     #[account(mut)]
     pub settlement_account: Account<'info, TokenAccount>,
@@ -543,6 +986,7 @@ pub fn record_settlement(
     ctx: Context<RecordSettlement>,
     received_amount: u64,
     is_final: bool,
+    settlement_reference: [u8; 32],
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let treaty = &mut ctx.accounts.treaty;
@@ -554,11 +998,11 @@ pub fn record_settlement(
         || ctx.accounts.authority.key() == config.reinsurance_committee;
     require!(is_authorized, ReinsuranceError::Unauthorized);
 
-    // Update claim
-    claim.received_amount = claim
-        .received_amount
-        .checked_add(received_amount)
-        .ok_or(ReinsuranceError::Overflow)?;
+    // Only the part of the pending amount earlier payments left open
+    let pending_settled = claim.pending_settled(received_amount);
+
+    // Update claim (rejects a replayed payment reference)
+    claim.apply_settlement(settlement_reference, received_amount)?;
     claim.settlement_timestamp = clock.unix_timestamp;
 
     if is_final {
@@ -572,6 +1016,7 @@ pub fn record_settlement(
             claim.status = RecoveryStatus::PartiallySettled;
         }
         treaty.claims_pending_count = treaty.claims_pending_count.saturating_sub(1);
+        close_accumulator_recovery(claim, ctx.accounts.accumulator.as_mut())?;
     }
 
     // Update global tracking
@@ -580,9 +1025,7 @@ pub fn record_settlement(
         .checked_add(received_amount)
         .ok_or(ReinsuranceError::Overflow)?;
 
-    config.pending_recoveries = config
-        .pending_recoveries
-        .saturating_sub(received_amount.min(claim.claimed_amount));
+    config.pending_recoveries = config.pending_recoveries.saturating_sub(pending_settled);
 
     treaty.total_recoveries_received = treaty
         .total_recoveries_received
@@ -597,6 +1040,7 @@ pub fn record_settlement(
         approved_amount: claim.approved_amount,
         received_amount: claim.received_amount,
         is_partial: claim.status == RecoveryStatus::PartiallySettled,
+        settlement_reference,
         timestamp: clock.unix_timestamp,
     });
 
@@ -608,3 +1052,165 @@ pub fn record_settlement(
 
     Ok(())
 }
+
+// ============================================================================
+// YEAR-END RECONCILIATION
+// ============================================================================
+
+/// Reconcile claimed vs. approved vs. received recoveries for a policy year.
+///
+/// `remaining_accounts` holds the treaties first (`treaty_count` of them),
+/// followed by their recovery claims.
+#[derive(Accounts)]
+#[instruction(policy_year: u16)]
+pub struct ReconcilePolicyYear<'info> {
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PolicyYearReconciliation::SIZE,
+        seeds = [b"year_end_reconciliation", policy_year.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub reconciliation: Account<'info, PolicyYearReconciliation>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn reconcile_policy_year<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReconcilePolicyYear<'info>>,
+    policy_year: u16,
+    treaty_count: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    let is_authorized = ctx.accounts.authority.key() == config.authority
+        || ctx.accounts.authority.key() == config.reinsurance_committee;
+    require!(is_authorized, ReinsuranceError::Unauthorized);
+
+    require!(
+        clock.unix_timestamp > config.policy_year_end,
+        ReinsuranceError::CannotResetDuringActiveYear
+    );
+    require!(
+        treaty_count as usize <= ctx.remaining_accounts.len(),
+        ReinsuranceError::InvalidConfiguration
+    );
+
+    let recon = &mut ctx.accounts.reconciliation;
+    recon.policy_year = policy_year;
+
+    let (treaty_infos, claim_infos) = ctx.remaining_accounts.split_at(treaty_count as usize);
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+
+    for account_info in treaty_infos.iter() {
+        require!(
+            !seen.contains(account_info.key),
+            ReinsuranceError::DuplicateAccount
+        );
+        seen.push(*account_info.key);
+
+        let treaty: Account<ReinsuranceTreaty> = Account::try_from(account_info)?;
+        recon.add_treaty(&treaty);
+    }
+
+    for account_info in claim_infos.iter() {
+        require!(
+            !seen.contains(account_info.key),
+            ReinsuranceError::DuplicateAccount
+        );
+        seen.push(*account_info.key);
+
+        let claim: Account<RecoveryClaim> = Account::try_from(account_info)?;
+        require!(
+            treaty_infos.iter().any(|t| t.key == &claim.treaty),
+            ReinsuranceError::RecoveryClaimTreatyMismatch
+        );
+        recon.add_recovery_claim(&claim);
+    }
+
+    recon.reconciled_by = ctx.accounts.authority.key();
+    recon.reconciled_at = clock.unix_timestamp;
+    recon.bump = ctx.bumps.reconciliation;
+
+    emit!(PolicyYearReconciled {
+        policy_year,
+        treaties_reconciled: recon.treaties_reconciled,
+        recovery_claims_reconciled: recon.recovery_claims_reconciled,
+        total_claims_submitted: recon.total_claims_submitted,
+        total_recoveries_received: recon.total_recoveries_received,
+        outstanding_approved: recon.outstanding_approved,
+        partial_settlement_shortfall: recon.partial_settlement_shortfall,
+        denied_amount: recon.denied_amount,
+        net_recovery_shortfall: recon.net_recovery_shortfall,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Policy year {} reconciled: {} USDC net recovery shortfall",
+        policy_year,
+        recon.net_recovery_shortfall / 1_000_000
+    );
+
+    Ok(())
+}
+
+// ============================================================================
+// RECOVERY AGING
+// ============================================================================
+
+/// Permissionless view over the recovery claims passed as `remaining_accounts`
+#[derive(Accounts)]
+pub struct ReportRecoveryAging<'info> {
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+}
+
+/// Bucket outstanding recoveries by days since submission so slow-paying
+/// reinsurers can be chased before collection becomes doubtful
+pub fn report_recovery_aging<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ReportRecoveryAging<'info>>,
+) -> Result<RecoveryAgingReport> {
+    let clock = Clock::get()?;
+    let mut report = RecoveryAgingReport::default();
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+
+    for account_info in ctx.remaining_accounts.iter() {
+        require!(
+            !seen.contains(account_info.key),
+            ReinsuranceError::DuplicateAccount
+        );
+        seen.push(*account_info.key);
+
+        let claim: Account<RecoveryClaim> = Account::try_from(account_info)?;
+        report.add(&claim, clock.unix_timestamp);
+    }
+
+    emit!(RecoveryAgingReported {
+        claims_0_30: report.counts[0],
+        claims_31_60: report.counts[1],
+        claims_61_90: report.counts[2],
+        claims_over_90: report.counts[3],
+        amount_0_30: report.amounts[0],
+        amount_31_60: report.amounts[1],
+        amount_61_90: report.amounts[2],
+        amount_over_90: report.amounts[3],
+        total_outstanding: report.total_outstanding(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(report)
+}
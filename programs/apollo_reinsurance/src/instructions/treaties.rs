@@ -1,9 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use apollo_core::DocRef;
 
 use crate::errors::ReinsuranceError;
 use crate::events::*;
-use crate::state::{ReinsuranceConfig, ReinsuranceLayerType, ReinsuranceTreaty, TreatyStatus};
+use crate::state::{
+    PremiumEarnings, PremiumSchedule, ReinsuranceConfig, ReinsuranceLayerType, ReinsuranceTreaty,
+    TreatyPanel, TreatyStatus, TreatyUtilization, DEFAULT_MIN_ACTIVATION_PREMIUM_BPS,
+    MAX_PREMIUM_INSTALLMENTS,
+};
 
 // ============================================================================
 // CREATE TREATY
@@ -62,7 +67,7 @@ pub struct CreateTreatyParams {
     /// E.g., 2000 = 20% retained, 80% to reinsurer
     pub coinsurance_rate_bps: u16,
 
-    /// Maximum coverage per occurrence (0 = unlimited)
+    /// Maximum reinsurer coverage across all recoveries (0 = unlimited)
     pub coverage_limit: u64,
 
     /// For aggregate layers: trigger ratio in bps
@@ -74,8 +79,15 @@ pub struct CreateTreatyParams {
     /// Annual premium for this treaty
     pub annual_premium: u64,
 
-    /// Optional notes hash
-    pub notes_hash: [u8; 32],
+    /// Optional notes document
+    pub notes: DocRef,
+
+    /// Share of the annual premium required before activation (bps).
+    /// Defaults to 2500 (25%) when not provided.
+    pub min_activation_premium_bps: Option<u16>,
+
+    /// Days after expiration recoveries may still be filed (0 = none)
+    pub reporting_tail_days: u16,
 }
 
 pub fn create_treaty(ctx: Context<CreateTreaty>, params: CreateTreatyParams) -> Result<()> {
@@ -120,6 +132,15 @@ pub fn create_treaty(ctx: Context<CreateTreaty>, params: CreateTreatyParams) ->
         );
     }
 
+    // Validate upfront premium requirement (0-100%)
+    let min_activation_premium_bps = params
+        .min_activation_premium_bps
+        .unwrap_or(DEFAULT_MIN_ACTIVATION_PREMIUM_BPS);
+    require!(
+        min_activation_premium_bps <= 10_000,
+        ReinsuranceError::InvalidActivationPremium
+    );
+
     // Increment treaty counter
     config.total_treaties = config
         .total_treaties
@@ -139,9 +160,11 @@ pub fn create_treaty(ctx: Context<CreateTreaty>, params: CreateTreatyParams) ->
     treaty.trigger_ratio_bps = params.trigger_ratio_bps;
     treaty.ceiling_ratio_bps = params.ceiling_ratio_bps;
     treaty.annual_premium = params.annual_premium;
+    treaty.min_activation_premium_bps = min_activation_premium_bps;
+    treaty.reporting_tail_days = params.reporting_tail_days;
     treaty.authority = ctx.accounts.authority.key();
     treaty.last_updated = clock.unix_timestamp;
-    treaty.notes_hash = params.notes_hash;
+    treaty.notes = params.notes;
     treaty.bump = ctx.bumps.treaty;
 
     // Initialize counters
@@ -161,6 +184,7 @@ pub fn create_treaty(ctx: Context<CreateTreaty>, params: CreateTreatyParams) ->
         coinsurance_rate_bps: params.coinsurance_rate_bps,
         coverage_limit: params.coverage_limit,
         annual_premium: params.annual_premium,
+        min_activation_premium_bps,
         effective_date: params.effective_date,
         expiration_date: params.expiration_date,
         timestamp: clock.unix_timestamp,
@@ -175,6 +199,114 @@ pub fn create_treaty(ctx: Context<CreateTreaty>, params: CreateTreatyParams) ->
     Ok(())
 }
 
+// ============================================================================
+// TREATY PANEL
+// ============================================================================
+
+/// Group treaties that share one layer across several reinsurers.
+///
+/// `remaining_accounts` holds the panelist treaties (writable), in the same
+/// order as `shares_bps`. The first is the panel's lead treaty.
+#[derive(Accounts)]
+#[instruction(panel_id: u32)]
+pub struct CreateTreatyPanel<'info> {
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TreatyPanel::SIZE,
+        seeds = [b"treaty_panel", config.key().as_ref(), &panel_id.to_le_bytes()],
+        bump
+    )]
+    pub panel: Account<'info, TreatyPanel>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_treaty_panel<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateTreatyPanel<'info>>,
+    panel_id: u32,
+    shares_bps: Vec<u16>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    let is_authorized = ctx.accounts.authority.key() == config.authority
+        || ctx.accounts.authority.key() == config.reinsurance_committee;
+    require!(is_authorized, ReinsuranceError::Unauthorized);
+
+    require!(panel_id != 0, ReinsuranceError::InvalidConfiguration);
+    require!(
+        TreatyPanel::validate_shares(&shares_bps),
+        ReinsuranceError::InvalidPanelShares
+    );
+    require!(
+        ctx.remaining_accounts.len() == shares_bps.len(),
+        ReinsuranceError::InvalidConfiguration
+    );
+
+    let mut treaties: Vec<Account<ReinsuranceTreaty>> = Vec::with_capacity(shares_bps.len());
+    for account_info in ctx.remaining_accounts.iter() {
+        require!(
+            !treaties.iter().any(|t| t.key() == *account_info.key),
+            ReinsuranceError::DuplicateAccount
+        );
+        let treaty: Account<ReinsuranceTreaty> = Account::try_from(account_info)?;
+        require!(treaty.panel_id == 0, ReinsuranceError::TreatyAlreadyInPanel);
+        treaties.push(treaty);
+    }
+
+    let lead = &treaties[0];
+    require!(
+        treaties.iter().all(|t| t.layer_type == lead.layer_type
+            && t.attachment_point == lead.attachment_point
+            && t.trigger_ratio_bps == lead.trigger_ratio_bps),
+        ReinsuranceError::PanelLayerMismatch
+    );
+
+    let count = treaties.len();
+    let panel = &mut ctx.accounts.panel;
+    panel.panel_id = panel_id;
+    panel.layer_type = lead.layer_type;
+    panel.attachment_point = lead.attachment_point;
+    panel.member_count = count as u8;
+    for (slot, treaty) in panel.treaties.iter_mut().zip(treaties.iter()) {
+        *slot = treaty.key();
+    }
+    panel.shares_bps[..count].copy_from_slice(&shares_bps);
+    panel.created_by = ctx.accounts.authority.key();
+    panel.created_at = clock.unix_timestamp;
+    panel.bump = ctx.bumps.panel;
+
+    // Each treaty records its panel so recoveries cannot bypass the split
+    for treaty in treaties.iter_mut() {
+        treaty.panel_id = panel_id;
+        treaty.last_updated = clock.unix_timestamp;
+        treaty.exit(&crate::ID)?;
+    }
+
+    emit!(TreatyPanelCreated {
+        panel_id,
+        layer_type: panel.layer_type,
+        attachment_point: panel.attachment_point,
+        treaties: panel.treaties[..count].to_vec(),
+        shares_bps,
+        created_by: panel.created_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 // ============================================================================
 // ACTIVATE TREATY
 // ============================================================================
@@ -208,12 +340,15 @@ pub fn activate_treaty(ctx: Context<ActivateTreaty>) -> Result<()> {
         || ctx.accounts.authority.key() == treaty.authority;
     require!(is_authorized, ReinsuranceError::Unauthorized);
 
-    // Check premium is paid (at least partially)
-    // For initial activation, we may require full premium or partial
-    // Here we require at least 25% of annual premium
-    let min_premium = treaty.annual_premium / 4;
+    // Coverage must have begun before the treaty can be Active
+    require!(
+        treaty.has_started(clock.unix_timestamp),
+        ReinsuranceError::TreatyNotYetEffective
+    );
+
+    // Check the negotiated upfront share of the premium is paid
     require!(
-        treaty.premium_paid >= min_premium,
+        treaty.meets_activation_premium(),
         ReinsuranceError::PremiumNotPaid
     );
 
@@ -266,6 +401,14 @@ pub struct PayPremium<'info> {
     #[account(mut)]
     pub premium_destination: Account<'info, TokenAccount>,
 
+    /// Installment schedule, if the treaty has one (reinstates a lapsed treaty)
+    #[account(
+        mut,
+        seeds = [b"premium_schedule", treaty.key().as_ref()],
+        bump = premium_schedule.bump,
+    )]
+    pub premium_schedule: Option<Account<'info, PremiumSchedule>>,
+
     /// Authority to sign transfer
     pub authority: Signer<'info>,
 
@@ -285,7 +428,11 @@ pub fn pay_premium(ctx: Context<PayPremium>, amount: u64) -> Result<()> {
         .premium_paid_ytd
         .checked_add(amount)
         .ok_or(ReinsuranceError::Overflow)?;
-    // Warning but don't block if over budget
+    // Strict mode blocks over-budget payments; otherwise warn only
+    require!(
+        config.permits_premium(new_ytd),
+        ReinsuranceError::PremiumBudgetExceeded
+    );
     if new_ytd > config.premium_budget {
         msg!("Warning: Premium payment exceeds annual budget");
     }
@@ -319,6 +466,35 @@ pub fn pay_premium(ctx: Context<PayPremium>, amount: u64) -> Result<()> {
         timestamp: clock.unix_timestamp,
     });
 
+    // Catch-up payment reinstates a treaty suspended for a missed installment
+    if let Some(schedule) = ctx.accounts.premium_schedule.as_mut() {
+        if schedule.lapsed && schedule.is_caught_up(treaty.premium_paid, clock.unix_timestamp) {
+            schedule.lapsed = false;
+
+            if treaty.status == TreatyStatus::Suspended
+                && clock.unix_timestamp <= treaty.expiration_date
+            {
+                treaty.status = TreatyStatus::Active;
+                config.active_treaties = config
+                    .active_treaties
+                    .checked_add(1)
+                    .ok_or(ReinsuranceError::Overflow)?;
+
+                emit!(TreatyPremiumReinstated {
+                    treaty_id: treaty.treaty_id,
+                    treaty_pubkey: treaty.key(),
+                    premium_paid: treaty.premium_paid,
+                    timestamp: clock.unix_timestamp,
+                });
+
+                msg!(
+                    "Treaty {} reinstated after catch-up payment",
+                    treaty.treaty_id
+                );
+            }
+        }
+    }
+
     msg!(
         "Premium paid: {} USDC, total: {} of {} USDC",
         amount / 1_000_000,
@@ -329,6 +505,166 @@ pub fn pay_premium(ctx: Context<PayPremium>, amount: u64) -> Result<()> {
     Ok(())
 }
 
+// ============================================================================
+// PREMIUM SCHEDULE
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetPremiumSchedule<'info> {
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+
+    #[account(
+        constraint = treaty.status == TreatyStatus::Pending
+            || treaty.status == TreatyStatus::Active @ ReinsuranceError::TreatyCannotBeModified,
+    )]
+    pub treaty: Account<'info, ReinsuranceTreaty>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = PremiumSchedule::SIZE,
+        seeds = [b"premium_schedule", treaty.key().as_ref()],
+        bump
+    )]
+    pub premium_schedule: Account<'info, PremiumSchedule>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PremiumScheduleParams {
+    /// Installment due dates (ascending)
+    pub due_dates: Vec<i64>,
+
+    /// Installment amounts (USDC, 6 decimals)
+    pub amounts: Vec<u64>,
+
+    /// Days after a due date before the treaty lapses
+    pub grace_period_days: u16,
+}
+
+pub fn set_premium_schedule(
+    ctx: Context<SetPremiumSchedule>,
+    params: PremiumScheduleParams,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let treaty = &ctx.accounts.treaty;
+    let clock = Clock::get()?;
+
+    // Validate authority
+    let is_authorized = ctx.accounts.authority.key() == config.authority
+        || ctx.accounts.authority.key() == config.reinsurance_committee;
+    require!(is_authorized, ReinsuranceError::Unauthorized);
+
+    let count = params.due_dates.len();
+    require!(
+        count > 0 && count <= MAX_PREMIUM_INSTALLMENTS && params.amounts.len() == count,
+        ReinsuranceError::InvalidPremiumSchedule
+    );
+    require!(
+        params.due_dates.windows(2).all(|w| w[0] < w[1]),
+        ReinsuranceError::InvalidPremiumSchedule
+    );
+
+    let total_scheduled = params
+        .amounts
+        .iter()
+        .try_fold(0u64, |acc, a| acc.checked_add(*a))
+        .ok_or(ReinsuranceError::Overflow)?;
+    require!(
+        total_scheduled == treaty.annual_premium,
+        ReinsuranceError::InvalidPremiumSchedule
+    );
+
+    let schedule = &mut ctx.accounts.premium_schedule;
+    schedule.treaty = treaty.key();
+    schedule.installment_count = count as u8;
+    schedule.due_dates[..count].copy_from_slice(&params.due_dates);
+    schedule.amounts[..count].copy_from_slice(&params.amounts);
+    schedule.grace_period_days = params.grace_period_days;
+    schedule.lapsed = false;
+    schedule.lapsed_at = 0;
+    schedule.bump = ctx.bumps.premium_schedule;
+
+    emit!(PremiumScheduleSet {
+        treaty_id: treaty.treaty_id,
+        treaty_pubkey: treaty.key(),
+        installment_count: schedule.installment_count,
+        total_scheduled,
+        grace_period_days: params.grace_period_days,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CheckPremiumLapse<'info> {
+    #[account(
+        mut,
+        seeds = [b"reinsurance_config"],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, ReinsuranceConfig>,
+
+    #[account(
+        mut,
+        constraint = treaty.status == TreatyStatus::Active @ ReinsuranceError::TreatyNotActive,
+    )]
+    pub treaty: Account<'info, ReinsuranceTreaty>,
+
+    #[account(
+        mut,
+        seeds = [b"premium_schedule", treaty.key().as_ref()],
+        bump = premium_schedule.bump,
+        constraint = premium_schedule.treaty == treaty.key() @ ReinsuranceError::PremiumScheduleMismatch,
+    )]
+    pub premium_schedule: Account<'info, PremiumSchedule>,
+}
+
+/// Permissionless - anyone can call to suspend a treaty with an overdue installment
+pub fn check_premium_lapse(ctx: Context<CheckPremiumLapse>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let treaty = &mut ctx.accounts.treaty;
+    let schedule = &mut ctx.accounts.premium_schedule;
+    let clock = Clock::get()?;
+
+    if schedule.is_lapsed(treaty.premium_paid, clock.unix_timestamp) {
+        config.active_treaties = config.active_treaties.saturating_sub(1);
+        treaty.status = TreatyStatus::Suspended;
+        treaty.last_updated = clock.unix_timestamp;
+        schedule.lapsed = true;
+        schedule.lapsed_at = clock.unix_timestamp;
+
+        let amount_overdue = schedule
+            .amount_due_by(clock.unix_timestamp)
+            .saturating_sub(treaty.premium_paid);
+
+        emit!(TreatyPremiumLapsed {
+            treaty_id: treaty.treaty_id,
+            treaty_pubkey: treaty.key(),
+            premium_paid: treaty.premium_paid,
+            amount_overdue,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Treaty {} suspended: {} USDC premium overdue",
+            treaty.treaty_id,
+            amount_overdue / 1_000_000
+        );
+    }
+
+    Ok(())
+}
+
 // ============================================================================
 // UPDATE TREATY STATUS
 // ============================================================================
@@ -378,6 +714,13 @@ pub fn update_treaty_status(
         _ => return Err(ReinsuranceError::TreatyCannotBeModified.into()),
     }
 
+    if new_status == TreatyStatus::Active {
+        require!(
+            treaty.has_started(clock.unix_timestamp),
+            ReinsuranceError::TreatyNotYetEffective
+        );
+    }
+
     // Update active count
     if old_status == TreatyStatus::Active && new_status != TreatyStatus::Active {
         config.active_treaties = config.active_treaties.saturating_sub(1);
@@ -388,6 +731,27 @@ pub fn update_treaty_status(
             .ok_or(ReinsuranceError::Overflow)?;
     }
 
+    // Early cancellation: the unexpired share of paid premium is owed back
+    // (priced before the status change, which stops premium accrual)
+    if new_status == TreatyStatus::Cancelled {
+        let refund = treaty.unearned_premium(clock.unix_timestamp);
+        treaty.unearned_premium_refund = refund;
+        config.premium_refunds_receivable = config
+            .premium_refunds_receivable
+            .checked_add(refund)
+            .ok_or(ReinsuranceError::Overflow)?;
+
+        emit!(UnearnedPremiumRefund {
+            treaty_id: treaty.treaty_id,
+            treaty_pubkey: treaty.key(),
+            premium_paid: treaty.premium_paid,
+            refund_amount: refund,
+            cancelled_at: clock.unix_timestamp,
+            expiration_date: treaty.expiration_date,
+            total_receivable: config.premium_refunds_receivable,
+        });
+    }
+
     treaty.status = new_status;
     treaty.last_updated = clock.unix_timestamp;
 
@@ -556,3 +920,75 @@ pub fn update_treaty_params(
 
     Ok(())
 }
+
+// ============================================================================
+// TREATY UTILIZATION
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct TreatyUtilizationView<'info> {
+    pub treaty: Account<'info, ReinsuranceTreaty>,
+}
+
+/// Permissionless view - loss ratio and limit usage for renewal decisions
+pub fn treaty_utilization(ctx: Context<TreatyUtilizationView>) -> Result<TreatyUtilization> {
+    let treaty = &ctx.accounts.treaty;
+    let clock = Clock::get()?;
+    let utilization = treaty.utilization();
+
+    emit!(TreatyUtilizationReported {
+        treaty_id: treaty.treaty_id,
+        treaty_pubkey: treaty.key(),
+        layer_type: treaty.layer_type,
+        annual_premium: treaty.annual_premium,
+        coverage_limit: treaty.coverage_limit,
+        total_recoveries_received: treaty.total_recoveries_received,
+        loss_ratio_bps: utilization.loss_ratio_bps,
+        limit_utilization_bps: utilization.limit_utilization_bps,
+        remaining_coverage: utilization.remaining_coverage,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(utilization)
+}
+
+// ============================================================================
+// PREMIUM EARNINGS
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct TreatyPremiumEarningsView<'info> {
+    pub treaty: Account<'info, ReinsuranceTreaty>,
+
+    /// Installment schedule, if the treaty has one (a lapse stops accrual)
+    #[account(
+        seeds = [b"premium_schedule", treaty.key().as_ref()],
+        bump = premium_schedule.bump,
+    )]
+    pub premium_schedule: Option<Account<'info, PremiumSchedule>>,
+}
+
+/// Permissionless view - paid premium split into earned and unearned
+pub fn treaty_premium_earnings(ctx: Context<TreatyPremiumEarningsView>) -> Result<PremiumEarnings> {
+    let treaty = &ctx.accounts.treaty;
+    let clock = Clock::get()?;
+    let as_of = ctx
+        .accounts
+        .premium_schedule
+        .as_ref()
+        .map_or(clock.unix_timestamp, |s| {
+            s.accrual_end(clock.unix_timestamp)
+        });
+    let earnings = treaty.premium_earnings(as_of);
+
+    emit!(TreatyPremiumEarningsReported {
+        treaty_id: treaty.treaty_id,
+        treaty_pubkey: treaty.key(),
+        premium_paid: earnings.premium_paid,
+        earned: earnings.earned,
+        unearned: earnings.unearned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(earnings)
+}
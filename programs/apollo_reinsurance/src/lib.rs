@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use apollo_core::DocRef;
 
 pub mod errors;
 pub mod events;
@@ -6,7 +7,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
-use state::TreatyStatus;
+use state::{DisputeResolutionMethod, TreatyStatus};
 
 declare_id!("7b2bnKcX2jBZ5VoV9HE7i1HWsFLTUbsLDNLuSjLBsnpo");
 
@@ -74,6 +75,22 @@ pub mod apollo_reinsurance {
         )
     }
 
+    /// Toggle strict premium budget enforcement
+    pub fn set_strict_budget(
+        ctx: Context<UpdateReinsuranceConfig>,
+        strict_budget: bool,
+    ) -> Result<()> {
+        instructions::initialize::set_strict_budget(ctx, strict_budget)
+    }
+
+    /// Apply pool-size-appropriate trigger ratios and suggested attachment
+    pub fn apply_scalable_params(
+        ctx: Context<UpdateReinsuranceConfig>,
+        member_count: u64,
+    ) -> Result<()> {
+        instructions::initialize::apply_scalable_params(ctx, member_count)
+    }
+
     /// Start a new policy year with fresh counters
     pub fn start_new_policy_year(
         ctx: Context<UpdateReinsuranceConfig>,
@@ -91,6 +108,13 @@ pub mod apollo_reinsurance {
         )
     }
 
+    /// Summarize layer triggers, claims ratio and premium budget usage
+    pub fn get_reinsurance_status(
+        ctx: Context<ReinsuranceStatusView>,
+    ) -> Result<state::ReinsuranceStatus> {
+        instructions::initialize::get_reinsurance_status(ctx)
+    }
+
     // ========================================================================
     // TREATY MANAGEMENT
     // ========================================================================
@@ -110,6 +134,29 @@ pub mod apollo_reinsurance {
         instructions::treaties::pay_premium(ctx, amount)
     }
 
+    /// Split a layer across several panelist treaties (treaties passed as
+    /// remaining accounts, in share order)
+    pub fn create_treaty_panel<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateTreatyPanel<'info>>,
+        panel_id: u32,
+        shares_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::treaties::create_treaty_panel(ctx, panel_id, shares_bps)
+    }
+
+    /// Attach a premium installment schedule to a treaty
+    pub fn set_premium_schedule(
+        ctx: Context<SetPremiumSchedule>,
+        params: PremiumScheduleParams,
+    ) -> Result<()> {
+        instructions::treaties::set_premium_schedule(ctx, params)
+    }
+
+    /// Suspend a treaty with an installment overdue past grace (permissionless)
+    pub fn check_premium_lapse(ctx: Context<CheckPremiumLapse>) -> Result<()> {
+        instructions::treaties::check_premium_lapse(ctx)
+    }
+
     /// Update treaty status (suspend, expire, cancel)
     pub fn update_treaty_status(
         ctx: Context<UpdateTreatyStatus>,
@@ -132,32 +179,46 @@ pub mod apollo_reinsurance {
         instructions::treaties::update_treaty_params(ctx, params)
     }
 
+    /// Report treaty loss ratio and coverage limit utilization
+    pub fn treaty_utilization(
+        ctx: Context<TreatyUtilizationView>,
+    ) -> Result<state::TreatyUtilization> {
+        instructions::treaties::treaty_utilization(ctx)
+    }
+
+    /// Report paid premium split into earned and unearned
+    pub fn treaty_premium_earnings(
+        ctx: Context<TreatyPremiumEarningsView>,
+    ) -> Result<state::PremiumEarnings> {
+        instructions::treaties::treaty_premium_earnings(ctx)
+    }
+
     // ========================================================================
     // RECOVERY CLAIMS
     // ========================================================================
 
     /// File a specific stop-loss recovery claim
-    pub fn file_specific_recovery(
-        ctx: Context<FileSpecificRecovery>,
+    pub fn file_specific_recovery<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FileSpecificRecovery<'info>>,
         params: FileSpecificRecoveryParams,
     ) -> Result<()> {
         instructions::recovery::file_specific_recovery(ctx, params)
     }
 
     /// File an aggregate stop-loss recovery claim
-    pub fn file_aggregate_recovery(
-        ctx: Context<FileAggregateRecovery>,
-        documentation_hash: [u8; 32],
+    pub fn file_aggregate_recovery<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FileAggregateRecovery<'info>>,
+        documentation: DocRef,
     ) -> Result<()> {
-        instructions::recovery::file_aggregate_recovery(ctx, documentation_hash)
+        instructions::recovery::file_aggregate_recovery(ctx, documentation)
     }
 
     /// Submit recovery claim to reinsurer (marks as submitted)
     pub fn submit_recovery_to_reinsurer(
         ctx: Context<SubmitRecoveryToReinsurer>,
-        documentation_hash: [u8; 32],
+        documentation: DocRef,
     ) -> Result<()> {
-        instructions::recovery::submit_recovery_to_reinsurer(ctx, documentation_hash)
+        instructions::recovery::submit_recovery_to_reinsurer(ctx, documentation)
     }
 
     /// Record reinsurer's decision on a claim
@@ -168,13 +229,47 @@ pub mod apollo_reinsurance {
         instructions::recovery::record_reinsurer_decision(ctx, decision)
     }
 
+    /// Resolve a disputed recovery claim at a negotiated final amount
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        final_amount: u64,
+        method: DisputeResolutionMethod,
+        resolution_notes: DocRef,
+    ) -> Result<()> {
+        instructions::recovery::resolve_dispute(ctx, final_amount, method, resolution_notes)
+    }
+
     /// Record settlement payment received from reinsurer
     pub fn record_settlement(
         ctx: Context<RecordSettlement>,
         received_amount: u64,
         is_final: bool,
+        settlement_reference: [u8; 32],
+    ) -> Result<()> {
+        instructions::recovery::record_settlement(
+            ctx,
+            received_amount,
+            is_final,
+            settlement_reference,
+        )
+    }
+
+    /// Reconcile a policy year's recoveries (treaties then recovery claims
+    /// passed as remaining accounts)
+    pub fn reconcile_policy_year<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReconcilePolicyYear<'info>>,
+        policy_year: u16,
+        treaty_count: u8,
     ) -> Result<()> {
-        instructions::recovery::record_settlement(ctx, received_amount, is_final)
+        instructions::recovery::reconcile_policy_year(ctx, policy_year, treaty_count)
+    }
+
+    /// Tally outstanding recoveries by days since submission (recovery
+    /// claims passed as remaining accounts)
+    pub fn report_recovery_aging<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReportRecoveryAging<'info>>,
+    ) -> Result<state::RecoveryAgingReport> {
+        instructions::recovery::report_recovery_aging(ctx)
     }
 
     // ========================================================================
@@ -190,13 +285,35 @@ pub mod apollo_reinsurance {
         instructions::accumulator::create_member_accumulator(ctx, member, policy_year)
     }
 
-    /// Record a claim to a member's accumulator
+    /// Record a claim to a member's accumulator, creating it on first use
     pub fn record_claim_to_accumulator(
         ctx: Context<RecordClaimToAccumulator>,
+        member: Pubkey,
+        policy_year: u16,
+        claim_amount: u64,
+        original_claim_id: u64,
+    ) -> Result<()> {
+        instructions::accumulator::record_claim_to_accumulator(
+            ctx,
+            member,
+            policy_year,
+            claim_amount,
+            original_claim_id,
+        )
+    }
+
+    /// Take a claim denied after approval back off a member's accumulator
+    /// (claims program only)
+    pub fn reverse_claim_from_accumulator(
+        ctx: Context<ReverseClaimFromAccumulator>,
         claim_amount: u64,
         original_claim_id: u64,
     ) -> Result<()> {
-        instructions::accumulator::record_claim_to_accumulator(ctx, claim_amount, original_claim_id)
+        instructions::accumulator::reverse_claim_from_accumulator(
+            ctx,
+            claim_amount,
+            original_claim_id,
+        )
     }
 
     /// Update accumulator with recovery amount received
@@ -226,19 +343,13 @@ pub mod apollo_reinsurance {
         )
     }
 
-    /// Update monthly aggregate with claim data
+    /// Update monthly aggregate with claim data (YTD rolled up from prior month)
     pub fn update_monthly_aggregate(
         ctx: Context<UpdateMonthlyAggregate>,
         claim_amount: u64,
         is_shock_claim: bool,
-        ytd_total: u64,
     ) -> Result<()> {
-        instructions::accumulator::update_monthly_aggregate(
-            ctx,
-            claim_amount,
-            is_shock_claim,
-            ytd_total,
-        )
+        instructions::accumulator::update_monthly_aggregate(ctx, claim_amount, is_shock_claim)
     }
 
     // ========================================================================
@@ -254,6 +365,11 @@ pub mod apollo_reinsurance {
     pub fn mark_accumulators_for_reset(ctx: Context<ResetAccumulators>) -> Result<()> {
         instructions::accumulator::mark_accumulators_for_reset(ctx)
     }
+
+    /// Archive a prior-year member accumulator and close it
+    pub fn archive_member_accumulator(ctx: Context<ArchiveMemberAccumulator>) -> Result<()> {
+        instructions::accumulator::archive_member_accumulator(ctx)
+    }
 }
 
 // ============================================================================
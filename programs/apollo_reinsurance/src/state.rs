@@ -1,4 +1,7 @@
+use crate::errors::ReinsuranceError;
 use anchor_lang::prelude::*;
+use apollo_core::protocol_constants::{ratio_bps, BPS_DENOMINATOR, SECONDS_PER_DAY};
+use apollo_core::DocRef;
 
 /// ============================================================================
 /// REINSURANCE LAYER TYPES
@@ -73,6 +76,26 @@ pub enum RecoveryStatus {
     Denied,
 }
 
+/// How a disputed recovery claim was resolved
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DisputeResolutionMethod {
+    /// Claim was never disputed (or the dispute is still open)
+    #[default]
+    None,
+
+    /// Amount negotiated directly with the reinsurer
+    Negotiated,
+
+    /// Amount set by an arbitration award
+    Arbitration,
+
+    /// Apollo accepted the reinsurer's reduced amount
+    AcceptedReduction,
+
+    /// Apollo withdrew the claim
+    Withdrawn,
+}
+
 /// ============================================================================
 /// REINSURANCE GLOBAL CONFIG
 /// ============================================================================
@@ -139,8 +162,20 @@ pub struct ReinsuranceConfig {
     /// Bump seed for PDA
     pub bump: u8,
 
+    /// Specific stop-loss attachment suggested for new treaties (USDC, 6 decimals)
+    /// Set by `apply_scalable_params`; zero until applied
+    pub suggested_specific_attachment: u64,
+
+    /// Reject premium payments that would exceed `premium_budget`
+    /// (false = warn only)
+    pub strict_budget: bool,
+
+    /// Unearned premium owed back by reinsurers on cancelled treaties
+    /// (USDC, 6 decimals)
+    pub premium_refunds_receivable: u64,
+
     /// Reserved for future use (split to avoid Default trait limitation)
-    pub _reserved1: [u8; 32],
+    pub _reserved1: [u8; 15],
     pub _reserved2: [u8; 32],
 }
 
@@ -165,31 +200,73 @@ impl ReinsuranceConfig {
         2 + // catastrophic_trigger_ratio_bps
         2 + // catastrophic_ceiling_ratio_bps
         1 + // bump
-        64; // reserved
+        8 + // suggested_specific_attachment
+        1 + // strict_budget
+        8 + // premium_refunds_receivable
+        47; // reserved
 
     /// Calculate current claims ratio in basis points
-    pub fn current_claims_ratio_bps(&self) -> u64 {
-        if self.expected_annual_claims == 0 {
-            return 0;
+    pub fn current_claims_ratio_bps(&self) -> u16 {
+        ratio_bps(self.ytd_claims_paid, self.expected_annual_claims)
+    }
+
+    /// Layer status summary for dashboards
+    pub fn status(&self) -> ReinsuranceStatus {
+        ReinsuranceStatus {
+            current_claims_ratio_bps: self.current_claims_ratio_bps(),
+            aggregate_triggered: self.aggregate_triggered,
+            catastrophic_triggered: self.catastrophic_triggered,
+            pending_recoveries: self.pending_recoveries,
+            premium_paid_ytd: self.premium_paid_ytd,
+            premium_budget: self.premium_budget,
+            premium_budget_utilization_bps: ratio_bps(self.premium_paid_ytd, self.premium_budget),
+            active_treaties: self.active_treaties,
         }
-        // (ytd_claims * 10000) / expected
-        self.ytd_claims_paid
-            .checked_mul(10_000)
-            .unwrap_or(0)
-            .checked_div(self.expected_annual_claims)
-            .unwrap_or(0)
     }
 
     /// Check if aggregate stop-loss should trigger
     pub fn should_trigger_aggregate(&self) -> bool {
         !self.aggregate_triggered
-            && self.current_claims_ratio_bps() >= self.aggregate_trigger_ratio_bps as u64
+            && self.current_claims_ratio_bps() >= self.aggregate_trigger_ratio_bps
     }
 
     /// Check if catastrophic layer should trigger
     pub fn should_trigger_catastrophic(&self) -> bool {
         !self.catastrophic_triggered
-            && self.current_claims_ratio_bps() >= self.catastrophic_trigger_ratio_bps as u64
+            && self.current_claims_ratio_bps() >= self.catastrophic_trigger_ratio_bps
+    }
+
+    /// Calendar year in which the current policy year starts, used as the
+    /// `policy_year` seed for member accumulators and monthly aggregates
+    pub fn policy_year(&self) -> u16 {
+        // Civil-from-days (proleptic Gregorian)
+        let days = self.policy_year_start.div_euclid(86_400) + 719_468;
+        let era = days.div_euclid(146_097);
+        let doe = days - era * 146_097;
+        let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let year = yoe + era * 400 + if mp >= 10 { 1 } else { 0 };
+        year.clamp(0, u16::MAX as i64) as u16
+    }
+
+    /// Raise YTD claims to at least `ytd` (monthly roll-ups never lower it)
+    pub fn sync_ytd_claims(&mut self, ytd: u64) {
+        self.ytd_claims_paid = self.ytd_claims_paid.max(ytd);
+    }
+
+    /// Set the aggregate/catastrophic triggered flags if their thresholds
+    /// have been crossed. Returns which flags were newly set.
+    pub fn apply_threshold_triggers(&mut self) -> (bool, bool) {
+        let aggregate = self.should_trigger_aggregate();
+        if aggregate {
+            self.aggregate_triggered = true;
+        }
+        let catastrophic = self.should_trigger_catastrophic();
+        if catastrophic {
+            self.catastrophic_triggered = true;
+        }
+        (aggregate, catastrophic)
     }
 
     /// Calculate recoverable amount under aggregate layer
@@ -230,6 +307,49 @@ impl ReinsuranceConfig {
             excess
         }
     }
+
+    /// Calculate recoverable amount under catastrophic layer
+    /// Returns amount above catastrophic threshold (USDC)
+    pub fn calculate_catastrophic_recoverable(&self) -> u64 {
+        if !self.catastrophic_triggered {
+            return 0;
+        }
+
+        let catastrophic_amount = self.ratio_amount(self.catastrophic_trigger_ratio_bps);
+        self.ytd_claims_paid.saturating_sub(catastrophic_amount)
+    }
+
+    /// Aggregate recoverable, clamped to the ceiling
+    pub fn aggregate_layer_recoverable(&self) -> u64 {
+        self.clamp_to_ceiling(self.calculate_aggregate_recoverable(), 0)
+            .0
+    }
+
+    /// Clamp combined aggregate + catastrophic recoveries to the loss dollars
+    /// between the aggregate trigger and the catastrophic ceiling, so the same
+    /// loss is never recovered twice. The aggregate layer attaches first and
+    /// keeps its share; catastrophic absorbs the cut.
+    pub fn clamp_to_ceiling(&self, aggregate: u64, catastrophic: u64) -> (u64, u64) {
+        let headroom = self
+            .ratio_amount(self.catastrophic_ceiling_ratio_bps)
+            .saturating_sub(self.ratio_amount(self.aggregate_trigger_ratio_bps));
+
+        let aggregate = aggregate.min(headroom);
+        let catastrophic = catastrophic.min(headroom - aggregate);
+        (aggregate, catastrophic)
+    }
+
+    /// Whether a premium payment bringing YTD premiums to `new_ytd` may
+    /// proceed; over-budget payments are only blocked under `strict_budget`
+    pub fn permits_premium(&self, new_ytd: u64) -> bool {
+        !self.strict_budget || new_ytd <= self.premium_budget
+    }
+
+    /// Expected annual claims scaled by a ratio (USDC)
+    fn ratio_amount(&self, ratio_bps: u16) -> u64 {
+        (self.expected_annual_claims as u128 * ratio_bps as u128 / 10_000).min(u64::MAX as u128)
+            as u64
+    }
 }
 
 /// ============================================================================
@@ -268,8 +388,8 @@ pub struct ReinsuranceTreaty {
     /// E.g., 2000 = 20% retained by Apollo, 80% to reinsurer
     pub coinsurance_rate_bps: u16,
 
-    /// Maximum coverage per occurrence/member (USDC, 6 decimals)
-    /// 0 = unlimited
+    /// Maximum reinsurer coverage across all recoveries under the treaty
+    /// (USDC, 6 decimals). 0 = unlimited
     pub coverage_limit: u64,
 
     // === AGGREGATE PARAMETERS ===
@@ -308,16 +428,38 @@ pub struct ReinsuranceTreaty {
     /// Last updated timestamp
     pub last_updated: i64,
 
-    /// Notes/reference (hashed)
-    pub notes_hash: [u8; 32],
+    /// Treaty notes document
+    pub notes: DocRef,
 
     /// Bump seed
     pub bump: u8,
 
+    /// Share of the annual premium that must be paid before activation (bps)
+    pub min_activation_premium_bps: u16,
+
+    /// Days after expiration during which recoveries for events in the
+    /// coverage period may still be filed
+    pub reporting_tail_days: u16,
+
+    /// Unearned premium recorded as receivable when the treaty was
+    /// cancelled early (USDC, 6 decimals)
+    pub unearned_premium_refund: u64,
+
+    /// Reinsurer portion of all recoveries filed under this treaty, less
+    /// amounts denied or conceded (USDC, 6 decimals)
+    pub cumulative_reinsurer_paid: u64,
+
+    /// Panel sharing this treaty's layer (0 if the treaty stands alone)
+    pub panel_id: u32,
+
     /// Reserved
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 6],
 }
 
+/// Upfront premium share required to activate a treaty unless the
+/// reinsurer negotiates otherwise (25%)
+pub const DEFAULT_MIN_ACTIVATION_PREMIUM_BPS: u16 = 2500;
+
 impl ReinsuranceTreaty {
     pub const SIZE: usize = 8 + // discriminator
         8 + // treaty_id
@@ -340,9 +482,14 @@ impl ReinsuranceTreaty {
         4 + // claims_pending_count
         32 + // authority
         8 + // last_updated
-        32 + // notes_hash
+        DocRef::SIZE + // notes
         1 + // bump
-        32; // reserved
+        2 + // min_activation_premium_bps
+        2 + // reporting_tail_days
+        8 + // unearned_premium_refund
+        8 + // cumulative_reinsurer_paid
+        4 + // panel_id
+        6; // reserved
 
     /// Check if treaty is currently active
     pub fn is_active(&self, current_time: i64) -> bool {
@@ -351,8 +498,107 @@ impl ReinsuranceTreaty {
             && current_time <= self.expiration_date
     }
 
+    /// Whether coverage has begun; status may only become Active from here on
+    pub fn has_started(&self, current_time: i64) -> bool {
+        current_time >= self.effective_date
+    }
+
+    /// Whether an event falls inside the treaty's coverage period
+    pub fn covers_event(&self, event_time: i64) -> bool {
+        event_time >= self.effective_date && event_time <= self.expiration_date
+    }
+
+    /// Last moment a recovery may be filed (expiration plus reporting tail)
+    pub fn reporting_deadline(&self) -> i64 {
+        self.expiration_date
+            .saturating_add(self.reporting_tail_days as i64 * SECONDS_PER_DAY)
+    }
+
+    /// Whether recoveries may still be filed, including during the
+    /// reporting tail after the treaty has expired
+    pub fn within_reporting_window(&self, current_time: i64) -> bool {
+        matches!(self.status, TreatyStatus::Active | TreatyStatus::Expired)
+            && self.has_started(current_time)
+            && current_time <= self.reporting_deadline()
+    }
+
+    /// Pro-rata premium for the unexpired term if cancelled at
+    /// `current_time` (see `earned_premium`). Cancelling before coverage
+    /// starts refunds everything paid.
+    pub fn unearned_premium(&self, current_time: i64) -> u64 {
+        self.premium_paid
+            .saturating_sub(self.earned_premium(current_time))
+    }
+
+    /// Premium earned by the reinsurer as of `current_time`: premium_paid
+    /// less premium_paid * remaining_days / total_days, so partial days are
+    /// earned. Accrual stops at cancellation, leaving what was not refunded.
+    pub fn earned_premium(&self, current_time: i64) -> u64 {
+        if self.status == TreatyStatus::Cancelled {
+            return self
+                .premium_paid
+                .saturating_sub(self.unearned_premium_refund);
+        }
+        if current_time <= self.effective_date {
+            return 0;
+        }
+        let total_days = (self.expiration_date - self.effective_date) / SECONDS_PER_DAY;
+        if total_days <= 0 {
+            return self.premium_paid;
+        }
+        let remaining_days = (self.expiration_date - current_time).max(0) / SECONDS_PER_DAY;
+        let unearned = self.premium_paid as u128 * remaining_days as u128 / total_days as u128;
+        self.premium_paid - unearned as u64
+    }
+
+    /// Paid premium split into earned and unearned as of `current_time`
+    pub fn premium_earnings(&self, current_time: i64) -> PremiumEarnings {
+        PremiumEarnings {
+            premium_paid: self.premium_paid,
+            earned: self.earned_premium(current_time),
+            unearned: self.unearned_premium(current_time),
+        }
+    }
+
+    /// Premium that must be paid before the treaty can be activated
+    pub fn min_activation_premium(&self) -> u64 {
+        (self.annual_premium as u128 * self.min_activation_premium_bps as u128 / 10_000) as u64
+    }
+
+    /// Whether enough premium has been paid upfront to activate
+    pub fn meets_activation_premium(&self) -> bool {
+        self.premium_paid >= self.min_activation_premium()
+    }
+
+    /// Reinsurer coverage left under `coverage_limit` (u64::MAX if unlimited)
+    pub fn remaining_limit(&self) -> u64 {
+        if self.coverage_limit == 0 {
+            return u64::MAX;
+        }
+        self.coverage_limit
+            .saturating_sub(self.cumulative_reinsurer_paid)
+    }
+
+    /// Whether recoveries have used up the whole coverage limit
+    pub fn is_limit_exhausted(&self) -> bool {
+        self.remaining_limit() == 0
+    }
+
+    /// Count a filed recovery's reinsurer portion against the limit
+    pub fn record_reinsurer_claim(&mut self, reinsurer_portion: u64) {
+        self.cumulative_reinsurer_paid = self
+            .cumulative_reinsurer_paid
+            .saturating_add(reinsurer_portion);
+    }
+
+    /// Return limit for recovery amounts the reinsurer will never pay
+    pub fn release_reinsurer_claim(&mut self, amount: u64) {
+        self.cumulative_reinsurer_paid = self.cumulative_reinsurer_paid.saturating_sub(amount);
+    }
+
     /// Calculate coverage amount for a claim exceeding attachment
-    /// Returns (apollo_portion, reinsurer_portion)
+    /// Returns (apollo_portion, reinsurer_portion); the reinsurer portion is
+    /// clamped to what is left of the treaty's coverage limit
     pub fn calculate_coverage(&self, excess_amount: u64) -> (u64, u64) {
         // Apollo retains coinsurance_rate_bps / 10000
         let apollo_portion = excess_amount
@@ -361,14 +607,282 @@ impl ReinsuranceTreaty {
             .checked_div(10_000)
             .unwrap_or(0);
 
-        let mut reinsurer_portion = excess_amount.saturating_sub(apollo_portion);
+        // Apply the coverage limit left after earlier recoveries
+        let reinsurer_portion = excess_amount
+            .saturating_sub(apollo_portion)
+            .min(self.remaining_limit());
+
+        (apollo_portion, reinsurer_portion)
+    }
+
+    /// Recoveries measured against premium and coverage limit. Limit usage
+    /// counts the same filed reinsurer portions that `remaining_limit`
+    /// clamps new recoveries against.
+    pub fn utilization(&self) -> TreatyUtilization {
+        // Heavy loss years run well past 655%, so the loss ratio is u32
+        let loss_ratio_bps = if self.annual_premium > 0 {
+            (self.total_recoveries_received as u128 * BPS_DENOMINATOR as u128
+                / self.annual_premium as u128)
+                .min(u32::MAX as u128) as u32
+        } else {
+            0
+        };
 
-        // Apply coverage limit if set
-        if self.coverage_limit > 0 {
-            reinsurer_portion = reinsurer_portion.min(self.coverage_limit);
+        TreatyUtilization {
+            loss_ratio_bps,
+            limit_utilization_bps: ratio_bps(self.cumulative_reinsurer_paid, self.coverage_limit),
+            remaining_coverage: self.remaining_limit(),
         }
+    }
+}
 
-        (apollo_portion, reinsurer_portion)
+/// Treaty cost-effectiveness snapshot (returned by `treaty_utilization`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreatyUtilization {
+    /// Recoveries received / annual premium (bps)
+    pub loss_ratio_bps: u32,
+    /// Reinsurer portions filed / coverage limit (bps, 0 if unlimited)
+    pub limit_utilization_bps: u16,
+    /// Coverage limit left this term (u64::MAX if unlimited)
+    pub remaining_coverage: u64,
+}
+
+/// Earned vs. unearned premium (returned by `treaty_premium_earnings`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PremiumEarnings {
+    pub premium_paid: u64,
+    /// Portion of premium_paid the reinsurer has earned so far
+    pub earned: u64,
+    /// Portion still attributable to the unexpired term
+    pub unearned: u64,
+}
+
+/// Age of an outstanding recovery since submission to the reinsurer
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AgingBucket {
+    Days0To30,
+    Days31To60,
+    Days61To90,
+    Over90,
+}
+
+/// Outstanding recoveries tallied by age (returned by `report_recovery_aging`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RecoveryAgingReport {
+    /// Claim counts per bucket: 0-30, 31-60, 61-90, 90+ days
+    pub counts: [u32; 4],
+    /// Outstanding amounts (claimed less received) per bucket
+    pub amounts: [u64; 4],
+}
+
+impl RecoveryAgingReport {
+    /// Tally a recovery claim into its bucket; settled, denied and
+    /// unsubmitted claims are ignored
+    pub fn add(&mut self, claim: &RecoveryClaim, now: i64) {
+        let Some(bucket) = claim.aging_bucket(now) else {
+            return;
+        };
+        let i = bucket as usize;
+        let outstanding = claim.claimed_amount.saturating_sub(claim.received_amount);
+        self.counts[i] = self.counts[i].saturating_add(1);
+        self.amounts[i] = self.amounts[i].saturating_add(outstanding);
+    }
+
+    pub fn total_outstanding(&self) -> u64 {
+        self.amounts
+            .iter()
+            .fold(0u64, |acc, a| acc.saturating_add(*a))
+    }
+}
+
+/// Reinsurance layer status (returned by `get_reinsurance_status`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReinsuranceStatus {
+    /// YTD claims / expected annual claims (bps)
+    pub current_claims_ratio_bps: u16,
+    pub aggregate_triggered: bool,
+    pub catastrophic_triggered: bool,
+    /// Recovery amounts filed but not yet settled
+    pub pending_recoveries: u64,
+    pub premium_paid_ytd: u64,
+    pub premium_budget: u64,
+    /// Premium paid YTD / premium budget (bps, capped at u16::MAX)
+    pub premium_budget_utilization_bps: u16,
+    pub active_treaties: u32,
+}
+
+// ============================================================================
+// PREMIUM INSTALLMENT SCHEDULE
+// ============================================================================
+
+/// Maximum installments per treaty (quarterly)
+pub const MAX_PREMIUM_INSTALLMENTS: usize = 4;
+
+/// Optional installment schedule for a treaty's annual premium
+#[account]
+#[derive(Default)]
+pub struct PremiumSchedule {
+    /// Treaty this schedule belongs to
+    pub treaty: Pubkey,
+
+    /// Number of installments in use
+    pub installment_count: u8,
+
+    /// Installment due dates (ascending)
+    pub due_dates: [i64; MAX_PREMIUM_INSTALLMENTS],
+
+    /// Installment amounts (USDC, 6 decimals)
+    pub amounts: [u64; MAX_PREMIUM_INSTALLMENTS],
+
+    /// Days after a due date before the treaty lapses
+    pub grace_period_days: u16,
+
+    /// Treaty was suspended for a missed installment
+    pub lapsed: bool,
+
+    /// When the treaty last lapsed
+    pub lapsed_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+
+    /// Reserved
+    pub _reserved: [u8; 16],
+}
+
+impl PremiumSchedule {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // treaty
+        1 + // installment_count
+        8 * MAX_PREMIUM_INSTALLMENTS + // due_dates
+        8 * MAX_PREMIUM_INSTALLMENTS + // amounts
+        2 + // grace_period_days
+        1 + // lapsed
+        8 + // lapsed_at
+        1 + // bump
+        16; // reserved
+
+    /// Cumulative premium due on or before `cutoff`
+    pub fn amount_due_by(&self, cutoff: i64) -> u64 {
+        self.due_dates
+            .iter()
+            .zip(self.amounts.iter())
+            .take(self.installment_count as usize)
+            .filter(|(due, _)| **due <= cutoff)
+            .fold(0u64, |acc, (_, amount)| acc.saturating_add(*amount))
+    }
+
+    /// An installment is unpaid past its grace window
+    pub fn is_lapsed(&self, premium_paid: u64, current_time: i64) -> bool {
+        let grace = self.grace_period_days as i64 * SECONDS_PER_DAY;
+        premium_paid < self.amount_due_by(current_time.saturating_sub(grace))
+    }
+
+    /// Every installment due so far has been paid
+    pub fn is_caught_up(&self, premium_paid: u64, current_time: i64) -> bool {
+        premium_paid >= self.amount_due_by(current_time)
+    }
+
+    /// Time premium stops accruing: the lapse while the treaty is lapsed
+    pub fn accrual_end(&self, current_time: i64) -> i64 {
+        if self.lapsed {
+            self.lapsed_at.min(current_time)
+        } else {
+            current_time
+        }
+    }
+}
+
+// ============================================================================
+// TREATY PANEL
+// ============================================================================
+
+/// Maximum reinsurers sharing one layer
+pub const MAX_PANEL_MEMBERS: usize = 5;
+
+/// A layer split across several reinsurers, each treaty taking a share
+#[account]
+#[derive(Default)]
+pub struct TreatyPanel {
+    /// Panel identifier
+    pub panel_id: u32,
+
+    /// Layer every panelist treaty covers
+    pub layer_type: ReinsuranceLayerType,
+
+    /// Shared attachment point of the panelist treaties
+    pub attachment_point: u64,
+
+    /// Number of panelists in use
+    pub member_count: u8,
+
+    /// Panelist treaties
+    pub treaties: [Pubkey; MAX_PANEL_MEMBERS],
+
+    /// Each panelist's share of the layer (bps, sums to 10000)
+    pub shares_bps: [u16; MAX_PANEL_MEMBERS],
+
+    /// Who created the panel
+    pub created_by: Pubkey,
+
+    /// Creation timestamp
+    pub created_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+
+    /// Reserved
+    pub _reserved: [u8; 16],
+}
+
+impl TreatyPanel {
+    pub const SIZE: usize = 8 + // discriminator
+        4 + // panel_id
+        1 + // layer_type
+        8 + // attachment_point
+        1 + // member_count
+        32 * MAX_PANEL_MEMBERS + // treaties
+        2 * MAX_PANEL_MEMBERS + // shares_bps
+        32 + // created_by
+        8 + // created_at
+        1 + // bump
+        16; // reserved
+
+    /// Shares must be nonzero, fit the panel, and cover the whole layer
+    pub fn validate_shares(shares_bps: &[u16]) -> bool {
+        !shares_bps.is_empty()
+            && shares_bps.len() <= MAX_PANEL_MEMBERS
+            && shares_bps.iter().all(|s| *s > 0)
+            && shares_bps.iter().map(|s| *s as u32).sum::<u32>() == 10_000
+    }
+
+    /// Split `amount` by share; the last panelist absorbs rounding dust so
+    /// the parts always sum to `amount`
+    pub fn allocate(&self, amount: u64) -> [u64; MAX_PANEL_MEMBERS] {
+        let mut parts = [0u64; MAX_PANEL_MEMBERS];
+        let count = self.member_count as usize;
+        let mut allocated = 0u64;
+        for (i, part) in parts.iter_mut().enumerate().take(count) {
+            *part = if i + 1 == count {
+                amount.saturating_sub(allocated)
+            } else {
+                (amount as u128 * self.shares_bps[i] as u128 / 10_000) as u64
+            };
+            allocated = allocated.saturating_add(*part);
+        }
+        parts
+    }
+
+    fn position(&self, treaty: &Pubkey) -> Option<usize> {
+        self.treaties[..self.member_count as usize]
+            .iter()
+            .position(|t| t == treaty)
+    }
+
+    /// A panelist's share and allocated portion of `amount`
+    pub fn allocation_for(&self, treaty: &Pubkey, amount: u64) -> Option<(u16, u64)> {
+        let i = self.position(treaty)?;
+        Some((self.shares_bps[i], self.allocate(amount)[i]))
     }
 }
 
@@ -450,20 +964,39 @@ pub struct RecoveryClaim {
     /// Who filed this claim
     pub filed_by: Pubkey,
 
-    /// Supporting documentation hash
-    pub documentation_hash: [u8; 32],
+    /// Supporting documentation
+    pub documentation: DocRef,
 
     /// Reinsurer's reference number (if provided)
     pub reinsurer_reference: [u8; 32],
 
-    /// Resolution notes hash
-    pub resolution_notes_hash: [u8; 32],
+    /// Denial, dispute or resolution notes
+    pub resolution_notes: DocRef,
 
     /// Bump seed
     pub bump: u8,
 
+    /// Panel this claim was allocated from (0 if the treaty stands alone)
+    pub panel_id: u32,
+
+    /// Panelist's share of the layer (bps, 0 if not a panel claim)
+    pub panel_share_bps: u16,
+
+    /// How a dispute on this claim was resolved (None if never disputed)
+    pub dispute_resolution: DisputeResolutionMethod,
+
+    /// External payment references already recorded (replay protection)
+    pub settlement_references: [[u8; 32]; 8],
+
+    /// Number of settlement references recorded
+    pub settlement_reference_count: u8,
+
+    /// Member accumulator a specific stop-loss claim was filed against
+    /// (default for aggregate claims)
+    pub accumulator: Pubkey,
+
     /// Reserved
-    pub _reserved: [u8; 16],
+    pub _reserved: [u8; 5],
 }
 
 impl RecoveryClaim {
@@ -488,11 +1021,233 @@ impl RecoveryClaim {
         8 + // resolution_timestamp
         8 + // settlement_timestamp
         32 + // filed_by
-        32 + // documentation_hash
+        DocRef::SIZE + // documentation
         32 + // reinsurer_reference
-        32 + // resolution_notes_hash
+        DocRef::SIZE + // resolution_notes
         1 + // bump
-        16; // reserved
+        4 + // panel_id
+        2 + // panel_share_bps
+        1 + // dispute_resolution
+        256 + // settlement_references (8 * 32)
+        1 + // settlement_reference_count
+        32 + // accumulator
+        5; // reserved
+
+    pub const MAX_SETTLEMENT_REFERENCES: usize = 8;
+
+    /// Aging bucket for a recovery still awaiting payment from the reinsurer,
+    /// measured from submission. None once the claim is settled or denied,
+    /// or if it has not been submitted yet.
+    pub fn aging_bucket(&self, now: i64) -> Option<AgingBucket> {
+        match self.status {
+            RecoveryStatus::Submitted
+            | RecoveryStatus::UnderReview
+            | RecoveryStatus::Approved
+            | RecoveryStatus::Disputed => {}
+            _ => return None,
+        }
+        let age_days = now.saturating_sub(self.submitted_timestamp).max(0) / SECONDS_PER_DAY;
+        Some(match age_days {
+            0..=30 => AgingBucket::Days0To30,
+            31..=60 => AgingBucket::Days31To60,
+            61..=90 => AgingBucket::Days61To90,
+            _ => AgingBucket::Over90,
+        })
+    }
+
+    /// Recorded settlement payment references
+    pub fn recorded_settlement_references(&self) -> &[[u8; 32]] {
+        &self.settlement_references[..self.settlement_reference_count as usize]
+    }
+
+    /// Part of a `received` payment still counted in `pending_recoveries`:
+    /// whatever of the pending amount earlier payments have not yet covered.
+    /// An approval has already released its unapproved remainder; a
+    /// disputed claim is still pending in full.
+    pub fn pending_settled(&self, received: u64) -> u64 {
+        let pending_cap = if self.status == RecoveryStatus::Approved {
+            self.approved_amount
+        } else {
+            self.claimed_amount
+        };
+        pending_cap
+            .saturating_sub(self.received_amount)
+            .min(received)
+    }
+
+    /// Credit a settlement payment identified by an external `reference`.
+    /// A reference can only be credited once, so a replayed settlement
+    /// cannot double-count the same payment.
+    pub fn apply_settlement(&mut self, reference: [u8; 32], amount: u64) -> Result<()> {
+        require!(
+            !self.recorded_settlement_references().contains(&reference),
+            ReinsuranceError::DuplicateSettlementReference
+        );
+        let count = self.settlement_reference_count as usize;
+        require!(
+            count < Self::MAX_SETTLEMENT_REFERENCES,
+            ReinsuranceError::TooManySettlements
+        );
+
+        self.received_amount = self
+            .received_amount
+            .checked_add(amount)
+            .ok_or(ReinsuranceError::Overflow)?;
+        self.settlement_references[count] = reference;
+        self.settlement_reference_count += 1;
+        Ok(())
+    }
+
+    /// Close out a dispute at the negotiated `final_amount`. A nonzero amount
+    /// approves the claim at that amount; zero denies it. Returns the amount
+    /// that will no longer be recovered and should leave `pending_recoveries`,
+    /// or None if the amount exceeds what was claimed.
+    pub fn resolve_dispute(
+        &mut self,
+        final_amount: u64,
+        method: DisputeResolutionMethod,
+        resolved_at: i64,
+    ) -> Option<u64> {
+        if final_amount > self.claimed_amount {
+            return None;
+        }
+
+        self.status = if final_amount > 0 {
+            RecoveryStatus::Approved
+        } else {
+            RecoveryStatus::Denied
+        };
+        self.approved_amount = final_amount;
+        self.dispute_resolution = method;
+        self.resolution_timestamp = resolved_at;
+
+        Some(self.claimed_amount - final_amount)
+    }
+}
+
+// ============================================================================
+// YEAR-END RECONCILIATION
+// ============================================================================
+
+/// Year-end reconciliation of claimed vs. approved vs. received recoveries
+#[account]
+#[derive(Default)]
+pub struct PolicyYearReconciliation {
+    /// Policy year reconciled
+    pub policy_year: u16,
+
+    /// Treaties included
+    pub treaties_reconciled: u32,
+
+    /// Recovery claims included
+    pub recovery_claims_reconciled: u32,
+
+    /// Sum of treaty `total_claims_submitted` (USDC)
+    pub total_claims_submitted: u64,
+
+    /// Sum of treaty `total_recoveries_received` (USDC)
+    pub total_recoveries_received: u64,
+
+    /// Total approved by reinsurers (USDC)
+    pub total_approved: u64,
+
+    /// Approved but not yet received (approved/disputed claims, USDC)
+    pub outstanding_approved: u64,
+
+    /// Approved but never received on partially settled claims (USDC)
+    pub partial_settlement_shortfall: u64,
+
+    /// Claimed amount on denied claims (USDC)
+    pub denied_amount: u64,
+
+    /// Claimed amount on claims still awaiting a decision (USDC)
+    pub undecided_amount: u64,
+
+    /// Claim counts by outcome
+    pub settled_count: u32,
+    pub partially_settled_count: u32,
+    pub denied_count: u32,
+
+    /// Submitted minus received across all treaties (USDC)
+    pub net_recovery_shortfall: u64,
+
+    /// Who ran the reconciliation
+    pub reconciled_by: Pubkey,
+
+    /// When the reconciliation was run
+    pub reconciled_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+
+    /// Reserved
+    pub _reserved: [u8; 32],
+}
+
+impl PolicyYearReconciliation {
+    pub const SIZE: usize = 8 + // discriminator
+        2 + // policy_year
+        4 + // treaties_reconciled
+        4 + // recovery_claims_reconciled
+        8 + // total_claims_submitted
+        8 + // total_recoveries_received
+        8 + // total_approved
+        8 + // outstanding_approved
+        8 + // partial_settlement_shortfall
+        8 + // denied_amount
+        8 + // undecided_amount
+        4 + // settled_count
+        4 + // partially_settled_count
+        4 + // denied_count
+        8 + // net_recovery_shortfall
+        32 + // reconciled_by
+        8 + // reconciled_at
+        1 + // bump
+        32; // reserved
+
+    /// Add a treaty's lifetime submitted/received totals
+    pub fn add_treaty(&mut self, treaty: &ReinsuranceTreaty) {
+        self.treaties_reconciled = self.treaties_reconciled.saturating_add(1);
+        self.total_claims_submitted = self
+            .total_claims_submitted
+            .saturating_add(treaty.total_claims_submitted);
+        self.total_recoveries_received = self
+            .total_recoveries_received
+            .saturating_add(treaty.total_recoveries_received);
+        self.net_recovery_shortfall = self
+            .total_claims_submitted
+            .saturating_sub(self.total_recoveries_received);
+    }
+
+    /// Classify a recovery claim's approved/received position
+    pub fn add_recovery_claim(&mut self, claim: &RecoveryClaim) {
+        self.recovery_claims_reconciled = self.recovery_claims_reconciled.saturating_add(1);
+        let unreceived = claim.approved_amount.saturating_sub(claim.received_amount);
+
+        match claim.status {
+            RecoveryStatus::Settled => {
+                self.settled_count = self.settled_count.saturating_add(1);
+                self.total_approved = self.total_approved.saturating_add(claim.approved_amount);
+            }
+            RecoveryStatus::PartiallySettled => {
+                self.partially_settled_count = self.partially_settled_count.saturating_add(1);
+                self.total_approved = self.total_approved.saturating_add(claim.approved_amount);
+                self.partial_settlement_shortfall =
+                    self.partial_settlement_shortfall.saturating_add(unreceived);
+            }
+            RecoveryStatus::Approved | RecoveryStatus::Disputed => {
+                self.total_approved = self.total_approved.saturating_add(claim.approved_amount);
+                self.outstanding_approved = self.outstanding_approved.saturating_add(unreceived);
+            }
+            RecoveryStatus::Denied => {
+                self.denied_count = self.denied_count.saturating_add(1);
+                self.denied_amount = self.denied_amount.saturating_add(claim.claimed_amount);
+            }
+            RecoveryStatus::Pending | RecoveryStatus::Submitted | RecoveryStatus::UnderReview => {
+                self.undecided_amount = self.undecided_amount.saturating_add(claim.claimed_amount);
+            }
+        }
+    }
 }
 
 /// ============================================================================
@@ -536,8 +1291,14 @@ pub struct MemberClaimsAccumulator {
     /// Bump seed
     pub bump: u8,
 
+    /// Recovery claims ever filed against this accumulator
+    pub recovery_claims_filed: u16,
+
+    /// Filed recovery claims not yet denied or finally settled
+    pub open_recovery_claims: u16,
+
     /// Reserved
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 28],
 }
 
 impl MemberClaimsAccumulator {
@@ -553,14 +1314,43 @@ impl MemberClaimsAccumulator {
         8 + // first_trigger_timestamp
         8 + // last_claim_timestamp
         1 + // bump
-        32; // reserved
+        2 + // recovery_claims_filed
+        2 + // open_recovery_claims
+        28; // reserved
 
-    /// Check if a new claim should trigger stop-loss
-    pub fn check_stop_loss_trigger(&self, new_claim_amount: u64, attachment: u64) -> Option<u64> {
-        let new_total = self.ytd_claims.saturating_add(new_claim_amount);
-        if new_total > attachment {
-            // Calculate excess - could be partial (just this claim) or include prior
-            if self.ytd_claims >= attachment {
+    /// Whether the account has been populated (false right after `init_if_needed`)
+    pub fn is_initialized(&self) -> bool {
+        self.member != Pubkey::default()
+    }
+
+    /// Reset to an empty accumulator for `member` in `policy_year`
+    pub fn initialize(&mut self, member: Pubkey, policy_year: u16, bump: u8) {
+        *self = Self {
+            member,
+            policy_year,
+            bump,
+            ..Default::default()
+        };
+    }
+
+    /// Whether this accumulator tracks a policy year before `current_policy_year`
+    pub fn is_prior_year(&self, current_policy_year: u16) -> bool {
+        self.policy_year < current_policy_year
+    }
+
+    /// A triggered stop-loss not yet filed with the reinsurer, or a filed
+    /// recovery still awaiting its outcome
+    pub fn has_outstanding_recoveries(&self) -> bool {
+        self.open_recovery_claims > 0
+            || (self.stop_loss_triggered && self.recovery_claims_filed == 0)
+    }
+
+    /// Check if a new claim should trigger stop-loss
+    pub fn check_stop_loss_trigger(&self, new_claim_amount: u64, attachment: u64) -> Option<u64> {
+        let new_total = self.ytd_claims.saturating_add(new_claim_amount);
+        if new_total > attachment {
+            // Calculate excess - could be partial (just this claim) or include prior
+            if self.ytd_claims >= attachment {
                 // Already over, entire new claim is excess
                 Some(new_claim_amount)
             } else {
@@ -571,6 +1361,95 @@ impl MemberClaimsAccumulator {
             None
         }
     }
+
+    /// Take back `amount` of previously recorded claims (a claim denied
+    /// after approval). A stop-loss that falls back under `attachment`
+    /// before any recovery was filed is no longer triggered.
+    pub fn reverse_claim(&mut self, amount: u64, attachment: u64) -> Result<()> {
+        self.ytd_claims = self
+            .ytd_claims
+            .checked_sub(amount)
+            .ok_or(ReinsuranceError::Underflow)?;
+        self.claims_count = self.claims_count.saturating_sub(1);
+
+        if self.stop_loss_triggered {
+            self.excess_claimed = self.ytd_claims.saturating_sub(attachment);
+            if self.excess_claimed == 0 && self.recovery_claims_filed == 0 {
+                self.stop_loss_triggered = false;
+                self.first_trigger_timestamp = 0;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Claims program ID. Not a crate dependency (claims depends on
+/// reinsurance); its config PDA signs claim reversals.
+pub const CLAIMS_PROGRAM_ID: Pubkey = pubkey!("J65pg6g7caJvSvfGBsuwzzYiyxR1EJePP1NGuaPqRK6C");
+
+/// Seed of the claims program's config PDA
+pub const CLAIMS_CONFIG_SEED: &[u8] = b"claims_config";
+
+/// Compact snapshot of a closed member accumulator, kept for audit
+#[account]
+#[derive(Default)]
+pub struct AccumulatorArchive {
+    /// Member identifier
+    pub member: Pubkey,
+
+    /// Policy year archived
+    pub policy_year: u16,
+
+    /// Final year-to-date claims (USDC)
+    pub ytd_claims: u64,
+
+    /// Claims count for the year
+    pub claims_count: u32,
+
+    /// Amount that exceeded stop-loss attachment
+    pub excess_claimed: u64,
+
+    /// Amount recovered from reinsurer
+    pub recovered_amount: u64,
+
+    /// Whether stop-loss was triggered
+    pub stop_loss_triggered: bool,
+
+    /// First trigger timestamp
+    pub first_trigger_timestamp: i64,
+
+    /// When the accumulator was archived
+    pub archived_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl AccumulatorArchive {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // member
+        2 + // policy_year
+        8 + // ytd_claims
+        4 + // claims_count
+        8 + // excess_claimed
+        8 + // recovered_amount
+        1 + // stop_loss_triggered
+        8 + // first_trigger_timestamp
+        8 + // archived_at
+        1; // bump
+
+    /// Snapshot a member accumulator's year-end totals
+    pub fn snapshot(&mut self, accumulator: &MemberClaimsAccumulator, archived_at: i64) {
+        self.member = accumulator.member;
+        self.policy_year = accumulator.policy_year;
+        self.ytd_claims = accumulator.ytd_claims;
+        self.claims_count = accumulator.claims_count;
+        self.excess_claimed = accumulator.excess_claimed;
+        self.recovered_amount = accumulator.recovered_amount;
+        self.stop_loss_triggered = accumulator.stop_loss_triggered;
+        self.first_trigger_timestamp = accumulator.first_trigger_timestamp;
+        self.archived_at = archived_at;
+    }
 }
 
 /// ============================================================================
@@ -636,6 +1515,34 @@ impl MonthlyAggregate {
         8 + // last_updated
         1 + // bump
         16; // reserved
+
+    /// Add a claim to this month, refreshing average and ratio
+    /// Returns None on overflow
+    pub fn record_claim(&mut self, claim_amount: u64, is_shock_claim: bool) -> Option<()> {
+        self.total_claims = self.total_claims.checked_add(claim_amount)?;
+        self.claims_count = self.claims_count.checked_add(1)?;
+        self.avg_claim_amount = self.total_claims / self.claims_count as u64;
+
+        if claim_amount > self.max_claim {
+            self.max_claim = claim_amount;
+        }
+        if is_shock_claim {
+            self.shock_claims_count = self.shock_claims_count.checked_add(1)?;
+        }
+
+        if self.expected_claims > 0 {
+            self.ratio_bps = ratio_bps(self.total_claims, self.expected_claims);
+        }
+        Some(())
+    }
+
+    /// Roll this month into the running YTD total given the prior month's
+    /// YTD (0 for month 1). Never moves backwards.
+    pub fn roll_up_ytd(&mut self, prior_ytd: u64) -> Option<()> {
+        let ytd = prior_ytd.checked_add(self.total_claims)?;
+        self.ytd_through_month = self.ytd_through_month.max(ytd);
+        Some(())
+    }
 }
 
 /// ============================================================================
@@ -808,6 +1715,15 @@ impl ScalableReinsuranceParams {
             "large"
         }
     }
+
+    /// Apply pool-size-appropriate trigger ratios to the config and record
+    /// the suggested specific attachment for new treaties
+    pub fn apply_to(&self, config: &mut ReinsuranceConfig, member_count: u64) {
+        config.aggregate_trigger_ratio_bps = self.get_aggregate_trigger_bps(member_count);
+        config.catastrophic_trigger_ratio_bps = self.catastrophic_trigger_bps;
+        config.catastrophic_ceiling_ratio_bps = self.catastrophic_ceiling_bps;
+        config.suggested_specific_attachment = self.get_attachment(member_count);
+    }
 }
 
 /// ============================================================================
@@ -906,4 +1822,1144 @@ mod scalable_tests {
         assert_eq!(params.get_pool_category(5000), "large"); // At threshold
         assert_eq!(params.get_pool_category(10000), "large");
     }
+
+    #[test]
+    fn test_apply_to_small_pool() {
+        let params = ScalableReinsuranceParams::default();
+        let mut config = ReinsuranceConfig::default();
+
+        params.apply_to(&mut config, 200);
+
+        assert_eq!(params.get_pool_category(200), "small");
+        assert_eq!(config.aggregate_trigger_ratio_bps, 10500);
+        assert_eq!(config.catastrophic_trigger_ratio_bps, 15000);
+        assert_eq!(config.catastrophic_ceiling_ratio_bps, 30000);
+        assert_eq!(config.suggested_specific_attachment, 50_000_000_000);
+    }
+
+    #[test]
+    fn test_apply_to_large_pool() {
+        let params = ScalableReinsuranceParams::default();
+        let mut config = ReinsuranceConfig::default();
+
+        params.apply_to(&mut config, 10_000);
+
+        assert_eq!(params.get_pool_category(10_000), "large");
+        assert_eq!(config.aggregate_trigger_ratio_bps, 11000);
+        assert_eq!(config.catastrophic_trigger_ratio_bps, 15000);
+        assert_eq!(config.catastrophic_ceiling_ratio_bps, 30000);
+        assert_eq!(config.suggested_specific_attachment, 100_000_000_000);
+    }
+}
+
+// ============================================================================
+// UNIT TESTS FOR AGGREGATE TRACKING
+// ============================================================================
+
+#[cfg(test)]
+mod aggregate_tests {
+    use super::*;
+
+    fn create_test_month(month: u8, expected_claims: u64) -> MonthlyAggregate {
+        MonthlyAggregate {
+            policy_year: 2026,
+            month,
+            expected_claims,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_record_claim_populates_avg_and_ratio() {
+        let mut month = create_test_month(1, 1_000_000_000_000); // $1M expected
+
+        month.record_claim(300_000_000_000, false).unwrap();
+        month.record_claim(100_000_000_000, true).unwrap();
+
+        assert_eq!(month.total_claims, 400_000_000_000);
+        assert_eq!(month.claims_count, 2);
+        assert_eq!(month.avg_claim_amount, 200_000_000_000);
+        assert_eq!(month.max_claim, 300_000_000_000);
+        assert_eq!(month.shock_claims_count, 1);
+        assert_eq!(month.ratio_bps, 4000); // 40%
+    }
+
+    #[test]
+    fn test_three_months_roll_up_ytd() {
+        let expected = 1_000_000_000_000; // $1M per month
+        let mut jan = create_test_month(1, expected);
+        let mut feb = create_test_month(2, expected);
+        let mut mar = create_test_month(3, expected);
+
+        jan.record_claim(900_000_000_000, false).unwrap();
+        jan.roll_up_ytd(0).unwrap();
+
+        feb.record_claim(1_100_000_000_000, false).unwrap();
+        feb.roll_up_ytd(jan.ytd_through_month).unwrap();
+
+        mar.record_claim(600_000_000_000, false).unwrap();
+        mar.record_claim(400_000_000_000, false).unwrap();
+        mar.roll_up_ytd(feb.ytd_through_month).unwrap();
+
+        assert_eq!(jan.ytd_through_month, 900_000_000_000);
+        assert_eq!(feb.ytd_through_month, 2_000_000_000_000);
+        assert_eq!(mar.ytd_through_month, 3_000_000_000_000);
+
+        assert_eq!(jan.ratio_bps, 9000);
+        assert_eq!(feb.ratio_bps, 11000);
+        assert_eq!(mar.ratio_bps, 10000);
+        assert_eq!(mar.avg_claim_amount, 500_000_000_000);
+    }
+
+    fn create_test_config(expected_annual_claims: u64) -> ReinsuranceConfig {
+        ReinsuranceConfig {
+            expected_annual_claims,
+            aggregate_trigger_ratio_bps: 11000,    // 110%
+            catastrophic_trigger_ratio_bps: 15000, // 150%
+            catastrophic_ceiling_ratio_bps: 30000, // 300%
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_monthly_update_past_110_percent_flips_aggregate() {
+        let mut config = create_test_config(12_000_000_000_000); // $12M expected
+        config.ytd_claims_paid = 12_500_000_000_000; // ~104%
+        assert_eq!(config.apply_threshold_triggers(), (false, false));
+
+        // A single month rolls YTD to $13.5M (112.5%)
+        let mut dec = create_test_month(12, 1_000_000_000_000);
+        dec.record_claim(1_000_000_000_000, false).unwrap();
+        dec.roll_up_ytd(12_500_000_000_000).unwrap();
+        config.sync_ytd_claims(dec.ytd_through_month);
+
+        assert_eq!(config.current_claims_ratio_bps(), 11250);
+        assert_eq!(config.apply_threshold_triggers(), (true, false));
+        assert!(config.aggregate_triggered);
+        assert!(!config.catastrophic_triggered);
+        assert_eq!(config.calculate_aggregate_recoverable(), 300_000_000_000);
+
+        // Already triggered: not reported again
+        assert_eq!(config.apply_threshold_triggers(), (false, false));
+    }
+
+    #[test]
+    fn test_lowering_expected_claims_crosses_aggregate_trigger() {
+        let mut config = create_test_config(12_000_000_000_000); // $12M expected
+        config.ytd_claims_paid = 12_500_000_000_000; // ~104%
+        assert_eq!(config.apply_threshold_triggers(), (false, false));
+
+        // Mid-year re-estimate to $11M puts YTD at ~113.6%
+        config.expected_annual_claims = 11_000_000_000_000;
+        assert_eq!(config.apply_threshold_triggers(), (true, false));
+        assert_eq!(config.current_claims_ratio_bps(), 11363);
+        assert!(config.aggregate_triggered);
+        assert!(!config.catastrophic_triggered);
+
+        // Raising it again does not clear the trigger or re-report it
+        config.expected_annual_claims = 20_000_000_000_000;
+        assert_eq!(config.apply_threshold_triggers(), (false, false));
+        assert!(config.aggregate_triggered);
+    }
+
+    #[test]
+    fn test_monthly_update_can_flip_both_layers() {
+        let mut config = create_test_config(1_000_000_000_000); // $1M expected
+        config.sync_ytd_claims(1_600_000_000_000); // 160%
+
+        assert_eq!(config.apply_threshold_triggers(), (true, true));
+
+        // Monthly roll-up below the recorded total never lowers YTD
+        config.sync_ytd_claims(500_000_000_000);
+        assert_eq!(config.ytd_claims_paid, 1_600_000_000_000);
+    }
+
+    #[test]
+    fn test_combined_recoveries_clamped_to_ceiling() {
+        let mut config = create_test_config(1_000_000_000_000); // $1M expected
+        config.sync_ytd_claims(4_000_000_000_000); // 400%
+        config.apply_threshold_triggers();
+
+        // Unclamped: $400k aggregate (110-150%) + $2.5M catastrophic (>150%)
+        assert_eq!(config.calculate_aggregate_recoverable(), 400_000_000_000);
+        assert_eq!(
+            config.calculate_catastrophic_recoverable(),
+            2_500_000_000_000
+        );
+
+        // Combined recovery stops at 300%: $1.9M above the 110% trigger
+        let (aggregate, catastrophic) = config.clamp_to_ceiling(
+            config.calculate_aggregate_recoverable(),
+            config.calculate_catastrophic_recoverable(),
+        );
+        assert_eq!(aggregate, 400_000_000_000);
+        assert_eq!(catastrophic, 1_500_000_000_000);
+        assert_eq!(aggregate + catastrophic, 1_900_000_000_000);
+    }
+
+    #[test]
+    fn test_aggregate_alone_clamped_to_ceiling() {
+        let mut config = create_test_config(1_000_000_000_000);
+        config.ytd_claims_paid = 3_500_000_000_000; // 350%
+        config.aggregate_triggered = true;
+
+        // Catastrophic not yet flagged: aggregate alone would cover 110-350%
+        assert_eq!(config.calculate_aggregate_recoverable(), 2_400_000_000_000);
+        assert_eq!(config.aggregate_layer_recoverable(), 1_900_000_000_000);
+
+        // Below the ceiling nothing is clamped
+        assert_eq!(
+            config.clamp_to_ceiling(300_000_000_000, 200_000_000_000),
+            (300_000_000_000, 200_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_strict_budget_rejects_premium_over_budget() {
+        let mut config = create_test_config(1_000_000_000_000);
+        config.premium_budget = 100_000_000_000; // $100k
+        config.strict_budget = true;
+
+        assert!(config.permits_premium(100_000_000_000)); // Exactly at budget
+        assert!(!config.permits_premium(100_000_000_001));
+    }
+
+    #[test]
+    fn test_lenient_budget_allows_premium_over_budget() {
+        let mut config = create_test_config(1_000_000_000_000);
+        config.premium_budget = 100_000_000_000;
+
+        assert!(config.permits_premium(100_000_000_000));
+        assert!(config.permits_premium(100_000_000_001));
+    }
+
+    #[test]
+    fn test_status_reflects_claims_and_payments() {
+        let mut config = create_test_config(12_000_000_000_000); // $12M expected
+        config.premium_budget = 400_000_000_000; // $400k
+        config.active_treaties = 2;
+
+        let status = config.status();
+        assert_eq!(status.current_claims_ratio_bps, 0);
+        assert!(!status.aggregate_triggered);
+        assert_eq!(status.premium_budget_utilization_bps, 0);
+
+        // Claims run to 120% of expected, a recovery is filed, and half
+        // the premium budget is paid
+        config.sync_ytd_claims(14_400_000_000_000);
+        config.apply_threshold_triggers();
+        config.pending_recoveries = 1_200_000_000_000;
+        config.premium_paid_ytd = 200_000_000_000;
+
+        let status = config.status();
+        assert_eq!(status.current_claims_ratio_bps, 12000);
+        assert!(status.aggregate_triggered);
+        assert!(!status.catastrophic_triggered);
+        assert_eq!(status.pending_recoveries, 1_200_000_000_000);
+        assert_eq!(status.premium_paid_ytd, 200_000_000_000);
+        assert_eq!(status.premium_budget, 400_000_000_000);
+        assert_eq!(status.premium_budget_utilization_bps, 5000);
+        assert_eq!(status.active_treaties, 2);
+
+        // Catastrophic flips past 150%
+        config.sync_ytd_claims(18_000_000_000_000);
+        config.apply_threshold_triggers();
+        assert!(config.status().catastrophic_triggered);
+    }
+
+    fn create_test_treaty(recoveries: u64) -> ReinsuranceTreaty {
+        // $200k premium for $2M of coverage
+        ReinsuranceTreaty {
+            annual_premium: 200_000_000_000,
+            coverage_limit: 2_000_000_000_000,
+            total_recoveries_received: recoveries,
+            cumulative_reinsurer_paid: recoveries,
+            ..Default::default()
+        }
+    }
+
+    fn create_tailed_treaty(tail_days: u16) -> ReinsuranceTreaty {
+        ReinsuranceTreaty {
+            status: TreatyStatus::Expired,
+            effective_date: 0,
+            expiration_date: 365 * SECONDS_PER_DAY,
+            reporting_tail_days: tail_days,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_recovery_files_within_reporting_tail() {
+        let treaty = create_tailed_treaty(90);
+        let last_covered_day = treaty.expiration_date - SECONDS_PER_DAY;
+
+        // Event on the last covered day, reported 60 days after expiry
+        assert!(treaty.covers_event(last_covered_day));
+        assert!(treaty.within_reporting_window(treaty.expiration_date + 60 * SECONDS_PER_DAY));
+        assert!(treaty.within_reporting_window(treaty.reporting_deadline()));
+
+        // Events after expiration are never covered
+        assert!(!treaty.covers_event(treaty.expiration_date + 1));
+    }
+
+    #[test]
+    fn test_recovery_rejected_after_reporting_tail() {
+        let treaty = create_tailed_treaty(90);
+        assert!(!treaty.within_reporting_window(treaty.reporting_deadline() + 1));
+
+        // With no tail the window closes at expiration
+        let treaty = create_tailed_treaty(0);
+        assert!(treaty.within_reporting_window(treaty.expiration_date));
+        assert!(!treaty.within_reporting_window(treaty.expiration_date + 1));
+
+        // Cancelled treaties accept nothing
+        let mut treaty = create_tailed_treaty(90);
+        treaty.status = TreatyStatus::Cancelled;
+        assert!(!treaty.within_reporting_window(treaty.expiration_date));
+    }
+
+    #[test]
+    fn test_unearned_premium_refund_halfway_through_term() {
+        let mut treaty = ReinsuranceTreaty {
+            status: TreatyStatus::Active,
+            effective_date: 0,
+            expiration_date: 366 * SECONDS_PER_DAY,
+            ..create_test_treaty(0)
+        };
+        treaty.premium_paid = treaty.annual_premium;
+
+        // 183 of 366 days left: half the paid premium comes back
+        assert_eq!(
+            treaty.unearned_premium(183 * SECONDS_PER_DAY),
+            100_000_000_000
+        );
+
+        // Partial days are earned by the reinsurer
+        assert_eq!(
+            treaty.unearned_premium(183 * SECONDS_PER_DAY + 1),
+            200_000_000_000 * 182 / 366
+        );
+
+        // Nothing is unearned once the term has run out
+        assert_eq!(treaty.unearned_premium(treaty.expiration_date), 0);
+        assert_eq!(treaty.unearned_premium(treaty.expiration_date + 1), 0);
+    }
+
+    #[test]
+    fn test_unearned_premium_before_coverage_starts() {
+        let treaty = ReinsuranceTreaty {
+            effective_date: 30 * SECONDS_PER_DAY,
+            expiration_date: 395 * SECONDS_PER_DAY,
+            premium_paid: 50_000_000_000,
+            ..create_test_treaty(0)
+        };
+        assert_eq!(treaty.unearned_premium(0), 50_000_000_000);
+    }
+
+    #[test]
+    fn test_earned_premium_across_term() {
+        let treaty = ReinsuranceTreaty {
+            effective_date: 0,
+            expiration_date: 366 * SECONDS_PER_DAY,
+            premium_paid: 200_000_000_000,
+            ..create_test_treaty(0)
+        };
+
+        // Start of term: nothing earned yet
+        let start = treaty.premium_earnings(0);
+        assert_eq!(start.earned, 0);
+        assert_eq!(start.unearned, 200_000_000_000);
+
+        // Midpoint: half earned, half unearned
+        let mid = treaty.premium_earnings(183 * SECONDS_PER_DAY);
+        assert_eq!(mid.earned, 100_000_000_000);
+        assert_eq!(mid.unearned, 100_000_000_000);
+
+        // End of term and beyond: fully earned
+        for now in [
+            treaty.expiration_date,
+            treaty.expiration_date + 90 * SECONDS_PER_DAY,
+        ] {
+            let end = treaty.premium_earnings(now);
+            assert_eq!(end.earned, 200_000_000_000);
+            assert_eq!(end.unearned, 0);
+        }
+    }
+
+    #[test]
+    fn test_earned_premium_before_coverage_starts() {
+        let treaty = ReinsuranceTreaty {
+            effective_date: 30 * SECONDS_PER_DAY,
+            expiration_date: 395 * SECONDS_PER_DAY,
+            premium_paid: 50_000_000_000,
+            ..create_test_treaty(0)
+        };
+        assert_eq!(treaty.earned_premium(0), 0);
+        assert_eq!(treaty.premium_earnings(0).unearned, 50_000_000_000);
+    }
+
+    #[test]
+    fn test_earned_and_unearned_premium_agree_on_partial_days() {
+        let treaty = ReinsuranceTreaty {
+            effective_date: 0,
+            expiration_date: 366 * SECONDS_PER_DAY,
+            premium_paid: 200_000_000_000,
+            ..create_test_treaty(0)
+        };
+        for now in [1, 183 * SECONDS_PER_DAY + 1, 300 * SECONDS_PER_DAY - 1] {
+            let earnings = treaty.premium_earnings(now);
+            assert_eq!(earnings.unearned, treaty.unearned_premium(now));
+            assert_eq!(earnings.earned + earnings.unearned, treaty.premium_paid);
+        }
+        // A started day counts as earned
+        assert_eq!(
+            treaty.earned_premium(183 * SECONDS_PER_DAY + 1),
+            200_000_000_000 - 200_000_000_000 * 182 / 366
+        );
+    }
+
+    #[test]
+    fn test_premium_stops_accruing_at_cancellation_and_lapse() {
+        let mut treaty = ReinsuranceTreaty {
+            effective_date: 0,
+            expiration_date: 366 * SECONDS_PER_DAY,
+            premium_paid: 200_000_000_000,
+            ..create_test_treaty(0)
+        };
+
+        // Lapsed at the midpoint: earnings stay at the lapse
+        let schedule = PremiumSchedule {
+            lapsed: true,
+            lapsed_at: 183 * SECONDS_PER_DAY,
+            ..Default::default()
+        };
+        let later = 300 * SECONDS_PER_DAY;
+        let as_of = schedule.accrual_end(later);
+        assert_eq!(treaty.earned_premium(as_of), 100_000_000_000);
+
+        // Cancelled at the midpoint: the refunded half is never earned
+        treaty.unearned_premium_refund = treaty.unearned_premium(183 * SECONDS_PER_DAY);
+        treaty.status = TreatyStatus::Cancelled;
+        let earnings = treaty.premium_earnings(later);
+        assert_eq!(earnings.earned, 100_000_000_000);
+        assert_eq!(earnings.unearned, 100_000_000_000);
+    }
+
+    #[test]
+    fn test_activation_requires_half_upfront() {
+        let mut treaty = create_test_treaty(0);
+        treaty.min_activation_premium_bps = 5000;
+
+        assert_eq!(treaty.min_activation_premium(), 100_000_000_000);
+
+        // The default quarter is no longer enough
+        treaty.premium_paid = 50_000_000_000;
+        assert!(!treaty.meets_activation_premium());
+
+        treaty.premium_paid = 100_000_000_000;
+        assert!(treaty.meets_activation_premium());
+    }
+
+    #[test]
+    fn test_zero_upfront_treaty_activates_immediately() {
+        let mut treaty = create_test_treaty(0);
+        treaty.min_activation_premium_bps = 0;
+
+        assert_eq!(treaty.min_activation_premium(), 0);
+        assert!(treaty.meets_activation_premium());
+    }
+
+    #[test]
+    fn test_treaty_activation_waits_for_effective_date() {
+        let treaty = ReinsuranceTreaty {
+            effective_date: 1_000,
+            expiration_date: 2_000,
+            ..create_test_treaty(0)
+        };
+
+        assert!(!treaty.has_started(999));
+        assert!(treaty.has_started(1_000));
+        assert!(treaty.has_started(1_500));
+    }
+
+    #[test]
+    fn test_underused_treaty_utilization() {
+        let treaty = create_test_treaty(20_000_000_000); // $20k recovered
+
+        let util = treaty.utilization();
+        assert_eq!(util.loss_ratio_bps, 1000); // 10% of premium
+        assert_eq!(util.limit_utilization_bps, 100); // 1% of limit
+        assert_eq!(util.remaining_coverage, 1_980_000_000_000);
+    }
+
+    #[test]
+    fn test_heavily_used_treaty_utilization() {
+        let treaty = create_test_treaty(1_500_000_000_000); // $1.5M recovered
+
+        let util = treaty.utilization();
+        assert_eq!(util.loss_ratio_bps, 75_000); // 750%
+        assert_eq!(util.limit_utilization_bps, 7500);
+        assert_eq!(util.remaining_coverage, 500_000_000_000);
+
+        // Unlimited treaty reports no limit utilization
+        let mut unlimited = treaty;
+        unlimited.coverage_limit = 0;
+        let util = unlimited.utilization();
+        assert_eq!(util.limit_utilization_bps, 0);
+        assert_eq!(util.remaining_coverage, u64::MAX);
+    }
+
+    #[test]
+    fn test_utilization_counts_filed_recoveries_against_limit() {
+        // $500k filed with the reinsurer, only $100k received so far
+        let mut treaty = create_test_treaty(100_000_000_000);
+        treaty.record_reinsurer_claim(400_000_000_000);
+
+        let util = treaty.utilization();
+        assert_eq!(util.loss_ratio_bps, 5000); // received / premium
+        assert_eq!(util.limit_utilization_bps, 2500);
+        assert_eq!(util.remaining_coverage, treaty.remaining_limit());
+        assert_eq!(util.remaining_coverage, 1_500_000_000_000);
+    }
+
+    #[test]
+    fn test_second_recovery_clamped_to_remaining_limit() {
+        // $2M limit, 20% coinsurance
+        let mut treaty = create_test_treaty(0);
+        treaty.coinsurance_rate_bps = 2000;
+
+        // $2.25M excess: reinsurer owes $1.8M, within the limit
+        let (apollo, reinsurer) = treaty.calculate_coverage(2_250_000_000_000);
+        assert_eq!((apollo, reinsurer), (450_000_000_000, 1_800_000_000_000));
+        treaty.record_reinsurer_claim(reinsurer);
+        assert_eq!(treaty.remaining_limit(), 200_000_000_000);
+        assert!(!treaty.is_limit_exhausted());
+
+        // $500k excess would owe $400k, but only $200k of limit is left
+        let (apollo, reinsurer) = treaty.calculate_coverage(500_000_000_000);
+        assert_eq!((apollo, reinsurer), (100_000_000_000, 200_000_000_000));
+        treaty.record_reinsurer_claim(reinsurer);
+        assert!(treaty.is_limit_exhausted());
+        assert_eq!(treaty.cumulative_reinsurer_paid, treaty.coverage_limit);
+
+        // Nothing more is recoverable until a recovery is denied
+        assert_eq!(treaty.calculate_coverage(500_000_000_000).1, 0);
+        treaty.release_reinsurer_claim(200_000_000_000);
+        assert_eq!(
+            treaty.calculate_coverage(500_000_000_000).1,
+            200_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_unlimited_treaty_never_exhausts() {
+        let mut treaty = create_test_treaty(0);
+        treaty.coverage_limit = 0;
+        treaty.record_reinsurer_claim(50_000_000_000_000);
+
+        assert!(!treaty.is_limit_exhausted());
+        assert_eq!(treaty.calculate_coverage(1_000_000_000).1, 1_000_000_000);
+    }
+
+    fn create_test_schedule() -> PremiumSchedule {
+        // Quarterly $25k installments, 30-day grace
+        PremiumSchedule {
+            installment_count: 4,
+            due_dates: [
+                0,
+                90 * SECONDS_PER_DAY,
+                180 * SECONDS_PER_DAY,
+                270 * SECONDS_PER_DAY,
+            ],
+            amounts: [25_000_000_000; MAX_PREMIUM_INSTALLMENTS],
+            grace_period_days: 30,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_missed_installment_lapses_after_grace() {
+        let schedule = create_test_schedule();
+        // First installment paid, second missed
+        let paid = 25_000_000_000;
+
+        // Still inside the grace window for the Q2 installment
+        assert!(!schedule.is_lapsed(paid, 100 * SECONDS_PER_DAY));
+        assert!(!schedule.is_caught_up(paid, 100 * SECONDS_PER_DAY));
+
+        // Grace window elapsed
+        assert!(schedule.is_lapsed(paid, 121 * SECONDS_PER_DAY));
+    }
+
+    #[test]
+    fn test_catch_up_payment_restores_coverage() {
+        let schedule = create_test_schedule();
+        let now = 150 * SECONDS_PER_DAY;
+        assert!(schedule.is_lapsed(25_000_000_000, now));
+
+        // Paying the missed Q2 installment brings the treaty current
+        let paid = 50_000_000_000;
+        assert!(schedule.is_caught_up(paid, now));
+        assert!(!schedule.is_lapsed(paid, now));
+    }
+
+    fn create_test_recovery(
+        treaty: Pubkey,
+        status: RecoveryStatus,
+        claimed: u64,
+        approved: u64,
+        received: u64,
+    ) -> RecoveryClaim {
+        RecoveryClaim {
+            treaty,
+            status,
+            claimed_amount: claimed,
+            approved_amount: approved,
+            received_amount: received,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_recovery_aging_buckets() {
+        let now = 1_000 * SECONDS_PER_DAY;
+        let aged = |status, days: i64, claimed, received| {
+            let mut claim =
+                create_test_recovery(Pubkey::new_unique(), status, claimed, 0, received);
+            claim.submitted_timestamp = now - days * SECONDS_PER_DAY;
+            claim
+        };
+
+        let fresh = aged(RecoveryStatus::Submitted, 10, 10_000_000_000, 0);
+        let boundary = aged(RecoveryStatus::UnderReview, 30, 5_000_000_000, 0);
+        let mid = aged(RecoveryStatus::Approved, 45, 20_000_000_000, 0);
+        let late = aged(RecoveryStatus::Disputed, 75, 30_000_000_000, 0);
+        let stale = aged(RecoveryStatus::Submitted, 120, 40_000_000_000, 0);
+
+        assert_eq!(fresh.aging_bucket(now), Some(AgingBucket::Days0To30));
+        assert_eq!(boundary.aging_bucket(now), Some(AgingBucket::Days0To30));
+        assert_eq!(mid.aging_bucket(now), Some(AgingBucket::Days31To60));
+        assert_eq!(late.aging_bucket(now), Some(AgingBucket::Days61To90));
+        assert_eq!(stale.aging_bucket(now), Some(AgingBucket::Over90));
+
+        let mut report = RecoveryAgingReport::default();
+        for claim in [&fresh, &boundary, &mid, &late, &stale] {
+            report.add(claim, now);
+        }
+        assert_eq!(report.counts, [2, 1, 1, 1]);
+        assert_eq!(
+            report.amounts,
+            [
+                15_000_000_000,
+                20_000_000_000,
+                30_000_000_000,
+                40_000_000_000
+            ]
+        );
+        assert_eq!(report.total_outstanding(), 105_000_000_000);
+    }
+
+    #[test]
+    fn test_recovery_aging_skips_closed_claims() {
+        let now = 1_000 * SECONDS_PER_DAY;
+        let old = now - 200 * SECONDS_PER_DAY;
+        let mut report = RecoveryAgingReport::default();
+
+        for status in [
+            RecoveryStatus::Pending,
+            RecoveryStatus::Settled,
+            RecoveryStatus::PartiallySettled,
+            RecoveryStatus::Denied,
+        ] {
+            let mut claim = create_test_recovery(Pubkey::new_unique(), status, 1_000, 0, 0);
+            claim.submitted_timestamp = old;
+            assert_eq!(claim.aging_bucket(now), None);
+            report.add(&claim, now);
+        }
+        assert_eq!(report, RecoveryAgingReport::default());
+    }
+
+    #[test]
+    fn test_dispute_resolved_to_partial_approval() {
+        let mut claim = create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Disputed,
+            100_000_000_000,
+            0,
+            0,
+        );
+
+        let released = claim
+            .resolve_dispute(60_000_000_000, DisputeResolutionMethod::Negotiated, 1_000)
+            .unwrap();
+
+        assert_eq!(claim.status, RecoveryStatus::Approved);
+        assert_eq!(claim.approved_amount, 60_000_000_000);
+        assert_eq!(
+            claim.dispute_resolution,
+            DisputeResolutionMethod::Negotiated
+        );
+        assert_eq!(claim.resolution_timestamp, 1_000);
+        // The conceded $40k no longer counts as pending
+        assert_eq!(released, 40_000_000_000);
+    }
+
+    #[test]
+    fn test_dispute_resolved_to_full_denial() {
+        let mut claim = create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Disputed,
+            100_000_000_000,
+            0,
+            0,
+        );
+
+        let released = claim
+            .resolve_dispute(0, DisputeResolutionMethod::Arbitration, 1_000)
+            .unwrap();
+
+        assert_eq!(claim.status, RecoveryStatus::Denied);
+        assert_eq!(claim.approved_amount, 0);
+        assert_eq!(
+            claim.dispute_resolution,
+            DisputeResolutionMethod::Arbitration
+        );
+        assert_eq!(released, 100_000_000_000);
+    }
+
+    #[test]
+    fn test_settlement_reference_cannot_be_recorded_twice() {
+        let mut claim = create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Approved,
+            100_000_000_000,
+            100_000_000_000,
+            0,
+        );
+
+        claim.apply_settlement([1u8; 32], 40_000_000_000).unwrap();
+
+        // Replaying the same payment is rejected and credits nothing
+        assert!(claim.apply_settlement([1u8; 32], 40_000_000_000).is_err());
+        assert_eq!(claim.received_amount, 40_000_000_000);
+        assert_eq!(claim.settlement_reference_count, 1);
+    }
+
+    #[test]
+    fn test_distinct_settlement_references_accumulate() {
+        let mut claim = create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Approved,
+            100_000_000_000,
+            100_000_000_000,
+            0,
+        );
+
+        claim.apply_settlement([1u8; 32], 40_000_000_000).unwrap();
+        claim.apply_settlement([2u8; 32], 60_000_000_000).unwrap();
+        assert_eq!(claim.received_amount, 100_000_000_000);
+        assert_eq!(
+            claim.recorded_settlement_references(),
+            &[[1u8; 32], [2u8; 32]]
+        );
+
+        // The reference list is bounded
+        for i in 3..=RecoveryClaim::MAX_SETTLEMENT_REFERENCES as u8 {
+            claim.apply_settlement([i; 32], 0).unwrap();
+        }
+        assert!(claim.apply_settlement([99u8; 32], 0).is_err());
+    }
+
+    #[test]
+    fn test_partial_settlements_release_pending_once() {
+        let mut claim = create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Approved,
+            120_000_000_000,
+            100_000_000_000,
+            0,
+        );
+        let mut pending: u64 = 100_000_000_000;
+
+        // Three installments, the last overpaying the approval
+        for (i, payment) in [40_000_000_000, 50_000_000_000, 30_000_000_000]
+            .into_iter()
+            .enumerate()
+        {
+            pending -= claim.pending_settled(payment);
+            claim.apply_settlement([i as u8; 32], payment).unwrap();
+        }
+        assert_eq!(pending, 0);
+        assert_eq!(claim.received_amount, 120_000_000_000);
+
+        // Nothing is left pending for a further payment
+        assert_eq!(claim.pending_settled(10_000_000_000), 0);
+    }
+
+    #[test]
+    fn test_disputed_settlement_pending_is_claimed_amount() {
+        let mut claim = create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Disputed,
+            80_000_000_000,
+            0,
+            0,
+        );
+        claim.apply_settlement([1u8; 32], 50_000_000_000).unwrap();
+        assert_eq!(claim.pending_settled(50_000_000_000), 30_000_000_000);
+    }
+
+    #[test]
+    fn test_dispute_resolution_cannot_exceed_claim() {
+        let mut claim = create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Disputed,
+            100_000_000_000,
+            0,
+            0,
+        );
+
+        assert!(claim
+            .resolve_dispute(100_000_000_001, DisputeResolutionMethod::Negotiated, 1_000)
+            .is_none());
+        assert_eq!(claim.status, RecoveryStatus::Disputed);
+    }
+
+    #[test]
+    fn test_policy_year_from_start_timestamp() {
+        let mut config = ReinsuranceConfig {
+            policy_year_start: 1_767_225_600, // 2026-01-01T00:00:00Z
+            ..Default::default()
+        };
+        assert_eq!(config.policy_year(), 2026);
+
+        config.policy_year_start -= 1; // 2025-12-31T23:59:59Z
+        assert_eq!(config.policy_year(), 2025);
+
+        config.policy_year_start = 1_709_164_800; // 2024-02-29 (leap day)
+        assert_eq!(config.policy_year(), 2024);
+    }
+
+    #[test]
+    fn test_first_claim_initializes_missing_accumulator() {
+        let member = Pubkey::new_unique();
+        // Freshly allocated by init_if_needed: all zeroes
+        let mut accumulator = MemberClaimsAccumulator::default();
+        assert!(!accumulator.is_initialized());
+
+        accumulator.initialize(member, 2026, 254);
+        assert!(accumulator.is_initialized());
+        assert_eq!(accumulator.member, member);
+        assert_eq!(accumulator.policy_year, 2026);
+        assert_eq!(accumulator.bump, 254);
+        assert_eq!(accumulator.ytd_claims, 0);
+        assert!(!accumulator.stop_loss_triggered);
+
+        // First large claim can trigger straight away
+        assert_eq!(
+            accumulator.check_stop_loss_trigger(150_000_000_000, 100_000_000_000),
+            Some(50_000_000_000)
+        );
+    }
+
+    #[test]
+    fn test_archive_preserves_triggered_accumulator() {
+        let member = Pubkey::new_unique();
+        let accumulator = MemberClaimsAccumulator {
+            member,
+            policy_year: 2026,
+            ytd_claims: 180_000_000_000,
+            claims_count: 7,
+            excess_claimed: 80_000_000_000,
+            recovered_amount: 64_000_000_000,
+            max_single_claim: 90_000_000_000,
+            stop_loss_triggered: true,
+            first_trigger_timestamp: 1_780_000_000,
+            last_claim_timestamp: 1_790_000_000,
+            bump: 255,
+            recovery_claims_filed: 1,
+            open_recovery_claims: 0,
+            _reserved: [0; 28],
+        };
+        assert!(!accumulator.has_outstanding_recoveries());
+
+        let mut archive = AccumulatorArchive::default();
+        archive.snapshot(&accumulator, 1_800_000_000);
+
+        assert_eq!(archive.member, member);
+        assert_eq!(archive.policy_year, 2026);
+        assert!(archive.stop_loss_triggered);
+        assert_eq!(archive.first_trigger_timestamp, 1_780_000_000);
+        assert_eq!(archive.ytd_claims, 180_000_000_000);
+        assert_eq!(archive.claims_count, 7);
+        assert_eq!(archive.excess_claimed, 80_000_000_000);
+        assert_eq!(archive.recovered_amount, 64_000_000_000);
+        assert_eq!(archive.archived_at, 1_800_000_000);
+    }
+
+    #[test]
+    fn test_only_prior_year_accumulators_are_archivable() {
+        let config = ReinsuranceConfig {
+            policy_year_start: 1_767_225_600, // 2026-01-01T00:00:00Z
+            ..Default::default()
+        };
+        let mut accumulator = MemberClaimsAccumulator::default();
+
+        // Current year with no claims yet stays active
+        accumulator.initialize(Pubkey::new_unique(), 2026, 255);
+        assert!(!accumulator.is_prior_year(config.policy_year()));
+
+        // Prior year is archivable even if its last claim came late
+        accumulator.policy_year = 2025;
+        accumulator.last_claim_timestamp = config.policy_year_start + 86_400;
+        assert!(accumulator.is_prior_year(config.policy_year()));
+    }
+
+    #[test]
+    fn test_outstanding_recoveries_block_archiving() {
+        let mut accumulator = MemberClaimsAccumulator {
+            stop_loss_triggered: true,
+            ..Default::default()
+        };
+        // Triggered but never filed
+        assert!(accumulator.has_outstanding_recoveries());
+
+        // Filed and still open
+        accumulator.recovery_claims_filed = 1;
+        accumulator.open_recovery_claims = 1;
+        assert!(accumulator.has_outstanding_recoveries());
+
+        // Denied or finally settled
+        accumulator.open_recovery_claims = 0;
+        assert!(!accumulator.has_outstanding_recoveries());
+
+        // Never reached the attachment point: nothing to recover
+        assert!(!MemberClaimsAccumulator::default().has_outstanding_recoveries());
+    }
+
+    #[test]
+    fn test_reversed_claim_releases_stop_loss() {
+        let attachment = 100_000_000_000;
+        let mut accumulator = MemberClaimsAccumulator {
+            ytd_claims: 130_000_000_000,
+            claims_count: 3,
+            excess_claimed: 30_000_000_000,
+            stop_loss_triggered: true,
+            first_trigger_timestamp: 1_780_000_000,
+            ..Default::default()
+        };
+
+        // Still over the attachment: the excess shrinks
+        accumulator
+            .reverse_claim(10_000_000_000, attachment)
+            .unwrap();
+        assert_eq!(accumulator.ytd_claims, 120_000_000_000);
+        assert_eq!(accumulator.claims_count, 2);
+        assert_eq!(accumulator.excess_claimed, 20_000_000_000);
+        assert!(accumulator.stop_loss_triggered);
+
+        // Back under with nothing filed: no longer triggered
+        accumulator
+            .reverse_claim(40_000_000_000, attachment)
+            .unwrap();
+        assert_eq!(accumulator.excess_claimed, 0);
+        assert!(!accumulator.stop_loss_triggered);
+        assert!(!accumulator.has_outstanding_recoveries());
+
+        // Cannot take back more than was recorded
+        assert!(accumulator
+            .reverse_claim(90_000_000_000, attachment)
+            .is_err());
+    }
+
+    #[test]
+    fn test_reversal_keeps_filed_stop_loss_triggered() {
+        let mut accumulator = MemberClaimsAccumulator {
+            ytd_claims: 110_000_000_000,
+            excess_claimed: 10_000_000_000,
+            stop_loss_triggered: true,
+            recovery_claims_filed: 1,
+            ..Default::default()
+        };
+        accumulator
+            .reverse_claim(20_000_000_000, 100_000_000_000)
+            .unwrap();
+        assert_eq!(accumulator.excess_claimed, 0);
+        assert!(accumulator.stop_loss_triggered);
+    }
+
+    #[test]
+    fn test_reconciliation_mixed_outcomes() {
+        let treaty_key = Pubkey::new_unique();
+        // $400k submitted across four claims, $170k received so far
+        let treaty = ReinsuranceTreaty {
+            total_claims_submitted: 400_000_000_000,
+            total_recoveries_received: 170_000_000_000,
+            ..Default::default()
+        };
+
+        let claims = [
+            // Settled in full
+            create_test_recovery(
+                treaty_key,
+                RecoveryStatus::Settled,
+                100_000_000_000,
+                100_000_000_000,
+                100_000_000_000,
+            ),
+            // Approved $90k, only $70k received
+            create_test_recovery(
+                treaty_key,
+                RecoveryStatus::PartiallySettled,
+                100_000_000_000,
+                90_000_000_000,
+                70_000_000_000,
+            ),
+            // Denied
+            create_test_recovery(treaty_key, RecoveryStatus::Denied, 120_000_000_000, 0, 0),
+            // Approved, payment not yet received
+            create_test_recovery(
+                treaty_key,
+                RecoveryStatus::Approved,
+                80_000_000_000,
+                80_000_000_000,
+                0,
+            ),
+        ];
+
+        let mut recon = PolicyYearReconciliation::default();
+        recon.add_treaty(&treaty);
+        for claim in claims.iter() {
+            recon.add_recovery_claim(claim);
+        }
+
+        assert_eq!(recon.treaties_reconciled, 1);
+        assert_eq!(recon.recovery_claims_reconciled, 4);
+        assert_eq!(recon.settled_count, 1);
+        assert_eq!(recon.partially_settled_count, 1);
+        assert_eq!(recon.denied_count, 1);
+        assert_eq!(recon.total_approved, 270_000_000_000);
+        assert_eq!(recon.partial_settlement_shortfall, 20_000_000_000);
+        assert_eq!(recon.outstanding_approved, 80_000_000_000);
+        assert_eq!(recon.denied_amount, 120_000_000_000);
+        assert_eq!(recon.undecided_amount, 0);
+        // $400k submitted - $170k received
+        assert_eq!(recon.net_recovery_shortfall, 230_000_000_000);
+    }
+
+    #[test]
+    fn test_reconciliation_across_treaties() {
+        let mut recon = PolicyYearReconciliation::default();
+        recon.add_treaty(&ReinsuranceTreaty {
+            total_claims_submitted: 100_000_000_000,
+            total_recoveries_received: 100_000_000_000,
+            ..Default::default()
+        });
+        recon.add_treaty(&ReinsuranceTreaty {
+            total_claims_submitted: 50_000_000_000,
+            total_recoveries_received: 0,
+            ..Default::default()
+        });
+        recon.add_recovery_claim(&create_test_recovery(
+            Pubkey::new_unique(),
+            RecoveryStatus::Submitted,
+            50_000_000_000,
+            0,
+            0,
+        ));
+
+        assert_eq!(recon.total_claims_submitted, 150_000_000_000);
+        assert_eq!(recon.net_recovery_shortfall, 50_000_000_000);
+        assert_eq!(recon.undecided_amount, 50_000_000_000);
+    }
+
+    #[test]
+    fn test_ytd_is_monotonic() {
+        let mut month = create_test_month(2, 1_000_000_000_000);
+        month.record_claim(100_000_000_000, false).unwrap();
+        month.roll_up_ytd(500_000_000_000).unwrap();
+        assert_eq!(month.ytd_through_month, 600_000_000_000);
+
+        // A stale (lower) prior YTD never moves the running total backwards
+        month.roll_up_ytd(0).unwrap();
+        assert_eq!(month.ytd_through_month, 600_000_000_000);
+    }
+
+    #[test]
+    fn test_panel_splits_excess_by_share() {
+        let (lead, follower) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut panel = TreatyPanel {
+            member_count: 2,
+            ..Default::default()
+        };
+        panel.treaties[..2].copy_from_slice(&[lead, follower]);
+        panel.shares_bps[..2].copy_from_slice(&[6000, 4000]);
+
+        // $200k excess splits 60/40
+        let excess = 200_000_000_000;
+        assert_eq!(
+            panel.allocation_for(&lead, excess),
+            Some((6000, 120_000_000_000))
+        );
+        assert_eq!(
+            panel.allocation_for(&follower, excess),
+            Some((4000, 80_000_000_000))
+        );
+        assert_eq!(panel.allocation_for(&Pubkey::new_unique(), excess), None);
+
+        // Each panelist then applies its own 20% coinsurance
+        let treaty = ReinsuranceTreaty {
+            coinsurance_rate_bps: 2000,
+            ..Default::default()
+        };
+        assert_eq!(
+            treaty.calculate_coverage(120_000_000_000),
+            (24_000_000_000, 96_000_000_000)
+        );
+
+        // Rounding dust lands on the last panelist, never lost
+        let parts = panel.allocate(1_000_001);
+        assert_eq!(parts[0] + parts[1], 1_000_001);
+    }
+
+    #[test]
+    fn test_panel_shares_must_sum_to_whole_layer() {
+        assert!(TreatyPanel::validate_shares(&[6000, 4000]));
+        assert!(TreatyPanel::validate_shares(&[10_000]));
+        assert!(!TreatyPanel::validate_shares(&[6000, 3000]));
+        assert!(!TreatyPanel::validate_shares(&[6000, 4000, 0]));
+        assert!(!TreatyPanel::validate_shares(&[]));
+        assert!(!TreatyPanel::validate_shares(&[2000; 6]));
+    }
+}
+
+#[cfg(test)]
+mod doc_ref_tests {
+    use super::*;
+    use apollo_core::doc_ref::content_type;
+    use apollo_core::StorageKind;
+
+    #[test]
+    fn test_doc_ref_round_trip() {
+        let doc = DocRef::new([7u8; 32], StorageKind::Arweave, content_type::PDF);
+
+        let bytes = doc.try_to_vec().unwrap();
+        assert_eq!(bytes.len(), DocRef::SIZE);
+        assert_eq!(DocRef::try_from_slice(&bytes).unwrap(), doc);
+
+        // Bare hashes convert as legacy references of unknown type
+        let legacy = DocRef::from([7u8; 32]);
+        assert_eq!(legacy.storage, StorageKind::Legacy);
+        assert_eq!(legacy.content_type, content_type::UNSPECIFIED);
+        assert!(DocRef::default().is_empty());
+    }
+
+    #[test]
+    fn test_doc_ref_rejects_unknown_storage_kind() {
+        let mut bytes = DocRef::new([1u8; 32], StorageKind::Ipfs, content_type::JSON)
+            .try_to_vec()
+            .unwrap();
+        bytes[32] = 9;
+        assert!(DocRef::try_from_slice(&bytes).is_err());
+    }
 }
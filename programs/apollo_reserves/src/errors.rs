@@ -129,6 +129,48 @@ pub enum ReservesError {
 
     #[msg("Cohort flagged for adverse selection")]
     CohortFlagged,
+
+    #[msg("Run-off mode is active; pay claims from the run-off reserve")]
+    RunoffActive,
+
+    #[msg("Claim is not payable in run-off (incurred after activation or window closed)")]
+    RunoffClaimNotPayable,
+
+    // Loading Errors
+    #[msg("Admin + reserve margin loading exceeds the 10% cap (90% MLR)")]
+    LoadingExceedsMlrCap,
+
+    #[msg("Admin portion exceeds the configured admin load")]
+    AdminLoadExceeded,
+
+    // Circuit Breaker Errors
+    #[msg("Payouts are frozen in the Red zone except DAO-approved emergency payouts")]
+    PayoutsFrozenRedZone,
+
+    #[msg("Treasury committee signer set approval required")]
+    TreasuryApprovalRequired,
+
+    #[msg("Sweep exceeds the unswept admin load")]
+    InsufficientAdminLoad,
+
+    #[msg("Routing ratios must sum to 10000 bps")]
+    InvalidRoutingRatios,
+
+    #[msg("Committee signer set approval for this change required")]
+    CommitteeApprovalRequired,
+
+    // Emergency Capital Errors
+    #[msg("Liquid reserves cover the payout")]
+    NoReserveShortfall,
+
+    #[msg("Monthly staked APH liquidation cap reached")]
+    LiquidationCapReached,
+
+    #[msg("Claim is not approved with an unpaid balance covering the payout")]
+    ClaimNotPayable,
+
+    #[msg("Routing overflows to Tier 2 while a lower tier is below target")]
+    TierTargetBypassed,
 }
 
 // Re-export for backwards compatibility
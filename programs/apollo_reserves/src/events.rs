@@ -1,6 +1,6 @@
 // programs/apollo_reserves/src/events.rs
 
-use crate::state::WaterfallSource;
+use crate::state::{TierTarget, WaterfallSource};
 use anchor_lang::prelude::*;
 
 /// Emitted when reserves are initialized
@@ -35,6 +35,63 @@ pub struct ReserveTargetsUpdated {
     pub old_tier2_days: u16,
     pub new_tier2_days: u16,
     pub updater: Pubkey,
+    /// Actuarial Committee signer set that approved the change
+    pub signer_set: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the recovery deposit tier is changed
+#[event]
+pub struct RecoveryTargetTierUpdated {
+    pub old_tier: TierTarget,
+    pub new_tier: TierTarget,
+    pub updater: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the governance-approval threshold for tier refills changes
+#[event]
+pub struct RefillApprovalThresholdUpdated {
+    pub old_threshold: u64,
+    pub new_threshold: u64,
+    pub updater: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the fixed contribution routing split changes
+#[event]
+pub struct RoutingRatiosUpdated {
+    pub tier0_route_bps: u16,
+    pub tier1_route_bps: u16,
+    pub tier2_route_bps: u16,
+    pub admin_route_bps: u16,
+    /// Actuarial Committee signer that set the split
+    pub updater: Pubkey,
+    /// Actuarial Committee signer set that approved the split
+    pub signer_set: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when the operations account for admin-vault sweeps changes
+#[event]
+pub struct OperationsAccountUpdated {
+    pub old_account: Pubkey,
+    pub new_account: Pubkey,
+    pub updater: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a reinsurance settlement is recorded and deposited to reserves
+#[event]
+pub struct ReinsuranceRecoveryRouted {
+    pub recovery_claim: Pubkey,
+    pub claim_id: u64,
+    pub received_amount: u64,
+    pub total_received: u64,
+    pub is_final: bool,
+    pub tier: TierTarget,
+    pub new_tier_balance: u64,
+    pub authority: Pubkey,
     pub timestamp: i64,
 }
 
@@ -47,6 +104,8 @@ pub struct ContributionRouted {
     pub to_tier1: u64,
     pub to_tier2: u64,
     pub to_admin: u64,
+    /// MLR implied by the configured admin + reserve margin loading
+    pub implied_mlr_bps: u16,
     pub timestamp: i64,
 }
 
@@ -58,6 +117,8 @@ pub struct IbnrUpdated {
     pub avg_daily_claims: u64,
     pub reporting_lag_days: u16,
     pub development_factor_bps: u16,
+    /// Computed from the age-bucketed development triangle
+    pub bucketed: bool,
     pub timestamp: i64,
 }
 
@@ -80,6 +141,35 @@ pub struct ClaimPaidFromWaterfall {
     pub from_tier2: u64,
     pub from_staked: u64,
     pub recipient: Pubkey,
+    /// Paid in the Red zone under a DAO-approved emergency override
+    pub emergency_override: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when the solvency circuit breaker blocks a Red-zone payout
+#[event]
+pub struct PayoutsFrozenRedZone {
+    pub claim_id: u64,
+    pub amount: u64,
+    pub payout_authority: Pubkey,
+    pub emergency_active: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when staked APH is slashed to cover a reserve shortfall
+#[event]
+pub struct EmergencyCapitalRequested {
+    pub claim_id: u64,
+    pub payout_amount: u64,
+    /// Tier 0 + Tier 1 + Tier 2 at the time of the request
+    pub liquid_reserves: u64,
+    pub shortfall_usdc: u64,
+    /// Shortfall bounded by the monthly liquidation cap
+    pub requested_usdc: u64,
+    pub aph_slashed: u64,
+    pub remaining_cap_aph: u64,
+    /// Treasury committee signer set that approved the request
+    pub signer_set: Pubkey,
     pub timestamp: i64,
 }
 
@@ -100,6 +190,28 @@ pub struct RunoffSpent {
     pub new_balance: u64,
     pub reason: String,
     pub authorizer: Pubkey,
+    /// Treasury committee signer set that approved the spend
+    pub signer_set: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a claim is paid from the run-off reserve
+#[event]
+pub struct RunoffClaimPaid {
+    pub claim_id: u64,
+    pub amount: u64,
+    pub service_date: i64,
+    pub new_runoff_balance: u64,
+    pub recipient: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a terminated member's contribution refund is paid
+#[event]
+pub struct MemberRefundPaid {
+    pub amount: u64,
+    pub new_tier0_balance: u64,
+    pub recipient: Pubkey,
     pub timestamp: i64,
 }
 
@@ -109,6 +221,25 @@ pub struct TierRefilled {
     pub from_tier: WaterfallSource,
     pub to_tier: WaterfallSource,
     pub amount: u64,
+    pub authorizer: Pubkey,
+    /// Treasury committee signer set, for refills above the approval threshold
+    pub signer_set: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+/// Emitted when admin load is swept from the admin vault to operations
+#[event]
+pub struct AdminVaultSwept {
+    pub amount: u64,
+    pub operations_account: Pubkey,
+    /// All-time admin load routed to the admin vault
+    pub admin_load_collected: u64,
+    /// All-time admin funds swept, including this sweep
+    pub total_swept: u64,
+    /// Admin load still available to sweep
+    pub remaining: u64,
+    pub authorizer: Pubkey,
+    pub signer_set: Pubkey,
     pub timestamp: i64,
 }
 
@@ -122,6 +253,16 @@ pub struct CoverageRatioChanged {
     pub timestamp: i64,
 }
 
+/// Emitted when the coverage ratio is recomputed after an input change
+#[event]
+pub struct CoverageRatioUpdated {
+    pub old_ratio_bps: u16,
+    pub new_ratio_bps: u16,
+    pub total_reserves: u64,
+    pub required_reserves: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when reserve state snapshot is taken
 #[event]
 pub struct ReserveSnapshot {
@@ -134,6 +275,46 @@ pub struct ReserveSnapshot {
     pub timestamp: i64,
 }
 
+/// Emitted when cached reserve balances disagreed with the vault token
+/// accounts and were corrected (drift = actual - cached)
+#[event]
+pub struct ReserveBalanceDiscrepancy {
+    pub tier0_drift: i64,
+    pub tier1_drift: i64,
+    pub tier2_drift: i64,
+    pub runoff_drift: i64,
+    pub tier0_balance: u64,
+    pub tier1_balance: u64,
+    pub tier2_balance: u64,
+    pub runoff_balance: u64,
+    pub coverage_ratio_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the realized MLR is computed
+#[event]
+pub struct MlrComputed {
+    pub claims_paid: u64,
+    pub net_premiums: u64,
+    pub mlr_bps: u16,
+    pub below_target: bool,
+    pub below_aca_floor: bool,
+    pub timestamp: i64,
+}
+
+/// Emitted when a financial reconciliation snapshot is recorded
+#[event]
+pub struct FinancialSnapshotTaken {
+    pub index: u64,
+    pub total_contributions_received: u64,
+    pub total_reserves: u64,
+    pub runoff_balance: u64,
+    pub total_claims_paid: u64,
+    pub ytd_recoveries_received: u64,
+    pub taken_by: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when run-off mode is activated
 #[event]
 pub struct RunoffModeActivated {
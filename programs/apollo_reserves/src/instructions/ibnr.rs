@@ -1,12 +1,15 @@
 // programs/apollo_reserves/src/instructions/ibnr.rs
 
 use crate::errors::ReserveError;
-use crate::events::{ExpectedClaimsUpdated, IbnrUpdated, RunoffFunded, RunoffModeActivated};
-use crate::state::{IbnrParams, ReserveConfig, ReserveState, RunoffState};
+use crate::events::{
+    CoverageRatioUpdated, ExpectedClaimsUpdated, IbnrUpdated, RunoffFunded, RunoffModeActivated,
+};
+use crate::state::{IbnrDevelopmentBucket, IbnrParams, ReserveConfig, ReserveState, RunoffState};
 use anchor_lang::prelude::*;
 
 /// Compute and update IBNR reserve
-/// IBNR = (Avg Daily Claims × Reporting Lag Days) × Development Factor
+/// IBNR = (Avg Daily Claims × Reporting Lag Days) × Development Factor,
+/// or the sum over claim-age buckets when a development triangle is set
 #[derive(Accounts)]
 pub struct ComputeIbnr<'info> {
     #[account(
@@ -42,12 +45,7 @@ pub fn compute_ibnr(ctx: Context<ComputeIbnr>) -> Result<()> {
     // Use 30-day average for more responsive IBNR
     let avg_daily = params.avg_daily_claims_30d;
 
-    // Compute IBNR: avg_daily * lag_days * (dev_factor / 10000)
-    let base = avg_daily.saturating_mul(params.observed_reporting_lag as u64);
-    let new_ibnr = base
-        .saturating_mul(params.development_factor_bps as u64)
-        .checked_div(10000)
-        .unwrap_or(0);
+    let new_ibnr = params.compute_ibnr(avg_daily);
 
     state.ibnr_usdc = new_ibnr;
     state.avg_reporting_lag_days = params.observed_reporting_lag;
@@ -60,12 +58,28 @@ pub fn compute_ibnr(ctx: Context<ComputeIbnr>) -> Result<()> {
         avg_daily_claims: avg_daily,
         reporting_lag_days: params.observed_reporting_lag,
         development_factor_bps: params.development_factor_bps,
+        bucketed: params.is_bucketed(),
         timestamp: clock.unix_timestamp,
     });
 
+    emit_coverage_ratio_update(state, clock.unix_timestamp);
+
     Ok(())
 }
 
+/// Recompute the stored coverage ratio after its inputs change
+fn emit_coverage_ratio_update(state: &mut ReserveState, timestamp: i64) {
+    let old_ratio_bps = state.refresh_coverage_ratio();
+
+    emit!(CoverageRatioUpdated {
+        old_ratio_bps,
+        new_ratio_bps: state.current_coverage_ratio_bps,
+        total_reserves: state.total_reserves(),
+        required_reserves: state.required_reserves(),
+        timestamp,
+    });
+}
+
 /// Update expected claims (daily average)
 #[derive(Accounts)]
 pub struct UpdateExpectedClaims<'info> {
@@ -155,6 +169,8 @@ pub fn update_expected_claims(
         timestamp: clock.unix_timestamp,
     });
 
+    emit_coverage_ratio_update(state, clock.unix_timestamp);
+
     Ok(())
 }
 
@@ -201,6 +217,21 @@ pub fn update_ibnr_params(
     Ok(())
 }
 
+/// Set the age-bucketed development triangle; an empty list restores
+/// single-factor mode
+pub fn set_ibnr_development_buckets(
+    ctx: Context<UpdateIbnrParams>,
+    buckets: Vec<IbnrDevelopmentBucket>,
+) -> Result<()> {
+    IbnrParams::validate_buckets(&buckets)?;
+
+    let params = &mut ctx.accounts.ibnr_params;
+    params.development_buckets = buckets;
+    params.last_updated = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
 /// Fund the run-off reserve
 #[derive(Accounts)]
 pub struct FundRunoffReserve<'info> {
@@ -2,8 +2,11 @@
 
 use crate::errors::ReserveError;
 use crate::events::ReservesInitialized;
-use crate::state::{IbnrParams, ReserveConfig, ReserveState, RunoffState};
+use crate::instructions::vaults::consume_committee_approval;
+use crate::state::{IbnrParams, ReserveConfig, ReserveState, RunoffState, TierTarget};
 use anchor_lang::prelude::*;
+use apollo_governance::program::ApolloGovernance;
+use apollo_governance::state::{AdminAction, CommitteeType, DaoConfig, Multisig, SignerSet};
 
 #[derive(Accounts)]
 pub struct InitializeReserves<'info> {
@@ -103,6 +106,8 @@ pub fn handler(ctx: Context<InitializeReserves>, params: InitializeReservesParam
     config.admin_load_bps = admin_load;
     config.governance_program = params.governance_program;
     config.risk_engine_program = params.risk_engine_program;
+    config.recovery_target_tier = TierTarget::Tier1;
+    config.refill_approval_threshold = ReserveConfig::DEFAULT_REFILL_APPROVAL_THRESHOLD;
     config.is_initialized = true;
     config.bump = ctx.bumps.reserve_config;
     config.reserved = vec![];
@@ -122,6 +127,7 @@ pub fn handler(ctx: Context<InitializeReserves>, params: InitializeReservesParam
     state.last_waterfall_at = 0;
     state.last_ibnr_computed_at = 0;
     state.current_coverage_ratio_bps = 0;
+    state.financial_snapshot_count = 0;
     state.bump = ctx.bumps.reserve_state;
 
     // Initialize run-off state
@@ -143,6 +149,7 @@ pub fn handler(ctx: Context<InitializeReserves>, params: InitializeReservesParam
     ibnr.claims_std_dev = 0;
     ibnr.last_updated = clock.unix_timestamp;
     ibnr.sample_size = 0;
+    ibnr.development_buckets = vec![];
     ibnr.bump = ctx.bumps.ibnr_params;
 
     emit!(ReservesInitialized {
@@ -157,7 +164,7 @@ pub fn handler(ctx: Context<InitializeReserves>, params: InitializeReservesParam
     Ok(())
 }
 
-/// Set reserve targets (requires Actuarial Committee)
+/// Set reserve targets (requires an Actuarial Committee quorum)
 #[derive(Accounts)]
 pub struct SetReserveTargets<'info> {
     #[account(
@@ -167,13 +174,55 @@ pub struct SetReserveTargets<'info> {
     )]
     pub reserve_config: Account<'info, ReserveConfig>,
 
-    /// Must be DAO authority or pass through governance
+    /// Governance DAO config (actuarial committee)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        constraint = dao_config.committee(CommitteeType::Actuarial) == Some(actuarial_committee.key()) @ ReserveError::Unauthorized
+    )]
+    pub actuarial_committee: Account<'info, Multisig>,
+
+    /// Signer set approving these targets, targeting the reserve config
+    #[account(
+        mut,
+        constraint = signer_set.target == reserve_config.key() @ ReserveError::CommitteeApprovalRequired
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    #[account(
+        constraint = governance_program.key() == reserve_config.governance_program @ ReserveError::Unauthorized
+    )]
+    pub governance_program: Program<'info, ApolloGovernance>,
+
+    /// Actuarial Committee signer executing the approved change
+    #[account(
+        constraint = actuarial_committee.is_signer(&authority.key()) @ ReserveError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// Update operational reserve settings (DAO authority)
+#[derive(Accounts)]
+pub struct UpdateReserveSettings<'info> {
+    #[account(
+        mut,
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
     #[account(
         constraint = authority.key() == reserve_config.authority @ ReserveError::Unauthorized
     )]
     pub authority: Signer<'info>,
 }
 
+/// The approving signer set's `action_data` must be these params, serialized
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct SetReserveTargetsParams {
     pub tier0_target_days: Option<u16>,
@@ -187,6 +236,18 @@ pub fn set_reserve_targets(
     ctx: Context<SetReserveTargets>,
     params: SetReserveTargetsParams,
 ) -> Result<()> {
+    require!(
+        ctx.accounts.signer_set.action_data == params.try_to_vec()?,
+        ReserveError::CommitteeApprovalRequired
+    );
+    let signer_set = consume_committee_approval(
+        &ctx.accounts.actuarial_committee,
+        &ctx.accounts.signer_set,
+        &ctx.accounts.governance_program,
+        ctx.accounts.authority.to_account_info(),
+        AdminAction::SetReserveTargets,
+    )?;
+
     let config = &mut ctx.accounts.reserve_config;
     let clock = Clock::get()?;
 
@@ -235,8 +296,159 @@ pub fn set_reserve_targets(
         old_tier2_days: old_t2,
         new_tier2_days: config.tier2_target_days,
         updater: ctx.accounts.authority.key(),
+        signer_set,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
+
+/// Choose which tier settled reinsurance recoveries replenish
+pub fn set_recovery_target_tier(
+    ctx: Context<UpdateReserveSettings>,
+    tier: TierTarget,
+) -> Result<()> {
+    let config = &mut ctx.accounts.reserve_config;
+    let old_tier = config.recovery_target_tier;
+    config.recovery_target_tier = tier;
+
+    emit!(crate::events::RecoveryTargetTierUpdated {
+        old_tier,
+        new_tier: tier,
+        updater: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Set the refill size above which a treasury committee signer set is required
+pub fn set_refill_approval_threshold(
+    ctx: Context<UpdateReserveSettings>,
+    threshold: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.reserve_config;
+    let old_threshold = config.refill_approval_threshold;
+    config.refill_approval_threshold = threshold;
+
+    emit!(crate::events::RefillApprovalThresholdUpdated {
+        old_threshold,
+        new_threshold: threshold,
+        updater: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Set the operations token account that admin-vault sweeps pay into
+pub fn set_operations_account(
+    ctx: Context<UpdateReserveSettings>,
+    operations_account: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.reserve_config;
+    let old_account = config.operations_account;
+    config.operations_account = operations_account;
+
+    emit!(crate::events::OperationsAccountUpdated {
+        old_account,
+        new_account: operations_account,
+        updater: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Set the fixed contribution split (Actuarial Committee)
+#[derive(Accounts)]
+pub struct SetRoutingRatios<'info> {
+    #[account(
+        mut,
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
+    /// Governance DAO config (actuarial committee)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        constraint = dao_config.committee(CommitteeType::Actuarial) == Some(actuarial_committee.key()) @ ReserveError::Unauthorized
+    )]
+    pub actuarial_committee: Account<'info, Multisig>,
+
+    /// Signer set approving this split, targeting the reserve config
+    #[account(
+        mut,
+        constraint = signer_set.target == reserve_config.key() @ ReserveError::CommitteeApprovalRequired
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    #[account(
+        constraint = governance_program.key() == reserve_config.governance_program @ ReserveError::Unauthorized
+    )]
+    pub governance_program: Program<'info, ApolloGovernance>,
+
+    /// Actuarial Committee signer executing the approved change
+    #[account(
+        constraint = actuarial_committee.is_signer(&authority.key()) @ ReserveError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+/// The approving signer set's `action_data` must be these params,
+/// serialized. All zero clears the fixed split.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRoutingRatiosParams {
+    pub tier0_route_bps: u16,
+    pub tier1_route_bps: u16,
+    pub tier2_route_bps: u16,
+    pub admin_route_bps: u16,
+}
+
+pub fn set_routing_ratios(
+    ctx: Context<SetRoutingRatios>,
+    params: SetRoutingRatiosParams,
+) -> Result<()> {
+    require!(
+        ctx.accounts.signer_set.action_data == params.try_to_vec()?,
+        ReserveError::CommitteeApprovalRequired
+    );
+    ctx.accounts.reserve_config.validate_routing_ratios(
+        params.tier0_route_bps,
+        params.tier1_route_bps,
+        params.tier2_route_bps,
+        params.admin_route_bps,
+    )?;
+    let signer_set = consume_committee_approval(
+        &ctx.accounts.actuarial_committee,
+        &ctx.accounts.signer_set,
+        &ctx.accounts.governance_program,
+        ctx.accounts.authority.to_account_info(),
+        AdminAction::SetRoutingRatios,
+    )?;
+
+    let config = &mut ctx.accounts.reserve_config;
+    config.tier0_route_bps = params.tier0_route_bps;
+    config.tier1_route_bps = params.tier1_route_bps;
+    config.tier2_route_bps = params.tier2_route_bps;
+    config.admin_route_bps = params.admin_route_bps;
+
+    emit!(crate::events::RoutingRatiosUpdated {
+        tier0_route_bps: params.tier0_route_bps,
+        tier1_route_bps: params.tier1_route_bps,
+        tier2_route_bps: params.tier2_route_bps,
+        admin_route_bps: params.admin_route_bps,
+        updater: ctx.accounts.authority.key(),
+        signer_set,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
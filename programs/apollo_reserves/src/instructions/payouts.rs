@@ -1,13 +1,28 @@
 // programs/apollo_reserves/src/instructions/payouts.rs
 
 use crate::errors::ReserveError;
-use crate::events::{ClaimPaidFromWaterfall, CoverageRatioChanged, ReserveSnapshot, RunoffSpent};
-use crate::state::{ReserveConfig, ReserveState, RunoffState, VaultAuthority};
+use crate::events::{
+    ClaimPaidFromWaterfall, CoverageRatioChanged, EmergencyCapitalRequested,
+    FinancialSnapshotTaken, MemberRefundPaid, MlrComputed, PayoutsFrozenRedZone, ReserveSnapshot,
+    RunoffClaimPaid, RunoffSpent,
+};
+use crate::instructions::vaults::{consume_treasury_approval, require_approved_amount};
+use crate::state::{
+    ClaimPayable, EmergencyCapitalState, FinancialSnapshot, MlrReport, ReserveConfig,
+    ReserveRequirements, ReserveState, RunoffState, VaultAuthority, CLAIMS_PROGRAM_ID,
+    MEMBERSHIP_CONFIG_SEED, MEMBERSHIP_PROGRAM_ID,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
+use apollo_governance::authorization;
+use apollo_governance::program::ApolloGovernance;
+use apollo_governance::state::{AdminAction, DaoConfig, Multisig, SignerSet};
+use apollo_risk_engine::state::{Zone, ZoneState};
+use apollo_staking::program::ApolloStaking;
+use apollo_staking::state::{AphPriceFeed, StakingConfig};
 
 /// Pay a claim using the waterfall mechanism
-/// Order: Tier0 -> Tier1 -> Tier2 -> (Staked APH via separate instruction)
+/// Order: Tier0 -> Tier1 -> Tier2 -> (Staked APH via `request_emergency_capital`)
 #[derive(Accounts)]
 pub struct PayoutClaimFromWaterfall<'info> {
     #[account(
@@ -24,6 +39,30 @@ pub struct PayoutClaimFromWaterfall<'info> {
     )]
     pub reserve_state: Account<'info, ReserveState>,
 
+    /// In run-off, claims are paid via `runoff_payout_claim` instead
+    #[account(
+        seeds = [RunoffState::SEED_PREFIX],
+        bump = runoff_state.bump,
+        constraint = !runoff_state.runoff_active @ ReserveError::RunoffActive
+    )]
+    pub runoff_state: Account<'info, RunoffState>,
+
+    /// Risk engine zone state (solvency circuit breaker)
+    #[account(
+        seeds = [ZoneState::SEED_PREFIX],
+        bump = zone_state.bump,
+        seeds::program = apollo_risk_engine::ID,
+    )]
+    pub zone_state: Account<'info, ZoneState>,
+
+    /// Governance DAO config (emergency override in the Red zone)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
     #[account(
         seeds = [VaultAuthority::SEED_PREFIX],
         bump = vault_authority.bump,
@@ -32,19 +71,22 @@ pub struct PayoutClaimFromWaterfall<'info> {
 
     #[account(
         mut,
-        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier0_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier0_vault: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier1_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier1_vault: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = tier2_vault.key() == vault_authority.tier2_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier2_vault.key() == vault_authority.tier2_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier2_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier2_vault: Account<'info, TokenAccount>,
 
@@ -76,6 +118,23 @@ pub fn payout_claim_from_waterfall(
 
     require!(params.amount > 0, ReserveError::InvalidPayoutAmount);
 
+    // Solvency circuit breaker: in the Red zone only DAO-approved emergency
+    // payouts (emergency mode active, signed by the DAO authority) proceed
+    let red_zone = ctx.accounts.zone_state.current_zone == Zone::Red;
+    let emergency_override = red_zone
+        && authorization::is_emergency_active(&ctx.accounts.dao_config, clock.unix_timestamp)
+        && ctx.accounts.payout_authority.key() == ctx.accounts.dao_config.authority;
+    if red_zone && !emergency_override {
+        emit!(PayoutsFrozenRedZone {
+            claim_id: params.claim_id,
+            amount: params.amount,
+            payout_authority: ctx.accounts.payout_authority.key(),
+            emergency_active: ctx.accounts.dao_config.emergency_active,
+            timestamp: clock.unix_timestamp,
+        });
+        return err!(ReserveError::PayoutsFrozenRedZone);
+    }
+
     let mut remaining = params.amount;
     let mut from_tier0: u64 = 0;
     let mut from_tier1: u64 = 0;
@@ -145,7 +204,8 @@ pub fn payout_claim_from_waterfall(
         state.tier2_balance = state.tier2_balance.saturating_sub(take);
     }
 
-    // If still remaining, waterfall is exhausted (staked APH would be next via separate tx)
+    // If still remaining, waterfall is exhausted (staked APH is raised via
+    // request_emergency_capital)
     require!(remaining == 0, ReserveError::WaterfallExhausted);
 
     // Update totals
@@ -163,13 +223,343 @@ pub fn payout_claim_from_waterfall(
         from_tier2,
         from_staked: 0, // Would be populated if staking is tapped
         recipient: ctx.accounts.recipient.key(),
+        emergency_override,
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
 
-/// Emergency spend from run-off reserve (DAO + Emergency flag required)
+/// Request staked APH liquidation when Tier 0-2 cannot cover an approved,
+/// unpaid claim. Signed by the reserve authority (also the staking authority
+/// for the slash CPI) and approved by a treasury committee signer set that
+/// targets the claim and commits to `(claim_id, payout_amount)`.
+///
+/// Active stake positions of the slashed tiers, each followed by its reward
+/// vesting PDA, are forwarded to `slash_waterfall` as writable
+/// `remaining_accounts`.
+#[derive(Accounts)]
+#[instruction(params: EmergencyCapitalParams)]
+pub struct RequestEmergencyCapital<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+        constraint = reserve_config.is_initialized @ ReserveError::NotInitialized
+    )]
+    pub reserve_config: Box<Account<'info, ReserveConfig>>,
+
+    #[account(
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Box<Account<'info, ReserveState>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + EmergencyCapitalState::INIT_SPACE,
+        seeds = [EmergencyCapitalState::SEED_PREFIX],
+        bump
+    )]
+    pub emergency_capital: Box<Account<'info, EmergencyCapitalState>>,
+
+    #[account(
+        mut,
+        seeds = [StakingConfig::SEED_PREFIX],
+        bump = staking_config.bump,
+        seeds::program = apollo_staking::ID,
+    )]
+    pub staking_config: Box<Account<'info, StakingConfig>>,
+
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub aggressive_tier: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub standard_tier: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub conservative_tier: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the staking program
+    #[account(mut)]
+    pub liquidation_queue: UncheckedAccount<'info>,
+
+    /// APH price feed (converts the monthly cap to USDC)
+    #[account(
+        seeds = [AphPriceFeed::SEED_PREFIX],
+        bump = price_feed.bump,
+        seeds::program = apollo_staking::ID,
+    )]
+    pub price_feed: Box<Account<'info, AphPriceFeed>>,
+
+    /// CHECK: claims-program claim PDA for `params.claim_id`; read via
+    /// `ClaimPayable`
+    #[account(
+        seeds = [ClaimPayable::SEED_PREFIX, &params.claim_id.to_le_bytes()],
+        bump,
+        seeds::program = CLAIMS_PROGRAM_ID,
+        owner = CLAIMS_PROGRAM_ID @ ReserveError::ClaimNotPayable,
+    )]
+    pub claim: UncheckedAccount<'info>,
+
+    /// Governance DAO config (treasury committee)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Box<Account<'info, DaoConfig>>,
+
+    #[account(
+        constraint = treasury_committee.key() == dao_config.treasury_committee @ ReserveError::Unauthorized
+    )]
+    pub treasury_committee: Box<Account<'info, Multisig>>,
+
+    /// Signer set approving this request, targeting the claim
+    #[account(
+        mut,
+        constraint = signer_set.target == claim.key() @ ReserveError::TreasuryApprovalRequired
+    )]
+    pub signer_set: Box<Account<'info, SignerSet>>,
+
+    #[account(
+        constraint = governance_program.key() == reserve_config.governance_program @ ReserveError::Unauthorized
+    )]
+    pub governance_program: Program<'info, ApolloGovernance>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == reserve_config.authority @ ReserveError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub staking_program: Program<'info, ApolloStaking>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The approving signer set's `action_data` must be these params, serialized
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EmergencyCapitalParams {
+    pub claim_id: u64,
+    /// Payout the liquid reserves cannot cover in full (at most the claim's
+    /// unpaid approved amount)
+    pub payout_amount: u64,
+}
+
+pub fn request_emergency_capital<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RequestEmergencyCapital<'info>>,
+    params: EmergencyCapitalParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // The payout must be owed on an approved claim
+    let claim = ClaimPayable::from_account_data(&ctx.accounts.claim.try_borrow_data()?)
+        .ok_or(ReserveError::ClaimNotPayable)?;
+    require!(
+        claim.claim_id == params.claim_id
+            && params.payout_amount > 0
+            && params.payout_amount <= claim.unpaid_amount(),
+        ReserveError::ClaimNotPayable
+    );
+    require!(
+        ctx.accounts.signer_set.action_data == params.try_to_vec()?,
+        ReserveError::TreasuryApprovalRequired
+    );
+
+    // Only the part of the payout the waterfall cannot fund is requested
+    let liquid_reserves = ctx.accounts.reserve_state.total_reserves();
+    let shortfall_usdc = params.payout_amount.saturating_sub(liquid_reserves);
+    require!(shortfall_usdc > 0, ReserveError::NoReserveShortfall);
+
+    let staked_before = ctx.accounts.staking_config.total_staked;
+    let capital = &mut ctx.accounts.emergency_capital;
+    if capital.bump == 0 {
+        capital.bump = ctx.bumps.emergency_capital;
+    }
+    capital.roll_period(clock.unix_timestamp, staked_before);
+
+    // Bound the request by what the monthly liquidation cap still allows
+    let cap_usdc = ctx
+        .accounts
+        .price_feed
+        .usdc_value(capital.remaining_cap_aph());
+    let requested_usdc = shortfall_usdc.min(cap_usdc);
+    require!(requested_usdc > 0, ReserveError::LiquidationCapReached);
+
+    let signer_set = consume_treasury_approval(
+        Some(&ctx.accounts.treasury_committee),
+        Some(&ctx.accounts.signer_set),
+        Some(&ctx.accounts.governance_program),
+        ctx.accounts.authority.to_account_info(),
+        AdminAction::RequestEmergencyCapital,
+    )?;
+
+    apollo_staking::cpi::slash_waterfall(
+        CpiContext::new(
+            ctx.accounts.staking_program.to_account_info(),
+            apollo_staking::cpi::accounts::SlashWaterfall {
+                staking_config: ctx.accounts.staking_config.to_account_info(),
+                aggressive_tier: ctx.accounts.aggressive_tier.to_account_info(),
+                standard_tier: ctx.accounts.standard_tier.to_account_info(),
+                conservative_tier: ctx.accounts.conservative_tier.to_account_info(),
+                liquidation_queue: ctx.accounts.liquidation_queue.to_account_info(),
+                price_feed: ctx.accounts.price_feed.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        )
+        .with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        requested_usdc,
+        format!("Reserve shortfall for claim {}", params.claim_id),
+    )?;
+
+    ctx.accounts.staking_config.reload()?;
+    let aph_slashed = staked_before.saturating_sub(ctx.accounts.staking_config.total_staked);
+
+    let capital = &mut ctx.accounts.emergency_capital;
+    capital.record_slash(requested_usdc, aph_slashed);
+
+    emit!(EmergencyCapitalRequested {
+        claim_id: params.claim_id,
+        payout_amount: params.payout_amount,
+        liquid_reserves,
+        shortfall_usdc,
+        requested_usdc,
+        aph_slashed,
+        remaining_cap_aph: capital.remaining_cap_aph(),
+        signer_set,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Pay a claim from the segregated run-off reserve (run-off mode only)
+/// Only claims incurred before run-off activation are payable
+#[derive(Accounts)]
+pub struct RunoffPayoutClaim<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+        constraint = reserve_config.is_initialized @ ReserveError::NotInitialized
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
+    #[account(
+        mut,
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, ReserveState>,
+
+    #[account(
+        seeds = [RunoffState::SEED_PREFIX],
+        bump = runoff_state.bump,
+        constraint = runoff_state.runoff_active @ ReserveError::RunoffNotActive
+    )]
+    pub runoff_state: Account<'info, RunoffState>,
+
+    #[account(
+        seeds = [VaultAuthority::SEED_PREFIX],
+        bump = vault_authority.bump,
+    )]
+    pub vault_authority: Account<'info, VaultAuthority>,
+
+    #[account(
+        mut,
+        constraint = runoff_vault.key() == vault_authority.runoff_vault @ ReserveError::InvalidVaultConfig
+    )]
+    pub runoff_vault: Account<'info, TokenAccount>,
+
+    /// Recipient token account (member or provider)
+    #[account(
+        mut,
+        constraint = recipient.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+
+    /// Governance DAO config (run-off payout authority)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    /// Must be the DAO authority: the run-off vault is the last money
+    /// standing, so payouts from it are never open to arbitrary signers
+    #[account(
+        constraint = payout_authority.key() == dao_config.authority @ ReserveError::Unauthorized
+    )]
+    pub payout_authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RunoffPayoutParams {
+    pub claim_id: u64,
+    pub amount: u64,
+    pub service_date: i64,
+}
+
+pub fn runoff_payout_claim(
+    ctx: Context<RunoffPayoutClaim>,
+    params: RunoffPayoutParams,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(params.amount > 0, ReserveError::InvalidPayoutAmount);
+    require!(
+        ctx.accounts
+            .runoff_state
+            .is_runoff_payable(params.service_date, clock.unix_timestamp),
+        ReserveError::RunoffClaimNotPayable
+    );
+    require!(
+        ctx.accounts.runoff_vault.amount >= params.amount,
+        ReserveError::InsufficientReserves
+    );
+
+    let seeds = &[
+        VaultAuthority::SEED_PREFIX,
+        &[ctx.accounts.vault_authority.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.runoff_vault.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        params.amount,
+    )?;
+
+    let state = &mut ctx.accounts.reserve_state;
+    state.runoff_balance = state.runoff_balance.saturating_sub(params.amount);
+    state.total_claims_paid = state.total_claims_paid.saturating_add(params.amount);
+
+    emit!(RunoffClaimPaid {
+        claim_id: params.claim_id,
+        amount: params.amount,
+        service_date: params.service_date,
+        new_runoff_balance: state.runoff_balance,
+        recipient: ctx.accounts.recipient.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Emergency spend from run-off reserve (DAO authority + treasury committee signer set)
 #[derive(Accounts)]
 pub struct EmergencySpendRunoff<'info> {
     #[account(
@@ -209,6 +599,31 @@ pub struct EmergencySpendRunoff<'info> {
     )]
     pub recipient: Account<'info, TokenAccount>,
 
+    /// Governance DAO config (treasury committee)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        constraint = treasury_committee.key() == dao_config.treasury_committee @ ReserveError::Unauthorized
+    )]
+    pub treasury_committee: Option<Account<'info, Multisig>>,
+
+    /// Signer set approving this spend, targeting the recipient account
+    #[account(
+        mut,
+        constraint = signer_set.target == recipient.key() @ ReserveError::TreasuryApprovalRequired
+    )]
+    pub signer_set: Option<Account<'info, SignerSet>>,
+
+    #[account(
+        constraint = governance_program.key() == reserve_config.governance_program @ ReserveError::Unauthorized
+    )]
+    pub governance_program: Option<Program<'info, ApolloGovernance>>,
+
     /// Must be DAO authority
     #[account(
         constraint = authority.key() == reserve_config.authority @ ReserveError::Unauthorized
@@ -231,8 +646,15 @@ pub fn emergency_spend_runoff(
         ReserveError::InsufficientReserves
     );
 
-    // In production, this would also check emergency flag from governance
-    // For now, only DAO authority can execute
+    // Every run-off spend needs a treasury committee signer set for this amount
+    require_approved_amount(ctx.accounts.signer_set.as_ref(), amount)?;
+    let signer_set = consume_treasury_approval(
+        ctx.accounts.treasury_committee.as_ref(),
+        ctx.accounts.signer_set.as_ref(),
+        ctx.accounts.governance_program.as_ref(),
+        ctx.accounts.authority.to_account_info(),
+        AdminAction::EmergencySpendRunoff,
+    )?;
 
     let seeds = &[
         VaultAuthority::SEED_PREFIX,
@@ -261,6 +683,97 @@ pub fn emergency_spend_runoff(
         new_balance: state.runoff_balance,
         reason,
         authorizer: ctx.accounts.authority.key(),
+        signer_set,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Pay a terminated member's contribution refund from Tier 0. Only the
+/// membership program books refunds, so it signs with its global config PDA.
+#[derive(Accounts)]
+pub struct PayMemberRefund<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+        constraint = reserve_config.is_initialized @ ReserveError::NotInitialized
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
+    #[account(
+        mut,
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, ReserveState>,
+
+    #[account(
+        seeds = [VaultAuthority::SEED_PREFIX],
+        bump = vault_authority.bump,
+    )]
+    pub vault_authority: Account<'info, VaultAuthority>,
+
+    #[account(
+        mut,
+        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig
+    )]
+    pub tier0_vault: Account<'info, TokenAccount>,
+
+    /// Member's USDC token account
+    #[account(
+        mut,
+        constraint = recipient.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
+    )]
+    pub recipient: Account<'info, TokenAccount>,
+
+    /// CHECK: membership-program global config PDA, signing via CPI
+    #[account(
+        signer @ ReserveError::Unauthorized,
+        seeds = [MEMBERSHIP_CONFIG_SEED],
+        bump,
+        seeds::program = MEMBERSHIP_PROGRAM_ID,
+    )]
+    pub membership_config: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn pay_member_refund(ctx: Context<PayMemberRefund>, amount: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(amount > 0, ReserveError::InvalidPayoutAmount);
+    require!(
+        ctx.accounts.tier0_vault.amount >= amount,
+        ReserveError::InsufficientReserves
+    );
+
+    let seeds = &[
+        VaultAuthority::SEED_PREFIX,
+        &[ctx.accounts.vault_authority.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.tier0_vault.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let state = &mut ctx.accounts.reserve_state;
+    state.tier0_balance = state.tier0_balance.saturating_sub(amount);
+
+    emit!(MemberRefundPaid {
+        amount,
+        new_tier0_balance: state.tier0_balance,
+        recipient: ctx.accounts.recipient.key(),
         timestamp: clock.unix_timestamp,
     });
 
@@ -300,27 +813,152 @@ pub fn take_reserve_snapshot(ctx: Context<TakeReserveSnapshot>) -> Result<()> {
     Ok(())
 }
 
-/// Helper to update coverage ratio
-fn update_coverage_ratio(config: &ReserveConfig, state: &mut ReserveState) -> Result<()> {
-    if state.expected_daily_claims == 0 {
-        state.current_coverage_ratio_bps = 0;
-        return Ok(());
-    }
+/// Record a point-in-time financial reconciliation snapshot
+#[derive(Accounts)]
+pub struct TakeFinancialSnapshot<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
 
-    // Expected annual claims approximation
-    let expected_annual = state.expected_daily_claims.saturating_mul(365);
+    #[account(
+        mut,
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, ReserveState>,
+
+    /// Reinsurance program config (YTD recoveries)
+    #[account(
+        seeds = [b"reinsurance_config"],
+        bump = reinsurance_config.bump,
+        seeds::program = apollo_reinsurance::ID,
+    )]
+    pub reinsurance_config: Account<'info, apollo_reinsurance::state::ReinsuranceConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FinancialSnapshot::INIT_SPACE,
+        seeds = [
+            FinancialSnapshot::SEED_PREFIX,
+            &reserve_state.financial_snapshot_count.to_le_bytes()
+        ],
+        bump
+    )]
+    pub financial_snapshot: Account<'info, FinancialSnapshot>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == reserve_config.authority @ ReserveError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
 
-    // Total available (Tier0 + Tier1 + Tier2)
-    let total_reserves = state.total_reserves();
+    pub system_program: Program<'info, System>,
+}
 
-    // Coverage ratio = (total_reserves / expected_annual) * 10000
-    let ratio_bps = total_reserves
-        .saturating_mul(10000)
-        .checked_div(expected_annual)
-        .unwrap_or(0) as u16;
+pub fn take_financial_snapshot(ctx: Context<TakeFinancialSnapshot>) -> Result<()> {
+    let clock = Clock::get()?;
+    let state = &mut ctx.accounts.reserve_state;
+    let reinsurance = &ctx.accounts.reinsurance_config;
+    let snapshot = &mut ctx.accounts.financial_snapshot;
+
+    let index = state.financial_snapshot_count;
+    snapshot.capture(
+        index,
+        state,
+        reinsurance.ytd_recoveries_received,
+        reinsurance.policy_year_start,
+    );
+    snapshot.taken_by = ctx.accounts.authority.key();
+    snapshot.taken_at = clock.unix_timestamp;
+    snapshot.bump = ctx.bumps.financial_snapshot;
+
+    state.financial_snapshot_count = index.saturating_add(1);
+
+    emit!(FinancialSnapshotTaken {
+        index,
+        total_contributions_received: snapshot.total_contributions_received,
+        total_reserves: snapshot.total_reserves,
+        runoff_balance: snapshot.runoff_balance,
+        total_claims_paid: snapshot.total_claims_paid,
+        ytd_recoveries_received: snapshot.ytd_recoveries_received,
+        taken_by: snapshot.taken_by,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
 
-    let old_ratio = state.current_coverage_ratio_bps;
-    state.current_coverage_ratio_bps = ratio_bps;
+/// Compute the realized Medical Loss Ratio (view, emits event)
+#[derive(Accounts)]
+pub struct ComputeMlr<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
+    #[account(
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, ReserveState>,
+}
+
+pub fn compute_mlr(ctx: Context<ComputeMlr>) -> Result<MlrReport> {
+    let clock = Clock::get()?;
+    let report = ctx
+        .accounts
+        .reserve_state
+        .compute_mlr(&ctx.accounts.reserve_config);
+
+    emit!(MlrComputed {
+        claims_paid: report.claims_paid,
+        net_premiums: report.net_premiums,
+        mlr_bps: report.mlr_bps,
+        below_target: report.below_target,
+        below_aca_floor: report.below_aca_floor,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(report)
+}
+
+/// Read-only view of required vs. actual reserves
+#[derive(Accounts)]
+pub struct GetRequiredReserves<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
+    #[account(
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, ReserveState>,
+
+    #[account(
+        seeds = [RunoffState::SEED_PREFIX],
+        bump = runoff_state.bump,
+    )]
+    pub runoff_state: Account<'info, RunoffState>,
+}
+
+pub fn get_required_reserves(ctx: Context<GetRequiredReserves>) -> Result<ReserveRequirements> {
+    Ok(ctx
+        .accounts
+        .reserve_state
+        .reserve_requirements(&ctx.accounts.reserve_config, &ctx.accounts.runoff_state))
+}
+
+/// Helper to update coverage ratio
+fn update_coverage_ratio(config: &ReserveConfig, state: &mut ReserveState) -> Result<()> {
+    let old_ratio = state.refresh_coverage_ratio();
+    let ratio_bps = state.current_coverage_ratio_bps;
 
     // Emit if significant change (>5%)
     if ratio_bps.abs_diff(old_ratio) > 500 {
@@ -13,6 +13,8 @@ use crate::state::{
     ReinsuranceConfig, ReserveState,
 };
 use anchor_lang::prelude::*;
+use apollo_core::BootstrapConfig;
+use apollo_risk_engine::program::ApolloRiskEngine;
 
 // =============================================================================
 // INITIALIZE PHASE MANAGER
@@ -381,6 +383,14 @@ pub struct InitializeReinsurance<'info> {
     )]
     pub reinsurance_config: Account<'info, ReinsuranceConfig>,
 
+    /// Core bootstrap config (selects the default specific attachment)
+    #[account(
+        seeds = [BootstrapConfig::SEED_PREFIX],
+        bump = bootstrap_config.bump,
+        seeds::program = apollo_core::ID,
+    )]
+    pub bootstrap_config: Account<'info, BootstrapConfig>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
@@ -389,7 +399,8 @@ pub struct InitializeReinsurance<'info> {
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeReinsuranceParams {
-    pub specific_attachment: u64,
+    /// Defaults to the bootstrap or standard attachment for the current mode
+    pub specific_attachment: Option<u64>,
     pub specific_coverage_bps: u16,
     pub aggregate_trigger_bps: u16,
     pub aggregate_ceiling_bps: u16,
@@ -407,7 +418,11 @@ pub fn initialize_reinsurance(
     let config = &mut ctx.accounts.reinsurance_config;
 
     config.authority = ctx.accounts.authority.key();
-    config.specific_attachment = params.specific_attachment;
+    config.specific_attachment = params.specific_attachment.unwrap_or_else(|| {
+        ReinsuranceConfig::default_specific_attachment(
+            ctx.accounts.bootstrap_config.bootstrap_active,
+        )
+    });
     config.specific_coverage_bps = params.specific_coverage_bps;
     config.aggregate_trigger_bps = params.aggregate_trigger_bps;
     config.aggregate_ceiling_bps = params.aggregate_ceiling_bps;
@@ -424,7 +439,7 @@ pub fn initialize_reinsurance(
     config.bump = ctx.bumps.reinsurance_config;
 
     emit!(ReinsuranceInitialized {
-        specific_attachment: params.specific_attachment,
+        specific_attachment: config.specific_attachment,
         aggregate_trigger_bps: params.aggregate_trigger_bps,
         policy_start: params.policy_period_start,
         policy_end: params.policy_period_end,
@@ -544,6 +559,18 @@ pub struct UpdateCohortMetrics<'info> {
 
     /// Authority (membership or claims program)
     pub authority: Signer<'info>,
+
+    /// Risk engine config
+    /// CHECK: Validated by the risk engine program
+    #[account(mut)]
+    pub risk_config: UncheckedAccount<'info>,
+
+    /// Risk engine zone state
+    /// CHECK: Validated by the risk engine program
+    #[account(mut)]
+    pub zone_state: UncheckedAccount<'info>,
+
+    pub risk_engine_program: Program<'info, ApolloRiskEngine>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -560,6 +587,7 @@ pub fn update_cohort_metrics(
     params: CohortUpdateParams,
 ) -> Result<()> {
     let cohort = &mut ctx.accounts.cohort;
+    let was_flagged = cohort.flagged;
 
     if let Some(add) = params.add_members {
         cohort.member_count = cohort.member_count.saturating_add(add);
@@ -590,6 +618,28 @@ pub fn update_cohort_metrics(
         });
     }
 
+    // Alert the risk engine once, when the cohort first crosses the threshold
+    if cohort.flagged && !was_flagged {
+        let cohort_id = cohort.cohort_id;
+        let loss_ratio_bps = cohort.loss_ratio_bps;
+        let id_bytes = cohort_id.to_le_bytes();
+        let seeds: &[&[u8]] = &[CohortMetrics::SEED_PREFIX, &id_bytes, &[cohort.bump]];
+
+        apollo_risk_engine::cpi::record_cohort_alert(
+            CpiContext::new_with_signer(
+                ctx.accounts.risk_engine_program.to_account_info(),
+                apollo_risk_engine::cpi::accounts::RecordCohortAlert {
+                    risk_config: ctx.accounts.risk_config.to_account_info(),
+                    zone_state: ctx.accounts.zone_state.to_account_info(),
+                    cohort: ctx.accounts.cohort.to_account_info(),
+                },
+                &[seeds],
+            ),
+            cohort_id,
+            loss_ratio_bps,
+        )?;
+    }
+
     Ok(())
 }
 
@@ -1,10 +1,12 @@
 // programs/apollo_reserves/src/instructions/routing.rs
 
 use crate::errors::ReserveError;
-use crate::events::ContributionRouted;
-use crate::state::{ContributionRouting, ReserveConfig, ReserveState, VaultAuthority};
+use crate::events::{ContributionRouted, ReinsuranceRecoveryRouted};
+use crate::state::{ContributionRouting, ReserveConfig, ReserveState, TierTarget, VaultAuthority};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount};
+use apollo_reinsurance::program::ApolloReinsurance;
+use apollo_reinsurance::state::RecoveryClaim;
 
 /// Route a contribution to appropriate vaults based on reserve policy
 #[derive(Accounts)]
@@ -38,25 +40,29 @@ pub struct RouteContribution<'info> {
 
     #[account(
         mut,
-        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier0_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier0_vault: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier1_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier1_vault: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = tier2_vault.key() == vault_authority.tier2_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier2_vault.key() == vault_authority.tier2_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier2_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier2_vault: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = admin_vault.key() == vault_authority.admin_vault @ ReserveError::InvalidVaultConfig
+        constraint = admin_vault.key() == vault_authority.admin_vault @ ReserveError::InvalidVaultConfig,
+        constraint = admin_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub admin_vault: Account<'info, TokenAccount>,
 
@@ -66,23 +72,22 @@ pub struct RouteContribution<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-pub fn route_contribution(ctx: Context<RouteContribution>, total_amount: u64) -> Result<()> {
+pub fn route_contribution(
+    ctx: Context<RouteContribution>,
+    total_amount: u64,
+) -> Result<ContributionRouting> {
     require!(total_amount > 0, ReserveError::ZeroAmount);
 
     let clock = Clock::get()?;
     let config = &ctx.accounts.reserve_config;
     let state = &mut ctx.accounts.reserve_state;
 
+    // Enforce the 90% MLR loading cap before splitting
+    config.validate_loading()?;
+
     // Calculate routing based on policy
     let routing = calculate_routing(config, state, total_amount)?;
-
-    // Verify total matches
-    let sum = routing
-        .to_tier0
-        .saturating_add(routing.to_tier1)
-        .saturating_add(routing.to_tier2)
-        .saturating_add(routing.to_admin);
-    require!(sum == total_amount, ReserveError::RoutingMismatch);
+    routing.validate_against(config, state)?;
 
     // Execute transfers
     // To Tier 0 (liquidity buffer - majority goes here initially)
@@ -146,6 +151,7 @@ pub fn route_contribution(ctx: Context<RouteContribution>, total_amount: u64) ->
             ),
             routing.to_admin,
         )?;
+        state.admin_load_collected = state.admin_load_collected.saturating_add(routing.to_admin);
     }
 
     // Update total contributions
@@ -160,10 +166,11 @@ pub fn route_contribution(ctx: Context<RouteContribution>, total_amount: u64) ->
         to_tier1: routing.to_tier1,
         to_tier2: routing.to_tier2,
         to_admin: routing.to_admin,
+        implied_mlr_bps: config.implied_mlr_bps(),
         timestamp: clock.unix_timestamp,
     });
 
-    Ok(())
+    Ok(routing)
 }
 
 /// Calculate how to route a contribution based on current reserve levels
@@ -172,20 +179,17 @@ fn calculate_routing(
     state: &ReserveState,
     total: u64,
 ) -> Result<ContributionRouting> {
+    // A governance-set fixed split overrides deficit-based routing
+    if config.has_fixed_routing() {
+        return Ok(config.fixed_routing(total));
+    }
+
     // Calculate targets
-    let tier0_target = state
-        .expected_daily_claims
-        .saturating_mul(config.tier0_target_days as u64);
-    let tier1_target = state
-        .expected_daily_claims
-        .saturating_mul(config.tier1_target_days as u64)
-        .saturating_add(state.ibnr_usdc);
+    let tier0_target = state.required_tier0(config);
+    let tier1_target = state.required_tier1(config);
 
     // Admin load (always taken)
-    let admin_amount = total
-        .saturating_mul(config.admin_load_bps as u64)
-        .checked_div(10000)
-        .unwrap_or(0);
+    let admin_amount = config.max_admin_amount(total);
 
     // Reserve margin (goes to Tier 1)
     let reserve_margin = total
@@ -257,7 +261,10 @@ pub struct DepositToTier<'info> {
     pub source: Account<'info, TokenAccount>,
 
     /// The target vault (tier0, tier1, or tier2)
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = target_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
+    )]
     pub target_vault: Account<'info, TokenAccount>,
 
     pub depositor: Signer<'info>,
@@ -265,26 +272,12 @@ pub struct DepositToTier<'info> {
     pub token_program: Program<'info, Token>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
-pub enum TierTarget {
-    Tier0,
-    Tier1,
-    Tier2,
-}
-
 pub fn deposit_to_tier(ctx: Context<DepositToTier>, tier: TierTarget, amount: u64) -> Result<()> {
     require!(amount > 0, ReserveError::ZeroAmount);
 
-    let vault_authority = &ctx.accounts.vault_authority;
-
     // Verify target vault matches requested tier
-    let expected_vault = match tier {
-        TierTarget::Tier0 => vault_authority.tier0_vault,
-        TierTarget::Tier1 => vault_authority.tier1_vault,
-        TierTarget::Tier2 => vault_authority.tier2_vault,
-    };
     require!(
-        ctx.accounts.target_vault.key() == expected_vault,
+        ctx.accounts.target_vault.key() == ctx.accounts.vault_authority.tier_vault(tier),
         ReserveError::InvalidVaultConfig
     );
 
@@ -302,18 +295,141 @@ pub fn deposit_to_tier(ctx: Context<DepositToTier>, tier: TierTarget, amount: u6
     )?;
 
     // Update state
-    let state = &mut ctx.accounts.reserve_state;
-    match tier {
-        TierTarget::Tier0 => {
-            state.tier0_balance = state.tier0_balance.saturating_add(amount);
-        }
-        TierTarget::Tier1 => {
-            state.tier1_balance = state.tier1_balance.saturating_add(amount);
-        }
-        TierTarget::Tier2 => {
-            state.tier2_balance = state.tier2_balance.saturating_add(amount);
-        }
-    }
+    ctx.accounts.reserve_state.credit_tier(tier, amount);
+
+    Ok(())
+}
+
+/// Record a reinsurer settlement and route the received USDC into reserves
+///
+/// Wraps the reinsurance program's `record_settlement` so recovered funds
+/// land in `reserve_config.recovery_target_tier` in the same transaction.
+/// The settlement account must be owned by the signing authority.
+#[derive(Accounts)]
+pub struct SettleReinsuranceRecovery<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+        constraint = reserve_config.is_initialized @ ReserveError::NotInitialized
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
+    #[account(
+        mut,
+        seeds = [ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, ReserveState>,
+
+    #[account(
+        seeds = [VaultAuthority::SEED_PREFIX],
+        bump = vault_authority.bump,
+    )]
+    pub vault_authority: Account<'info, VaultAuthority>,
+
+    /// Vault for the configured recovery tier
+    #[account(
+        mut,
+        constraint = target_vault.key() == vault_authority.tier_vault(reserve_config.recovery_target_tier)
+            @ ReserveError::InvalidVaultConfig
+    )]
+    pub target_vault: Account<'info, TokenAccount>,
+
+    /// Reinsurance config
+    /// CHECK: Validated by the reinsurance program
+    #[account(mut)]
+    pub reinsurance_config: UncheckedAccount<'info>,
+
+    /// Treaty the recovery was filed under
+    /// CHECK: Validated by the reinsurance program
+    #[account(mut)]
+    pub treaty: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub recovery_claim: Account<'info, RecoveryClaim>,
+
+    /// Member accumulator a specific stop-loss claim was filed against
+    /// CHECK: Validated by the reinsurance program
+    #[account(mut)]
+    pub accumulator: Option<UncheckedAccount<'info>>,
+
+    /// Where the reinsurer payment was received
+    #[account(
+        mut,
+        constraint = settlement_account.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
+    )]
+    pub settlement_account: Account<'info, TokenAccount>,
+
+    /// Reinsurance authority or committee, and owner of the settlement account
+    pub authority: Signer<'info>,
+
+    pub reinsurance_program: Program<'info, ApolloReinsurance>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn settle_reinsurance_recovery(
+    ctx: Context<SettleReinsuranceRecovery>,
+    received_amount: u64,
+    is_final: bool,
+    settlement_reference: [u8; 32],
+) -> Result<()> {
+    require!(received_amount > 0, ReserveError::ZeroAmount);
+    let clock = Clock::get()?;
+
+    apollo_reinsurance::cpi::record_settlement(
+        CpiContext::new(
+            ctx.accounts.reinsurance_program.to_account_info(),
+            apollo_reinsurance::cpi::accounts::RecordSettlement {
+                config: ctx.accounts.reinsurance_config.to_account_info(),
+                treaty: ctx.accounts.treaty.to_account_info(),
+                recovery_claim: ctx.accounts.recovery_claim.to_account_info(),
+                accumulator: ctx
+                    .accounts
+                    .accumulator
+                    .as_ref()
+                    .map(|accumulator| accumulator.to_account_info()),
+                settlement_account: ctx.accounts.settlement_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        received_amount,
+        is_final,
+        settlement_reference,
+    )?;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.settlement_account.to_account_info(),
+                to: ctx.accounts.target_vault.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ),
+        received_amount,
+    )?;
+
+    let tier = ctx.accounts.reserve_config.recovery_target_tier;
+    let new_tier_balance = ctx
+        .accounts
+        .reserve_state
+        .credit_tier(tier, received_amount);
+
+    let claim = &mut ctx.accounts.recovery_claim;
+    claim.reload()?;
+
+    emit!(ReinsuranceRecoveryRouted {
+        recovery_claim: claim.key(),
+        claim_id: claim.claim_id,
+        received_amount,
+        total_received: claim.received_amount,
+        is_final,
+        tier,
+        new_tier_balance,
+        authority: ctx.accounts.authority.key(),
+        timestamp: clock.unix_timestamp,
+    });
 
     Ok(())
 }
@@ -5,6 +5,8 @@ use crate::events::VaultsCreated;
 use crate::state::{ReserveConfig, VaultAuthority};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use apollo_governance::program::ApolloGovernance;
+use apollo_governance::state::{AdminAction, DaoConfig, Multisig, SignerSet};
 
 /// Create all reserve vaults
 #[derive(Accounts)]
@@ -144,16 +146,45 @@ pub struct RefillTier0<'info> {
 
     #[account(
         mut,
-        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier0_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier0_vault: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier1_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier1_vault: Account<'info, TokenAccount>,
 
+    /// Governance DAO config (treasury committee)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    /// Treasury committee multisig (committee signers and large refills)
+    #[account(
+        constraint = treasury_committee.key() == dao_config.treasury_committee @ ReserveError::Unauthorized
+    )]
+    pub treasury_committee: Option<Account<'info, Multisig>>,
+
+    /// Signer set approving this refill (refills above the approval threshold)
+    #[account(
+        mut,
+        constraint = signer_set.target == tier0_vault.key() @ ReserveError::TreasuryApprovalRequired
+    )]
+    pub signer_set: Option<Account<'info, SignerSet>>,
+
+    #[account(
+        constraint = governance_program.key() == reserve_config.governance_program @ ReserveError::Unauthorized
+    )]
+    pub governance_program: Option<Program<'info, ApolloGovernance>>,
+
+    /// Reserve authority or a treasury committee signer
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
@@ -168,6 +199,15 @@ pub fn refill_tier0(ctx: Context<RefillTier0>, amount: u64) -> Result<()> {
 
     let clock = Clock::get()?;
 
+    let signer_set = authorize_refill(
+        &ctx.accounts.reserve_config,
+        ctx.accounts.treasury_committee.as_ref(),
+        ctx.accounts.signer_set.as_ref(),
+        ctx.accounts.governance_program.as_ref(),
+        &ctx.accounts.authority,
+        amount,
+    )?;
+
     // Transfer from Tier1 to Tier0
     let seeds = &[
         VaultAuthority::SEED_PREFIX,
@@ -197,6 +237,8 @@ pub fn refill_tier0(ctx: Context<RefillTier0>, amount: u64) -> Result<()> {
         from_tier: crate::state::WaterfallSource::Tier1,
         to_tier: crate::state::WaterfallSource::Tier0,
         amount,
+        authorizer: ctx.accounts.authority.key(),
+        signer_set,
         timestamp: clock.unix_timestamp,
     });
 
@@ -227,16 +269,45 @@ pub struct RefillTier1<'info> {
 
     #[account(
         mut,
-        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier1_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier1_vault: Account<'info, TokenAccount>,
 
     #[account(
         mut,
-        constraint = tier2_vault.key() == vault_authority.tier2_vault @ ReserveError::InvalidVaultConfig
+        constraint = tier2_vault.key() == vault_authority.tier2_vault @ ReserveError::InvalidVaultConfig,
+        constraint = tier2_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
     )]
     pub tier2_vault: Account<'info, TokenAccount>,
 
+    /// Governance DAO config (treasury committee)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    /// Treasury committee multisig (committee signers and large refills)
+    #[account(
+        constraint = treasury_committee.key() == dao_config.treasury_committee @ ReserveError::Unauthorized
+    )]
+    pub treasury_committee: Option<Account<'info, Multisig>>,
+
+    /// Signer set approving this refill (refills above the approval threshold)
+    #[account(
+        mut,
+        constraint = signer_set.target == tier1_vault.key() @ ReserveError::TreasuryApprovalRequired
+    )]
+    pub signer_set: Option<Account<'info, SignerSet>>,
+
+    #[account(
+        constraint = governance_program.key() == reserve_config.governance_program @ ReserveError::Unauthorized
+    )]
+    pub governance_program: Option<Program<'info, ApolloGovernance>>,
+
+    /// Reserve authority or a treasury committee signer
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
@@ -251,6 +322,15 @@ pub fn refill_tier1(ctx: Context<RefillTier1>, amount: u64) -> Result<()> {
 
     let clock = Clock::get()?;
 
+    let signer_set = authorize_refill(
+        &ctx.accounts.reserve_config,
+        ctx.accounts.treasury_committee.as_ref(),
+        ctx.accounts.signer_set.as_ref(),
+        ctx.accounts.governance_program.as_ref(),
+        &ctx.accounts.authority,
+        amount,
+    )?;
+
     let seeds = &[
         VaultAuthority::SEED_PREFIX,
         &[ctx.accounts.vault_authority.bump],
@@ -278,6 +358,314 @@ pub fn refill_tier1(ctx: Context<RefillTier1>, amount: u64) -> Result<()> {
         from_tier: crate::state::WaterfallSource::Tier2,
         to_tier: crate::state::WaterfallSource::Tier1,
         amount,
+        authorizer: ctx.accounts.authority.key(),
+        signer_set,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Sweep collected admin load from the admin vault to operations
+#[derive(Accounts)]
+pub struct SweepAdminVault<'info> {
+    #[account(
+        seeds = [ReserveConfig::SEED_PREFIX],
+        bump = reserve_config.bump,
+    )]
+    pub reserve_config: Account<'info, ReserveConfig>,
+
+    #[account(
+        mut,
+        seeds = [crate::state::ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, crate::state::ReserveState>,
+
+    #[account(
+        seeds = [VaultAuthority::SEED_PREFIX],
+        bump = vault_authority.bump,
+    )]
+    pub vault_authority: Account<'info, VaultAuthority>,
+
+    #[account(
+        mut,
+        constraint = admin_vault.key() == vault_authority.admin_vault @ ReserveError::InvalidVaultConfig,
+        constraint = admin_vault.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
+    )]
+    pub admin_vault: Account<'info, TokenAccount>,
+
+    /// Designated operations account (set via set_operations_account)
+    #[account(
+        mut,
+        constraint = operations_account.key() == reserve_config.operations_account @ ReserveError::Unauthorized,
+        constraint = operations_account.mint == reserve_config.usdc_mint @ ReserveError::InvalidMint
+    )]
+    pub operations_account: Account<'info, TokenAccount>,
+
+    /// Governance DAO config (treasury committee)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    #[account(
+        constraint = treasury_committee.key() == dao_config.treasury_committee @ ReserveError::Unauthorized
+    )]
+    pub treasury_committee: Account<'info, Multisig>,
+
+    /// Signer set approving this sweep, targeting the admin vault
+    #[account(
+        mut,
+        constraint = signer_set.target == admin_vault.key() @ ReserveError::TreasuryApprovalRequired
+    )]
+    pub signer_set: Account<'info, SignerSet>,
+
+    #[account(
+        constraint = governance_program.key() == reserve_config.governance_program @ ReserveError::Unauthorized
+    )]
+    pub governance_program: Program<'info, ApolloGovernance>,
+
+    /// Reserve authority or a treasury committee signer
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn sweep_admin_vault(ctx: Context<SweepAdminVault>, amount: u64) -> Result<()> {
+    require!(amount > 0, ReserveError::ZeroAmount);
+    require!(
+        amount <= ctx.accounts.reserve_state.unswept_admin_load(),
+        ReserveError::InsufficientAdminLoad
+    );
+    require!(
+        ctx.accounts.admin_vault.amount >= amount,
+        ReserveError::InsufficientReserves
+    );
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.reserve_config.authority
+            || ctx
+                .accounts
+                .treasury_committee
+                .is_signer(&ctx.accounts.authority.key()),
+        ReserveError::Unauthorized
+    );
+
+    let clock = Clock::get()?;
+
+    // Every sweep needs a treasury committee signer set for this amount
+    require_approved_amount(Some(&ctx.accounts.signer_set), amount)?;
+    let signer_set = consume_treasury_approval(
+        Some(&ctx.accounts.treasury_committee),
+        Some(&ctx.accounts.signer_set),
+        Some(&ctx.accounts.governance_program),
+        ctx.accounts.authority.to_account_info(),
+        AdminAction::SweepAdminVault,
+    )?;
+
+    let seeds = &[
+        VaultAuthority::SEED_PREFIX,
+        &[ctx.accounts.vault_authority.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.admin_vault.to_account_info(),
+                to: ctx.accounts.operations_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let state = &mut ctx.accounts.reserve_state;
+    state.record_admin_sweep(amount)?;
+
+    emit!(crate::events::AdminVaultSwept {
+        amount,
+        operations_account: ctx.accounts.operations_account.key(),
+        admin_load_collected: state.admin_load_collected,
+        total_swept: state.admin_swept,
+        remaining: state.unswept_admin_load(),
+        authorizer: ctx.accounts.authority.key(),
+        signer_set,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Routine refills are open to the reserve authority and treasury committee
+/// signers; anything above the approval threshold also consumes a treasury
+/// committee signer set. Returns the signer set used, if any.
+fn authorize_refill<'info>(
+    reserve_config: &ReserveConfig,
+    treasury_committee: Option<&Account<'info, Multisig>>,
+    signer_set: Option<&Account<'info, SignerSet>>,
+    governance_program: Option<&Program<'info, ApolloGovernance>>,
+    authority: &Signer<'info>,
+    amount: u64,
+) -> Result<Option<Pubkey>> {
+    let is_treasury_signer = treasury_committee
+        .map(|committee| committee.is_signer(&authority.key()))
+        .unwrap_or(false);
+    require!(
+        authority.key() == reserve_config.authority || is_treasury_signer,
+        ReserveError::Unauthorized
+    );
+
+    if !reserve_config.refill_requires_approval(amount) {
+        return Ok(None);
+    }
+
+    require_approved_amount(signer_set, amount)?;
+    consume_treasury_approval(
+        treasury_committee,
+        signer_set,
+        governance_program,
+        authority.to_account_info(),
+        AdminAction::RefillReserveTier,
+    )
+    .map(Some)
+}
+
+/// Require the signer set's `action_data` to commit to exactly `amount`
+/// (little-endian), so an approval cannot be replayed for a larger transfer
+pub(crate) fn require_approved_amount(
+    signer_set: Option<&Account<SignerSet>>,
+    amount: u64,
+) -> Result<()> {
+    let Some(signer_set) = signer_set else {
+        return err!(ReserveError::TreasuryApprovalRequired);
+    };
+    require!(
+        signer_set.action_data == amount.to_le_bytes(),
+        ReserveError::TreasuryApprovalRequired
+    );
+    Ok(())
+}
+
+/// Verify a treasury committee signer set for `action` via governance CPI and
+/// mark it executed so it cannot authorize a second transfer
+pub(crate) fn consume_treasury_approval<'info>(
+    treasury_committee: Option<&Account<'info, Multisig>>,
+    signer_set: Option<&Account<'info, SignerSet>>,
+    governance_program: Option<&Program<'info, ApolloGovernance>>,
+    executor: AccountInfo<'info>,
+    action: AdminAction,
+) -> Result<Pubkey> {
+    let (Some(multisig), Some(signer_set), Some(governance_program)) =
+        (treasury_committee, signer_set, governance_program)
+    else {
+        return err!(ReserveError::TreasuryApprovalRequired);
+    };
+
+    consume_committee_approval(multisig, signer_set, governance_program, executor, action)
+}
+
+/// Verify that a committee signer set for `action` has reached the
+/// committee's threshold via governance CPI, then mark it executed so it
+/// cannot authorize a second change
+pub(crate) fn consume_committee_approval<'info>(
+    multisig: &Account<'info, Multisig>,
+    signer_set: &Account<'info, SignerSet>,
+    governance_program: &Program<'info, ApolloGovernance>,
+    executor: AccountInfo<'info>,
+    action: AdminAction,
+) -> Result<Pubkey> {
+    apollo_governance::cpi::assert_signed(
+        CpiContext::new(
+            governance_program.to_account_info(),
+            apollo_governance::cpi::accounts::AssertSigned {
+                multisig: multisig.to_account_info(),
+                signer_set: signer_set.to_account_info(),
+            },
+        ),
+        action,
+    )?;
+
+    apollo_governance::cpi::mark_executed(CpiContext::new(
+        governance_program.to_account_info(),
+        apollo_governance::cpi::accounts::MarkExecuted {
+            multisig: multisig.to_account_info(),
+            signer_set: signer_set.to_account_info(),
+            executor,
+        },
+    ))?;
+
+    Ok(signer_set.key())
+}
+
+/// Resync cached tier balances with the vault token accounts (permissionless;
+/// the token accounts are the source of truth)
+#[derive(Accounts)]
+pub struct ReconcileReserveBalances<'info> {
+    #[account(
+        mut,
+        seeds = [crate::state::ReserveState::SEED_PREFIX],
+        bump = reserve_state.bump,
+    )]
+    pub reserve_state: Account<'info, crate::state::ReserveState>,
+
+    #[account(
+        seeds = [VaultAuthority::SEED_PREFIX],
+        bump = vault_authority.bump,
+    )]
+    pub vault_authority: Account<'info, VaultAuthority>,
+
+    #[account(
+        constraint = tier0_vault.key() == vault_authority.tier0_vault @ ReserveError::InvalidVaultConfig
+    )]
+    pub tier0_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = tier1_vault.key() == vault_authority.tier1_vault @ ReserveError::InvalidVaultConfig
+    )]
+    pub tier1_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = tier2_vault.key() == vault_authority.tier2_vault @ ReserveError::InvalidVaultConfig
+    )]
+    pub tier2_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = runoff_vault.key() == vault_authority.runoff_vault @ ReserveError::InvalidVaultConfig
+    )]
+    pub runoff_vault: Account<'info, TokenAccount>,
+}
+
+pub fn reconcile_reserve_balances(ctx: Context<ReconcileReserveBalances>) -> Result<()> {
+    let clock = Clock::get()?;
+    let state = &mut ctx.accounts.reserve_state;
+
+    let drift = state.reconcile_balances(
+        ctx.accounts.tier0_vault.amount,
+        ctx.accounts.tier1_vault.amount,
+        ctx.accounts.tier2_vault.amount,
+        ctx.accounts.runoff_vault.amount,
+    );
+    if drift.is_zero() {
+        msg!("Reserve balances in sync with vaults");
+        return Ok(());
+    }
+    state.refresh_coverage_ratio();
+
+    emit!(crate::events::ReserveBalanceDiscrepancy {
+        tier0_drift: drift.tier0,
+        tier1_drift: drift.tier1,
+        tier2_drift: drift.tier2,
+        runoff_drift: drift.runoff,
+        tier0_balance: state.tier0_balance,
+        tier1_balance: state.tier1_balance,
+        tier2_balance: state.tier2_balance,
+        runoff_balance: state.runoff_balance,
+        coverage_ratio_bps: state.current_coverage_ratio_bps,
         timestamp: clock.unix_timestamp,
     });
 
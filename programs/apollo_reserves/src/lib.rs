@@ -16,6 +16,9 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
+use state::{
+    ContributionRouting, IbnrDevelopmentBucket, MlrReport, ReserveRequirements, TierTarget,
+};
 
 declare_id!("4kZvvmDi1d9xKFzB7i7qK2psgFnbpmNfNTPJEnyAsUhh");
 
@@ -41,6 +44,39 @@ pub mod apollo_reserves {
         instructions::initialize::set_reserve_targets(ctx, params)
     }
 
+    /// Set the tier that settled reinsurance recoveries are deposited into
+    pub fn set_recovery_target_tier(
+        ctx: Context<UpdateReserveSettings>,
+        tier: TierTarget,
+    ) -> Result<()> {
+        instructions::initialize::set_recovery_target_tier(ctx, tier)
+    }
+
+    /// Set the refill size above which treasury committee approval is required
+    pub fn set_refill_approval_threshold(
+        ctx: Context<UpdateReserveSettings>,
+        threshold: u64,
+    ) -> Result<()> {
+        instructions::initialize::set_refill_approval_threshold(ctx, threshold)
+    }
+
+    /// Set the operations token account that admin-vault sweeps pay into
+    pub fn set_operations_account(
+        ctx: Context<UpdateReserveSettings>,
+        operations_account: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize::set_operations_account(ctx, operations_account)
+    }
+
+    /// Set the fixed contribution split across tiers and admin, or clear it
+    /// with all zero (Actuarial Committee signer set required)
+    pub fn set_routing_ratios(
+        ctx: Context<SetRoutingRatios>,
+        params: SetRoutingRatiosParams,
+    ) -> Result<()> {
+        instructions::initialize::set_routing_ratios(ctx, params)
+    }
+
     // ==================== VAULT MANAGEMENT ====================
 
     /// Create all USDC vaults for the reserve tiers
@@ -58,13 +94,23 @@ pub mod apollo_reserves {
         instructions::vaults::refill_tier1(ctx, amount)
     }
 
+    /// Sweep collected admin load to the operations account (signer set required)
+    pub fn sweep_admin_vault(ctx: Context<SweepAdminVault>, amount: u64) -> Result<()> {
+        instructions::vaults::sweep_admin_vault(ctx, amount)
+    }
+
+    /// Resync cached tier balances with the vault token accounts
+    pub fn reconcile_reserve_balances(ctx: Context<ReconcileReserveBalances>) -> Result<()> {
+        instructions::vaults::reconcile_reserve_balances(ctx)
+    }
+
     // ==================== CONTRIBUTION ROUTING ====================
 
     /// Route a member contribution to appropriate vaults
     pub fn route_contribution_to_vaults(
         ctx: Context<RouteContribution>,
         total_amount: u64,
-    ) -> Result<()> {
+    ) -> Result<ContributionRouting> {
         instructions::routing::route_contribution(ctx, total_amount)
     }
 
@@ -77,6 +123,21 @@ pub mod apollo_reserves {
         instructions::routing::deposit_to_tier(ctx, tier, amount)
     }
 
+    /// Record a reinsurer settlement and deposit it to the recovery tier
+    pub fn settle_reinsurance_recovery(
+        ctx: Context<SettleReinsuranceRecovery>,
+        received_amount: u64,
+        is_final: bool,
+        settlement_reference: [u8; 32],
+    ) -> Result<()> {
+        instructions::routing::settle_reinsurance_recovery(
+            ctx,
+            received_amount,
+            is_final,
+            settlement_reference,
+        )
+    }
+
     // ==================== IBNR & CLAIMS ESTIMATION ====================
 
     /// Compute IBNR (Incurred But Not Reported) reserve
@@ -101,6 +162,14 @@ pub mod apollo_reserves {
         instructions::ibnr::update_ibnr_params(ctx, reporting_lag, development_factor_bps)
     }
 
+    /// Set age-bucketed IBNR development factors (empty = single-factor)
+    pub fn set_ibnr_development_buckets(
+        ctx: Context<UpdateIbnrParams>,
+        buckets: Vec<IbnrDevelopmentBucket>,
+    ) -> Result<()> {
+        instructions::ibnr::set_ibnr_development_buckets(ctx, buckets)
+    }
+
     // ==================== RUN-OFF RESERVE ====================
 
     /// Fund the run-off reserve
@@ -131,7 +200,24 @@ pub mod apollo_reserves {
         instructions::payouts::payout_claim_from_waterfall(ctx, params)
     }
 
-    /// Emergency spend from run-off reserve (DAO gated)
+    /// Slash staked APH to cover what Tier 0-2 cannot pay, within the
+    /// monthly liquidation cap
+    pub fn request_emergency_capital<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RequestEmergencyCapital<'info>>,
+        params: EmergencyCapitalParams,
+    ) -> Result<()> {
+        instructions::payouts::request_emergency_capital(ctx, params)
+    }
+
+    /// Pay a pre-run-off claim from the run-off reserve (run-off mode only)
+    pub fn runoff_payout_claim(
+        ctx: Context<RunoffPayoutClaim>,
+        params: RunoffPayoutParams,
+    ) -> Result<()> {
+        instructions::payouts::runoff_payout_claim(ctx, params)
+    }
+
+    /// Emergency spend from run-off reserve (treasury committee signer set required)
     pub fn emergency_spend_runoff(
         ctx: Context<EmergencySpendRunoff>,
         amount: u64,
@@ -140,10 +226,46 @@ pub mod apollo_reserves {
         instructions::payouts::emergency_spend_runoff(ctx, amount, reason)
     }
 
+    /// Pay a terminated member's contribution refund (membership CPI only)
+    pub fn pay_member_refund(ctx: Context<PayMemberRefund>, amount: u64) -> Result<()> {
+        instructions::payouts::pay_member_refund(ctx, amount)
+    }
+
     /// Take a reserve state snapshot (emits event)
     pub fn take_reserve_snapshot(ctx: Context<TakeReserveSnapshot>) -> Result<()> {
         instructions::payouts::take_reserve_snapshot(ctx)
     }
+
+    /// Record a reconciliation snapshot of contributions, reserves, claims, and recoveries
+    pub fn take_financial_snapshot(ctx: Context<TakeFinancialSnapshot>) -> Result<()> {
+        instructions::payouts::take_financial_snapshot(ctx)
+    }
+
+    /// Compute the realized MLR against the 90% requirement and ACA floor
+    pub fn compute_mlr(ctx: Context<ComputeMlr>) -> Result<MlrReport> {
+        instructions::payouts::compute_mlr(ctx)
+    }
+
+    /// View total required reserves (tier targets + IBNR) against actual reserves
+    pub fn get_required_reserves(ctx: Context<GetRequiredReserves>) -> Result<ReserveRequirements> {
+        instructions::payouts::get_required_reserves(ctx)
+    }
+
+    // ==================== COHORT TRACKING ====================
+
+    /// Initialize an enrollment cohort
+    pub fn initialize_cohort(ctx: Context<InitializeCohort>, cohort_id: u32) -> Result<()> {
+        instructions::phase_management::initialize_cohort(ctx, cohort_id)
+    }
+
+    /// Update cohort metrics, alerting the risk engine when newly flagged
+    pub fn update_cohort_metrics(
+        ctx: Context<UpdateCohortMetrics>,
+        cohort_id: u32,
+        params: CohortUpdateParams,
+    ) -> Result<()> {
+        instructions::phase_management::update_cohort_metrics(ctx, cohort_id, params)
+    }
 }
 
 /// Public helpers for CPI
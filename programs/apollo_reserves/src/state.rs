@@ -1,7 +1,9 @@
 // programs/apollo_reserves/src/state.rs
 
+use crate::errors::ReserveError;
 use anchor_lang::prelude::*;
 pub use apollo_core::phase::ProtocolPhase;
+use apollo_core::{actuarial, protocol_constants};
 
 /// Reserve configuration - defines targets and parameters for the three-tier system
 /// PDA seeds: ["reserve_config"]
@@ -45,6 +47,23 @@ pub struct ReserveConfig {
     /// Risk engine program for CAR updates
     pub risk_engine_program: Pubkey,
 
+    /// Tier that settled reinsurance recoveries are deposited into
+    /// Default: Tier 1 (operating reserve)
+    pub recovery_target_tier: TierTarget,
+
+    /// Tier refills above this amount need a treasury committee signer set
+    pub refill_approval_threshold: u64,
+
+    /// Operations token account that admin-vault sweeps pay into
+    pub operations_account: Pubkey,
+
+    /// Fixed contribution split (bps, summing to 10000). All zero routes
+    /// by tier deficits instead.
+    pub tier0_route_bps: u16,
+    pub tier1_route_bps: u16,
+    pub tier2_route_bps: u16,
+    pub admin_route_bps: u16,
+
     /// Is the reserve system initialized and active
     pub is_initialized: bool,
 
@@ -74,6 +93,87 @@ impl ReserveConfig {
 
     pub const DEFAULT_MIN_COVERAGE_BPS: u16 = 10000; // 100%
     pub const DEFAULT_TARGET_COVERAGE_BPS: u16 = 12500; // 125%
+
+    pub const DEFAULT_REFILL_APPROVAL_THRESHOLD: u64 = 250_000_000_000; // $250k
+
+    /// Whether a tier refill of `amount` needs governance approval
+    pub fn refill_requires_approval(&self, amount: u64) -> bool {
+        amount > self.refill_approval_threshold
+    }
+
+    /// Combined admin + reserve margin loading (bps)
+    pub fn total_loading_bps(&self) -> u32 {
+        self.admin_load_bps as u32 + self.reserve_margin_bps as u32
+    }
+
+    /// MLR implied by the configured loading (10000 - loading)
+    pub fn implied_mlr_bps(&self) -> u16 {
+        10000u32.saturating_sub(self.total_loading_bps()) as u16
+    }
+
+    /// Admin + reserve margin must fit inside the 10% loading cap (90% MLR)
+    pub fn validate_loading(&self) -> Result<()> {
+        require!(
+            self.total_loading_bps() <= actuarial::MAX_TOTAL_LOADING_BPS as u32,
+            ReserveError::LoadingExceedsMlrCap
+        );
+        Ok(())
+    }
+
+    /// Whether governance has set a fixed contribution split
+    pub fn has_fixed_routing(&self) -> bool {
+        self.tier0_route_bps > 0
+            || self.tier1_route_bps > 0
+            || self.tier2_route_bps > 0
+            || self.admin_route_bps > 0
+    }
+
+    /// A fixed split must cover the whole contribution and keep the admin
+    /// share within the configured admin load. All zero clears the fixed
+    /// split and returns to deficit routing.
+    pub fn validate_routing_ratios(
+        &self,
+        tier0_bps: u16,
+        tier1_bps: u16,
+        tier2_bps: u16,
+        admin_bps: u16,
+    ) -> Result<()> {
+        let sum = tier0_bps as u32 + tier1_bps as u32 + tier2_bps as u32 + admin_bps as u32;
+        if sum == 0 {
+            return Ok(());
+        }
+        require!(sum == 10000, ReserveError::InvalidRoutingRatios);
+        require!(
+            admin_bps <= self.admin_load_bps,
+            ReserveError::AdminLoadExceeded
+        );
+        Ok(())
+    }
+
+    /// Split `total` by the fixed routing ratios. Rounding dust goes to
+    /// Tier 0 so the parts always sum to `total`.
+    pub fn fixed_routing(&self, total: u64) -> ContributionRouting {
+        let share = |bps: u16| (total as u128 * bps as u128 / 10000) as u64;
+        let to_tier1 = share(self.tier1_route_bps);
+        let to_tier2 = share(self.tier2_route_bps);
+        let to_admin = share(self.admin_route_bps);
+
+        ContributionRouting {
+            to_tier0: total - to_tier1 - to_tier2 - to_admin,
+            to_tier1,
+            to_tier2,
+            to_admin,
+            total,
+        }
+    }
+
+    /// Maximum admin portion allowed for a contribution of `total`
+    pub fn max_admin_amount(&self, total: u64) -> u64 {
+        total
+            .saturating_mul(self.admin_load_bps as u64)
+            .checked_div(10000)
+            .unwrap_or(0)
+    }
 }
 
 /// Reserve state - tracks current balances and computed metrics
@@ -120,6 +220,15 @@ pub struct ReserveState {
     /// Current computed reserve coverage ratio (bps)
     pub current_coverage_ratio_bps: u16,
 
+    /// Financial snapshots taken (next snapshot index)
+    pub financial_snapshot_count: u64,
+
+    /// Admin load routed to the admin vault (all-time)
+    pub admin_load_collected: u64,
+
+    /// Admin funds swept to operations (all-time)
+    pub admin_swept: u64,
+
     /// Bump seed
     pub bump: u8,
 }
@@ -136,6 +245,55 @@ impl ReserveState {
             .saturating_add(self.tier2_balance)
     }
 
+    /// Admin load collected but not yet swept to operations
+    pub fn unswept_admin_load(&self) -> u64 {
+        self.admin_load_collected.saturating_sub(self.admin_swept)
+    }
+
+    /// Record a sweep of `amount` from the admin vault. Fails if it would
+    /// exceed the admin load collected so far.
+    pub fn record_admin_sweep(&mut self, amount: u64) -> Result<()> {
+        require!(
+            amount <= self.unswept_admin_load(),
+            ReserveError::InsufficientAdminLoad
+        );
+        self.admin_swept = self.admin_swept.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Credit a deposit to the given tier, returning the new tier balance
+    pub fn credit_tier(&mut self, tier: TierTarget, amount: u64) -> u64 {
+        let balance = match tier {
+            TierTarget::Tier0 => &mut self.tier0_balance,
+            TierTarget::Tier1 => &mut self.tier1_balance,
+            TierTarget::Tier2 => &mut self.tier2_balance,
+        };
+        *balance = balance.saturating_add(amount);
+        *balance
+    }
+
+    /// Overwrite the cached tier and run-off balances with the actual vault
+    /// balances, returning how far each cached value had drifted
+    pub fn reconcile_balances(
+        &mut self,
+        tier0_actual: u64,
+        tier1_actual: u64,
+        tier2_actual: u64,
+        runoff_actual: u64,
+    ) -> BalanceDrift {
+        let drift = BalanceDrift {
+            tier0: BalanceDrift::delta(self.tier0_balance, tier0_actual),
+            tier1: BalanceDrift::delta(self.tier1_balance, tier1_actual),
+            tier2: BalanceDrift::delta(self.tier2_balance, tier2_actual),
+            runoff: BalanceDrift::delta(self.runoff_balance, runoff_actual),
+        };
+        self.tier0_balance = tier0_actual;
+        self.tier1_balance = tier1_actual;
+        self.tier2_balance = tier2_actual;
+        self.runoff_balance = runoff_actual;
+        drift
+    }
+
     /// Compute IBNR using formula: avg_daily_claims * reporting_lag * dev_factor
     pub fn compute_ibnr(&self) -> u64 {
         let base = self
@@ -145,6 +303,12 @@ impl ReserveState {
         base.saturating_mul(self.development_factor_bps as u64) / 10000
     }
 
+    /// Get required Tier 0 reserve based on config
+    pub fn required_tier0(&self, config: &ReserveConfig) -> u64 {
+        self.expected_daily_claims
+            .saturating_mul(config.tier0_target_days as u64)
+    }
+
     /// Get required Tier 1 reserve based on config
     pub fn required_tier1(&self, config: &ReserveConfig) -> u64 {
         let base_requirement = self
@@ -152,6 +316,137 @@ impl ReserveState {
             .saturating_mul(config.tier1_target_days as u64);
         base_requirement.saturating_add(self.ibnr_usdc)
     }
+
+    /// Total required reserves: each tier's day-target times expected daily
+    /// claims, plus the run-off target (IBNR + wind-down costs)
+    pub fn required_total_reserves(&self, config: &ReserveConfig, runoff: &RunoffState) -> u64 {
+        let target_days = (config.tier0_target_days as u64)
+            .saturating_add(config.tier1_target_days as u64)
+            .saturating_add(config.tier2_target_days as u64);
+        self.expected_daily_claims
+            .saturating_mul(target_days)
+            .saturating_add(runoff.required_runoff_reserve(self.ibnr_usdc))
+    }
+
+    /// Per-tier targets, total requirement, and implied coverage ratio
+    pub fn reserve_requirements(
+        &self,
+        config: &ReserveConfig,
+        runoff: &RunoffState,
+    ) -> ReserveRequirements {
+        let required_total = self.required_total_reserves(config, runoff);
+        let actual_reserves = self.total_reserves();
+
+        ReserveRequirements {
+            tier0_target: self.required_tier0(config),
+            tier1_target: self
+                .expected_daily_claims
+                .saturating_mul(config.tier1_target_days as u64),
+            tier2_target: self
+                .expected_daily_claims
+                .saturating_mul(config.tier2_target_days as u64),
+            ibnr: self.ibnr_usdc,
+            runoff_costs: runoff.winddown_costs(),
+            required_total,
+            actual_reserves,
+            coverage_ratio_bps: protocol_constants::ratio_bps(actual_reserves, required_total),
+        }
+    }
+
+    /// Reserves needed against a year of expected claims plus IBNR
+    pub fn required_reserves(&self) -> u64 {
+        self.expected_daily_claims
+            .saturating_mul(365)
+            .saturating_add(self.ibnr_usdc)
+    }
+
+    /// Coverage ratio = total_reserves * 10000 / required_reserves
+    pub fn compute_coverage_ratio(&self) -> u16 {
+        protocol_constants::ratio_bps(self.total_reserves(), self.required_reserves())
+    }
+
+    /// Recompute and store the coverage ratio, returning the previous value
+    pub fn refresh_coverage_ratio(&mut self) -> u16 {
+        let old_ratio = self.current_coverage_ratio_bps;
+        self.current_coverage_ratio_bps = self.compute_coverage_ratio();
+        old_ratio
+    }
+
+    /// Realized MLR: claims paid / contributions net of the admin load
+    pub fn compute_mlr(&self, config: &ReserveConfig) -> MlrReport {
+        let net_premiums = self
+            .total_contributions_received
+            .saturating_sub(config.max_admin_amount(self.total_contributions_received));
+
+        let mlr_bps = protocol_constants::ratio_bps(self.total_claims_paid, net_premiums);
+
+        // No premiums yet means nothing to report against
+        let has_premiums = net_premiums > 0;
+
+        MlrReport {
+            claims_paid: self.total_claims_paid,
+            net_premiums,
+            mlr_bps,
+            below_target: has_premiums && mlr_bps < actuarial::MIN_MLR_BPS,
+            below_aca_floor: has_premiums && mlr_bps < actuarial::ACA_MIN_MLR_SMALL_BPS,
+        }
+    }
+}
+
+/// Realized Medical Loss Ratio (returned by `compute_mlr`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MlrReport {
+    /// Total claims paid all-time
+    pub claims_paid: u64,
+    /// Contributions received net of the admin load
+    pub net_premiums: u64,
+    /// Claims paid / net premiums (bps)
+    pub mlr_bps: u16,
+    /// Below Apollo's 90% MLR requirement
+    pub below_target: bool,
+    /// Below the ACA individual/small-group floor (80%)
+    pub below_aca_floor: bool,
+}
+
+/// Actual minus cached balance per vault, found by `reconcile_reserve_balances`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BalanceDrift {
+    pub tier0: i64,
+    pub tier1: i64,
+    pub tier2: i64,
+    pub runoff: i64,
+}
+
+impl BalanceDrift {
+    /// `actual - cached`, saturating at the i64 range
+    fn delta(cached: u64, actual: u64) -> i64 {
+        (actual as i128 - cached as i128).clamp(i64::MIN as i128, i64::MAX as i128) as i64
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Required vs. actual reserves (returned by `get_required_reserves`)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReserveRequirements {
+    /// Tier 0 day-target times expected daily claims
+    pub tier0_target: u64,
+    /// Tier 1 day-target times expected daily claims (excluding IBNR)
+    pub tier1_target: u64,
+    /// Tier 2 day-target times expected daily claims
+    pub tier2_target: u64,
+    /// IBNR reserve
+    pub ibnr: u64,
+    /// Run-off wind-down costs (admin + legal) on top of IBNR
+    pub runoff_costs: u64,
+    /// Sum of the tier targets, IBNR and run-off costs
+    pub required_total: u64,
+    /// Tier 0 + Tier 1 + Tier 2 balances
+    pub actual_reserves: u64,
+    /// Actual / required (bps)
+    pub coverage_ratio_bps: u16,
 }
 
 /// Vault authority PDA - controls all reserve token accounts
@@ -183,6 +478,23 @@ pub struct VaultAuthority {
 
 impl VaultAuthority {
     pub const SEED_PREFIX: &'static [u8] = b"vault_authority";
+
+    /// Vault token account backing the given tier
+    pub fn tier_vault(&self, tier: TierTarget) -> Pubkey {
+        match tier {
+            TierTarget::Tier0 => self.tier0_vault,
+            TierTarget::Tier1 => self.tier1_vault,
+            TierTarget::Tier2 => self.tier2_vault,
+        }
+    }
+}
+
+/// Reserve tier selector for direct deposits
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub enum TierTarget {
+    Tier0,
+    Tier1,
+    Tier2,
 }
 
 /// Run-off state for wind-down scenarios
@@ -216,12 +528,32 @@ impl RunoffState {
     pub const SEED_PREFIX: &'static [u8] = b"runoff_state";
     pub const DEFAULT_WINDDOWN_MONTHS: u8 = 6;
 
+    /// Run-off claims window: claims incurred before activation may be
+    /// paid for `RUNOFF_IBNR_DAYS` afterwards
+    pub fn runoff_claims_window_end(&self) -> i64 {
+        self.runoff_activated_at.saturating_add(
+            actuarial::RUNOFF_IBNR_DAYS as i64 * protocol_constants::SECONDS_PER_DAY,
+        )
+    }
+
+    /// Whether a claim with `service_date` can be paid from the run-off
+    /// reserve at `current_time`
+    pub fn is_runoff_payable(&self, service_date: i64, current_time: i64) -> bool {
+        self.runoff_active
+            && service_date < self.runoff_activated_at
+            && current_time <= self.runoff_claims_window_end()
+    }
+
     /// Calculate total required run-off reserve
     pub fn required_runoff_reserve(&self, ibnr: u64) -> u64 {
+        ibnr.saturating_add(self.winddown_costs())
+    }
+
+    /// Admin costs over the wind-down period plus legal costs
+    pub fn winddown_costs(&self) -> u64 {
         let admin_total =
             (self.monthly_admin_costs as u64).saturating_mul(self.winddown_months as u64);
-        ibnr.saturating_add(admin_total)
-            .saturating_add(self.estimated_legal_costs)
+        admin_total.saturating_add(self.estimated_legal_costs)
     }
 }
 
@@ -251,12 +583,79 @@ pub struct IbnrParams {
     /// Number of claims in sample
     pub sample_size: u32,
 
+    /// Age-bucketed development triangle (empty = single-factor mode)
+    #[max_len(4)]
+    pub development_buckets: Vec<IbnrDevelopmentBucket>,
+
     /// Bump seed
     pub bump: u8,
 }
 
 impl IbnrParams {
     pub const SEED_PREFIX: &'static [u8] = b"ibnr_params";
+
+    /// Maximum number of claim-age buckets
+    pub const MAX_DEVELOPMENT_BUCKETS: usize = 4;
+
+    /// Bucketed mode applies once a development triangle is configured
+    pub fn is_bucketed(&self) -> bool {
+        !self.development_buckets.is_empty()
+    }
+
+    /// Buckets must strictly increase in age, and no band can be more than
+    /// fully unreported
+    pub fn validate_buckets(buckets: &[IbnrDevelopmentBucket]) -> Result<()> {
+        require!(
+            buckets.len() <= Self::MAX_DEVELOPMENT_BUCKETS,
+            ReserveError::InvalidIbnrParams
+        );
+        let mut prev_age = 0u16;
+        for bucket in buckets {
+            require!(
+                bucket.max_age_days > prev_age,
+                ReserveError::InvalidIbnrParams
+            );
+            require!(
+                bucket.development_factor_bps <= 10000,
+                ReserveError::InvalidIbnrParams
+            );
+            prev_age = bucket.max_age_days;
+        }
+        Ok(())
+    }
+
+    /// IBNR for the given daily claims rate.
+    /// Single-factor: avg_daily * reporting_lag * dev_factor.
+    /// Bucketed: sum over buckets of avg_daily * bucket_days * bucket_factor,
+    /// where each factor is the share of that age band still unreported.
+    pub fn compute_ibnr(&self, avg_daily: u64) -> u64 {
+        if !self.is_bucketed() {
+            return avg_daily
+                .saturating_mul(self.observed_reporting_lag as u64)
+                .saturating_mul(self.development_factor_bps as u64)
+                / 10000;
+        }
+
+        let mut prev_age = 0u16;
+        self.development_buckets.iter().fold(0u64, |total, bucket| {
+            let days = bucket.max_age_days.saturating_sub(prev_age) as u64;
+            prev_age = bucket.max_age_days;
+            let incurred = avg_daily.saturating_mul(days);
+            total.saturating_add(
+                incurred.saturating_mul(bucket.development_factor_bps as u64) / 10000,
+            )
+        })
+    }
+}
+
+/// One claim-age band of the IBNR development triangle
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct IbnrDevelopmentBucket {
+    /// Oldest claim age (days since incurred) in this band; the band starts
+    /// after the previous bucket's `max_age_days`
+    pub max_age_days: u16,
+    /// Share of claims incurred in this band not yet reported (bps)
+    pub development_factor_bps: u16,
 }
 
 /// Waterfall execution log - tracks payout sources
@@ -284,6 +683,44 @@ pub struct ContributionRouting {
     pub total: u64,
 }
 
+impl ContributionRouting {
+    /// Check a split against invariants independent of how it was computed:
+    /// the parts add up to the contribution, the admin portion stays within
+    /// the configured admin load, a fixed split's ratios sum to 10000 bps,
+    /// and deficit routing sends nothing to Tier 2 while Tier 0 or Tier 1
+    /// is left below target. `state` holds the balances before the split.
+    pub fn validate_against(&self, config: &ReserveConfig, state: &ReserveState) -> Result<()> {
+        let sum = self.to_tier0 as u128
+            + self.to_tier1 as u128
+            + self.to_tier2 as u128
+            + self.to_admin as u128;
+        require!(sum == self.total as u128, ReserveError::RoutingMismatch);
+        require!(
+            self.to_admin <= config.max_admin_amount(self.total),
+            ReserveError::AdminLoadExceeded
+        );
+
+        if config.has_fixed_routing() {
+            return config.validate_routing_ratios(
+                config.tier0_route_bps,
+                config.tier1_route_bps,
+                config.tier2_route_bps,
+                config.admin_route_bps,
+            );
+        }
+
+        if self.to_tier2 > 0 {
+            require!(
+                state.tier0_balance.saturating_add(self.to_tier0) >= state.required_tier0(config)
+                    && state.tier1_balance.saturating_add(self.to_tier1)
+                        >= state.required_tier1(config),
+                ReserveError::TierTargetBypassed
+            );
+        }
+        Ok(())
+    }
+}
+
 // =============================================================================
 // REINSURANCE CONFIGURATION
 // Critical at bootstrap scale for variance smoothing
@@ -360,6 +797,15 @@ impl ReinsuranceConfig {
     pub const STANDARD_AGGREGATE_TRIGGER_BPS: u16 = 11000; // 110%
     pub const STANDARD_AGGREGATE_CEILING_BPS: u16 = 15000; // 150%
 
+    /// Specific stop-loss attachment for the protocol's bootstrap mode
+    pub fn default_specific_attachment(bootstrap_active: bool) -> u64 {
+        if bootstrap_active {
+            Self::BOOTSTRAP_SPECIFIC_ATTACHMENT
+        } else {
+            Self::STANDARD_SPECIFIC_ATTACHMENT
+        }
+    }
+
     /// Check if a claim triggers specific stop-loss
     pub fn triggers_specific(&self, claim_amount: u64) -> bool {
         claim_amount > self.specific_attachment
@@ -548,19 +994,196 @@ impl CohortMetrics {
 
     /// Update loss ratio calculation
     pub fn update_loss_ratio(&mut self) {
-        if self.total_premiums == 0 {
-            self.loss_ratio_bps = 0;
-            return;
+        self.loss_ratio_bps = protocol_constants::ratio_bps(self.total_claims, self.total_premiums);
+        self.flagged = self.loss_ratio_bps > Self::LOSS_RATIO_ALERT_BPS;
+    }
+}
+
+// =============================================================================
+// FINANCIAL SNAPSHOT - Point-in-time audit reconciliation
+// =============================================================================
+
+/// Contributions, reserves, claims, and reinsurance recoveries at one point in time
+/// PDA seeds: ["financial_snapshot", index]
+#[account]
+#[derive(InitSpace)]
+pub struct FinancialSnapshot {
+    /// Monotonic snapshot index
+    pub index: u64,
+
+    /// Total contributions received all-time
+    pub total_contributions_received: u64,
+
+    /// Tier 0 balance
+    pub tier0_balance: u64,
+
+    /// Tier 1 balance
+    pub tier1_balance: u64,
+
+    /// Tier 2 balance
+    pub tier2_balance: u64,
+
+    /// Run-off reserve balance
+    pub runoff_balance: u64,
+
+    /// Tier 0 + Tier 1 + Tier 2
+    pub total_reserves: u64,
+
+    /// Total claims paid all-time
+    pub total_claims_paid: u64,
+
+    /// Reinsurance recoveries received this policy year
+    pub ytd_recoveries_received: u64,
+
+    /// Reinsurance policy year the recoveries belong to
+    pub policy_year_start: i64,
+
+    /// Who took the snapshot
+    pub taken_by: Pubkey,
+
+    /// Snapshot timestamp
+    pub taken_at: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl FinancialSnapshot {
+    pub const SEED_PREFIX: &'static [u8] = b"financial_snapshot";
+
+    /// Copy reserve totals and the reinsurance YTD recoveries
+    pub fn capture(
+        &mut self,
+        index: u64,
+        state: &ReserveState,
+        ytd_recoveries_received: u64,
+        policy_year_start: i64,
+    ) {
+        self.index = index;
+        self.total_contributions_received = state.total_contributions_received;
+        self.tier0_balance = state.tier0_balance;
+        self.tier1_balance = state.tier1_balance;
+        self.tier2_balance = state.tier2_balance;
+        self.runoff_balance = state.runoff_balance;
+        self.total_reserves = state.total_reserves();
+        self.total_claims_paid = state.total_claims_paid;
+        self.ytd_recoveries_received = ytd_recoveries_received;
+        self.policy_year_start = policy_year_start;
+    }
+}
+
+/// Staked APH liquidated to cover reserve shortfalls, for the monthly cap
+/// PDA seeds: ["emergency_capital"]
+#[account]
+#[derive(InitSpace)]
+pub struct EmergencyCapitalState {
+    /// Start of the current 30-day liquidation period
+    pub period_start: i64,
+
+    /// Total staked APH when the period began (cap base)
+    pub staked_at_period_start: u64,
+
+    /// APH slashed for reserves this period
+    pub aph_slashed_this_period: u64,
+
+    /// USDC requested from staking all-time
+    pub total_usdc_requested: u64,
+
+    /// APH slashed for reserves all-time
+    pub total_aph_slashed: u64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl EmergencyCapitalState {
+    pub const SEED_PREFIX: &'static [u8] = b"emergency_capital";
+
+    /// Start a new period once the last one has run its course
+    pub fn roll_period(&mut self, current_time: i64, total_staked: u64) {
+        if self.period_start == 0
+            || current_time >= self.period_start + protocol_constants::SECONDS_PER_MONTH
+        {
+            self.period_start = current_time;
+            self.staked_at_period_start = total_staked;
+            self.aph_slashed_this_period = 0;
         }
+    }
 
-        let ratio = self
-            .total_claims
-            .saturating_mul(10000)
-            .checked_div(self.total_premiums)
-            .unwrap_or(0);
+    /// APH that can still be liquidated this period
+    /// (`LIQUIDATION_MONTHLY_CAP_BPS` of the staked APH at period start)
+    pub fn remaining_cap_aph(&self) -> u64 {
+        let cap = (self.staked_at_period_start as u128
+            * actuarial::LIQUIDATION_MONTHLY_CAP_BPS as u128
+            / 10000) as u64;
+        cap.saturating_sub(self.aph_slashed_this_period)
+    }
 
-        self.loss_ratio_bps = ratio.min(u16::MAX as u64) as u16;
-        self.flagged = self.loss_ratio_bps > Self::LOSS_RATIO_ALERT_BPS;
+    /// Record a completed slash
+    pub fn record_slash(&mut self, usdc_requested: u64, aph_slashed: u64) {
+        self.aph_slashed_this_period = self.aph_slashed_this_period.saturating_add(aph_slashed);
+        self.total_aph_slashed = self.total_aph_slashed.saturating_add(aph_slashed);
+        self.total_usdc_requested = self.total_usdc_requested.saturating_add(usdc_requested);
+    }
+}
+
+/// Claims program ID. Not a crate dependency (claims depends on reserves),
+/// so claim accounts are read through `ClaimPayable`.
+pub const CLAIMS_PROGRAM_ID: Pubkey = pubkey!("J65pg6g7caJvSvfGBsuwzzYiyxR1EJePP1NGuaPqRK6C");
+
+/// Membership program ID. Not a crate dependency (membership depends on
+/// reserves); its global config PDA signs member refund payouts.
+pub const MEMBERSHIP_PROGRAM_ID: Pubkey = pubkey!("CHJ4Bdc9wqKy6pjSiC3URjs53iDQpn58MPeAgLQVqRW1");
+
+/// Seed of the membership program's global config PDA
+pub const MEMBERSHIP_CONFIG_SEED: &[u8] = b"global_config";
+
+/// Payable view of a claims-program `ClaimAccount`
+/// PDA seeds (claims program): ["claim", claim_id]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ClaimPayable {
+    pub claim_id: u64,
+    pub approved_amount: u64,
+    pub paid_amount: u64,
+}
+
+impl ClaimPayable {
+    pub const SEED_PREFIX: &'static [u8] = b"claim";
+
+    /// `ClaimStatus::Approved` and `ClaimStatus::PaymentPending`
+    const PAYABLE_STATUSES: [u8; 2] = [4, 5];
+
+    /// Read an approved, not fully paid claim from its account data. The
+    /// layout is the claims program's `ClaimAccount` prefix: discriminator,
+    /// claim_id, member, provider (Option), category, requested, approved,
+    /// paid, status.
+    pub fn from_account_data(data: &[u8]) -> Option<Self> {
+        let read_u64 = |at: usize| -> Option<u64> {
+            Some(u64::from_le_bytes(data.get(at..at + 8)?.try_into().ok()?))
+        };
+
+        let claim_id = read_u64(8)?;
+        let mut offset = 8 + 8 + 32;
+        offset += match *data.get(offset)? {
+            0 => 1,
+            1 => 1 + 32,
+            _ => return None,
+        };
+        offset += 1; // category
+        let approved_amount = read_u64(offset + 8)?;
+        let paid_amount = read_u64(offset + 16)?;
+        let status = *data.get(offset + 24)?;
+
+        Self::PAYABLE_STATUSES.contains(&status).then_some(Self {
+            claim_id,
+            approved_amount,
+            paid_amount,
+        })
+    }
+
+    /// Approved amount not yet paid out
+    pub fn unpaid_amount(&self) -> u64 {
+        self.approved_amount.saturating_sub(self.paid_amount)
     }
 }
 
@@ -587,10 +1210,147 @@ mod tests {
             last_waterfall_at: 0,
             last_ibnr_computed_at: 0,
             current_coverage_ratio_bps: 16000, // 160%
+            financial_snapshot_count: 0,
+            admin_load_collected: 0,
+            admin_swept: 0,
             bump: 255,
         }
     }
 
+    #[test]
+    fn test_reconcile_corrects_injected_discrepancy() {
+        let mut state = create_test_reserve_state();
+        // A transfer bypassed state: $25k left Tier 0, $10k landed in Tier 2
+        let tier0_actual = state.tier0_balance - 25_000_000_000;
+        let tier2_actual = state.tier2_balance + 10_000_000_000;
+
+        let drift = state.reconcile_balances(
+            tier0_actual,
+            state.tier1_balance,
+            tier2_actual,
+            state.runoff_balance,
+        );
+
+        assert!(!drift.is_zero());
+        assert_eq!(drift.tier0, -25_000_000_000);
+        assert_eq!(drift.tier1, 0);
+        assert_eq!(drift.tier2, 10_000_000_000);
+        assert_eq!(drift.runoff, 0);
+        assert_eq!(state.tier0_balance, tier0_actual);
+        assert_eq!(state.tier2_balance, tier2_actual);
+    }
+
+    #[test]
+    fn test_reconcile_in_sync_reports_no_drift() {
+        let mut state = create_test_reserve_state();
+        let before = (state.tier0_balance, state.runoff_balance);
+
+        let drift = state.reconcile_balances(
+            state.tier0_balance,
+            state.tier1_balance,
+            state.tier2_balance,
+            state.runoff_balance,
+        );
+
+        assert!(drift.is_zero());
+        assert_eq!((state.tier0_balance, state.runoff_balance), before);
+    }
+
+    #[test]
+    fn test_admin_sweep_bounded_by_collected_load() {
+        let mut state = create_test_reserve_state();
+        state.admin_load_collected = 80_000_000; // $80 of admin load
+
+        state.record_admin_sweep(50_000_000).unwrap();
+        assert_eq!(state.admin_swept, 50_000_000);
+        assert_eq!(state.unswept_admin_load(), 30_000_000);
+
+        // Sweeping more than what remains fails and changes nothing
+        assert!(state.record_admin_sweep(30_000_001).is_err());
+        assert_eq!(state.admin_swept, 50_000_000);
+
+        state.record_admin_sweep(30_000_000).unwrap();
+        assert_eq!(state.unswept_admin_load(), 0);
+        assert!(state.record_admin_sweep(1).is_err());
+    }
+
+    #[test]
+    fn test_financial_snapshot_captures_consistent_totals() {
+        let mut state = create_test_reserve_state();
+        let mut snapshots = Vec::new();
+
+        for recoveries in [0u64, 750_000_000_000] {
+            let index = state.financial_snapshot_count;
+            let mut snapshot = FinancialSnapshot {
+                index: 0,
+                total_contributions_received: 0,
+                tier0_balance: 0,
+                tier1_balance: 0,
+                tier2_balance: 0,
+                runoff_balance: 0,
+                total_reserves: 0,
+                total_claims_paid: 0,
+                ytd_recoveries_received: 0,
+                policy_year_start: 0,
+                taken_by: Pubkey::default(),
+                taken_at: 0,
+                bump: 255,
+            };
+            snapshot.capture(index, &state, recoveries, 1_767_225_600);
+            state.financial_snapshot_count += 1;
+            snapshots.push(snapshot);
+
+            // Claims paid between snapshots
+            state.total_claims_paid += 400_000_000_000;
+            state.tier0_balance -= 400_000_000_000;
+        }
+
+        let (first, second) = (&snapshots[0], &snapshots[1]);
+        assert_eq!((first.index, second.index), (0, 1));
+        assert_eq!(
+            first.total_reserves,
+            first.tier0_balance + first.tier1_balance + first.tier2_balance
+        );
+        assert_eq!(first.total_contributions_received, 60_000_000_000_000);
+        assert_eq!(first.total_claims_paid, 50_000_000_000_000);
+
+        // The $400k paid out shows up as claims paid and as a lower Tier 0
+        assert_eq!(
+            second.total_claims_paid - first.total_claims_paid,
+            first.total_reserves - second.total_reserves
+        );
+        assert_eq!(second.ytd_recoveries_received, 750_000_000_000);
+    }
+
+    #[test]
+    fn test_raising_expected_claims_lowers_coverage_ratio() {
+        let mut state = create_test_reserve_state();
+        state.ibnr_usdc = 0;
+        state.expected_daily_claims = 40_000_000_000; // $40k/day → $14.6M/yr
+
+        state.refresh_coverage_ratio();
+        // $16M reserves / $14.6M required
+        assert_eq!(state.current_coverage_ratio_bps, 10958);
+
+        state.expected_daily_claims = 80_000_000_000; // doubled
+        let old = state.refresh_coverage_ratio();
+        assert_eq!(old, 10958);
+        assert_eq!(state.current_coverage_ratio_bps, 5479);
+
+        // IBNR adds to the requirement too
+        state.ibnr_usdc = state.compute_ibnr();
+        state.refresh_coverage_ratio();
+        assert!(state.current_coverage_ratio_bps < 5479);
+    }
+
+    #[test]
+    fn test_coverage_ratio_zero_requirement() {
+        let mut state = create_test_reserve_state();
+        state.expected_daily_claims = 0;
+        state.ibnr_usdc = 0;
+        assert_eq!(state.compute_coverage_ratio(), 0);
+    }
+
     #[test]
     fn test_total_reserves() {
         let state = create_test_reserve_state();
@@ -612,6 +1372,19 @@ mod tests {
         assert_eq!(total, u64::MAX);
     }
 
+    #[test]
+    fn test_credit_tier_updates_only_target_tier() {
+        let mut state = create_test_reserve_state();
+
+        // $250k recovery into the default Tier 1
+        let balance = state.credit_tier(TierTarget::Tier1, 250_000_000_000);
+        assert_eq!(balance, 5_250_000_000_000);
+        assert_eq!(state.tier1_balance, 5_250_000_000_000);
+        assert_eq!(state.tier0_balance, 1_000_000_000_000);
+        assert_eq!(state.tier2_balance, 10_000_000_000_000);
+        assert_eq!(state.total_reserves(), 16_250_000_000_000);
+    }
+
     #[test]
     fn test_compute_ibnr() {
         let state = create_test_reserve_state();
@@ -641,6 +1414,300 @@ mod tests {
         assert_eq!(ibnr, 0);
     }
 
+    fn create_test_ibnr_params() -> IbnrParams {
+        IbnrParams {
+            avg_daily_claims_30d: 100_000_000_000, // $100k/day
+            avg_daily_claims_90d: 100_000_000_000,
+            observed_reporting_lag: 21,
+            development_factor_bps: 11500,
+            claims_std_dev: 0,
+            last_updated: 0,
+            sample_size: 0,
+            development_buckets: vec![],
+            bump: 255,
+        }
+    }
+
+    fn bucket(max_age_days: u16, development_factor_bps: u16) -> IbnrDevelopmentBucket {
+        IbnrDevelopmentBucket {
+            max_age_days,
+            development_factor_bps,
+        }
+    }
+
+    #[test]
+    fn test_single_factor_vs_bucketed_ibnr() {
+        let mut params = create_test_ibnr_params();
+        let avg_daily = params.avg_daily_claims_30d;
+
+        // Single-factor default matches the reserve state formula
+        assert!(!params.is_bucketed());
+        let single = params.compute_ibnr(avg_daily);
+        assert_eq!(single, create_test_reserve_state().compute_ibnr());
+        assert_eq!(single, 2_415_000_000_000); // $2.415M
+
+        // 0-30d: 60% unreported, 31-60d: 15%, 61-90d: 5%
+        // $3M * 0.60 + $3M * 0.15 + $3M * 0.05 = $2.4M
+        params.development_buckets = vec![bucket(30, 6000), bucket(60, 1500), bucket(90, 500)];
+        assert!(params.is_bucketed());
+        let bucketed = params.compute_ibnr(avg_daily);
+        assert_eq!(bucketed, 2_400_000_000_000);
+
+        // A slower-reporting tail raises bucketed IBNR past the single factor,
+        // which only looks at the 21-day lag window
+        params.development_buckets = vec![bucket(30, 6000), bucket(60, 3000), bucket(90, 1500)];
+        let slow_tail = params.compute_ibnr(avg_daily);
+        assert_eq!(slow_tail, 3_150_000_000_000);
+        assert!(slow_tail > single);
+
+        // Clearing the triangle restores single-factor mode
+        params.development_buckets.clear();
+        assert_eq!(params.compute_ibnr(avg_daily), single);
+    }
+
+    #[test]
+    fn test_ibnr_bucket_validation() {
+        assert!(IbnrParams::validate_buckets(&[]).is_ok());
+        assert!(IbnrParams::validate_buckets(&[bucket(30, 6000), bucket(60, 1500)]).is_ok());
+
+        // Ages must strictly increase and start after day 0
+        assert!(IbnrParams::validate_buckets(&[bucket(0, 6000)]).is_err());
+        assert!(IbnrParams::validate_buckets(&[bucket(60, 6000), bucket(30, 1500)]).is_err());
+        // No band is more than fully unreported
+        assert!(IbnrParams::validate_buckets(&[bucket(30, 10001)]).is_err());
+        // At most four buckets
+        let five: Vec<_> = (1..=5).map(|i| bucket(i * 30, 1000)).collect();
+        assert!(IbnrParams::validate_buckets(&five).is_err());
+    }
+
+    fn create_test_reserve_config() -> ReserveConfig {
+        ReserveConfig {
+            authority: Pubkey::default(),
+            usdc_mint: Pubkey::default(),
+            tier0_target_days: 30,
+            tier1_target_days: 60,
+            tier2_target_days: 180,
+            min_coverage_ratio_bps: 10000,
+            target_coverage_ratio_bps: 12500,
+            reserve_margin_bps: ReserveConfig::DEFAULT_RESERVE_MARGIN_BPS,
+            admin_load_bps: ReserveConfig::DEFAULT_ADMIN_LOAD_BPS,
+            governance_program: Pubkey::default(),
+            risk_engine_program: Pubkey::default(),
+            recovery_target_tier: TierTarget::Tier1,
+            refill_approval_threshold: ReserveConfig::DEFAULT_REFILL_APPROVAL_THRESHOLD,
+            operations_account: Pubkey::default(),
+            tier0_route_bps: 0,
+            tier1_route_bps: 0,
+            tier2_route_bps: 0,
+            admin_route_bps: 0,
+            is_initialized: true,
+            bump: 255,
+            reserved: vec![],
+        }
+    }
+
+    #[test]
+    fn test_compute_mlr() {
+        let config = create_test_reserve_config();
+        let mut state = create_test_reserve_state();
+        // $1,000 contributions, 8% admin → $920 net premiums
+        state.total_contributions_received = 1_000_000_000;
+
+        state.total_claims_paid = 920_000_000;
+        let report = state.compute_mlr(&config);
+        assert_eq!(report.net_premiums, 920_000_000);
+        assert_eq!(report.mlr_bps, 10000);
+        assert!(!report.below_target);
+        assert!(!report.below_aca_floor);
+
+        state.total_claims_paid = 828_000_000; // exactly 90%
+        let report = state.compute_mlr(&config);
+        assert_eq!(report.mlr_bps, 9000);
+        assert!(!report.below_target);
+    }
+
+    #[test]
+    fn test_compute_mlr_below_thresholds() {
+        let config = create_test_reserve_config();
+        let mut state = create_test_reserve_state();
+        state.total_contributions_received = 1_000_000_000;
+
+        // 85%: under Apollo's 90% requirement, above the ACA floor
+        state.total_claims_paid = 782_000_000;
+        let report = state.compute_mlr(&config);
+        assert_eq!(report.mlr_bps, 8500);
+        assert!(report.below_target);
+        assert!(!report.below_aca_floor);
+
+        // 50%: under both
+        state.total_claims_paid = 460_000_000;
+        let report = state.compute_mlr(&config);
+        assert_eq!(report.mlr_bps, 5000);
+        assert!(report.below_target);
+        assert!(report.below_aca_floor);
+
+        // No premiums collected yet: nothing to flag
+        state.total_contributions_received = 0;
+        let report = state.compute_mlr(&config);
+        assert_eq!(report.mlr_bps, 0);
+        assert!(!report.below_target);
+    }
+
+    #[test]
+    fn test_compliant_loading_passes() {
+        let config = create_test_reserve_config();
+        let state = create_test_reserve_state();
+        assert!(config.validate_loading().is_ok());
+        assert_eq!(config.implied_mlr_bps(), 9000);
+
+        // 8% admin on a $1,000 contribution
+        let routing = ContributionRouting {
+            to_tier0: 900_000_000,
+            to_tier1: 20_000_000,
+            to_tier2: 0,
+            to_admin: 80_000_000,
+            total: 1_000_000_000,
+        };
+        assert!(routing.validate_against(&config, &state).is_ok());
+    }
+
+    #[test]
+    fn test_excessive_admin_split_rejected() {
+        let mut config = create_test_reserve_config();
+        config.admin_load_bps = 1500; // 15% admin + 2% margin = 17% loading
+        assert!(config.validate_loading().is_err());
+        assert_eq!(config.implied_mlr_bps(), 8300);
+
+        // A split taking 15% admin against an 8% configured load
+        let config = create_test_reserve_config();
+        let state = create_test_reserve_state();
+        let routing = ContributionRouting {
+            to_tier0: 830_000_000,
+            to_tier1: 20_000_000,
+            to_tier2: 0,
+            to_admin: 150_000_000,
+            total: 1_000_000_000,
+        };
+        assert!(routing.validate_against(&config, &state).is_err());
+    }
+
+    #[test]
+    fn test_fixed_routing_splits_exact_amounts() {
+        let mut config = create_test_reserve_config();
+        let state = create_test_reserve_state();
+        assert!(!config.has_fixed_routing());
+
+        // 50% / 25% / 17% / 8% admin
+        config
+            .validate_routing_ratios(5000, 2500, 1700, 800)
+            .unwrap();
+        config.tier0_route_bps = 5000;
+        config.tier1_route_bps = 2500;
+        config.tier2_route_bps = 1700;
+        config.admin_route_bps = 800;
+        assert!(config.has_fixed_routing());
+
+        let routing = config.fixed_routing(1_000_000_000); // $1,000
+        assert_eq!(routing.to_tier0, 500_000_000);
+        assert_eq!(routing.to_tier1, 250_000_000);
+        assert_eq!(routing.to_tier2, 170_000_000);
+        assert_eq!(routing.to_admin, 80_000_000);
+        assert!(routing.validate_against(&config, &state).is_ok());
+
+        // Rounding dust lands in Tier 0 and nothing is lost
+        let routing = config.fixed_routing(1_000_003);
+        assert_eq!(
+            routing.to_tier0 + routing.to_tier1 + routing.to_tier2 + routing.to_admin,
+            1_000_003
+        );
+        assert_eq!(routing.to_tier0, 500_003);
+    }
+
+    #[test]
+    fn test_routing_rejects_split_breaking_invariants() {
+        let mut config = create_test_reserve_config();
+        let state = create_test_reserve_state();
+
+        // Parts that don't add up to the contribution
+        let routing = ContributionRouting {
+            to_tier0: 900_000_000,
+            to_tier1: 20_000_000,
+            to_tier2: 0,
+            to_admin: 80_000_000,
+            total: 1_100_000_000,
+        };
+        assert!(routing.validate_against(&config, &state).is_err());
+
+        // Tier 0 is $2M short of its 30-day target, yet part of the
+        // contribution overflows to Tier 2
+        let routing = ContributionRouting {
+            to_tier0: 400_000_000,
+            to_tier1: 20_000_000,
+            to_tier2: 500_000_000,
+            to_admin: 80_000_000,
+            total: 1_000_000_000,
+        };
+        assert!(routing.validate_against(&config, &state).is_err());
+
+        // Once both lower tiers are at target the overflow is allowed
+        let mut funded = create_test_reserve_state();
+        funded.tier0_balance = funded.required_tier0(&config);
+        funded.tier1_balance = funded.required_tier1(&config);
+        assert!(routing.validate_against(&config, &funded).is_ok());
+
+        // A stored fixed split whose ratios don't cover the contribution
+        config.tier0_route_bps = 5000;
+        config.tier1_route_bps = 2500;
+        config.admin_route_bps = 800;
+        let routing = config.fixed_routing(1_000_000_000);
+        assert!(routing.validate_against(&config, &state).is_err());
+    }
+
+    #[test]
+    fn test_routing_ratios_must_total_100_percent() {
+        let config = create_test_reserve_config();
+        assert!(config
+            .validate_routing_ratios(5000, 2500, 1700, 700)
+            .is_err());
+        assert!(config
+            .validate_routing_ratios(5000, 2500, 1800, 800)
+            .is_err());
+
+        // Sums to 100% but takes more admin than the 8% admin load
+        assert!(config
+            .validate_routing_ratios(5000, 2500, 1500, 1000)
+            .is_err());
+    }
+
+    #[test]
+    fn test_zero_routing_ratios_restore_deficit_routing() {
+        let mut config = create_test_reserve_config();
+        config.tier0_route_bps = 5000;
+        config.tier1_route_bps = 2500;
+        config.tier2_route_bps = 1700;
+        config.admin_route_bps = 800;
+        assert!(config.has_fixed_routing());
+
+        assert!(config.validate_routing_ratios(0, 0, 0, 0).is_ok());
+        config.tier0_route_bps = 0;
+        config.tier1_route_bps = 0;
+        config.tier2_route_bps = 0;
+        config.admin_route_bps = 0;
+        assert!(!config.has_fixed_routing());
+    }
+
+    #[test]
+    fn test_refill_approval_threshold() {
+        let mut config = create_test_reserve_config();
+        config.refill_approval_threshold = 250_000_000_000;
+        assert!(!config.refill_requires_approval(250_000_000_000));
+        assert!(config.refill_requires_approval(250_000_000_001));
+
+        // Zero threshold routes every refill through governance
+        config.refill_approval_threshold = 0;
+        assert!(config.refill_requires_approval(1));
+    }
+
     #[test]
     fn test_required_tier1() {
         let state = create_test_reserve_state();
@@ -657,6 +1724,13 @@ mod tests {
             admin_load_bps: 800,
             governance_program: Pubkey::default(),
             risk_engine_program: Pubkey::default(),
+            recovery_target_tier: TierTarget::Tier1,
+            refill_approval_threshold: ReserveConfig::DEFAULT_REFILL_APPROVAL_THRESHOLD,
+            operations_account: Pubkey::default(),
+            tier0_route_bps: 0,
+            tier1_route_bps: 0,
+            tier2_route_bps: 0,
+            admin_route_bps: 0,
             is_initialized: true,
             bump: 255,
             reserved: vec![],
@@ -670,6 +1744,50 @@ mod tests {
         assert_eq!(required, expected);
     }
 
+    #[test]
+    fn test_required_total_reserves_sums_tier_targets_ibnr_and_runoff() {
+        let state = create_test_reserve_state();
+        let config = create_test_reserve_config();
+        let runoff = create_test_runoff_state();
+
+        // $100k/day * (30 + 60 + 180) days + $2.415M IBNR
+        // + $600k wind-down admin + $500k legal = $30.515M
+        let required = state.required_total_reserves(&config, &runoff);
+        assert_eq!(required, 30_515_000_000_000);
+
+        let report = state.reserve_requirements(&config, &runoff);
+        assert_eq!(report.tier0_target, 3_000_000_000_000);
+        assert_eq!(report.tier1_target, 6_000_000_000_000);
+        assert_eq!(report.tier2_target, 18_000_000_000_000);
+        assert_eq!(report.runoff_costs, 1_100_000_000_000);
+        assert_eq!(
+            report.tier0_target
+                + report.tier1_target
+                + report.tier2_target
+                + report.ibnr
+                + report.runoff_costs,
+            report.required_total
+        );
+        assert_eq!(report.actual_reserves, 16_000_000_000_000);
+        // $16M / $30.515M = 52.43%
+        assert_eq!(report.coverage_ratio_bps, 5243);
+    }
+
+    #[test]
+    fn test_required_total_reserves_without_expected_claims() {
+        let mut state = create_test_reserve_state();
+        let config = create_test_reserve_config();
+        state.expected_daily_claims = 0;
+        state.ibnr_usdc = 0;
+        let mut runoff = create_test_runoff_state();
+        runoff.monthly_admin_costs = 0;
+        runoff.estimated_legal_costs = 0;
+
+        let report = state.reserve_requirements(&config, &runoff);
+        assert_eq!(report.required_total, 0);
+        assert_eq!(report.coverage_ratio_bps, 0);
+    }
+
     // ==================== RUNOFF STATE TESTS ====================
 
     fn create_test_runoff_state() -> RunoffState {
@@ -722,6 +1840,36 @@ mod tests {
         assert_eq!(required, expected);
     }
 
+    #[test]
+    fn test_runoff_pays_pre_runoff_claim() {
+        let day = 24 * 60 * 60;
+        let mut state = create_test_runoff_state();
+        state.runoff_active = true;
+        state.runoff_activated_at = 1_000 * day;
+
+        // Incurred before activation, paid within the IBNR window
+        assert!(state.is_runoff_payable(990 * day, 1_010 * day));
+        assert!(state.is_runoff_payable(990 * day, 1_180 * day));
+        assert_eq!(state.runoff_claims_window_end(), 1_180 * day);
+    }
+
+    #[test]
+    fn test_runoff_rejects_post_runoff_claim() {
+        let day = 24 * 60 * 60;
+        let mut state = create_test_runoff_state();
+        state.runoff_active = true;
+        state.runoff_activated_at = 1_000 * day;
+
+        // Incurred on/after activation
+        assert!(!state.is_runoff_payable(1_000 * day, 1_010 * day));
+        assert!(!state.is_runoff_payable(1_005 * day, 1_010 * day));
+        // Submitted after the run-off window closed
+        assert!(!state.is_runoff_payable(990 * day, 1_181 * day));
+        // Run-off not active
+        state.runoff_active = false;
+        assert!(!state.is_runoff_payable(990 * day, 1_010 * day));
+    }
+
     // ==================== RESERVE CONFIG TESTS ====================
 
     #[test]
@@ -748,6 +1896,95 @@ mod tests {
         assert_eq!(RunoffState::DEFAULT_WINDDOWN_MONTHS, 6);
     }
 
+    // ==================== EMERGENCY CAPITAL TESTS ====================
+
+    fn create_test_emergency_capital() -> EmergencyCapitalState {
+        EmergencyCapitalState {
+            period_start: 0,
+            staked_at_period_start: 0,
+            aph_slashed_this_period: 0,
+            total_usdc_requested: 0,
+            total_aph_slashed: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_emergency_capital_monthly_cap() {
+        let aph = 1_000_000_000u64;
+        let mut capital = create_test_emergency_capital();
+        capital.roll_period(1_000, 10_000 * aph);
+
+        // 10% of the staked APH per month
+        assert_eq!(capital.remaining_cap_aph(), 1_000 * aph);
+
+        capital.record_slash(400_000_000, 400 * aph);
+        assert_eq!(capital.remaining_cap_aph(), 600 * aph);
+
+        // Staked balance falling mid-period does not reset the base
+        capital.roll_period(2_000, 9_600 * aph);
+        assert_eq!(capital.remaining_cap_aph(), 600 * aph);
+
+        capital.record_slash(600_000_000, 600 * aph);
+        assert_eq!(capital.remaining_cap_aph(), 0);
+    }
+
+    #[test]
+    fn test_emergency_capital_period_rolls_after_a_month() {
+        let aph = 1_000_000_000u64;
+        let mut capital = create_test_emergency_capital();
+        capital.roll_period(1_000, 10_000 * aph);
+        capital.record_slash(1_000_000_000, 1_000 * aph);
+        assert_eq!(capital.remaining_cap_aph(), 0);
+
+        capital.roll_period(1_000 + protocol_constants::SECONDS_PER_MONTH, 9_000 * aph);
+        assert_eq!(capital.remaining_cap_aph(), 900 * aph);
+        assert_eq!(capital.total_aph_slashed, 1_000 * aph);
+        assert_eq!(capital.total_usdc_requested, 1_000_000_000);
+    }
+
+    // ==================== CLAIM PAYABLE TESTS ====================
+
+    fn claim_account_data(provider: Option<Pubkey>, status: u8) -> Vec<u8> {
+        let mut data = vec![0u8; 8];
+        data.extend_from_slice(&7u64.to_le_bytes());
+        data.extend_from_slice(&[1u8; 32]);
+        match provider {
+            Some(provider) => {
+                data.push(1);
+                data.extend_from_slice(provider.as_ref());
+            }
+            None => data.push(0),
+        }
+        data.push(1); // category
+        data.extend_from_slice(&500_000_000u64.to_le_bytes()); // requested
+        data.extend_from_slice(&400_000_000u64.to_le_bytes()); // approved
+        data.extend_from_slice(&150_000_000u64.to_le_bytes()); // paid
+        data.push(status);
+        data
+    }
+
+    #[test]
+    fn test_claim_payable_reads_approved_claims() {
+        for provider in [None, Some(Pubkey::new_unique())] {
+            let claim = ClaimPayable::from_account_data(&claim_account_data(provider, 4)).unwrap();
+            assert_eq!(claim.claim_id, 7);
+            assert_eq!(claim.approved_amount, 400_000_000);
+            assert_eq!(claim.unpaid_amount(), 250_000_000);
+        }
+        assert!(ClaimPayable::from_account_data(&claim_account_data(None, 5)).is_some());
+    }
+
+    #[test]
+    fn test_claim_payable_rejects_unapproved_claims() {
+        // Submitted, Paid, Denied
+        for status in [0u8, 6, 7] {
+            assert!(ClaimPayable::from_account_data(&claim_account_data(None, status)).is_none());
+        }
+        let truncated = claim_account_data(None, 4);
+        assert!(ClaimPayable::from_account_data(&truncated[..truncated.len() - 1]).is_none());
+    }
+
     // ==================== SEED PREFIX TESTS ====================
 
     #[test]
@@ -814,10 +2051,25 @@ mod tests {
             last_waterfall_at: 0,
             last_ibnr_computed_at: 0,
             current_coverage_ratio_bps: 0,
+            financial_snapshot_count: 0,
+            admin_load_collected: 0,
+            admin_swept: 0,
             bump: 0,
         };
 
         assert_eq!(state.total_reserves(), 0);
         assert_eq!(state.compute_ibnr(), 0);
     }
+
+    #[test]
+    fn test_specific_attachment_follows_bootstrap_mode() {
+        assert_eq!(
+            ReinsuranceConfig::default_specific_attachment(true),
+            50_000_000_000 // $50k while bootstrapping
+        );
+        assert_eq!(
+            ReinsuranceConfig::default_specific_attachment(false),
+            100_000_000_000 // $100k once scaled
+        );
+    }
 }
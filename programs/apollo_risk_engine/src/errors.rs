@@ -78,4 +78,12 @@ pub enum RiskEngineError {
 
     #[msg("Risk engine is not active")]
     RiskEngineNotActive,
+
+    #[msg("ShockFactor decay period has not elapsed")]
+    ShockDecayNotDue,
+
+    #[msg(
+        "Invalid enrollment freeze: a reason is required and auto-unfreeze must be in the future"
+    )]
+    InvalidEnrollmentFreeze,
 }
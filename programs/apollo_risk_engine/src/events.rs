@@ -1,6 +1,6 @@
 // programs/apollo_risk_engine/src/events.rs
 
-use crate::state::Zone;
+use crate::state::{EnrollmentFreezeReason, Zone};
 use anchor_lang::prelude::*;
 
 /// Emitted when risk engine is initialized
@@ -64,6 +64,25 @@ pub struct ShockFactorUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted for each ShockFactor decay step toward base
+#[event]
+pub struct ShockFactorDecayed {
+    pub old_shock_factor_bps: u16,
+    pub new_shock_factor_bps: u16,
+    pub green_periods: u8,
+    pub car_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when the ShockFactor decay schedule changes
+#[event]
+pub struct ShockDecayConfigUpdated {
+    pub shock_decay_periods: u8,
+    pub shock_decay_step_bps: u16,
+    pub updater: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when enrollment caps are set
 #[event]
 pub struct EnrollmentCapsUpdated {
@@ -81,15 +100,58 @@ pub struct EnrollmentRecorded {
     pub current_zone: Zone,
     pub month_enrollments: u32,
     pub cap: u32,
+    pub remaining_capacity: u32,
+    pub timestamp: i64,
+}
+
+/// Emitted when the monthly enrollment counter rolls into a new month
+#[event]
+pub struct EnrollmentCounterReset {
+    pub previous_month_enrollments: u32,
+    pub month_start: i64,
     pub timestamp: i64,
 }
 
-/// Emitted when enrollment freeze is toggled
+/// Emitted when enrollment is frozen
 #[event]
-pub struct EnrollmentFreezeToggled {
-    pub frozen: bool,
+pub struct EnrollmentFreezeStarted {
+    pub reason: EnrollmentFreezeReason,
+    /// 0 = until lifted manually
+    pub auto_unfreeze_at: i64,
     pub zone: Zone,
-    pub toggler: Pubkey,
+    pub frozen_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when an enrollment freeze is lifted
+#[event]
+pub struct EnrollmentFreezeLifted {
+    /// Reason the freeze had been imposed for
+    pub reason: EnrollmentFreezeReason,
+    /// Lifted by `check_enrollment_freeze` after `auto_unfreeze_at`
+    pub expired: bool,
+    pub zone: Zone,
+    pub lifted_by: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Emitted when a flagged cohort tightens enrollment and ShockFactor
+#[event]
+pub struct CohortAdverseSelectionAlert {
+    pub cohort_id: u32,
+    pub loss_ratio_bps: u16,
+    pub zone: Zone,
+    pub enrollment_cap: u32,
+    pub old_shock_factor_bps: u16,
+    pub new_shock_factor_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when a cohort alert enrollment ceiling is lifted
+#[event]
+pub struct CohortAlertCleared {
+    pub previous_cap: u32,
+    pub cleared_by: Pubkey,
     pub timestamp: i64,
 }
 
@@ -99,5 +161,6 @@ pub struct ZoneThresholdsUpdated {
     pub green_threshold_bps: u16,
     pub yellow_threshold_bps: u16,
     pub orange_threshold_bps: u16,
+    pub hysteresis_bps: u16,
     pub timestamp: i64,
 }
@@ -1,8 +1,10 @@
 // programs/apollo_risk_engine/src/instructions/car.rs
 
 use crate::errors::RiskEngineError;
-use crate::events::{CarStateUpdated, ZoneTransition};
-use crate::state::{CarState, RiskConfig, Zone, ZoneState};
+use crate::events::{
+    CarStateUpdated, EnrollmentFreezeLifted, EnrollmentFreezeStarted, ZoneTransition,
+};
+use crate::state::{CarState, EnrollmentFreezeReason, RiskConfig, Zone, ZoneState};
 use anchor_lang::prelude::*;
 
 /// Update CAR state with latest data
@@ -67,26 +69,45 @@ pub fn update_car_state(ctx: Context<UpdateCarState>, params: UpdateCarStatePara
     car_state.current_car_bps = new_car;
     car_state.last_computed_at = clock.unix_timestamp;
 
-    // Determine new zone
-    let new_zone = determine_zone(new_car, zone_state);
+    // Determine new zone (hysteresis around thresholds)
+    let new_zone = zone_state.confirmed_zone(new_car);
     car_state.current_zone = new_zone;
 
-    // Update zone state if changed
+    // Update zone state only on a confirmed transition
     if new_zone != old_zone {
         zone_state.current_zone = new_zone;
         zone_state.last_zone_change_at = clock.unix_timestamp;
 
         // Handle zone-specific actions
+        // A governance freeze already in place keeps its own reason and
+        // is not lifted by leaving the Red zone
         match new_zone {
-            Zone::Red => {
-                zone_state.enrollment_frozen = true;
+            Zone::Red if !zone_state.enrollment_frozen => {
+                zone_state.freeze_enrollment(EnrollmentFreezeReason::RedZone, 0);
+
+                emit!(EnrollmentFreezeStarted {
+                    reason: EnrollmentFreezeReason::RedZone,
+                    auto_unfreeze_at: 0,
+                    zone: new_zone,
+                    frozen_by: ctx.accounts.updater.key(),
+                    timestamp: clock.unix_timestamp,
+                });
             }
-            Zone::Orange | Zone::Yellow | Zone::Green => {
-                // Enrollment may be unfrozen if coming from Red
-                if old_zone == Zone::Red {
-                    zone_state.enrollment_frozen = false;
-                }
+            Zone::Orange | Zone::Yellow | Zone::Green
+                if old_zone == Zone::Red
+                    && zone_state.freeze_reason == EnrollmentFreezeReason::RedZone =>
+            {
+                let reason = zone_state.unfreeze_enrollment();
+
+                emit!(EnrollmentFreezeLifted {
+                    reason,
+                    expired: false,
+                    zone: new_zone,
+                    lifted_by: ctx.accounts.updater.key(),
+                    timestamp: clock.unix_timestamp,
+                });
             }
+            _ => {}
         }
 
         emit!(ZoneTransition {
@@ -109,19 +130,6 @@ pub fn update_car_state(ctx: Context<UpdateCarState>, params: UpdateCarStatePara
     Ok(())
 }
 
-/// Determine zone based on CAR and zone thresholds
-fn determine_zone(car_bps: u16, zone_state: &ZoneState) -> Zone {
-    if car_bps >= zone_state.green_threshold_bps {
-        Zone::Green
-    } else if car_bps >= zone_state.yellow_threshold_bps {
-        Zone::Yellow
-    } else if car_bps >= zone_state.orange_threshold_bps {
-        Zone::Orange
-    } else {
-        Zone::Red
-    }
-}
-
 /// Force recompute CAR (anyone can call)
 #[derive(Accounts)]
 pub struct RecomputeCar<'info> {
@@ -1,7 +1,9 @@
 // programs/apollo_risk_engine/src/instructions/initialize.rs
 
 use crate::events::RiskEngineInitialized;
-use crate::state::{default_age_bands, CarState, RatingTable, RiskConfig, Zone, ZoneState};
+use crate::state::{
+    default_age_bands, CarState, EnrollmentFreezeReason, RatingTable, RiskConfig, Zone, ZoneState,
+};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -80,6 +82,10 @@ pub fn handler(
     config.min_contribution = 0;
     config.is_active = true;
     config.bump = ctx.bumps.risk_config;
+    config.shock_decay_periods = RiskConfig::DEFAULT_SHOCK_DECAY_PERIODS;
+    config.shock_decay_step_bps = RiskConfig::DEFAULT_SHOCK_DECAY_STEP_BPS;
+    config.green_periods = 0;
+    config.last_shock_decay_at = 0;
     config.reserved = vec![];
 
     // Initialize rating table with CMS-compliant defaults
@@ -113,9 +119,13 @@ pub fn handler(
     zone_state.yellow_enrollment_cap = ZoneState::YELLOW_CAP;
     zone_state.orange_enrollment_cap = ZoneState::ORANGE_CAP;
     zone_state.current_month_enrollments = 0;
-    zone_state.month_start_timestamp = clock.unix_timestamp;
+    zone_state.month_start_timestamp = ZoneState::month_start(clock.unix_timestamp);
     zone_state.enrollment_frozen = false;
     zone_state.last_zone_change_at = clock.unix_timestamp;
+    zone_state.hysteresis_bps = ZoneState::DEFAULT_HYSTERESIS_BPS;
+    zone_state.cohort_alert_cap = 0;
+    zone_state.freeze_reason = EnrollmentFreezeReason::None;
+    zone_state.auto_unfreeze_at = 0;
     zone_state.bump = ctx.bumps.zone_state;
 
     emit!(RiskEngineInitialized {
@@ -2,11 +2,18 @@
 
 use crate::errors::RiskEngineError;
 use crate::events::{
-    EnrollmentCapsUpdated, EnrollmentFreezeToggled, EnrollmentRecorded, ShockFactorUpdated,
-    ZoneThresholdsUpdated,
+    CohortAdverseSelectionAlert, CohortAlertCleared, EnrollmentCapsUpdated, EnrollmentCounterReset,
+    EnrollmentFreezeLifted, EnrollmentFreezeStarted, EnrollmentRecorded, ShockDecayConfigUpdated,
+    ShockFactorDecayed, ShockFactorUpdated, ZoneThresholdsUpdated, ZoneTransition,
+};
+use crate::state::{
+    CarState, EnrollmentFreezeReason, RiskConfig, Zone, ZoneState, MEMBERSHIP_CONFIG_SEED,
+    MEMBERSHIP_PROGRAM_ID,
 };
-use crate::state::{CarState, RiskConfig, Zone, ZoneState};
 use anchor_lang::prelude::*;
+use apollo_governance::authorization;
+use apollo_governance::program::ApolloGovernance;
+use apollo_governance::state::{AdminAction, CommitteeType, DaoConfig, Multisig, SignerSet};
 
 /// Set ShockFactor (zone-gated)
 #[derive(Accounts)]
@@ -30,6 +37,32 @@ pub struct SetShockFactor<'info> {
     )]
     pub zone_state: Account<'info, ZoneState>,
 
+    /// Governance DAO config (risk committee, emergency flag)
+    #[account(
+        seeds = [DaoConfig::SEED_PREFIX],
+        bump = dao_config.bump,
+        seeds::program = apollo_governance::ID,
+    )]
+    pub dao_config: Account<'info, DaoConfig>,
+
+    /// Risk Committee multisig (changes above the auto limit)
+    #[account(
+        constraint = dao_config.committee(CommitteeType::Risk) == Some(risk_committee.key()) @ RiskEngineError::Unauthorized
+    )]
+    pub risk_committee: Option<Account<'info, Multisig>>,
+
+    /// Signer set approving this ShockFactor, targeting the risk config
+    #[account(
+        mut,
+        constraint = signer_set.target == risk_config.key() @ RiskEngineError::ShockFactorRequiresCommittee
+    )]
+    pub signer_set: Option<Account<'info, SignerSet>>,
+
+    #[account(
+        constraint = governance_program.key() == risk_config.governance_program @ RiskEngineError::Unauthorized
+    )]
+    pub governance_program: Option<Program<'info, ApolloGovernance>>,
+
     /// Setter must have appropriate authority based on zone
     pub setter: Signer<'info>,
 }
@@ -76,17 +109,28 @@ pub fn set_shock_factor(ctx: Context<SetShockFactor>, new_shock_factor_bps: u16)
         RiskEngineError::ShockFactorExceedsZoneLimit
     );
 
-    // In production, if requires_approval is true, we would validate
-    // multisig signatures via CPI to governance program
-    // For now, we just check authority
+    // Above the auto limit a Risk Committee quorum must have approved this
+    // exact value; above the committee limit the DAO emergency flag is also
+    // required
     if requires_approval {
-        require!(
-            ctx.accounts.setter.key() == config.authority,
-            RiskEngineError::ShockFactorRequiresCommittee
-        );
+        if new_shock_factor_bps > config.max_committee_shock_factor_bps {
+            require!(
+                authorization::is_emergency_active(&ctx.accounts.dao_config, clock.unix_timestamp),
+                RiskEngineError::ShockFactorRequiresEmergency
+            );
+        }
+        consume_risk_committee_approval(
+            ctx.accounts.risk_committee.as_ref(),
+            ctx.accounts.signer_set.as_ref(),
+            ctx.accounts.governance_program.as_ref(),
+            &ctx.accounts.setter,
+            new_shock_factor_bps,
+        )?;
     }
 
     config.shock_factor_bps = new_shock_factor_bps;
+    // Decay toward base only after a fresh Green streak from this setting
+    config.green_periods = 0;
 
     emit!(ShockFactorUpdated {
         old_shock_factor_bps: old_shock,
@@ -100,6 +144,143 @@ pub fn set_shock_factor(ctx: Context<SetShockFactor>, new_shock_factor_bps: u16)
     Ok(())
 }
 
+/// Verify a Risk Committee signer set approving `new_shock_factor_bps` via
+/// governance CPI and mark it executed so it cannot be replayed
+fn consume_risk_committee_approval<'info>(
+    risk_committee: Option<&Account<'info, Multisig>>,
+    signer_set: Option<&Account<'info, SignerSet>>,
+    governance_program: Option<&Program<'info, ApolloGovernance>>,
+    setter: &Signer<'info>,
+    new_shock_factor_bps: u16,
+) -> Result<()> {
+    let (Some(multisig), Some(signer_set), Some(governance_program)) =
+        (risk_committee, signer_set, governance_program)
+    else {
+        return err!(RiskEngineError::ShockFactorRequiresCommittee);
+    };
+    require!(
+        multisig.is_signer(&setter.key()),
+        RiskEngineError::Unauthorized
+    );
+    require!(
+        signer_set.action_data == new_shock_factor_bps.to_le_bytes(),
+        RiskEngineError::ShockFactorRequiresCommittee
+    );
+
+    apollo_governance::cpi::assert_signed(
+        CpiContext::new(
+            governance_program.to_account_info(),
+            apollo_governance::cpi::accounts::AssertSigned {
+                multisig: multisig.to_account_info(),
+                signer_set: signer_set.to_account_info(),
+            },
+        ),
+        AdminAction::SetShockFactor,
+    )?;
+
+    apollo_governance::cpi::mark_executed(CpiContext::new(
+        governance_program.to_account_info(),
+        apollo_governance::cpi::accounts::MarkExecuted {
+            multisig: multisig.to_account_info(),
+            signer_set: signer_set.to_account_info(),
+            executor: setter.to_account_info(),
+        },
+    ))
+}
+
+/// Step the ShockFactor toward base after sustained Green-zone CAR
+/// (permissionless, once per decay period)
+#[derive(Accounts)]
+pub struct DecayShockFactor<'info> {
+    #[account(
+        mut,
+        seeds = [RiskConfig::SEED_PREFIX],
+        bump = risk_config.bump,
+    )]
+    pub risk_config: Account<'info, RiskConfig>,
+
+    #[account(
+        seeds = [CarState::SEED_PREFIX],
+        bump = car_state.bump,
+    )]
+    pub car_state: Account<'info, CarState>,
+
+    #[account(
+        seeds = [ZoneState::SEED_PREFIX],
+        bump = zone_state.bump,
+    )]
+    pub zone_state: Account<'info, ZoneState>,
+}
+
+pub fn decay_shock_factor(ctx: Context<DecayShockFactor>) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.risk_config;
+
+    require!(
+        config.shock_decay_due(clock.unix_timestamp),
+        RiskEngineError::ShockDecayNotDue
+    );
+
+    let old_shock = config.shock_factor_bps;
+    let zone_state = &ctx.accounts.zone_state;
+    if let Some(new_shock) = config.apply_shock_decay(
+        zone_state.current_zone,
+        zone_state.last_zone_change_at,
+        clock.unix_timestamp,
+    ) {
+        emit!(ShockFactorDecayed {
+            old_shock_factor_bps: old_shock,
+            new_shock_factor_bps: new_shock,
+            green_periods: config.green_periods,
+            car_bps: ctx.accounts.car_state.current_car_bps,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Set the ShockFactor decay schedule
+#[derive(Accounts)]
+pub struct SetShockDecay<'info> {
+    #[account(
+        mut,
+        seeds = [RiskConfig::SEED_PREFIX],
+        bump = risk_config.bump,
+    )]
+    pub risk_config: Account<'info, RiskConfig>,
+
+    #[account(
+        constraint = authority.key() == risk_config.authority @ RiskEngineError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn set_shock_decay(
+    ctx: Context<SetShockDecay>,
+    shock_decay_periods: u8,
+    shock_decay_step_bps: u16,
+) -> Result<()> {
+    require!(shock_decay_periods > 0, RiskEngineError::InvalidZoneConfig);
+    require!(
+        shock_decay_step_bps > 0 && shock_decay_step_bps <= RiskConfig::MAX_SHOCK_DECAY_STEP_BPS,
+        RiskEngineError::InvalidBasisPoints
+    );
+
+    let config = &mut ctx.accounts.risk_config;
+    config.shock_decay_periods = shock_decay_periods;
+    config.shock_decay_step_bps = shock_decay_step_bps;
+
+    emit!(ShockDecayConfigUpdated {
+        shock_decay_periods,
+        shock_decay_step_bps,
+        updater: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 /// Set enrollment caps for each zone
 #[derive(Accounts)]
 pub struct SetEnrollmentCaps<'info> {
@@ -181,25 +362,24 @@ pub fn compute_zone(ctx: Context<ComputeZone>) -> Result<Zone> {
     let zone_state = &mut ctx.accounts.zone_state;
     let clock = Clock::get()?;
 
-    let new_zone = if car >= zone_state.green_threshold_bps {
-        Zone::Green
-    } else if car >= zone_state.yellow_threshold_bps {
-        Zone::Yellow
-    } else if car >= zone_state.orange_threshold_bps {
-        Zone::Orange
-    } else {
-        Zone::Red
-    };
+    let old_zone = zone_state.current_zone;
+    let new_zone = zone_state.confirmed_zone(car);
 
     // Reset monthly counter if new month
-    const MONTH_SECONDS: i64 = 30 * 24 * 60 * 60;
-    if clock.unix_timestamp - zone_state.month_start_timestamp > MONTH_SECONDS {
-        zone_state.month_start_timestamp = clock.unix_timestamp;
-        zone_state.current_month_enrollments = 0;
+    roll_enrollment_month(zone_state, clock.unix_timestamp);
+
+    if new_zone != old_zone {
+        zone_state.current_zone = new_zone;
+        zone_state.last_zone_change_at = clock.unix_timestamp;
+
+        emit!(ZoneTransition {
+            old_zone,
+            new_zone,
+            car_bps: car,
+            timestamp: clock.unix_timestamp,
+        });
     }
 
-    zone_state.current_zone = new_zone;
-
     Ok(new_zone)
 }
 
@@ -216,12 +396,26 @@ pub struct RecordEnrollment<'info> {
     /// Member being enrolled
     /// CHECK: Just recording the pubkey
     pub member: UncheckedAccount<'info>,
+
+    /// CHECK: membership-program global config PDA, signing via CPI
+    #[account(
+        signer @ RiskEngineError::Unauthorized,
+        seeds = [MEMBERSHIP_CONFIG_SEED],
+        bump,
+        seeds::program = MEMBERSHIP_PROGRAM_ID,
+    )]
+    pub membership_config: UncheckedAccount<'info>,
 }
 
-pub fn record_enrollment(ctx: Context<RecordEnrollment>) -> Result<()> {
+/// Returns the remaining monthly enrollment capacity after this enrollment
+pub fn record_enrollment(ctx: Context<RecordEnrollment>) -> Result<u32> {
     let clock = Clock::get()?;
     let zone_state = &mut ctx.accounts.zone_state;
 
+    // Reset counter if new month (before checking the cap, so a stale
+    // count from last month can't block enrollment)
+    roll_enrollment_month(zone_state, clock.unix_timestamp);
+
     // Check enrollment is allowed
     require!(
         !zone_state.enrollment_frozen,
@@ -232,23 +426,102 @@ pub fn record_enrollment(ctx: Context<RecordEnrollment>) -> Result<()> {
         RiskEngineError::EnrollmentCapExceeded
     );
 
-    // Reset counter if new month
-    const MONTH_SECONDS: i64 = 30 * 24 * 60 * 60;
-    if clock.unix_timestamp - zone_state.month_start_timestamp > MONTH_SECONDS {
-        zone_state.month_start_timestamp = clock.unix_timestamp;
-        zone_state.current_month_enrollments = 0;
-    }
-
     zone_state.current_month_enrollments += 1;
+    let remaining_capacity = zone_state.remaining_capacity();
 
     emit!(EnrollmentRecorded {
         member: ctx.accounts.member.key(),
         current_zone: zone_state.current_zone,
         month_enrollments: zone_state.current_month_enrollments,
         cap: zone_state.get_current_cap(),
+        remaining_capacity,
         timestamp: clock.unix_timestamp,
     });
 
+    Ok(remaining_capacity)
+}
+
+/// Roll the enrollment counter into the current month, emitting on reset
+fn roll_enrollment_month(zone_state: &mut ZoneState, current_time: i64) {
+    if let Some(previous_month_enrollments) = zone_state.roll_enrollment_month(current_time) {
+        emit!(EnrollmentCounterReset {
+            previous_month_enrollments,
+            month_start: zone_state.month_start_timestamp,
+            timestamp: current_time,
+        });
+    }
+}
+
+/// Record an adverse-selection alert for a flagged cohort (called by the
+/// reserves program, signed by the cohort PDA)
+#[derive(Accounts)]
+#[instruction(cohort_id: u32)]
+pub struct RecordCohortAlert<'info> {
+    #[account(
+        mut,
+        seeds = [RiskConfig::SEED_PREFIX],
+        bump = risk_config.bump,
+    )]
+    pub risk_config: Account<'info, RiskConfig>,
+
+    #[account(
+        mut,
+        seeds = [ZoneState::SEED_PREFIX],
+        bump = zone_state.bump,
+    )]
+    pub zone_state: Account<'info, ZoneState>,
+
+    /// Flagged cohort in the reserves program
+    #[account(
+        seeds = [RiskConfig::RESERVES_COHORT_SEED, &cohort_id.to_le_bytes()],
+        bump,
+        seeds::program = risk_config.reserves_program,
+    )]
+    pub cohort: Signer<'info>,
+}
+
+pub fn record_cohort_alert(
+    ctx: Context<RecordCohortAlert>,
+    cohort_id: u32,
+    loss_ratio_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &mut ctx.accounts.risk_config;
+    let zone_state = &mut ctx.accounts.zone_state;
+
+    // Roll first so the tightened ceiling is measured against this month
+    roll_enrollment_month(zone_state, clock.unix_timestamp);
+    let enrollment_cap = zone_state.tighten_for_cohort_alert();
+
+    let old_shock_factor_bps = config.shock_factor_bps;
+    config.shock_factor_bps = config.cohort_alert_shock_factor();
+
+    emit!(CohortAdverseSelectionAlert {
+        cohort_id,
+        loss_ratio_bps,
+        zone: zone_state.current_zone,
+        enrollment_cap,
+        old_shock_factor_bps,
+        new_shock_factor_bps: config.shock_factor_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Lift the cohort alert enrollment ceiling
+pub fn clear_cohort_alert(ctx: Context<SetEnrollmentCaps>) -> Result<()> {
+    let zone_state = &mut ctx.accounts.zone_state;
+
+    let previous_cap = zone_state.cohort_alert_cap;
+    zone_state.cohort_alert_cap = 0;
+
+    emit!(CohortAlertCleared {
+        previous_cap,
+        cleared_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     Ok(())
 }
 
@@ -274,22 +547,94 @@ pub struct ToggleEnrollmentFreeze<'info> {
     pub authority: Signer<'info>,
 }
 
-pub fn toggle_enrollment_freeze(ctx: Context<ToggleEnrollmentFreeze>, freeze: bool) -> Result<()> {
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct EnrollmentFreezeParams {
+    pub freeze: bool,
+    /// Required when freezing; ignored when unfreezing
+    pub reason: EnrollmentFreezeReason,
+    /// Lift the freeze automatically after this time (None = manual only)
+    pub auto_unfreeze_at: Option<i64>,
+}
+
+pub fn toggle_enrollment_freeze(
+    ctx: Context<ToggleEnrollmentFreeze>,
+    params: EnrollmentFreezeParams,
+) -> Result<()> {
     let clock = Clock::get()?;
     let zone_state = &mut ctx.accounts.zone_state;
 
-    zone_state.enrollment_frozen = freeze;
+    if !params.freeze {
+        let reason = zone_state.unfreeze_enrollment();
+
+        emit!(EnrollmentFreezeLifted {
+            reason,
+            expired: false,
+            zone: zone_state.current_zone,
+            lifted_by: ctx.accounts.authority.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        return Ok(());
+    }
+
+    let auto_unfreeze_at = params.auto_unfreeze_at.unwrap_or(0);
+    require!(
+        params.reason != EnrollmentFreezeReason::None,
+        RiskEngineError::InvalidEnrollmentFreeze
+    );
+    require!(
+        params.auto_unfreeze_at.is_none() || auto_unfreeze_at > clock.unix_timestamp,
+        RiskEngineError::InvalidEnrollmentFreeze
+    );
 
-    emit!(EnrollmentFreezeToggled {
-        frozen: freeze,
+    zone_state.freeze_enrollment(params.reason, auto_unfreeze_at);
+
+    emit!(EnrollmentFreezeStarted {
+        reason: params.reason,
+        auto_unfreeze_at,
         zone: zone_state.current_zone,
-        toggler: ctx.accounts.authority.key(),
+        frozen_by: ctx.accounts.authority.key(),
         timestamp: clock.unix_timestamp,
     });
 
     Ok(())
 }
 
+/// Lift a time-boxed enrollment freeze once it has expired (permissionless)
+#[derive(Accounts)]
+pub struct CheckEnrollmentFreeze<'info> {
+    #[account(
+        mut,
+        seeds = [ZoneState::SEED_PREFIX],
+        bump = zone_state.bump,
+    )]
+    pub zone_state: Account<'info, ZoneState>,
+
+    pub caller: Signer<'info>,
+}
+
+/// Returns whether the freeze was lifted
+pub fn check_enrollment_freeze(ctx: Context<CheckEnrollmentFreeze>) -> Result<bool> {
+    let clock = Clock::get()?;
+    let zone_state = &mut ctx.accounts.zone_state;
+
+    if !zone_state.freeze_expired(clock.unix_timestamp) {
+        return Ok(false);
+    }
+
+    let reason = zone_state.unfreeze_enrollment();
+
+    emit!(EnrollmentFreezeLifted {
+        reason,
+        expired: true,
+        zone: zone_state.current_zone,
+        lifted_by: ctx.accounts.caller.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(true)
+}
+
 /// Set zone thresholds
 #[derive(Accounts)]
 pub struct SetZoneThresholds<'info> {
@@ -317,6 +662,7 @@ pub fn set_zone_thresholds(
     green_bps: Option<u16>,
     yellow_bps: Option<u16>,
     orange_bps: Option<u16>,
+    hysteresis_bps: Option<u16>,
 ) -> Result<()> {
     let clock = Clock::get()?;
     let zone = &mut ctx.accounts.zone_state;
@@ -330,14 +676,24 @@ pub fn set_zone_thresholds(
     require!(new_yellow > new_orange, RiskEngineError::InvalidZoneConfig);
     require!(new_orange > 0, RiskEngineError::InvalidZoneConfig);
 
+    // Bands on either side of a threshold must not overlap the next one
+    let new_hysteresis = hysteresis_bps.unwrap_or(zone.hysteresis_bps);
+    let min_gap = (new_green - new_yellow).min(new_yellow - new_orange);
+    require!(
+        new_hysteresis.saturating_mul(2) < min_gap,
+        RiskEngineError::InvalidZoneConfig
+    );
+
     zone.green_threshold_bps = new_green;
     zone.yellow_threshold_bps = new_yellow;
     zone.orange_threshold_bps = new_orange;
+    zone.hysteresis_bps = new_hysteresis;
 
     emit!(ZoneThresholdsUpdated {
         green_threshold_bps: new_green,
         yellow_threshold_bps: new_yellow,
         orange_threshold_bps: new_orange,
+        hysteresis_bps: new_hysteresis,
         timestamp: clock.unix_timestamp,
     });
 
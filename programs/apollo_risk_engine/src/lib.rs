@@ -105,6 +105,20 @@ pub mod apollo_risk_engine {
         instructions::zones::set_shock_factor(ctx, new_shock_factor_bps)
     }
 
+    /// Step ShockFactor toward base after sustained Green-zone CAR (permissionless)
+    pub fn decay_shock_factor(ctx: Context<DecayShockFactor>) -> Result<()> {
+        instructions::zones::decay_shock_factor(ctx)
+    }
+
+    /// Set the ShockFactor decay schedule
+    pub fn set_shock_decay(
+        ctx: Context<SetShockDecay>,
+        shock_decay_periods: u8,
+        shock_decay_step_bps: u16,
+    ) -> Result<()> {
+        instructions::zones::set_shock_decay(ctx, shock_decay_periods, shock_decay_step_bps)
+    }
+
     /// Set enrollment caps per zone
     pub fn set_enrollment_caps(
         ctx: Context<SetEnrollmentCaps>,
@@ -119,16 +133,35 @@ pub mod apollo_risk_engine {
     }
 
     /// Record an enrollment (called by membership program)
-    pub fn record_enrollment(ctx: Context<RecordEnrollment>) -> Result<()> {
+    pub fn record_enrollment(ctx: Context<RecordEnrollment>) -> Result<u32> {
         instructions::zones::record_enrollment(ctx)
     }
 
-    /// Toggle enrollment freeze
+    /// Record an adverse-selection alert for a flagged cohort (called by reserves program)
+    pub fn record_cohort_alert(
+        ctx: Context<RecordCohortAlert>,
+        cohort_id: u32,
+        loss_ratio_bps: u16,
+    ) -> Result<()> {
+        instructions::zones::record_cohort_alert(ctx, cohort_id, loss_ratio_bps)
+    }
+
+    /// Lift the cohort alert enrollment ceiling
+    pub fn clear_cohort_alert(ctx: Context<SetEnrollmentCaps>) -> Result<()> {
+        instructions::zones::clear_cohort_alert(ctx)
+    }
+
+    /// Freeze enrollment with a reason, optionally time-boxed, or lift it
     pub fn toggle_enrollment_freeze(
         ctx: Context<ToggleEnrollmentFreeze>,
-        freeze: bool,
+        params: EnrollmentFreezeParams,
     ) -> Result<()> {
-        instructions::zones::toggle_enrollment_freeze(ctx, freeze)
+        instructions::zones::toggle_enrollment_freeze(ctx, params)
+    }
+
+    /// Lift a time-boxed enrollment freeze past its auto-unfreeze time
+    pub fn check_enrollment_freeze(ctx: Context<CheckEnrollmentFreeze>) -> Result<bool> {
+        instructions::zones::check_enrollment_freeze(ctx)
     }
 
     /// Set zone thresholds
@@ -137,8 +170,15 @@ pub mod apollo_risk_engine {
         green_bps: Option<u16>,
         yellow_bps: Option<u16>,
         orange_bps: Option<u16>,
+        hysteresis_bps: Option<u16>,
     ) -> Result<()> {
-        instructions::zones::set_zone_thresholds(ctx, green_bps, yellow_bps, orange_bps)
+        instructions::zones::set_zone_thresholds(
+            ctx,
+            green_bps,
+            yellow_bps,
+            orange_bps,
+            hysteresis_bps,
+        )
     }
 }
 
@@ -50,6 +50,20 @@ pub struct RiskConfig {
     /// Bump seed
     pub bump: u8,
 
+    /// Consecutive Green-zone periods before the ShockFactor starts
+    /// decaying toward base
+    pub shock_decay_periods: u8,
+
+    /// Maximum ShockFactor reduction per decay step
+    pub shock_decay_step_bps: u16,
+
+    /// Consecutive decay periods observed in Green (reset outside Green,
+    /// on a zone change, and when the ShockFactor is set)
+    pub green_periods: u8,
+
+    /// Last decay check (0 = never)
+    pub last_shock_decay_at: i64,
+
     /// Reserved
     #[max_len(32)]
     pub reserved: Vec<u8>,
@@ -72,6 +86,70 @@ impl RiskConfig {
     pub const MAX_EMERGENCY_SHOCK_BPS: u16 = 20000; // 2.0x (Red zone - DAO emergency)
                                                     // Note: Red zone 2.0x is an actuarial override from spec's 1.5x
                                                     // Rationale: 50% increase insufficient for catastrophic scenarios
+
+    // ShockFactor decay defaults
+    pub const DEFAULT_SHOCK_DECAY_PERIODS: u8 = 3;
+    pub const DEFAULT_SHOCK_DECAY_STEP_BPS: u16 = 500; // -0.05x per period
+    pub const MAX_SHOCK_DECAY_STEP_BPS: u16 = 1000; // -0.10x per period
+    /// One decay period (30 days, matching the enrollment month)
+    pub const SHOCK_DECAY_PERIOD_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+    /// ShockFactor step applied when the reserves program flags a cohort
+    pub const COHORT_ALERT_SHOCK_STEP_BPS: u16 = 500; // +0.05x
+
+    /// Seed prefix of the reserves program's cohort PDA (signs alert CPIs)
+    pub const RESERVES_COHORT_SEED: &'static [u8] = b"cohort";
+
+    /// ShockFactor after a cohort alert: one step up, never past the
+    /// auto-adjustable limit and never below the current value
+    pub fn cohort_alert_shock_factor(&self) -> u16 {
+        self.shock_factor_bps
+            .saturating_add(Self::COHORT_ALERT_SHOCK_STEP_BPS)
+            .min(self.max_auto_shock_factor_bps)
+            .max(self.shock_factor_bps)
+    }
+
+    /// Whether a full decay period has passed since the last check
+    pub fn shock_decay_due(&self, current_time: i64) -> bool {
+        self.last_shock_decay_at == 0
+            || current_time >= self.last_shock_decay_at + Self::SHOCK_DECAY_PERIOD_SECONDS
+    }
+
+    /// Record one decay period in `zone`, entered at `zone_since`
+    /// (ZoneState.last_zone_change_at). The streak never exceeds the whole
+    /// periods spent continuously in Green, so a dip out of Green between
+    /// samples starts it over. Once Green has held for
+    /// `shock_decay_periods`, each further Green period steps the
+    /// ShockFactor down by at most `shock_decay_step_bps`, never below
+    /// base (SHOCK_FACTOR_BASE_BPS). Returns the new factor when it moved.
+    pub fn apply_shock_decay(
+        &mut self,
+        zone: Zone,
+        zone_since: i64,
+        current_time: i64,
+    ) -> Option<u16> {
+        self.last_shock_decay_at = current_time;
+        if zone != Zone::Green {
+            self.green_periods = 0;
+            return None;
+        }
+
+        let periods_in_green = (current_time.saturating_sub(zone_since).max(0)
+            / Self::SHOCK_DECAY_PERIOD_SECONDS)
+            .min(u8::MAX as i64) as u8;
+        self.green_periods = self.green_periods.saturating_add(1).min(periods_in_green);
+        if self.green_periods < self.shock_decay_periods
+            || self.shock_factor_bps <= Self::DEFAULT_SHOCK_FACTOR_BPS
+        {
+            return None;
+        }
+
+        self.shock_factor_bps = self
+            .shock_factor_bps
+            .saturating_sub(self.shock_decay_step_bps)
+            .max(Self::DEFAULT_SHOCK_FACTOR_BPS);
+        Some(self.shock_factor_bps)
+    }
 }
 
 /// CMS-compliant age band rating table
@@ -372,12 +450,26 @@ pub struct ZoneState {
     /// Month start timestamp
     pub month_start_timestamp: i64,
 
-    /// Is enrollment frozen (Red zone)
+    /// Is enrollment frozen (Red zone or governance freeze)
     pub enrollment_frozen: bool,
 
     /// Last zone transition timestamp
     pub last_zone_change_at: i64,
 
+    /// Margin (bps of CAR) a move must clear past a threshold before the
+    /// zone change is confirmed into `current_zone`
+    pub hysteresis_bps: u16,
+
+    /// Monthly enrollment ceiling imposed by an adverse-selection cohort
+    /// alert, applied on top of the zone cap (0 when no alert is active)
+    pub cohort_alert_cap: u32,
+
+    /// Why enrollment is frozen (`None` when not frozen)
+    pub freeze_reason: EnrollmentFreezeReason,
+
+    /// When a time-boxed freeze may be lifted (0 = until lifted manually)
+    pub auto_unfreeze_at: i64,
+
     /// Bump seed
     pub bump: u8,
 }
@@ -389,6 +481,10 @@ impl ZoneState {
     pub const DEFAULT_GREEN_BPS: u16 = 15000; // 150%
     pub const DEFAULT_YELLOW_BPS: u16 = 12500; // 125%
     pub const DEFAULT_ORANGE_BPS: u16 = 10000; // 100%
+    pub const DEFAULT_HYSTERESIS_BPS: u16 = 200; // 2%
+
+    /// Enrollment cap period (30-day months aligned to the epoch)
+    pub const MONTH_SECONDS: i64 = 30 * 24 * 60 * 60;
 
     // Default caps
     pub const GREEN_CAP: u32 = u32::MAX; // Unlimited
@@ -401,25 +497,142 @@ impl ZoneState {
             return false;
         }
 
-        let cap = match self.current_zone {
+        self.current_month_enrollments < self.get_current_cap()
+    }
+
+    /// Get current enrollment cap (zone cap, further limited by any
+    /// active cohort alert)
+    pub fn get_current_cap(&self) -> u32 {
+        let zone_cap = match self.current_zone {
             Zone::Green => self.green_enrollment_cap,
             Zone::Yellow => self.yellow_enrollment_cap,
             Zone::Orange => self.orange_enrollment_cap,
             Zone::Red => 0,
         };
 
-        self.current_month_enrollments < cap
+        if self.cohort_alert_cap > 0 {
+            zone_cap.min(self.cohort_alert_cap)
+        } else {
+            zone_cap
+        }
     }
 
-    /// Get current enrollment cap
-    pub fn get_current_cap(&self) -> u32 {
-        match self.current_zone {
-            Zone::Green => self.green_enrollment_cap,
-            Zone::Yellow => self.yellow_enrollment_cap,
-            Zone::Orange => self.orange_enrollment_cap,
-            Zone::Red => 0,
+    /// Tighten enrollment for subsequent cohorts after an adverse-selection
+    /// alert: halve the current cap, treating Green's unlimited cap as the
+    /// Yellow cap. Returns the new ceiling.
+    pub fn tighten_for_cohort_alert(&mut self) -> u32 {
+        let base = self.get_current_cap().min(self.yellow_enrollment_cap);
+        self.cohort_alert_cap = (base / 2).max(1);
+        self.cohort_alert_cap
+    }
+
+    /// Freeze enrollment for `reason`, optionally until `auto_unfreeze_at`
+    pub fn freeze_enrollment(&mut self, reason: EnrollmentFreezeReason, auto_unfreeze_at: i64) {
+        self.enrollment_frozen = true;
+        self.freeze_reason = reason;
+        self.auto_unfreeze_at = auto_unfreeze_at;
+    }
+
+    /// Lift the freeze, returning the reason it was imposed for
+    pub fn unfreeze_enrollment(&mut self) -> EnrollmentFreezeReason {
+        let reason = self.freeze_reason;
+        self.enrollment_frozen = false;
+        self.freeze_reason = EnrollmentFreezeReason::None;
+        self.auto_unfreeze_at = 0;
+        reason
+    }
+
+    /// Time-boxed freeze whose end has passed
+    pub fn freeze_expired(&self, current_time: i64) -> bool {
+        self.enrollment_frozen && self.auto_unfreeze_at > 0 && current_time >= self.auto_unfreeze_at
+    }
+
+    /// Start of the cap period containing `timestamp`
+    pub fn month_start(timestamp: i64) -> i64 {
+        timestamp - timestamp.rem_euclid(Self::MONTH_SECONDS)
+    }
+
+    /// Reset the monthly counter if `current_time` is in a later period than
+    /// `month_start_timestamp`. Returns the discarded count on reset.
+    pub fn roll_enrollment_month(&mut self, current_time: i64) -> Option<u32> {
+        let month_start = Self::month_start(current_time);
+        if month_start <= self.month_start_timestamp {
+            return None;
         }
+
+        let previous = self.current_month_enrollments;
+        self.month_start_timestamp = month_start;
+        self.current_month_enrollments = 0;
+        Some(previous)
     }
+
+    /// Enrollments still available this month (0 when frozen)
+    pub fn remaining_capacity(&self) -> u32 {
+        if self.enrollment_frozen {
+            return 0;
+        }
+        self.get_current_cap()
+            .saturating_sub(self.current_month_enrollments)
+    }
+
+    /// Zone for a CAR with every threshold shifted by `offset_bps`
+    fn zone_with_offset(&self, car_bps: u16, offset_bps: i32) -> Zone {
+        let shifted = |threshold: u16| (threshold as i32 + offset_bps).max(0);
+        let car = car_bps as i32;
+        if car >= shifted(self.green_threshold_bps) {
+            Zone::Green
+        } else if car >= shifted(self.yellow_threshold_bps) {
+            Zone::Yellow
+        } else if car >= shifted(self.orange_threshold_bps) {
+            Zone::Orange
+        } else {
+            Zone::Red
+        }
+    }
+
+    /// Zone from raw thresholds, ignoring hysteresis
+    pub fn raw_zone(&self, car_bps: u16) -> Zone {
+        self.zone_with_offset(car_bps, 0)
+    }
+
+    /// Confirmed zone for a CAR reading. Moving to a healthier zone requires
+    /// clearing its threshold by `hysteresis_bps`; moving to a worse zone
+    /// requires falling that far below. Otherwise the current zone holds.
+    pub fn confirmed_zone(&self, car_bps: u16) -> Zone {
+        let margin = self.hysteresis_bps as i32;
+
+        let up = self.zone_with_offset(car_bps, margin);
+        if up.severity() < self.current_zone.severity() {
+            return up;
+        }
+
+        let down = self.zone_with_offset(car_bps, -margin);
+        if down.severity() > self.current_zone.severity() {
+            return down;
+        }
+
+        self.current_zone
+    }
+}
+
+/// Why enrollment was frozen
+#[derive(
+    AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace,
+)]
+pub enum EnrollmentFreezeReason {
+    /// Not frozen
+    #[default]
+    None,
+    /// CAR fell into the Red zone
+    RedZone,
+    /// Reserves or CAR under review
+    SolvencyReview,
+    /// Adverse selection under investigation
+    AdverseSelection,
+    /// Operational incident (oracle, pricing, or program issue)
+    Operational,
+    /// Other governance decision
+    Governance,
 }
 
 /// Zone classification based on CAR
@@ -441,6 +654,13 @@ impl Default for Zone {
     }
 }
 
+impl Zone {
+    /// Ordering from healthiest (Green = 0) to most stressed (Red = 3)
+    pub fn severity(&self) -> u8 {
+        *self as u8
+    }
+}
+
 /// Member risk tier for internal classification
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
 pub enum MemberRiskTier {
@@ -478,3 +698,284 @@ pub struct ContributionQuote {
     /// Computed at timestamp
     pub quoted_at: i64,
 }
+
+/// Membership program ID. Not a crate dependency (membership depends on
+/// the risk engine); its global config PDA signs enrollment records.
+pub const MEMBERSHIP_PROGRAM_ID: Pubkey = pubkey!("CHJ4Bdc9wqKy6pjSiC3URjs53iDQpn58MPeAgLQVqRW1");
+
+/// Seed of the membership program's global config PDA
+pub const MEMBERSHIP_CONFIG_SEED: &[u8] = b"global_config";
+
+// ==================== UNIT TESTS ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_zone_state(current_zone: Zone) -> ZoneState {
+        ZoneState {
+            current_zone,
+            green_threshold_bps: ZoneState::DEFAULT_GREEN_BPS,
+            yellow_threshold_bps: ZoneState::DEFAULT_YELLOW_BPS,
+            orange_threshold_bps: ZoneState::DEFAULT_ORANGE_BPS,
+            green_enrollment_cap: ZoneState::GREEN_CAP,
+            yellow_enrollment_cap: ZoneState::YELLOW_CAP,
+            orange_enrollment_cap: ZoneState::ORANGE_CAP,
+            current_month_enrollments: 0,
+            month_start_timestamp: 0,
+            enrollment_frozen: false,
+            last_zone_change_at: 0,
+            hysteresis_bps: ZoneState::DEFAULT_HYSTERESIS_BPS,
+            cohort_alert_cap: 0,
+            freeze_reason: EnrollmentFreezeReason::None,
+            auto_unfreeze_at: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_enrollment_counter_caps_within_month() {
+        let mut zone = create_test_zone_state(Zone::Orange);
+        let month = ZoneState::MONTH_SECONDS;
+        zone.roll_enrollment_month(month);
+
+        for _ in 0..ZoneState::ORANGE_CAP {
+            assert_eq!(zone.roll_enrollment_month(month + 100), None);
+            assert!(zone.can_enroll());
+            zone.current_month_enrollments += 1;
+        }
+
+        // Last second of the same month: still capped
+        assert_eq!(zone.roll_enrollment_month(2 * month - 1), None);
+        assert!(!zone.can_enroll());
+        assert_eq!(zone.remaining_capacity(), 0);
+    }
+
+    #[test]
+    fn test_enrollment_counter_resets_at_new_month() {
+        let mut zone = create_test_zone_state(Zone::Orange);
+        let month = ZoneState::MONTH_SECONDS;
+        zone.month_start_timestamp = month;
+        zone.current_month_enrollments = ZoneState::ORANGE_CAP;
+        assert!(!zone.can_enroll());
+
+        // First second of the next month
+        assert_eq!(
+            zone.roll_enrollment_month(2 * month),
+            Some(ZoneState::ORANGE_CAP)
+        );
+        assert_eq!(zone.month_start_timestamp, 2 * month);
+        assert_eq!(zone.current_month_enrollments, 0);
+        assert!(zone.can_enroll());
+
+        // Skipping several months still resets once, to the current month
+        zone.current_month_enrollments = 7;
+        assert_eq!(zone.roll_enrollment_month(5 * month + 10), Some(7));
+        assert_eq!(zone.month_start_timestamp, 5 * month);
+    }
+
+    #[test]
+    fn test_car_bouncing_around_green_line_holds_zone() {
+        for start in [Zone::Green, Zone::Yellow] {
+            let mut zone = create_test_zone_state(start);
+            for car in [14900, 15100, 14950, 15050, 14900, 15100] {
+                zone.current_zone = zone.confirmed_zone(car);
+                assert_eq!(zone.current_zone, start);
+            }
+        }
+        // Raw thresholds would have flipped on every reading
+        let zone = create_test_zone_state(Zone::Green);
+        assert_eq!(zone.raw_zone(14900), Zone::Yellow);
+        assert_eq!(zone.raw_zone(15100), Zone::Green);
+    }
+
+    #[test]
+    fn test_zone_changes_once_margin_is_cleared() {
+        let zone = create_test_zone_state(Zone::Yellow);
+        assert_eq!(zone.confirmed_zone(15199), Zone::Yellow);
+        assert_eq!(zone.confirmed_zone(15200), Zone::Green);
+
+        let zone = create_test_zone_state(Zone::Green);
+        assert_eq!(zone.confirmed_zone(14800), Zone::Green);
+        assert_eq!(zone.confirmed_zone(14799), Zone::Yellow);
+
+        // A collapse skips straight past intermediate zones
+        assert_eq!(zone.confirmed_zone(9000), Zone::Red);
+    }
+
+    #[test]
+    fn test_time_boxed_freeze_lifts_after_expiry() {
+        let mut zone = create_test_zone_state(Zone::Green);
+        let week = 7 * 24 * 60 * 60;
+        zone.freeze_enrollment(EnrollmentFreezeReason::SolvencyReview, 1_000 + week);
+        assert!(!zone.can_enroll());
+        assert_eq!(zone.remaining_capacity(), 0);
+
+        assert!(!zone.freeze_expired(1_000 + week - 1));
+        assert!(zone.freeze_expired(1_000 + week));
+
+        assert_eq!(
+            zone.unfreeze_enrollment(),
+            EnrollmentFreezeReason::SolvencyReview
+        );
+        assert!(zone.can_enroll());
+        assert_eq!(zone.freeze_reason, EnrollmentFreezeReason::None);
+        assert_eq!(zone.auto_unfreeze_at, 0);
+    }
+
+    #[test]
+    fn test_manual_freeze_never_expires() {
+        let mut zone = create_test_zone_state(Zone::Green);
+        zone.freeze_enrollment(EnrollmentFreezeReason::AdverseSelection, 0);
+
+        assert!(!zone.freeze_expired(i64::MAX));
+        assert!(zone.enrollment_frozen);
+        assert!(!zone.can_enroll());
+    }
+
+    #[test]
+    fn test_cohort_alert_halves_next_month_cap() {
+        let month = ZoneState::MONTH_SECONDS;
+        let mut zone = create_test_zone_state(Zone::Yellow);
+        zone.month_start_timestamp = month;
+        zone.current_month_enrollments = 100;
+
+        assert_eq!(zone.tighten_for_cohort_alert(), ZoneState::YELLOW_CAP / 2);
+
+        // The ceiling survives the monthly counter reset
+        zone.roll_enrollment_month(2 * month);
+        assert_eq!(zone.get_current_cap(), ZoneState::YELLOW_CAP / 2);
+        zone.current_month_enrollments = ZoneState::YELLOW_CAP / 2;
+        assert!(!zone.can_enroll());
+
+        // A lower zone cap still wins, and Green is capped from the Yellow cap
+        zone.current_zone = Zone::Orange;
+        assert_eq!(zone.get_current_cap(), ZoneState::ORANGE_CAP);
+        let mut green = create_test_zone_state(Zone::Green);
+        assert_eq!(green.tighten_for_cohort_alert(), ZoneState::YELLOW_CAP / 2);
+    }
+
+    fn create_test_risk_config() -> RiskConfig {
+        RiskConfig {
+            authority: Pubkey::default(),
+            governance_program: Pubkey::default(),
+            reserves_program: Pubkey::default(),
+            base_rate_adult: RiskConfig::DEFAULT_BASE_RATE,
+            child_factor_bps: RiskConfig::DEFAULT_CHILD_FACTOR_BPS,
+            max_children: RiskConfig::DEFAULT_MAX_CHILDREN,
+            tobacco_factor_bps: RiskConfig::DEFAULT_TOBACCO_FACTOR_BPS,
+            shock_factor_bps: RiskConfig::DEFAULT_SHOCK_FACTOR_BPS,
+            max_auto_shock_factor_bps: RiskConfig::MAX_AUTO_SHOCK_BPS,
+            max_committee_shock_factor_bps: RiskConfig::MAX_COMMITTEE_SHOCK_BPS,
+            max_emergency_shock_factor_bps: RiskConfig::MAX_EMERGENCY_SHOCK_BPS,
+            min_contribution: 0,
+            is_active: true,
+            bump: 255,
+            shock_decay_periods: RiskConfig::DEFAULT_SHOCK_DECAY_PERIODS,
+            shock_decay_step_bps: RiskConfig::DEFAULT_SHOCK_DECAY_STEP_BPS,
+            green_periods: 0,
+            last_shock_decay_at: 0,
+            reserved: vec![],
+        }
+    }
+
+    #[test]
+    fn test_cohort_alert_shock_stays_within_auto_limit() {
+        let mut config = create_test_risk_config();
+        assert_eq!(config.cohort_alert_shock_factor(), 10500);
+
+        config.shock_factor_bps = 11800;
+        assert_eq!(config.cohort_alert_shock_factor(), 12000);
+
+        // Already above the auto limit (committee-approved): left unchanged
+        config.shock_factor_bps = 14000;
+        assert_eq!(config.cohort_alert_shock_factor(), 14000);
+    }
+
+    #[test]
+    fn test_sustained_green_decays_shock_to_base() {
+        let period = RiskConfig::SHOCK_DECAY_PERIOD_SECONDS;
+        let mut config = create_test_risk_config();
+        config.shock_factor_bps = 12000; // 1.2x after stress
+
+        // Two Green periods build up the streak without moving the factor
+        assert_eq!(config.apply_shock_decay(Zone::Green, 0, period), None);
+        assert_eq!(config.apply_shock_decay(Zone::Green, 0, 2 * period), None);
+        assert!(!config.shock_decay_due(2 * period + 1));
+
+        // From the third period on, one bounded step per period
+        let mut now = 3 * period;
+        let mut steps = vec![];
+        while let Some(factor) = config.apply_shock_decay(Zone::Green, 0, now) {
+            steps.push(factor);
+            now += period;
+        }
+        assert_eq!(steps, vec![11500, 11000, 10500, 10000]);
+        assert_eq!(
+            config.shock_factor_bps,
+            RiskConfig::DEFAULT_SHOCK_FACTOR_BPS
+        );
+    }
+
+    #[test]
+    fn test_leaving_green_resets_decay_streak() {
+        let period = RiskConfig::SHOCK_DECAY_PERIOD_SECONDS;
+        let mut config = create_test_risk_config();
+        config.shock_factor_bps = 12000;
+
+        config.apply_shock_decay(Zone::Green, 0, period);
+        config.apply_shock_decay(Zone::Green, 0, 2 * period);
+        assert_eq!(config.apply_shock_decay(Zone::Yellow, 0, 3 * period), None);
+        assert_eq!(config.green_periods, 0);
+
+        // Back in Green at 3.5 periods: the streak starts over from there
+        let green_since = 3 * period + period / 2;
+        assert_eq!(
+            config.apply_shock_decay(Zone::Green, green_since, 4 * period),
+            None
+        );
+        assert_eq!(config.green_periods, 0);
+        assert_eq!(
+            config.apply_shock_decay(Zone::Green, green_since, 5 * period),
+            None
+        );
+        assert_eq!(
+            config.apply_shock_decay(Zone::Green, green_since, 6 * period),
+            None
+        );
+        assert_eq!(
+            config.apply_shock_decay(Zone::Green, green_since, 7 * period),
+            Some(11500)
+        );
+    }
+
+    #[test]
+    fn test_dip_between_samples_resets_decay_streak() {
+        let period = RiskConfig::SHOCK_DECAY_PERIOD_SECONDS;
+        let mut config = create_test_risk_config();
+        config.shock_factor_bps = 12000;
+
+        config.apply_shock_decay(Zone::Green, 0, period);
+        config.apply_shock_decay(Zone::Green, 0, 2 * period);
+        assert_eq!(config.green_periods, 2);
+
+        // The zone left Green and came back just before the next sample,
+        // which still reads Green: the streak is capped by the new stint
+        let green_since = 3 * period - 1;
+        assert_eq!(
+            config.apply_shock_decay(Zone::Green, green_since, 3 * period),
+            None
+        );
+        assert_eq!(config.green_periods, 0);
+        assert_eq!(config.shock_factor_bps, 12000);
+    }
+
+    #[test]
+    fn test_zero_hysteresis_matches_raw_thresholds() {
+        let mut zone = create_test_zone_state(Zone::Green);
+        zone.hysteresis_bps = 0;
+        for car in [9999, 10000, 12499, 12500, 14999, 15000] {
+            assert_eq!(zone.confirmed_zone(car), zone.raw_zone(car));
+        }
+    }
+}
@@ -78,4 +78,34 @@ pub enum StakingError {
 
     #[msg("Token-2022 operation failed")]
     Token2022Error,
+
+    #[msg("Position passed more than once")]
+    DuplicatePosition,
+
+    #[msg("Oracle price is stale")]
+    StaleOraclePrice,
+
+    #[msg("Liquidation proceeds deviate too far from oracle price")]
+    OraclePriceDeviation,
+
+    #[msg("Oracle price must be greater than zero")]
+    InvalidOraclePrice,
+
+    #[msg("Emergency exit fee cannot exceed 100%")]
+    InvalidEmergencyExitFee,
+
+    #[msg("Reward vesting period cannot be negative")]
+    InvalidRewardVesting,
+
+    #[msg("Tier vests rewards; a reward vesting account is required")]
+    RewardVestingRequired,
+
+    #[msg("Position already has a receipt")]
+    PositionAlreadyTokenized,
+
+    #[msg("Tokenized position requires the receipt mint and token account")]
+    PositionReceiptRequired,
+
+    #[msg("Slash reward forfeit cannot exceed 100%")]
+    InvalidSlashRewardForfeit,
 }
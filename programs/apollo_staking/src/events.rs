@@ -20,6 +20,7 @@ pub struct StakingTierCreated {
     pub max_apy_bps: u16,
     pub max_loss_bps: u16,
     pub lock_period: i64,
+    pub emergency_exit_fee_bps: u16,
     pub timestamp: i64,
 }
 
@@ -34,6 +35,16 @@ pub struct Staked {
     pub timestamp: i64,
 }
 
+/// Emitted when a position receipt is minted, making the position transferable
+#[event]
+pub struct PositionTokenized {
+    pub staker: Pubkey,
+    pub position: Pubkey,
+    pub position_id: u64,
+    pub receipt_mint: Pubkey,
+    pub timestamp: i64,
+}
+
 /// Emitted when APH is unstaked
 #[event]
 pub struct Unstaked {
@@ -46,6 +57,18 @@ pub struct Unstaked {
     pub timestamp: i64,
 }
 
+/// Emitted when an emergency unstake is charged an exit fee
+#[event]
+pub struct EmergencyExitFeeCharged {
+    pub staker: Pubkey,
+    pub position_id: u64,
+    pub tier_id: u8,
+    pub tier_fee_bps: u16,
+    pub effective_fee_bps: u16,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when rewards are claimed
 #[event]
 pub struct RewardsClaimed {
@@ -55,6 +78,19 @@ pub struct RewardsClaimed {
     pub timestamp: i64,
 }
 
+/// Emitted when a claim exceeds the tier's rewards pool and the
+/// unpaid remainder is deferred on the position
+#[event]
+pub struct RewardShortfall {
+    pub staker: Pubkey,
+    pub position_id: u64,
+    pub tier_id: u8,
+    pub requested: u64,
+    pub paid: u64,
+    pub deferred: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when rewards are computed
 #[event]
 pub struct RewardsComputed {
@@ -76,6 +112,22 @@ pub struct Slashed {
     pub timestamp: i64,
 }
 
+/// Emitted when a USDC shortfall is slashed across tiers in waterfall order
+#[event]
+pub struct SlashWaterfallExecuted {
+    pub target_usdc: u64,
+    /// APH needed to cover the target at the oracle price
+    pub aph_required: u64,
+    pub aggressive_slashed: u64,
+    pub standard_slashed: u64,
+    pub conservative_slashed: u64,
+    /// APH the tier caps could not absorb
+    pub unabsorbed_aph: u64,
+    pub oracle_price: u64,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
 /// Emitted when a position is slashed
 #[event]
 pub struct PositionSlashed {
@@ -83,6 +135,18 @@ pub struct PositionSlashed {
     pub position_id: u64,
     pub slash_amount: u64,
     pub remaining_amount: u64,
+    pub rewards_forfeited: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a tier slash cannot be fully absorbed within position loss caps
+#[event]
+pub struct UnslashableShortfall {
+    pub tier_id: u8,
+    pub requested_amount: u64,
+    pub slashed_amount: u64,
+    pub shortfall: u64,
+    pub cumulative_shortfall: u64,
     pub timestamp: i64,
 }
 
@@ -102,6 +166,15 @@ pub struct LiquidationExecuted {
     pub aph_sold: u64,
     pub usdc_received: u64,
     pub slippage_bps: u16,
+    pub oracle_price: u64,
+    pub timestamp: i64,
+}
+
+/// Emitted when the APH price feed is updated
+#[event]
+pub struct AphPriceUpdated {
+    pub price: u64,
+    pub publisher: Pubkey,
     pub timestamp: i64,
 }
 
@@ -141,6 +214,45 @@ pub struct TierApyUpdated {
     pub timestamp: i64,
 }
 
+/// Emitted when a tier's reward vesting period changes
+#[event]
+pub struct TierRewardVestingUpdated {
+    pub tier_id: u8,
+    pub old_vesting_seconds: i64,
+    pub new_vesting_seconds: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when a tier's slash reward forfeit share changes
+#[event]
+pub struct TierSlashRewardForfeitUpdated {
+    pub tier_id: u8,
+    pub old_forfeit_bps: u16,
+    pub new_forfeit_bps: u16,
+    pub timestamp: i64,
+}
+
+/// Emitted when claimed rewards are placed into vesting
+#[event]
+pub struct RewardsVesting {
+    pub staker: Pubkey,
+    pub position_id: u64,
+    pub amount: u64,
+    pub total_vesting: u64,
+    pub vesting_end: i64,
+    pub timestamp: i64,
+}
+
+/// Emitted when vested rewards are released to the staker
+#[event]
+pub struct VestedRewardsReleased {
+    pub staker: Pubkey,
+    pub position: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+    pub timestamp: i64,
+}
+
 /// Emitted when rewards are added to pool
 #[event]
 pub struct RewardsPoolFunded {
@@ -150,11 +262,12 @@ pub struct RewardsPoolFunded {
     pub timestamp: i64,
 }
 
-/// Emitted when eligible APH is reported to reserves
+/// Emitted when eligible APH is reported to the risk engine
 #[event]
 pub struct EligibleAphReported {
     pub total_staked: u64,
     pub haircut_bps: u16,
     pub eligible_usdc_value: u64,
+    pub oracle_price: u64,
     pub timestamp: i64,
 }
@@ -201,6 +201,13 @@ pub struct CreateStakingTierParams {
     pub max_apy_bps: u16,
     pub max_loss_bps: u16,
     pub lock_period: i64,
+    /// Defaults to the config's emergency unstake fee
+    pub emergency_exit_fee_bps: Option<u16>,
+    /// Seconds over which claimed rewards vest (defaults to 0, instant)
+    pub reward_vesting_seconds: Option<i64>,
+    /// Share of slashed principal's unclaimed rewards forfeited (bps,
+    /// defaults to fully pro-rata)
+    pub slash_reward_forfeit_bps: Option<u16>,
 }
 
 pub fn create_staking_tier(
@@ -215,6 +222,28 @@ pub fn create_staking_tier(
     );
     require!(params.lock_period > 0, StakingError::InvalidLockPeriod);
 
+    let emergency_exit_fee_bps = params
+        .emergency_exit_fee_bps
+        .unwrap_or(ctx.accounts.staking_config.emergency_unstake_fee_bps);
+    require!(
+        emergency_exit_fee_bps <= 10000,
+        StakingError::InvalidEmergencyExitFee
+    );
+
+    let reward_vesting_seconds = params.reward_vesting_seconds.unwrap_or(0);
+    require!(
+        reward_vesting_seconds >= 0,
+        StakingError::InvalidRewardVesting
+    );
+
+    let slash_reward_forfeit_bps = params
+        .slash_reward_forfeit_bps
+        .unwrap_or(StakingTier::DEFAULT_SLASH_REWARD_FORFEIT_BPS);
+    require!(
+        slash_reward_forfeit_bps <= 10000,
+        StakingError::InvalidSlashRewardForfeit
+    );
+
     let tier = &mut ctx.accounts.staking_tier;
     tier.tier_id = params.tier_id;
     tier.name = params.name.clone();
@@ -223,9 +252,13 @@ pub fn create_staking_tier(
     tier.current_apy_bps = params.min_apy_bps; // Start at minimum
     tier.max_loss_bps = params.max_loss_bps;
     tier.lock_period = params.lock_period;
+    tier.emergency_exit_fee_bps = emergency_exit_fee_bps;
     tier.total_staked = 0;
     tier.staker_count = 0;
     tier.rewards_pool = 0;
+    tier.unslashable_shortfall = 0;
+    tier.reward_vesting_seconds = reward_vesting_seconds;
+    tier.slash_reward_forfeit_bps = slash_reward_forfeit_bps;
     tier.is_active = true;
     tier.bump = ctx.bumps.staking_tier;
 
@@ -236,6 +269,7 @@ pub fn create_staking_tier(
         max_apy_bps: params.max_apy_bps,
         max_loss_bps: params.max_loss_bps,
         lock_period: params.lock_period,
+        emergency_exit_fee_bps,
         timestamp: clock.unix_timestamp,
     });
 
@@ -304,9 +338,13 @@ pub fn initialize_default_tiers(ctx: Context<InitializeDefaultTiers>) -> Result<
     cons.current_apy_bps = cons_cfg.min_apy_bps;
     cons.max_loss_bps = cons_cfg.max_loss_bps;
     cons.lock_period = cons_cfg.lock_period;
+    cons.emergency_exit_fee_bps = cons_cfg.emergency_exit_fee_bps;
     cons.total_staked = 0;
     cons.staker_count = 0;
     cons.rewards_pool = 0;
+    cons.unslashable_shortfall = 0;
+    cons.reward_vesting_seconds = 0;
+    cons.slash_reward_forfeit_bps = StakingTier::DEFAULT_SLASH_REWARD_FORFEIT_BPS;
     cons.is_active = true;
     cons.bump = ctx.bumps.conservative_tier;
 
@@ -320,9 +358,13 @@ pub fn initialize_default_tiers(ctx: Context<InitializeDefaultTiers>) -> Result<
     std.current_apy_bps = std_cfg.min_apy_bps;
     std.max_loss_bps = std_cfg.max_loss_bps;
     std.lock_period = std_cfg.lock_period;
+    std.emergency_exit_fee_bps = std_cfg.emergency_exit_fee_bps;
     std.total_staked = 0;
     std.staker_count = 0;
     std.rewards_pool = 0;
+    std.unslashable_shortfall = 0;
+    std.reward_vesting_seconds = 0;
+    std.slash_reward_forfeit_bps = StakingTier::DEFAULT_SLASH_REWARD_FORFEIT_BPS;
     std.is_active = true;
     std.bump = ctx.bumps.standard_tier;
 
@@ -336,9 +378,13 @@ pub fn initialize_default_tiers(ctx: Context<InitializeDefaultTiers>) -> Result<
     agg.current_apy_bps = agg_cfg.min_apy_bps;
     agg.max_loss_bps = agg_cfg.max_loss_bps;
     agg.lock_period = agg_cfg.lock_period;
+    agg.emergency_exit_fee_bps = agg_cfg.emergency_exit_fee_bps;
     agg.total_staked = 0;
     agg.staker_count = 0;
     agg.rewards_pool = 0;
+    agg.unslashable_shortfall = 0;
+    agg.reward_vesting_seconds = 0;
+    agg.slash_reward_forfeit_bps = StakingTier::DEFAULT_SLASH_REWARD_FORFEIT_BPS;
     agg.is_active = true;
     agg.bump = ctx.bumps.aggressive_tier;
 
@@ -350,6 +396,7 @@ pub fn initialize_default_tiers(ctx: Context<InitializeDefaultTiers>) -> Result<
             max_apy_bps: cfg.max_apy_bps,
             max_loss_bps: cfg.max_loss_bps,
             lock_period: cfg.lock_period,
+            emergency_exit_fee_bps: cfg.emergency_exit_fee_bps,
             timestamp: clock.unix_timestamp,
         });
     }
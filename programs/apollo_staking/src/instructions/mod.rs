@@ -1,11 +1,13 @@
 // programs/apollo_staking/src/instructions/mod.rs
 
 pub mod initialize;
+pub mod reporting;
 pub mod rewards;
 pub mod slashing;
 pub mod staking;
 
 pub use initialize::*;
+pub use reporting::*;
 pub use rewards::*;
 pub use slashing::*;
 pub use staking::*;
@@ -0,0 +1,106 @@
+// programs/apollo_staking/src/instructions/reporting.rs
+
+use crate::errors::StakingError;
+use crate::events::EligibleAphReported;
+use crate::state::{eligible_staked_aph, AphPriceFeed, StakingConfig, StakingTier};
+use anchor_lang::prelude::*;
+use apollo_risk_engine::instructions::UpdateCarStateParams;
+use apollo_risk_engine::program::ApolloRiskEngine;
+
+/// Report CAR-eligible staked APH to the risk engine (permissionless crank)
+#[derive(Accounts)]
+pub struct ReportEligibleAph<'info> {
+    #[account(
+        seeds = [StakingConfig::SEED_PREFIX],
+        bump = staking_config.bump,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        seeds = [StakingTier::SEED_PREFIX, &[StakingTier::CONSERVATIVE]],
+        bump = conservative_tier.bump,
+    )]
+    pub conservative_tier: Account<'info, StakingTier>,
+
+    #[account(
+        seeds = [StakingTier::SEED_PREFIX, &[StakingTier::STANDARD]],
+        bump = standard_tier.bump,
+    )]
+    pub standard_tier: Account<'info, StakingTier>,
+
+    #[account(
+        seeds = [StakingTier::SEED_PREFIX, &[StakingTier::AGGRESSIVE]],
+        bump = aggressive_tier.bump,
+    )]
+    pub aggressive_tier: Account<'info, StakingTier>,
+
+    #[account(
+        seeds = [AphPriceFeed::SEED_PREFIX],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, AphPriceFeed>,
+
+    /// Risk engine config
+    /// CHECK: Validated by the risk engine program
+    pub risk_config: UncheckedAccount<'info>,
+
+    /// Risk engine CAR state
+    /// CHECK: Validated by the risk engine program
+    #[account(mut)]
+    pub car_state: UncheckedAccount<'info>,
+
+    /// Risk engine zone state
+    /// CHECK: Validated by the risk engine program
+    #[account(mut)]
+    pub zone_state: UncheckedAccount<'info>,
+
+    pub risk_engine_program: Program<'info, ApolloRiskEngine>,
+}
+
+pub fn report_eligible_aph(ctx: Context<ReportEligibleAph>) -> Result<()> {
+    let clock = Clock::get()?;
+    let config = &ctx.accounts.staking_config;
+    let feed = &ctx.accounts.price_feed;
+
+    require!(
+        feed.is_fresh(clock.unix_timestamp),
+        StakingError::StaleOraclePrice
+    );
+
+    let total_staked = eligible_staked_aph(&[
+        &ctx.accounts.conservative_tier,
+        &ctx.accounts.standard_tier,
+        &ctx.accounts.aggressive_tier,
+    ]);
+    let eligible_usdc_value = config.apply_haircut(feed.usdc_value(total_staked));
+
+    // Staking config PDA signs as the CAR updater
+    let seeds: &[&[u8]] = &[StakingConfig::SEED_PREFIX, &[config.bump]];
+    apollo_risk_engine::cpi::update_car_state(
+        CpiContext::new_with_signer(
+            ctx.accounts.risk_engine_program.to_account_info(),
+            apollo_risk_engine::cpi::accounts::UpdateCarState {
+                risk_config: ctx.accounts.risk_config.to_account_info(),
+                car_state: ctx.accounts.car_state.to_account_info(),
+                zone_state: ctx.accounts.zone_state.to_account_info(),
+                updater: ctx.accounts.staking_config.to_account_info(),
+            },
+            &[seeds],
+        ),
+        UpdateCarStateParams {
+            total_usdc_reserves: None,
+            eligible_aph_usdc: Some(eligible_usdc_value),
+            expected_annual_claims: None,
+        },
+    )?;
+
+    emit!(EligibleAphReported {
+        total_staked,
+        haircut_bps: config.aph_haircut_bps,
+        eligible_usdc_value,
+        oracle_price: feed.price,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -5,8 +5,14 @@
 // Reward computation, claiming, and pool funding using Token-2022.
 
 use crate::errors::StakingError;
-use crate::events::{RewardsClaimed, RewardsComputed, RewardsPoolFunded, TierApyUpdated};
-use crate::state::{AphVault, StakePosition, StakerAccount, StakingConfig, StakingTier};
+use crate::events::{
+    RewardShortfall, RewardsClaimed, RewardsComputed, RewardsPoolFunded, RewardsVesting,
+    TierApyUpdated, TierRewardVestingUpdated, TierSlashRewardForfeitUpdated, VestedRewardsReleased,
+};
+use crate::instructions::staking::held_receipt_mint;
+use crate::state::{
+    AphVault, RewardVesting, StakePosition, StakerAccount, StakingConfig, StakingTier,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{
     self, Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
@@ -58,33 +64,12 @@ pub fn compute_rewards(ctx: Context<ComputeRewards>) -> Result<()> {
     let position = &mut ctx.accounts.stake_position;
     let staker_account = &mut ctx.accounts.staker_account;
 
-    // Calculate time since last reward computation
-    let time_elapsed = clock.unix_timestamp - position.last_reward_at;
-    if time_elapsed <= 0 {
-        return Ok(());
-    }
-
-    let effective_stake = position.effective_stake();
-    if effective_stake == 0 {
+    let apy_applied_bps = position.checkpoint_apy_bps;
+    let rewards = position.accrue(clock.unix_timestamp, tier.current_apy_bps);
+    if rewards == 0 {
         return Ok(());
     }
 
-    // Calculate rewards: (stake * apy * time) / (365 days * 10000 bps)
-    // Using safe math to avoid overflow
-    let seconds_per_year: u64 = 365 * 24 * 60 * 60;
-    let apy_bps = tier.current_apy_bps as u64;
-
-    let rewards = effective_stake
-        .saturating_mul(apy_bps)
-        .saturating_mul(time_elapsed as u64)
-        .checked_div(seconds_per_year)
-        .unwrap_or(0)
-        .checked_div(10000)
-        .unwrap_or(0);
-
-    position.rewards_earned = position.rewards_earned.saturating_add(rewards);
-    position.last_reward_at = clock.unix_timestamp;
-
     staker_account.total_rewards_earned =
         staker_account.total_rewards_earned.saturating_add(rewards);
 
@@ -93,7 +78,7 @@ pub fn compute_rewards(ctx: Context<ComputeRewards>) -> Result<()> {
         position_id: position.position_id,
         rewards_added: rewards,
         total_rewards: position.rewards_earned,
-        apy_applied_bps: tier.current_apy_bps,
+        apy_applied_bps,
         timestamp: clock.unix_timestamp,
     });
 
@@ -130,7 +115,7 @@ pub struct ClaimRewards<'info> {
 
     #[account(
         mut,
-        seeds = [StakerAccount::SEED_PREFIX, staker.key().as_ref()],
+        seeds = [StakerAccount::SEED_PREFIX, stake_position.staker.as_ref()],
         bump = staker_account.bump,
     )]
     pub staker_account: Account<'info, StakerAccount>,
@@ -139,15 +124,31 @@ pub struct ClaimRewards<'info> {
         mut,
         seeds = [
             StakePosition::SEED_PREFIX,
-            staker.key().as_ref(),
+            stake_position.staker.as_ref(),
             &stake_position.position_id.to_le_bytes()
         ],
         bump = stake_position.bump,
-        constraint = stake_position.staker == staker.key() @ StakingError::Unauthorized,
+        constraint = stake_position.is_controlled_by(
+            staker.key(),
+            held_receipt_mint(&receipt_token_account, staker.key())
+        ) @ StakingError::Unauthorized,
         constraint = stake_position.is_active @ StakingError::PositionAlreadyClosed
     )]
     pub stake_position: Account<'info, StakePosition>,
 
+    /// Signer's receipt token account (tokenized positions only)
+    pub receipt_token_account: Option<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Vesting account for this position (required if the tier vests rewards)
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + RewardVesting::INIT_SPACE,
+        seeds = [RewardVesting::SEED_PREFIX, stake_position.key().as_ref()],
+        bump
+    )]
+    pub reward_vesting: Option<Account<'info, RewardVesting>>,
+
     /// APH Token-2022 mint
     #[account(
         constraint = aph_mint.key() == staking_config.aph_mint @ StakingError::InvalidTokenAccount
@@ -167,10 +168,13 @@ pub struct ClaimRewards<'info> {
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccountInterface>,
 
+    #[account(mut)]
     pub staker: Signer<'info>,
 
     /// Token-2022 program for APH operations
     pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
@@ -184,20 +188,203 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     let staker_account = &mut ctx.accounts.staker_account;
     let config = &mut ctx.accounts.staking_config;
 
-    let claimable = position
-        .rewards_earned
-        .saturating_sub(position.rewards_claimed);
-    require!(claimable > 0, StakingError::NoRewardsToClaim);
-    require!(
-        tier.rewards_pool >= claimable,
-        StakingError::InsufficientRewardsPool
-    );
+    let accrued = position.accrued_unclaimed;
+    require!(accrued > 0, StakingError::NoRewardsToClaim);
+    require!(tier.rewards_pool > 0, StakingError::InsufficientRewardsPool);
 
-    // Get decimals for transfer_checked
-    let decimals = ctx.accounts.aph_mint.decimals;
+    // Underfunded pool: pay what is available, defer the rest
+    let claimable = position.take_accrued_up_to(tier.rewards_pool);
 
-    // Transfer rewards from vault to staker using Token-2022
-    let vault_seeds = &[AphVault::SEED_PREFIX, &[vault.bump]];
+    let payout = vest_claimed_rewards(
+        ctx.accounts.reward_vesting.as_mut(),
+        ctx.bumps.reward_vesting,
+        position,
+        ctx.accounts.staker.key(),
+        claimable,
+        tier,
+        clock.unix_timestamp,
+    )?;
+
+    if payout > 0 {
+        // Get decimals for transfer_checked
+        let decimals = ctx.accounts.aph_mint.decimals;
+
+        // Transfer rewards from vault to staker using Token-2022
+        let vault_seeds = &[AphVault::SEED_PREFIX, &[vault.bump]];
+        let signer_seeds = &[&vault_seeds[..]];
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    mint: ctx.accounts.aph_mint.to_account_info(),
+                    to: ctx.accounts.staker_token_account.to_account_info(),
+                    authority: vault_account_info.clone(),
+                },
+                signer_seeds,
+            ),
+            payout,
+            decimals,
+        )?;
+    }
+
+    // Update state. Vesting rewards leave the pool now but stay in the
+    // vault until released.
+    staker_account.total_rewards_claimed = staker_account
+        .total_rewards_claimed
+        .saturating_add(claimable);
+    tier.rewards_pool = tier.rewards_pool.saturating_sub(claimable);
+    vault.rewards_available = vault.rewards_available.saturating_sub(claimable);
+    vault.total_aph = vault.total_aph.saturating_sub(payout);
+    config.total_rewards_distributed = config.total_rewards_distributed.saturating_add(payout);
+
+    emit!(RewardsClaimed {
+        staker: ctx.accounts.staker.key(),
+        position_id: position.position_id,
+        amount: claimable,
+        timestamp: clock.unix_timestamp,
+    });
+
+    if position.accrued_unclaimed > 0 {
+        emit!(RewardShortfall {
+            staker: ctx.accounts.staker.key(),
+            position_id: position.position_id,
+            tier_id: tier.tier_id,
+            requested: accrued,
+            paid: claimable,
+            deferred: position.accrued_unclaimed,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    Ok(())
+}
+
+/// Vesting tiers hold a reward claim back and pay out only what has vested.
+/// Returns the amount payable now (the full claim on non-vesting tiers).
+pub(crate) fn vest_claimed_rewards(
+    reward_vesting: Option<&mut Account<RewardVesting>>,
+    vesting_bump: Option<u8>,
+    position: &Account<StakePosition>,
+    staker: Pubkey,
+    claimable: u64,
+    tier: &StakingTier,
+    current_time: i64,
+) -> Result<u64> {
+    if tier.reward_vesting_seconds <= 0 {
+        return Ok(claimable);
+    }
+
+    let vesting = reward_vesting.ok_or(StakingError::RewardVestingRequired)?;
+    if vesting.staker == Pubkey::default() {
+        vesting.position = position.key();
+        vesting.bump = vesting_bump.unwrap_or_default();
+    }
+    // Unreleased vesting follows the position to its current holder
+    vesting.staker = staker;
+    let payout = vesting.settle_claim(claimable, current_time, tier.reward_vesting_seconds);
+
+    emit!(RewardsVesting {
+        staker,
+        position_id: position.position_id,
+        amount: claimable,
+        total_vesting: vesting.total_amount,
+        vesting_end: vesting.vesting_end,
+        timestamp: current_time,
+    });
+
+    Ok(payout)
+}
+
+// =============================================================================
+// RELEASE VESTED REWARDS
+// =============================================================================
+
+/// Release rewards that have vested since the last claim or release.
+/// While the position is open, vesting follows whoever controls it (the
+/// receipt holder once tokenized); after it closes, whoever closed it.
+#[derive(Accounts)]
+pub struct ReleaseVestedRewards<'info> {
+    #[account(
+        mut,
+        seeds = [StakingConfig::SEED_PREFIX],
+        bump = staking_config.bump,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        seeds = [AphVault::SEED_PREFIX],
+        bump = aph_vault.bump,
+    )]
+    pub aph_vault: Account<'info, AphVault>,
+
+    #[account(
+        mut,
+        seeds = [RewardVesting::SEED_PREFIX, stake_position.key().as_ref()],
+        bump = reward_vesting.bump,
+        constraint = if stake_position.is_active {
+            stake_position.is_controlled_by(
+                staker.key(),
+                held_receipt_mint(&receipt_token_account, staker.key())
+            )
+        } else {
+            reward_vesting.staker == staker.key()
+        } @ StakingError::Unauthorized
+    )]
+    pub reward_vesting: Account<'info, RewardVesting>,
+
+    #[account(
+        seeds = [
+            StakePosition::SEED_PREFIX,
+            stake_position.staker.as_ref(),
+            &stake_position.position_id.to_le_bytes()
+        ],
+        bump = stake_position.bump,
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    /// Signer's receipt token account (tokenized positions only)
+    pub receipt_token_account: Option<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// APH Token-2022 mint
+    #[account(
+        constraint = aph_mint.key() == staking_config.aph_mint @ StakingError::InvalidTokenAccount
+    )]
+    pub aph_mint: InterfaceAccount<'info, MintInterface>,
+
+    #[account(
+        mut,
+        constraint = staker_token_account.mint == staking_config.aph_mint @ StakingError::InvalidTokenAccount,
+        constraint = staker_token_account.owner == staker.key() @ StakingError::Unauthorized
+    )]
+    pub staker_token_account: InterfaceAccount<'info, TokenAccountInterface>,
+
+    #[account(
+        mut,
+        constraint = vault_token_account.key() == aph_vault.token_account @ StakingError::InvalidTokenAccount
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccountInterface>,
+
+    pub staker: Signer<'info>,
+
+    /// Token-2022 program for APH operations
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn release_vested_rewards(ctx: Context<ReleaseVestedRewards>) -> Result<()> {
+    let clock = Clock::get()?;
+    let vault_account_info = ctx.accounts.aph_vault.to_account_info();
+
+    let vesting = &mut ctx.accounts.reward_vesting;
+    // Unreleased vesting follows the position to its current holder
+    vesting.staker = ctx.accounts.staker.key();
+    let amount = vesting.release(clock.unix_timestamp);
+    require!(amount > 0, StakingError::NoRewardsToClaim);
+
+    let decimals = ctx.accounts.aph_mint.decimals;
+    let vault_seeds = &[AphVault::SEED_PREFIX, &[ctx.accounts.aph_vault.bump]];
     let signer_seeds = &[&vault_seeds[..]];
 
     token_interface::transfer_checked(
@@ -207,28 +394,24 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
                 from: ctx.accounts.vault_token_account.to_account_info(),
                 mint: ctx.accounts.aph_mint.to_account_info(),
                 to: ctx.accounts.staker_token_account.to_account_info(),
-                authority: vault_account_info.clone(),
+                authority: vault_account_info,
             },
             signer_seeds,
         ),
-        claimable,
+        amount,
         decimals,
     )?;
 
-    // Update state
-    position.rewards_claimed = position.rewards_earned;
-    staker_account.total_rewards_claimed = staker_account
-        .total_rewards_claimed
-        .saturating_add(claimable);
-    tier.rewards_pool = tier.rewards_pool.saturating_sub(claimable);
-    vault.rewards_available = vault.rewards_available.saturating_sub(claimable);
-    vault.total_aph = vault.total_aph.saturating_sub(claimable);
-    config.total_rewards_distributed = config.total_rewards_distributed.saturating_add(claimable);
+    let vault = &mut ctx.accounts.aph_vault;
+    let config = &mut ctx.accounts.staking_config;
+    vault.total_aph = vault.total_aph.saturating_sub(amount);
+    config.total_rewards_distributed = config.total_rewards_distributed.saturating_add(amount);
 
-    emit!(RewardsClaimed {
-        staker: ctx.accounts.staker.key(),
-        position_id: position.position_id,
-        amount: claimable,
+    emit!(VestedRewardsReleased {
+        staker: vesting.staker,
+        position: vesting.position,
+        amount,
+        remaining: vesting.total_amount.saturating_sub(vesting.released_amount),
         timestamp: clock.unix_timestamp,
     });
 
@@ -374,3 +557,52 @@ pub fn update_tier_apy(ctx: Context<UpdateTierApy>, new_apy_bps: u16) -> Result<
 
     Ok(())
 }
+
+// =============================================================================
+// SET TIER REWARD VESTING
+// =============================================================================
+
+pub fn set_tier_reward_vesting(ctx: Context<UpdateTierApy>, vesting_seconds: i64) -> Result<()> {
+    let clock = Clock::get()?;
+    let tier = &mut ctx.accounts.staking_tier;
+
+    require!(vesting_seconds >= 0, StakingError::InvalidRewardVesting);
+
+    let old_vesting_seconds = tier.reward_vesting_seconds;
+    tier.reward_vesting_seconds = vesting_seconds;
+
+    emit!(TierRewardVestingUpdated {
+        tier_id: tier.tier_id,
+        old_vesting_seconds,
+        new_vesting_seconds: vesting_seconds,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// =============================================================================
+// SET TIER SLASH REWARD FORFEIT
+// =============================================================================
+
+pub fn set_tier_slash_reward_forfeit(ctx: Context<UpdateTierApy>, forfeit_bps: u16) -> Result<()> {
+    let clock = Clock::get()?;
+    let tier = &mut ctx.accounts.staking_tier;
+
+    require!(
+        forfeit_bps <= 10000,
+        StakingError::InvalidSlashRewardForfeit
+    );
+
+    let old_forfeit_bps = tier.slash_reward_forfeit_bps;
+    tier.slash_reward_forfeit_bps = forfeit_bps;
+
+    emit!(TierSlashRewardForfeitUpdated {
+        tier_id: tier.tier_id,
+        old_forfeit_bps,
+        new_forfeit_bps: forfeit_bps,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
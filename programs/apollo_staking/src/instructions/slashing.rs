@@ -2,12 +2,50 @@
 
 use crate::errors::StakingError;
 use crate::events::{
-    CircuitBreakerTriggered, LiquidationExecuted, LiquidationQueued, PositionSlashed, Slashed,
+    AphPriceUpdated, CircuitBreakerTriggered, LiquidationExecuted, LiquidationQueued,
+    PositionSlashed, SlashWaterfallExecuted, Slashed, UnslashableShortfall,
 };
 use crate::state::{
-    AphVault, LiquidationEntry, LiquidationQueue, StakePosition, StakingConfig, StakingTier,
+    slash_waterfall_allocation, AphPriceFeed, AphVault, LiquidationEntry, LiquidationQueue,
+    RewardVesting, StakePosition, StakingConfig, StakingTier, SLASH_WATERFALL_ORDER,
 };
 use anchor_lang::prelude::*;
+use apollo_core::actuarial::LIQUIDATION_CIRCUIT_BREAKER_BPS;
+
+/// Forfeit the slashed share of a position's unreleased vesting rewards.
+/// `reward_vesting` must be the position's vesting PDA, which may not have
+/// been created yet. Returns the rewards forfeited.
+fn forfeit_vesting_on_slash(
+    reward_vesting: &AccountInfo,
+    position: &Pubkey,
+    slash: u64,
+    principal: u64,
+    forfeit_bps: u16,
+) -> Result<u64> {
+    let (expected, _) =
+        Pubkey::find_program_address(&[RewardVesting::SEED_PREFIX, position.as_ref()], &crate::ID);
+    require_keys_eq!(
+        reward_vesting.key(),
+        expected,
+        StakingError::RewardVestingRequired
+    );
+
+    if reward_vesting.data_is_empty() {
+        return Ok(0);
+    }
+    require_keys_eq!(
+        *reward_vesting.owner,
+        crate::ID,
+        StakingError::InvalidRewardVesting
+    );
+
+    let mut data = reward_vesting.try_borrow_mut_data()?;
+    let mut vesting = RewardVesting::try_deserialize(&mut &data[..])?;
+    let forfeited = vesting.forfeit_on_slash(slash, principal, forfeit_bps);
+    vesting.try_serialize(&mut &mut data[..])?;
+
+    Ok(forfeited)
+}
 
 /// Slash a specific position (called during claim shortfall)
 #[derive(Accounts)]
@@ -44,6 +82,15 @@ pub struct SlashPosition<'info> {
     )]
     pub liquidation_queue: Account<'info, LiquidationQueue>,
 
+    /// CHECK: The position's reward vesting PDA, which may not exist yet;
+    /// the slashed share of any unreleased rewards is forfeited
+    #[account(
+        mut,
+        seeds = [RewardVesting::SEED_PREFIX, stake_position.key().as_ref()],
+        bump,
+    )]
+    pub reward_vesting: UncheckedAccount<'info>,
+
     /// Must be authorized (DAO or reserves program via CPI)
     #[account(
         constraint = authority.key() == staking_config.authority @ StakingError::Unauthorized
@@ -73,9 +120,25 @@ pub fn slash_position(
     let actual_slash = slash_amount.min(max_slash).min(effective_stake);
     require!(actual_slash > 0, StakingError::SlashExceedsPosition);
 
-    // Apply slash to position
-    position.slash_amount = position.slash_amount.saturating_add(actual_slash);
-    position.was_slashed = true;
+    // Apply slash to position, forfeiting rewards on the slashed principal
+    let vesting_forfeited = forfeit_vesting_on_slash(
+        &ctx.accounts.reward_vesting,
+        &position.key(),
+        actual_slash,
+        effective_stake,
+        tier.slash_reward_forfeit_bps,
+    )?;
+    let rewards_forfeited = position
+        .apply_slash(
+            actual_slash,
+            clock.unix_timestamp,
+            tier.current_apy_bps,
+            tier.slash_reward_forfeit_bps,
+        )
+        .saturating_add(vesting_forfeited);
+
+    // Forfeited vesting rewards return to the tier's pool
+    tier.rewards_pool = tier.rewards_pool.saturating_add(vesting_forfeited);
 
     // Update tier totals
     tier.total_staked = tier.total_staked.saturating_sub(actual_slash);
@@ -98,6 +161,7 @@ pub fn slash_position(
         liquidated_aph: 0,
         received_usdc: 0,
         is_complete: false,
+        oracle_price: 0,
     });
 
     liq_queue.pending_liquidation = liq_queue.pending_liquidation.saturating_add(actual_slash);
@@ -107,6 +171,7 @@ pub fn slash_position(
         position_id: position.position_id,
         slash_amount: actual_slash,
         remaining_amount: position.effective_stake(),
+        rewards_forfeited,
         timestamp: clock.unix_timestamp,
     });
 
@@ -122,6 +187,12 @@ pub fn slash_position(
 }
 
 /// Slash across an entire tier (proportional)
+///
+/// The tier's active positions are passed as writable `remaining_accounts`,
+/// each followed by its reward vesting PDA (which may not exist yet).
+/// Each position loses its proportional share of `total_slash_amount`, capped
+/// at the tier's max loss for that position. Whatever cannot be absorbed within
+/// the caps is recorded as an unslashable shortfall for escalation.
 #[derive(Accounts)]
 pub struct SlashTier<'info> {
     #[account(
@@ -151,8 +222,8 @@ pub struct SlashTier<'info> {
     pub authority: Signer<'info>,
 }
 
-pub fn slash_tier(
-    ctx: Context<SlashTier>,
+pub fn slash_tier<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SlashTier<'info>>,
     total_slash_amount: u64,
     target_usdc_value: u64,
     reason: String,
@@ -162,40 +233,107 @@ pub fn slash_tier(
     let tier = &mut ctx.accounts.staking_tier;
     let liq_queue = &mut ctx.accounts.liquidation_queue;
 
-    // Calculate max slashable for the tier
-    let max_tier_slash = tier
-        .total_staked
-        .saturating_mul(tier.max_loss_bps as u64)
-        .checked_div(10000)
-        .unwrap_or(0);
+    require!(total_slash_amount > 0, StakingError::SlashExceedsPosition);
+
+    // Slash each position by its capped proportional share
+    let mut actual_slash: u64 = 0;
+    let mut positions_affected: u32 = 0;
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len() / 2);
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let [account_info, reward_vesting] = accounts else {
+            return err!(StakingError::RewardVestingRequired);
+        };
+        require!(
+            !seen.contains(account_info.key),
+            StakingError::DuplicatePosition
+        );
+        seen.push(*account_info.key);
+
+        let mut position: Account<StakePosition> = Account::try_from(account_info)?;
+        require!(position.tier_id == tier.tier_id, StakingError::InvalidTier);
+        require!(position.is_active, StakingError::PositionAlreadyClosed);
+
+        let slash = tier.proportional_slash(total_slash_amount, position.effective_stake());
+        if slash == 0 {
+            continue;
+        }
+
+        let vesting_forfeited = forfeit_vesting_on_slash(
+            reward_vesting,
+            account_info.key,
+            slash,
+            position.effective_stake(),
+            tier.slash_reward_forfeit_bps,
+        )?;
+        let rewards_forfeited = position
+            .apply_slash(
+                slash,
+                clock.unix_timestamp,
+                tier.current_apy_bps,
+                tier.slash_reward_forfeit_bps,
+            )
+            .saturating_add(vesting_forfeited);
+        position.exit(&crate::ID)?;
+
+        tier.rewards_pool = tier.rewards_pool.saturating_add(vesting_forfeited);
+        actual_slash = actual_slash.saturating_add(slash);
+        positions_affected += 1;
+
+        emit!(PositionSlashed {
+            staker: position.staker,
+            position_id: position.position_id,
+            slash_amount: slash,
+            remaining_amount: position.effective_stake(),
+            rewards_forfeited,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
-    let actual_slash = total_slash_amount
-        .min(max_tier_slash)
-        .min(tier.total_staked);
     require!(actual_slash > 0, StakingError::SlashExceedsPosition);
 
-    // Update tier totals (individual positions updated separately)
+    // Update tier totals
     tier.total_staked = tier.total_staked.saturating_sub(actual_slash);
     config.total_staked = config.total_staked.saturating_sub(actual_slash);
 
+    // Record anything the caps could not absorb
+    let shortfall = total_slash_amount.saturating_sub(actual_slash);
+    if shortfall > 0 {
+        tier.unslashable_shortfall = tier.unslashable_shortfall.saturating_add(shortfall);
+
+        emit!(UnslashableShortfall {
+            tier_id: tier.tier_id,
+            requested_amount: total_slash_amount,
+            slashed_amount: actual_slash,
+            shortfall,
+            cumulative_shortfall: tier.unslashable_shortfall,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     // Queue for liquidation
     require!(
         liq_queue.entries.len() < 50,
         StakingError::LiquidationQueueFull
     );
 
+    // Only the absorbed portion of the USDC target is expected from liquidation
+    let target_usdc =
+        (target_usdc_value as u128 * actual_slash as u128 / total_slash_amount as u128) as u64;
+
     let twap_hours = liq_queue.twap_window_hours as i64;
     let twap_end = clock.unix_timestamp + (twap_hours * 60 * 60);
 
     liq_queue.entries.push(LiquidationEntry {
         aph_amount: actual_slash,
-        target_usdc: target_usdc_value,
+        target_usdc,
         created_at: clock.unix_timestamp,
         start_at: clock.unix_timestamp,
         end_at: twap_end,
         liquidated_aph: 0,
         received_usdc: 0,
         is_complete: false,
+        oracle_price: 0,
     });
 
     liq_queue.pending_liquidation = liq_queue.pending_liquidation.saturating_add(actual_slash);
@@ -203,14 +341,252 @@ pub fn slash_tier(
     emit!(Slashed {
         tier_id: tier.tier_id,
         total_slashed: actual_slash,
-        positions_affected: tier.staker_count as u32,
+        positions_affected,
         reason,
         timestamp: clock.unix_timestamp,
     });
 
     emit!(LiquidationQueued {
         aph_amount: actual_slash,
-        target_usdc: target_usdc_value,
+        target_usdc,
+        twap_start: clock.unix_timestamp,
+        twap_end,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Slash staked APH across tiers to cover a USDC shortfall
+///
+/// Aggressive absorbs first up to its loss cap, then Standard, then
+/// Conservative. The shortfall is converted to APH at the oracle price.
+/// Active positions of the affected tiers are passed as writable
+/// `remaining_accounts`, each followed by its reward vesting PDA (which may
+/// not exist yet); within a tier each loses its proportional share.
+#[derive(Accounts)]
+pub struct SlashWaterfall<'info> {
+    #[account(
+        mut,
+        seeds = [StakingConfig::SEED_PREFIX],
+        bump = staking_config.bump,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        mut,
+        seeds = [StakingTier::SEED_PREFIX, &[StakingTier::AGGRESSIVE]],
+        bump = aggressive_tier.bump,
+    )]
+    pub aggressive_tier: Account<'info, StakingTier>,
+
+    #[account(
+        mut,
+        seeds = [StakingTier::SEED_PREFIX, &[StakingTier::STANDARD]],
+        bump = standard_tier.bump,
+    )]
+    pub standard_tier: Account<'info, StakingTier>,
+
+    #[account(
+        mut,
+        seeds = [StakingTier::SEED_PREFIX, &[StakingTier::CONSERVATIVE]],
+        bump = conservative_tier.bump,
+    )]
+    pub conservative_tier: Account<'info, StakingTier>,
+
+    #[account(
+        mut,
+        seeds = [LiquidationQueue::SEED_PREFIX],
+        bump = liquidation_queue.bump,
+    )]
+    pub liquidation_queue: Account<'info, LiquidationQueue>,
+
+    #[account(
+        seeds = [AphPriceFeed::SEED_PREFIX],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, AphPriceFeed>,
+
+    #[account(
+        constraint = authority.key() == staking_config.authority @ StakingError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn slash_waterfall<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SlashWaterfall<'info>>,
+    target_usdc: u64,
+    reason: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let feed = &ctx.accounts.price_feed;
+
+    require!(target_usdc > 0, StakingError::SlashExceedsPosition);
+    require!(
+        feed.is_fresh(clock.unix_timestamp),
+        StakingError::StaleOraclePrice
+    );
+
+    let aph_required = feed.aph_for_usdc(target_usdc);
+    let allocation = slash_waterfall_allocation(
+        aph_required,
+        &[
+            &ctx.accounts.aggressive_tier,
+            &ctx.accounts.standard_tier,
+            &ctx.accounts.conservative_tier,
+        ],
+    );
+
+    // Slash each position by its capped share of its tier's allocation
+    let mut slashed = [0u64; 3];
+    let mut positions_affected = [0u32; 3];
+    let mut vesting_returned = [0u64; 3];
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len() / 2);
+
+    for accounts in ctx.remaining_accounts.chunks(2) {
+        let [account_info, reward_vesting] = accounts else {
+            return err!(StakingError::RewardVestingRequired);
+        };
+        require!(
+            !seen.contains(account_info.key),
+            StakingError::DuplicatePosition
+        );
+        seen.push(*account_info.key);
+
+        let mut position: Account<StakePosition> = Account::try_from(account_info)?;
+        require!(position.is_active, StakingError::PositionAlreadyClosed);
+
+        let index = SLASH_WATERFALL_ORDER
+            .iter()
+            .position(|id| *id == position.tier_id)
+            .ok_or(StakingError::InvalidTier)?;
+        let tier: &StakingTier = match index {
+            0 => &ctx.accounts.aggressive_tier,
+            1 => &ctx.accounts.standard_tier,
+            _ => &ctx.accounts.conservative_tier,
+        };
+
+        let slash = tier.proportional_slash(allocation[index], position.effective_stake());
+        if slash == 0 {
+            continue;
+        }
+
+        let vesting_forfeited = forfeit_vesting_on_slash(
+            reward_vesting,
+            account_info.key,
+            slash,
+            position.effective_stake(),
+            tier.slash_reward_forfeit_bps,
+        )?;
+        let rewards_forfeited = position
+            .apply_slash(
+                slash,
+                clock.unix_timestamp,
+                tier.current_apy_bps,
+                tier.slash_reward_forfeit_bps,
+            )
+            .saturating_add(vesting_forfeited);
+        position.exit(&crate::ID)?;
+
+        slashed[index] = slashed[index].saturating_add(slash);
+        vesting_returned[index] = vesting_returned[index].saturating_add(vesting_forfeited);
+        positions_affected[index] += 1;
+
+        emit!(PositionSlashed {
+            staker: position.staker,
+            position_id: position.position_id,
+            slash_amount: slash,
+            remaining_amount: position.effective_stake(),
+            rewards_forfeited,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let actual_slash = slashed.iter().fold(0u64, |acc, s| acc.saturating_add(*s));
+    require!(actual_slash > 0, StakingError::SlashExceedsPosition);
+
+    // Update tier totals
+    let tiers = [
+        &mut ctx.accounts.aggressive_tier,
+        &mut ctx.accounts.standard_tier,
+        &mut ctx.accounts.conservative_tier,
+    ];
+    for (index, tier) in tiers.into_iter().enumerate() {
+        // Forfeited vesting rewards return to the tier's pool
+        tier.rewards_pool = tier.rewards_pool.saturating_add(vesting_returned[index]);
+        if slashed[index] == 0 {
+            continue;
+        }
+        tier.total_staked = tier.total_staked.saturating_sub(slashed[index]);
+
+        emit!(Slashed {
+            tier_id: tier.tier_id,
+            total_slashed: slashed[index],
+            positions_affected: positions_affected[index],
+            reason: reason.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    let config = &mut ctx.accounts.staking_config;
+    config.total_staked = config.total_staked.saturating_sub(actual_slash);
+
+    // Whatever no tier could absorb escalates on the last tier in the waterfall
+    let unabsorbed_aph = aph_required.saturating_sub(actual_slash);
+    if unabsorbed_aph > 0 {
+        let last = &mut ctx.accounts.conservative_tier;
+        last.unslashable_shortfall = last.unslashable_shortfall.saturating_add(unabsorbed_aph);
+
+        emit!(UnslashableShortfall {
+            tier_id: last.tier_id,
+            requested_amount: aph_required,
+            slashed_amount: actual_slash,
+            shortfall: unabsorbed_aph,
+            cumulative_shortfall: last.unslashable_shortfall,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    // Queue for liquidation
+    let liq_queue = &mut ctx.accounts.liquidation_queue;
+    require!(
+        liq_queue.entries.len() < 50,
+        StakingError::LiquidationQueueFull
+    );
+
+    let target_usdc_queued = feed.usdc_value(actual_slash).min(target_usdc);
+    let twap_hours = liq_queue.twap_window_hours as i64;
+    let twap_end = clock.unix_timestamp + (twap_hours * 60 * 60);
+
+    liq_queue.entries.push(LiquidationEntry {
+        aph_amount: actual_slash,
+        target_usdc: target_usdc_queued,
+        created_at: clock.unix_timestamp,
+        start_at: clock.unix_timestamp,
+        end_at: twap_end,
+        liquidated_aph: 0,
+        received_usdc: 0,
+        is_complete: false,
+        oracle_price: 0,
+    });
+
+    liq_queue.pending_liquidation = liq_queue.pending_liquidation.saturating_add(actual_slash);
+
+    emit!(SlashWaterfallExecuted {
+        target_usdc,
+        aph_required,
+        aggressive_slashed: slashed[0],
+        standard_slashed: slashed[1],
+        conservative_slashed: slashed[2],
+        unabsorbed_aph,
+        oracle_price: feed.price,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
+
+    emit!(LiquidationQueued {
+        aph_amount: actual_slash,
+        target_usdc: target_usdc_queued,
         twap_start: clock.unix_timestamp,
         twap_end,
         timestamp: clock.unix_timestamp,
@@ -244,6 +620,14 @@ pub struct ExecuteLiquidation<'info> {
     )]
     pub liquidation_queue: Account<'info, LiquidationQueue>,
 
+    /// APH price feed - fills must be within the circuit breaker band of
+    /// the oracle value
+    #[account(
+        seeds = [AphPriceFeed::SEED_PREFIX],
+        bump = price_feed.bump,
+    )]
+    pub price_feed: Account<'info, AphPriceFeed>,
+
     /// Liquidation executor (can be anyone for permissionless execution)
     pub executor: Signer<'info>,
 }
@@ -312,7 +696,19 @@ pub fn execute_liquidation(
         return Err(StakingError::SlippageExceeded.into());
     }
 
+    // Reject fills that stray from the oracle value (under-reported proceeds)
+    let feed = &ctx.accounts.price_feed;
+    require!(
+        feed.is_fresh(clock.unix_timestamp),
+        StakingError::StaleOraclePrice
+    );
+    require!(
+        feed.deviation_bps(aph_sold, usdc_received) <= LIQUIDATION_CIRCUIT_BREAKER_BPS,
+        StakingError::OraclePriceDeviation
+    );
+
     // Update entry
+    entry.oracle_price = feed.price;
     entry.liquidated_aph = entry.liquidated_aph.saturating_add(aph_sold);
     entry.received_usdc = entry.received_usdc.saturating_add(usdc_received);
 
@@ -331,6 +727,7 @@ pub fn execute_liquidation(
         aph_sold,
         usdc_received,
         slippage_bps,
+        oracle_price: feed.price,
         timestamp: clock.unix_timestamp,
     });
 
@@ -364,6 +761,73 @@ pub fn reset_circuit_breaker(ctx: Context<ResetCircuitBreaker>) -> Result<()> {
     Ok(())
 }
 
+/// Initialize the APH price feed (authority only)
+#[derive(Accounts)]
+pub struct InitializePriceFeed<'info> {
+    #[account(
+        seeds = [StakingConfig::SEED_PREFIX],
+        bump = staking_config.bump,
+    )]
+    pub staking_config: Account<'info, StakingConfig>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AphPriceFeed::INIT_SPACE,
+        seeds = [AphPriceFeed::SEED_PREFIX],
+        bump
+    )]
+    pub price_feed: Account<'info, AphPriceFeed>,
+
+    #[account(
+        mut,
+        constraint = authority.key() == staking_config.authority @ StakingError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_price_feed(ctx: Context<InitializePriceFeed>, publisher: Pubkey) -> Result<()> {
+    let feed = &mut ctx.accounts.price_feed;
+    feed.publisher = publisher;
+    feed.price = 0;
+    feed.publish_time = 0;
+    feed.bump = ctx.bumps.price_feed;
+    Ok(())
+}
+
+/// Push a new APH price (publisher only)
+#[derive(Accounts)]
+pub struct UpdateAphPrice<'info> {
+    #[account(
+        mut,
+        seeds = [AphPriceFeed::SEED_PREFIX],
+        bump = price_feed.bump,
+        constraint = price_feed.publisher == publisher.key() @ StakingError::Unauthorized
+    )]
+    pub price_feed: Account<'info, AphPriceFeed>,
+
+    pub publisher: Signer<'info>,
+}
+
+pub fn update_aph_price(ctx: Context<UpdateAphPrice>, price: u64) -> Result<()> {
+    require!(price > 0, StakingError::InvalidOraclePrice);
+
+    let clock = Clock::get()?;
+    let feed = &mut ctx.accounts.price_feed;
+    feed.price = price;
+    feed.publish_time = clock.unix_timestamp;
+
+    emit!(AphPriceUpdated {
+        price,
+        publisher: feed.publisher,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
 /// Clear completed liquidation entries
 #[derive(Accounts)]
 pub struct ClearCompletedLiquidations<'info> {
@@ -6,12 +6,18 @@
 // Handles transfer fee extension awareness.
 
 use crate::errors::StakingError;
-use crate::events::{Staked, Unstaked};
-use crate::state::{AphVault, StakePosition, StakerAccount, StakingConfig, StakingTier};
+use crate::events::{
+    EmergencyExitFeeCharged, PositionTokenized, RewardShortfall, Staked, Unstaked,
+};
+use crate::instructions::rewards::vest_claimed_rewards;
+use crate::state::{
+    AphVault, RewardVesting, StakePosition, StakerAccount, StakingConfig, StakingTier,
+};
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{
-    self, Mint as MintInterface, TokenAccount as TokenAccountInterface, TokenInterface,
-    TransferChecked,
+    self, Burn, Mint as MintInterface, MintTo, TokenAccount as TokenAccountInterface,
+    TokenInterface, TransferChecked,
 };
 
 // =============================================================================
@@ -139,12 +145,16 @@ pub fn stake(ctx: Context<Stake>, amount: u64) -> Result<()> {
     position.original_amount = amount;
     position.rewards_earned = 0;
     position.rewards_claimed = 0;
+    position.accrued_unclaimed = 0;
     position.staked_at = clock.unix_timestamp;
     position.lock_ends_at = lock_ends_at;
-    position.last_reward_at = clock.unix_timestamp;
+    position.last_accrued_at = clock.unix_timestamp;
+    position.checkpoint_apy_bps = tier.current_apy_bps;
     position.is_active = true;
     position.was_slashed = false;
     position.slash_amount = 0;
+    position.rewards_forfeited = 0;
+    position.receipt_mint = Pubkey::default();
     position.bump = ctx.bumps.stake_position;
 
     // Update staker account
@@ -209,9 +219,11 @@ pub struct Unstake<'info> {
     )]
     pub aph_vault: Account<'info, AphVault>,
 
+    /// Original staker's account (totals stay with the original staker
+    /// after a receipt transfer)
     #[account(
         mut,
-        seeds = [StakerAccount::SEED_PREFIX, staker.key().as_ref()],
+        seeds = [StakerAccount::SEED_PREFIX, stake_position.staker.as_ref()],
         bump = staker_account.bump,
     )]
     pub staker_account: Account<'info, StakerAccount>,
@@ -220,15 +232,32 @@ pub struct Unstake<'info> {
         mut,
         seeds = [
             StakePosition::SEED_PREFIX,
-            staker.key().as_ref(),
+            stake_position.staker.as_ref(),
             &stake_position.position_id.to_le_bytes()
         ],
         bump = stake_position.bump,
-        constraint = stake_position.staker == staker.key() @ StakingError::Unauthorized,
         constraint = stake_position.is_active @ StakingError::PositionAlreadyClosed
     )]
     pub stake_position: Account<'info, StakePosition>,
 
+    /// Receipt mint (tokenized positions only; the receipt is burned)
+    #[account(mut)]
+    pub receipt_mint: Option<InterfaceAccount<'info, MintInterface>>,
+
+    /// Signer's receipt token account (tokenized positions only)
+    #[account(mut)]
+    pub receipt_token_account: Option<InterfaceAccount<'info, TokenAccountInterface>>,
+
+    /// Vesting account for this position (required if the tier vests rewards)
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + RewardVesting::INIT_SPACE,
+        seeds = [RewardVesting::SEED_PREFIX, stake_position.key().as_ref()],
+        bump
+    )]
+    pub reward_vesting: Option<Account<'info, RewardVesting>>,
+
     /// APH Token-2022 mint
     #[account(
         constraint = aph_mint.key() == staking_config.aph_mint @ StakingError::InvalidTokenAccount
@@ -250,14 +279,24 @@ pub struct Unstake<'info> {
     )]
     pub vault_token_account: InterfaceAccount<'info, TokenAccountInterface>,
 
+    #[account(mut)]
     pub staker: Signer<'info>,
 
     /// Token-2022 program for APH operations
     pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
     let clock = Clock::get()?;
+    burn_position_receipt(
+        &ctx.accounts.stake_position,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.staker,
+        &ctx.accounts.token_program,
+    )?;
     let position = &mut ctx.accounts.stake_position;
 
     // Check lock period is complete
@@ -266,11 +305,28 @@ pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
         StakingError::PositionLocked
     );
 
+    // Rewards run to the unstake; an underfunded pool pays what it can and
+    // vesting tiers hold the rest back, as with claim_rewards
+    let tier = &mut ctx.accounts.staking_tier;
+    let staker_account = &mut ctx.accounts.staker_account;
+    let earned = position.accrue(clock.unix_timestamp, tier.current_apy_bps);
+    staker_account.total_rewards_earned =
+        staker_account.total_rewards_earned.saturating_add(earned);
+
+    let accrued = position.accrued_unclaimed;
+    let rewards = position.take_accrued_up_to(tier.rewards_pool);
+    let payout = vest_claimed_rewards(
+        ctx.accounts.reward_vesting.as_mut(),
+        ctx.bumps.reward_vesting,
+        position,
+        ctx.accounts.staker.key(),
+        rewards,
+        tier,
+        clock.unix_timestamp,
+    )?;
+
     let effective_amount = position.effective_stake();
-    let rewards = position
-        .rewards_earned
-        .saturating_sub(position.rewards_claimed);
-    let total_withdrawal = effective_amount.saturating_add(rewards);
+    let total_withdrawal = effective_amount.saturating_add(payout);
 
     // Get decimals for transfer_checked
     let decimals = ctx.accounts.aph_mint.decimals;
@@ -295,14 +351,11 @@ pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
     )?;
 
     // Update state
-    let tier = &mut ctx.accounts.staking_tier;
     let config = &mut ctx.accounts.staking_config;
     let vault = &mut ctx.accounts.aph_vault;
-    let staker_account = &mut ctx.accounts.staker_account;
 
     // Mark position closed
     position.is_active = false;
-    position.rewards_claimed = position.rewards_earned;
 
     // Update staker account
     staker_account.total_staked = staker_account
@@ -315,17 +368,32 @@ pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
         .voting_power
         .saturating_sub(position.original_amount);
 
-    // Update tier
+    // Update tier. Vesting rewards leave the pool now but stay in the vault
+    // until released.
     tier.total_staked = tier.total_staked.saturating_sub(position.original_amount);
     tier.staker_count = tier.staker_count.saturating_sub(1);
+    tier.rewards_pool = tier.rewards_pool.saturating_sub(rewards);
 
     // Update config
     config.total_staked = config.total_staked.saturating_sub(position.original_amount);
-    config.total_rewards_distributed = config.total_rewards_distributed.saturating_add(rewards);
+    config.total_rewards_distributed = config.total_rewards_distributed.saturating_add(payout);
 
     // Update vault
     vault.total_aph = vault.total_aph.saturating_sub(total_withdrawal);
     vault.locked_aph = vault.locked_aph.saturating_sub(effective_amount);
+    vault.rewards_available = vault.rewards_available.saturating_sub(rewards);
+
+    if position.accrued_unclaimed > 0 {
+        emit!(RewardShortfall {
+            staker: ctx.accounts.staker.key(),
+            position_id: position.position_id,
+            tier_id: tier.tier_id,
+            requested: accrued,
+            paid: rewards,
+            deferred: position.accrued_unclaimed,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     emit!(Unstaked {
         staker: ctx.accounts.staker.key(),
@@ -368,9 +436,11 @@ pub struct EmergencyUnstake<'info> {
     )]
     pub aph_vault: Account<'info, AphVault>,
 
+    /// Original staker's account (totals stay with the original staker
+    /// after a receipt transfer)
     #[account(
         mut,
-        seeds = [StakerAccount::SEED_PREFIX, staker.key().as_ref()],
+        seeds = [StakerAccount::SEED_PREFIX, stake_position.staker.as_ref()],
         bump = staker_account.bump,
     )]
     pub staker_account: Account<'info, StakerAccount>,
@@ -379,15 +449,22 @@ pub struct EmergencyUnstake<'info> {
         mut,
         seeds = [
             StakePosition::SEED_PREFIX,
-            staker.key().as_ref(),
+            stake_position.staker.as_ref(),
             &stake_position.position_id.to_le_bytes()
         ],
         bump = stake_position.bump,
-        constraint = stake_position.staker == staker.key() @ StakingError::Unauthorized,
         constraint = stake_position.is_active @ StakingError::PositionAlreadyClosed
     )]
     pub stake_position: Account<'info, StakePosition>,
 
+    /// Receipt mint (tokenized positions only; the receipt is burned)
+    #[account(mut)]
+    pub receipt_mint: Option<InterfaceAccount<'info, MintInterface>>,
+
+    /// Signer's receipt token account (tokenized positions only)
+    #[account(mut)]
+    pub receipt_token_account: Option<InterfaceAccount<'info, TokenAccountInterface>>,
+
     /// APH Token-2022 mint
     #[account(
         constraint = aph_mint.key() == staking_config.aph_mint @ StakingError::InvalidTokenAccount
@@ -415,16 +492,25 @@ pub struct EmergencyUnstake<'info> {
 
 pub fn emergency_unstake(ctx: Context<EmergencyUnstake>) -> Result<()> {
     let clock = Clock::get()?;
-    let config = &ctx.accounts.staking_config;
+    burn_position_receipt(
+        &ctx.accounts.stake_position,
+        &ctx.accounts.receipt_mint,
+        &ctx.accounts.receipt_token_account,
+        &ctx.accounts.staker,
+        &ctx.accounts.token_program,
+    )?;
     let position = &mut ctx.accounts.stake_position;
 
     let effective_amount = position.effective_stake();
 
-    // Calculate emergency fee
-    let fee = effective_amount
-        .saturating_mul(config.emergency_unstake_fee_bps as u64)
-        .checked_div(10000)
-        .unwrap_or(0);
+    // Tier exit fee, scaled by the fraction of the lock still remaining
+    let tier_fee_bps = ctx.accounts.staking_tier.emergency_exit_fee_bps;
+    let (fee, effective_fee_bps) = ctx.accounts.staking_tier.emergency_exit_fee(
+        effective_amount,
+        position.staked_at,
+        position.lock_ends_at,
+        clock.unix_timestamp,
+    );
 
     let withdrawal_amount = effective_amount.saturating_sub(fee);
     // No rewards for emergency unstake
@@ -482,6 +568,16 @@ pub fn emergency_unstake(ctx: Context<EmergencyUnstake>) -> Result<()> {
     vault.locked_aph = vault.locked_aph.saturating_sub(effective_amount);
     vault.rewards_available = vault.rewards_available.saturating_add(fee);
 
+    emit!(EmergencyExitFeeCharged {
+        staker: ctx.accounts.staker.key(),
+        position_id: position.position_id,
+        tier_id: tier.tier_id,
+        tier_fee_bps,
+        effective_fee_bps,
+        fee,
+        timestamp: clock.unix_timestamp,
+    });
+
     emit!(Unstaked {
         staker: ctx.accounts.staker.key(),
         position_id: position.position_id,
@@ -494,3 +590,162 @@ pub fn emergency_unstake(ctx: Context<EmergencyUnstake>) -> Result<()> {
 
     Ok(())
 }
+
+// =============================================================================
+// POSITION RECEIPTS (Transferable Positions)
+// =============================================================================
+
+/// Mint a one-of-one receipt token for a position. Whoever holds the
+/// receipt can claim rewards and unstake; slashing follows the position.
+#[derive(Accounts)]
+pub struct TokenizePosition<'info> {
+    #[account(
+        seeds = [AphVault::SEED_PREFIX],
+        bump = aph_vault.bump,
+    )]
+    pub aph_vault: Account<'info, AphVault>,
+
+    #[account(
+        mut,
+        seeds = [
+            StakePosition::SEED_PREFIX,
+            staker.key().as_ref(),
+            &stake_position.position_id.to_le_bytes()
+        ],
+        bump = stake_position.bump,
+        constraint = stake_position.staker == staker.key() @ StakingError::Unauthorized,
+        constraint = stake_position.is_active @ StakingError::PositionAlreadyClosed,
+        constraint = !stake_position.is_tokenized() @ StakingError::PositionAlreadyTokenized
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+
+    /// Receipt mint (supply 1, no decimals), minted by the vault PDA
+    #[account(
+        init,
+        payer = staker,
+        seeds = [StakePosition::RECEIPT_SEED_PREFIX, stake_position.key().as_ref()],
+        bump,
+        mint::decimals = 0,
+        mint::authority = aph_vault,
+        mint::token_program = token_program,
+    )]
+    pub receipt_mint: InterfaceAccount<'info, MintInterface>,
+
+    #[account(
+        init,
+        payer = staker,
+        associated_token::mint = receipt_mint,
+        associated_token::authority = staker,
+        associated_token::token_program = token_program,
+    )]
+    pub receipt_token_account: InterfaceAccount<'info, TokenAccountInterface>,
+
+    #[account(mut)]
+    pub staker: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn tokenize_position(ctx: Context<TokenizePosition>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let vault_seeds = &[AphVault::SEED_PREFIX, &[ctx.accounts.aph_vault.bump]];
+    let signer_seeds = &[&vault_seeds[..]];
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.receipt_mint.to_account_info(),
+                to: ctx.accounts.receipt_token_account.to_account_info(),
+                authority: ctx.accounts.aph_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+
+    // Fix supply at one
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token_interface::SetAuthority {
+                current_authority: ctx.accounts.aph_vault.to_account_info(),
+                account_or_mint: ctx.accounts.receipt_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType::MintTokens,
+        None,
+    )?;
+
+    let position = &mut ctx.accounts.stake_position;
+    position.receipt_mint = ctx.accounts.receipt_mint.key();
+
+    emit!(PositionTokenized {
+        staker: ctx.accounts.staker.key(),
+        position: position.key(),
+        position_id: position.position_id,
+        receipt_mint: position.receipt_mint,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Mint of the receipt `holder` holds in `receipt_token_account`, if any
+pub(crate) fn held_receipt_mint(
+    receipt_token_account: &Option<InterfaceAccount<TokenAccountInterface>>,
+    holder: Pubkey,
+) -> Option<Pubkey> {
+    receipt_token_account
+        .as_ref()
+        .filter(|account| account.owner == holder && account.amount == 1)
+        .map(|account| account.mint)
+}
+
+/// Check the signer controls the position; for a tokenized position,
+/// burn the signer's receipt as the position closes
+fn burn_position_receipt<'info>(
+    position: &StakePosition,
+    receipt_mint: &Option<InterfaceAccount<'info, MintInterface>>,
+    receipt_token_account: &Option<InterfaceAccount<'info, TokenAccountInterface>>,
+    staker: &Signer<'info>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
+    require!(
+        position.is_controlled_by(
+            staker.key(),
+            held_receipt_mint(receipt_token_account, staker.key())
+        ),
+        StakingError::Unauthorized
+    );
+    if !position.is_tokenized() {
+        return Ok(());
+    }
+
+    let (Some(mint), Some(holder_account)) = (receipt_mint, receipt_token_account) else {
+        return err!(StakingError::PositionReceiptRequired);
+    };
+    require_keys_eq!(
+        mint.key(),
+        position.receipt_mint,
+        StakingError::PositionReceiptRequired
+    );
+
+    token_interface::burn(
+        CpiContext::new(
+            token_program.to_account_info(),
+            Burn {
+                mint: mint.to_account_info(),
+                from: holder_account.to_account_info(),
+                authority: staker.to_account_info(),
+            },
+        ),
+        1,
+    )
+}
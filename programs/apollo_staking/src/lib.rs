@@ -69,6 +69,11 @@ pub mod apollo_staking {
         instructions::staking::emergency_unstake(ctx)
     }
 
+    /// Mint a transferable receipt for a position; the holder controls it
+    pub fn tokenize_position(ctx: Context<TokenizePosition>) -> Result<()> {
+        instructions::staking::tokenize_position(ctx)
+    }
+
     // ==================== REWARDS ====================
 
     /// Compute rewards for a position
@@ -81,6 +86,11 @@ pub mod apollo_staking {
         instructions::rewards::claim_rewards(ctx)
     }
 
+    /// Release rewards that have vested from earlier claims
+    pub fn release_vested_rewards(ctx: Context<ReleaseVestedRewards>) -> Result<()> {
+        instructions::rewards::release_vested_rewards(ctx)
+    }
+
     /// Fund rewards pool for a tier
     pub fn fund_rewards_pool(ctx: Context<FundRewardsPool>, amount: u64) -> Result<()> {
         instructions::rewards::fund_rewards_pool(ctx, amount)
@@ -91,6 +101,22 @@ pub mod apollo_staking {
         instructions::rewards::update_tier_apy(ctx, new_apy_bps)
     }
 
+    /// Set how long claimed rewards vest for a tier (0 = instant)
+    pub fn set_tier_reward_vesting(
+        ctx: Context<UpdateTierApy>,
+        vesting_seconds: i64,
+    ) -> Result<()> {
+        instructions::rewards::set_tier_reward_vesting(ctx, vesting_seconds)
+    }
+
+    /// Set the share of slashed principal's unclaimed rewards forfeited
+    pub fn set_tier_slash_reward_forfeit(
+        ctx: Context<UpdateTierApy>,
+        forfeit_bps: u16,
+    ) -> Result<()> {
+        instructions::rewards::set_tier_slash_reward_forfeit(ctx, forfeit_bps)
+    }
+
     // ==================== SLASHING ====================
 
     /// Slash a specific position
@@ -103,9 +129,9 @@ pub mod apollo_staking {
         instructions::slashing::slash_position(ctx, slash_amount, target_usdc_value, reason)
     }
 
-    /// Slash across entire tier (proportional)
-    pub fn slash_tier(
-        ctx: Context<SlashTier>,
+    /// Slash across entire tier (proportional, capped per position)
+    pub fn slash_tier<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SlashTier<'info>>,
         total_slash_amount: u64,
         target_usdc_value: u64,
         reason: String,
@@ -113,6 +139,15 @@ pub mod apollo_staking {
         instructions::slashing::slash_tier(ctx, total_slash_amount, target_usdc_value, reason)
     }
 
+    /// Slash a USDC shortfall across tiers: Aggressive, then Standard, then Conservative
+    pub fn slash_waterfall<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SlashWaterfall<'info>>,
+        target_usdc: u64,
+        reason: String,
+    ) -> Result<()> {
+        instructions::slashing::slash_waterfall(ctx, target_usdc, reason)
+    }
+
     /// Execute liquidation (sell slashed APH for USDC)
     pub fn execute_liquidation(
         ctx: Context<ExecuteLiquidation>,
@@ -128,10 +163,30 @@ pub mod apollo_staking {
         instructions::slashing::reset_circuit_breaker(ctx)
     }
 
+    /// Initialize the APH price feed used to check liquidation fills
+    pub fn initialize_price_feed(
+        ctx: Context<InitializePriceFeed>,
+        publisher: Pubkey,
+    ) -> Result<()> {
+        instructions::slashing::initialize_price_feed(ctx, publisher)
+    }
+
+    /// Publish a new APH price
+    pub fn update_aph_price(ctx: Context<UpdateAphPrice>, price: u64) -> Result<()> {
+        instructions::slashing::update_aph_price(ctx, price)
+    }
+
     /// Clear completed liquidation entries
     pub fn clear_completed_liquidations(ctx: Context<ClearCompletedLiquidations>) -> Result<()> {
         instructions::slashing::clear_completed_liquidations(ctx)
     }
+
+    // ==================== CAR REPORTING ====================
+
+    /// Report CAR-eligible staked APH to the risk engine (permissionless)
+    pub fn report_eligible_aph(ctx: Context<ReportEligibleAph>) -> Result<()> {
+        instructions::reporting::report_eligible_aph(ctx)
+    }
 }
 
 /// Public helpers for CPI
@@ -1,6 +1,8 @@
 // programs/apollo_staking/src/state.rs
 
 use anchor_lang::prelude::*;
+use apollo_core::actuarial;
+use apollo_core::protocol_constants::{APH_DECIMALS, SECONDS_PER_YEAR};
 
 /// Global staking configuration
 /// PDA seeds: ["staking_config"]
@@ -53,6 +55,12 @@ impl StakingConfig {
     pub const DEFAULT_EPOCH_DURATION: i64 = 7 * 24 * 60 * 60; // 7 days
     pub const DEFAULT_HAIRCUT_BPS: u16 = 5000; // 50%
     pub const DEFAULT_EMERGENCY_FEE_BPS: u16 = 1000; // 10%
+
+    /// Value of staked APH that counts toward CAR after the haircut
+    pub fn apply_haircut(&self, aph_usdc_value: u64) -> u64 {
+        let retained_bps = 10000u64.saturating_sub(self.aph_haircut_bps as u64);
+        (aph_usdc_value as u128 * retained_bps as u128 / 10000) as u64
+    }
 }
 
 /// Staking tier configuration
@@ -83,6 +91,11 @@ pub struct StakingTier {
     /// Lock period in seconds
     pub lock_period: i64,
 
+    /// Emergency exit fee at the start of the lock (basis points),
+    /// scaled down by the fraction of the lock already served
+    /// Conservative: 500 (5%), Standard: 1000 (10%), Aggressive: 2000 (20%)
+    pub emergency_exit_fee_bps: u16,
+
     /// Total staked in this tier
     pub total_staked: u64,
 
@@ -92,6 +105,17 @@ pub struct StakingTier {
     /// Rewards pool for this tier
     pub rewards_pool: u64,
 
+    /// Cumulative slash amount that could not be absorbed within
+    /// position loss caps (escalated for coverage elsewhere)
+    pub unslashable_shortfall: u64,
+
+    /// Seconds over which claimed rewards vest (0 = paid out instantly)
+    pub reward_vesting_seconds: i64,
+
+    /// Share of a slashed position's unclaimed rewards forfeited pro-rata
+    /// to the principal slashed (bps; 10000 = fully pro-rata, 0 = none)
+    pub slash_reward_forfeit_bps: u16,
+
     /// Is this tier active
     pub is_active: bool,
 
@@ -106,6 +130,96 @@ impl StakingTier {
     pub const CONSERVATIVE: u8 = 0;
     pub const STANDARD: u8 = 1;
     pub const AGGRESSIVE: u8 = 2;
+
+    /// Slashed principal forfeits its full share of unclaimed rewards
+    pub const DEFAULT_SLASH_REWARD_FORFEIT_BPS: u16 = 10000;
+
+    /// Maximum amount a single slashing event may take from a stake,
+    /// bounded by this tier's max loss exposure
+    pub fn max_position_loss(&self, stake: u64) -> u64 {
+        (stake as u128 * self.max_loss_bps as u128 / 10000) as u64
+    }
+
+    /// Emergency exit fee for a position, scaled by the fraction of its
+    /// lock still remaining. Returns (fee, effective_fee_bps).
+    pub fn emergency_exit_fee(
+        &self,
+        amount: u64,
+        staked_at: i64,
+        lock_ends_at: i64,
+        current_time: i64,
+    ) -> (u64, u16) {
+        let lock_duration = lock_ends_at.saturating_sub(staked_at);
+        let remaining = lock_ends_at.saturating_sub(current_time).max(0);
+        if lock_duration <= 0 || remaining == 0 {
+            return (0, 0);
+        }
+
+        let remaining = remaining.min(lock_duration) as u128;
+        let effective_bps =
+            (self.emergency_exit_fee_bps as u128 * remaining / lock_duration as u128) as u16;
+        let fee = (amount as u128 * effective_bps as u128 / 10000) as u64;
+        (fee, effective_bps)
+    }
+
+    /// Proportional share of a tier-wide slash for a single stake,
+    /// capped at the tier's max loss for that stake
+    pub fn proportional_slash(&self, total_slash_amount: u64, stake: u64) -> u64 {
+        if self.total_staked == 0 {
+            return 0;
+        }
+
+        let share = (total_slash_amount as u128 * stake as u128 / self.total_staked as u128) as u64;
+
+        share.min(self.max_position_loss(stake)).min(stake)
+    }
+
+    /// Protocol ceiling on this tier's loss exposure
+    pub fn protocol_loss_max_bps(&self) -> u16 {
+        match self.tier_id {
+            Self::CONSERVATIVE => actuarial::STAKING_CONSERVATIVE_LOSS_MAX_BPS,
+            Self::STANDARD => actuarial::STAKING_STANDARD_LOSS_MAX_BPS,
+            _ => actuarial::STAKING_AGGRESSIVE_LOSS_MAX_BPS,
+        }
+    }
+
+    /// Most a single slashing event may take from the whole tier: its
+    /// configured max loss, never above the protocol ceiling
+    pub fn slash_capacity(&self) -> u64 {
+        let loss_bps = self.max_loss_bps.min(self.protocol_loss_max_bps());
+        (self.total_staked as u128 * loss_bps as u128 / 10000) as u64
+    }
+}
+
+/// Order in which tiers absorb a staking slash: Aggressive accepts the most
+/// loss, so it goes first
+pub const SLASH_WATERFALL_ORDER: [u8; 3] = [
+    StakingTier::AGGRESSIVE,
+    StakingTier::STANDARD,
+    StakingTier::CONSERVATIVE,
+];
+
+/// Split `total` APH across `tiers` (given in `SLASH_WATERFALL_ORDER`), each
+/// taking up to its slash capacity before the next is touched
+pub fn slash_waterfall_allocation(total: u64, tiers: &[&StakingTier]) -> Vec<u64> {
+    let mut remaining = total;
+    tiers
+        .iter()
+        .map(|tier| {
+            let take = remaining.min(tier.slash_capacity());
+            remaining -= take;
+            take
+        })
+        .collect()
+}
+
+/// CAR-eligible staked principal across tiers. Slashed APH is removed from
+/// tier totals when it is queued for liquidation, so it never counts here.
+pub fn eligible_staked_aph(tiers: &[&StakingTier]) -> u64 {
+    tiers
+        .iter()
+        .filter(|t| t.is_active)
+        .fold(0u64, |acc, t| acc.saturating_add(t.total_staked))
 }
 
 /// Default tier configurations
@@ -118,6 +232,7 @@ pub fn default_tier_configs() -> Vec<TierConfig> {
             max_apy_bps: 500,               // 5%
             max_loss_bps: 200,              // 2%
             lock_period: 30 * 24 * 60 * 60, // 30 days
+            emergency_exit_fee_bps: 500,    // 5%
         },
         TierConfig {
             tier_id: StakingTier::STANDARD,
@@ -126,6 +241,7 @@ pub fn default_tier_configs() -> Vec<TierConfig> {
             max_apy_bps: 800,               // 8%
             max_loss_bps: 500,              // 5%
             lock_period: 90 * 24 * 60 * 60, // 90 days
+            emergency_exit_fee_bps: 1000,   // 10%
         },
         TierConfig {
             tier_id: StakingTier::AGGRESSIVE,
@@ -134,6 +250,7 @@ pub fn default_tier_configs() -> Vec<TierConfig> {
             max_apy_bps: 1500,               // 15%
             max_loss_bps: 1000,              // 10%
             lock_period: 180 * 24 * 60 * 60, // 180 days
+            emergency_exit_fee_bps: 2000,    // 20%
         },
     ]
 }
@@ -146,6 +263,7 @@ pub struct TierConfig {
     pub max_apy_bps: u16,
     pub max_loss_bps: u16,
     pub lock_period: i64,
+    pub emergency_exit_fee_bps: u16,
 }
 
 /// Individual staker position
@@ -168,20 +286,26 @@ pub struct StakePosition {
     /// Original amount (before any slashing)
     pub original_amount: u64,
 
-    /// Rewards earned (claimable)
+    /// Rewards earned (lifetime)
     pub rewards_earned: u64,
 
     /// Rewards claimed
     pub rewards_claimed: u64,
 
+    /// Rewards accrued but not yet claimed
+    pub accrued_unclaimed: u64,
+
     /// Stake timestamp
     pub staked_at: i64,
 
     /// Lock end timestamp
     pub lock_ends_at: i64,
 
-    /// Last reward computation timestamp
-    pub last_reward_at: i64,
+    /// Reward accrual checkpoint
+    pub last_accrued_at: i64,
+
+    /// Tier APY in force at the checkpoint (applies until the next one)
+    pub checkpoint_apy_bps: u16,
 
     /// Is position active
     pub is_active: bool,
@@ -192,6 +316,13 @@ pub struct StakePosition {
     /// Slash amount (if any)
     pub slash_amount: u64,
 
+    /// Unclaimed rewards forfeited because their principal was slashed
+    pub rewards_forfeited: u64,
+
+    /// Receipt token mint once the position is tokenized (default = not
+    /// tokenized). The receipt holder controls the position.
+    pub receipt_mint: Pubkey,
+
     /// Bump seed
     pub bump: u8,
 }
@@ -199,6 +330,25 @@ pub struct StakePosition {
 impl StakePosition {
     pub const SEED_PREFIX: &'static [u8] = b"stake_position";
 
+    /// Seed for a tokenized position's receipt mint
+    pub const RECEIPT_SEED_PREFIX: &'static [u8] = b"position_receipt";
+
+    /// Whether a transferable receipt has been minted for this position
+    pub fn is_tokenized(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    /// Whether `signer` may unstake or claim: the original staker until the
+    /// position is tokenized, then whoever holds the receipt.
+    /// `held_receipt_mint` is the mint of a receipt the signer holds, if any.
+    pub fn is_controlled_by(&self, signer: Pubkey, held_receipt_mint: Option<Pubkey>) -> bool {
+        if self.is_tokenized() {
+            held_receipt_mint == Some(self.receipt_mint)
+        } else {
+            signer == self.staker
+        }
+    }
+
     /// Check if position is unlocked
     pub fn is_unlocked(&self, current_time: i64) -> bool {
         current_time >= self.lock_ends_at
@@ -208,6 +358,170 @@ impl StakePosition {
     pub fn effective_stake(&self) -> u64 {
         self.amount.saturating_sub(self.slash_amount)
     }
+
+    /// Accrue rewards since the checkpoint at the snapshotted APY, then move
+    /// the checkpoint to `current_time` and snapshot `current_apy_bps`.
+    /// Returns the rewards added (zero on a repeated call at the same time).
+    pub fn accrue(&mut self, current_time: i64, current_apy_bps: u16) -> u64 {
+        let elapsed = current_time.saturating_sub(self.last_accrued_at);
+        if elapsed <= 0 {
+            return 0;
+        }
+
+        // principal * apy_bps * elapsed / (SECONDS_PER_YEAR * 10000)
+        let rewards = (self.effective_stake() as u128)
+            .saturating_mul(self.checkpoint_apy_bps as u128)
+            .saturating_mul(elapsed as u128)
+            .checked_div(SECONDS_PER_YEAR as u128 * 10000)
+            .unwrap_or(0)
+            .min(u64::MAX as u128) as u64;
+
+        self.rewards_earned = self.rewards_earned.saturating_add(rewards);
+        self.accrued_unclaimed = self.accrued_unclaimed.saturating_add(rewards);
+        self.last_accrued_at = current_time;
+        self.checkpoint_apy_bps = current_apy_bps;
+
+        rewards
+    }
+
+    /// Slash `slash` of the effective stake. Rewards are first accrued up to
+    /// `current_time` on the pre-slash principal, then `forfeit_bps` of the
+    /// slashed share of everything unclaimed is forfeited, so a staker cannot
+    /// claim full rewards on principal that absorbed losses. The forfeited
+    /// rewards stay in the tier's pool. Returns the rewards forfeited.
+    pub fn apply_slash(
+        &mut self,
+        slash: u64,
+        current_time: i64,
+        current_apy_bps: u16,
+        forfeit_bps: u16,
+    ) -> u64 {
+        self.accrue(current_time, current_apy_bps);
+
+        let principal = self.effective_stake();
+        let slash = slash.min(principal);
+        let forfeited = if principal == 0 {
+            0
+        } else {
+            (self.accrued_unclaimed as u128 * slash as u128 / principal as u128
+                * forfeit_bps.min(10000) as u128
+                / 10000) as u64
+        };
+
+        self.accrued_unclaimed -= forfeited;
+        self.rewards_forfeited = self.rewards_forfeited.saturating_add(forfeited);
+        self.slash_amount = self.slash_amount.saturating_add(slash);
+        self.was_slashed = true;
+
+        forfeited
+    }
+
+    /// Pay out all accrued rewards, returning the amount claimed
+    pub fn take_accrued(&mut self) -> u64 {
+        self.take_accrued_up_to(u64::MAX)
+    }
+
+    /// Pay out accrued rewards up to `available`, carrying any shortfall
+    /// forward in `accrued_unclaimed`. Returns the amount claimed.
+    pub fn take_accrued_up_to(&mut self, available: u64) -> u64 {
+        let claimed = self.accrued_unclaimed.min(available);
+        self.accrued_unclaimed -= claimed;
+        self.rewards_claimed = self.rewards_claimed.saturating_add(claimed);
+        claimed
+    }
+}
+
+/// Claimed rewards released linearly over the tier's vesting period
+/// PDA seeds: ["reward_vesting", stake_position]
+#[account]
+#[derive(InitSpace)]
+pub struct RewardVesting {
+    /// Staker the rewards belong to
+    pub staker: Pubkey,
+
+    /// Stake position the rewards were claimed from
+    pub position: Pubkey,
+
+    /// Amount vesting in the current schedule
+    pub total_amount: u64,
+
+    /// Amount of the current schedule already released
+    pub released_amount: u64,
+
+    /// Vesting start timestamp
+    pub start_time: i64,
+
+    /// Full vesting end timestamp
+    pub vesting_end: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl RewardVesting {
+    pub const SEED_PREFIX: &'static [u8] = b"reward_vesting";
+
+    /// Calculate currently vested amount (linear from `start_time`)
+    pub fn vested_amount(&self, current_time: i64) -> u64 {
+        if current_time >= self.vesting_end {
+            return self.total_amount;
+        }
+        if current_time <= self.start_time {
+            return 0;
+        }
+
+        let duration = (self.vesting_end - self.start_time) as u128;
+        let elapsed = (current_time - self.start_time) as u128;
+        (self.total_amount as u128 * elapsed / duration) as u64
+    }
+
+    /// Calculate releasable amount (vested - already released)
+    pub fn releasable_amount(&self, current_time: i64) -> u64 {
+        self.vested_amount(current_time)
+            .saturating_sub(self.released_amount)
+    }
+
+    /// Mark everything vested so far as released, returning the amount
+    pub fn release(&mut self, current_time: i64) -> u64 {
+        let amount = self.releasable_amount(current_time);
+        self.released_amount = self.released_amount.saturating_add(amount);
+        amount
+    }
+
+    /// Route a reward claim through vesting. Anything already vested is
+    /// released; the unvested remainder restarts alongside `amount` over
+    /// `vesting_seconds`. With no vesting period the claim is paid in full.
+    /// Returns the amount payable now.
+    pub fn settle_claim(&mut self, amount: u64, current_time: i64, vesting_seconds: i64) -> u64 {
+        let released = self.release(current_time);
+        if vesting_seconds <= 0 {
+            return released.saturating_add(amount);
+        }
+
+        let unvested = self.total_amount.saturating_sub(self.released_amount);
+        self.total_amount = unvested.saturating_add(amount);
+        self.released_amount = 0;
+        self.start_time = current_time;
+        self.vesting_end = current_time.saturating_add(vesting_seconds);
+        released
+    }
+
+    /// Forfeit `forfeit_bps` of the slashed share (`slash` of `principal`)
+    /// of rewards not yet released, shrinking the remaining schedule.
+    /// Returns the amount forfeited.
+    pub fn forfeit_on_slash(&mut self, slash: u64, principal: u64, forfeit_bps: u16) -> u64 {
+        if principal == 0 {
+            return 0;
+        }
+
+        let unreleased = self.total_amount.saturating_sub(self.released_amount);
+        let forfeited = (unreleased as u128 * slash.min(principal) as u128 / principal as u128
+            * forfeit_bps.min(10000) as u128
+            / 10000) as u64;
+
+        self.total_amount -= forfeited;
+        forfeited
+    }
 }
 
 /// Staker account (aggregates all positions)
@@ -325,6 +639,60 @@ pub struct LiquidationEntry {
     pub received_usdc: u64,
     /// Is complete
     pub is_complete: bool,
+    /// Oracle price used for the last fill (USDC per APH, 0 if none)
+    pub oracle_price: u64,
+}
+
+/// APH/USDC price feed used to sanity-check liquidation fills
+/// (Pyth/Switchboard-style push oracle)
+/// PDA seeds: ["aph_price_feed"]
+#[account]
+#[derive(InitSpace)]
+pub struct AphPriceFeed {
+    /// Publisher allowed to push prices
+    pub publisher: Pubkey,
+
+    /// Price of 1 APH in USDC (6 decimals)
+    pub price: u64,
+
+    /// Last publish timestamp
+    pub publish_time: i64,
+
+    /// Bump seed
+    pub bump: u8,
+}
+
+impl AphPriceFeed {
+    pub const SEED_PREFIX: &'static [u8] = b"aph_price_feed";
+    pub const MAX_PRICE_AGE: i64 = 60 * 60; // 1 hour
+
+    pub fn is_fresh(&self, current_time: i64) -> bool {
+        self.price > 0 && current_time.saturating_sub(self.publish_time) <= Self::MAX_PRICE_AGE
+    }
+
+    /// USDC value of an APH amount at the feed price
+    pub fn usdc_value(&self, aph_amount: u64) -> u64 {
+        (aph_amount as u128 * self.price as u128 / 10u128.pow(APH_DECIMALS as u32)) as u64
+    }
+
+    /// APH needed to cover a USDC amount at the feed price (rounded up)
+    pub fn aph_for_usdc(&self, usdc_amount: u64) -> u64 {
+        if self.price == 0 {
+            return 0;
+        }
+        let scaled = usdc_amount as u128 * 10u128.pow(APH_DECIMALS as u32);
+        scaled.div_ceil(self.price as u128).min(u64::MAX as u128) as u64
+    }
+
+    /// Deviation of a fill from the oracle value (basis points, either direction)
+    pub fn deviation_bps(&self, aph_sold: u64, usdc_received: u64) -> u16 {
+        let expected = self.usdc_value(aph_sold);
+        if expected == 0 {
+            return if usdc_received == 0 { 0 } else { u16::MAX };
+        }
+        let diff = expected.abs_diff(usdc_received) as u128;
+        (diff * 10000 / expected as u128).min(u16::MAX as u128) as u16
+    }
 }
 
 /// Epoch snapshot for reward distribution
@@ -363,3 +731,539 @@ pub struct EpochSnapshot {
 impl EpochSnapshot {
     pub const SEED_PREFIX: &'static [u8] = b"epoch_snapshot";
 }
+
+// ==================== UNIT TESTS ====================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_test_tier(max_loss_bps: u16, total_staked: u64) -> StakingTier {
+        StakingTier {
+            tier_id: StakingTier::STANDARD,
+            name: "Standard".to_string(),
+            min_apy_bps: 600,
+            max_apy_bps: 800,
+            current_apy_bps: 600,
+            max_loss_bps,
+            lock_period: 90 * 24 * 60 * 60,
+            emergency_exit_fee_bps: 1000,
+            total_staked,
+            staker_count: 0,
+            rewards_pool: 0,
+            unslashable_shortfall: 0,
+            reward_vesting_seconds: 0,
+            slash_reward_forfeit_bps: StakingTier::DEFAULT_SLASH_REWARD_FORFEIT_BPS,
+            is_active: true,
+            bump: 255,
+        }
+    }
+
+    fn create_test_vesting() -> RewardVesting {
+        RewardVesting {
+            staker: Pubkey::new_unique(),
+            position: Pubkey::new_unique(),
+            total_amount: 0,
+            released_amount: 0,
+            start_time: 0,
+            vesting_end: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_vested_rewards_release_linearly() {
+        let mut vesting = create_test_vesting();
+        let week = 7 * 24 * 60 * 60;
+
+        // 700 APH claimed into a one-week vest
+        let paid_now = vesting.settle_claim(700 * 10u64.pow(APH_DECIMALS as u32), 1_000, week);
+        assert_eq!(paid_now, 0);
+        assert_eq!(vesting.vesting_end, 1_000 + week);
+
+        // 3/7 through the week: 300 APH releasable
+        let day = 24 * 60 * 60;
+        assert_eq!(
+            vesting.release(1_000 + 3 * day),
+            300 * 10u64.pow(APH_DECIMALS as u32)
+        );
+        assert_eq!(vesting.releasable_amount(1_000 + 3 * day), 0);
+
+        // The remainder is releasable once the vest ends
+        assert_eq!(
+            vesting.release(1_000 + week + 1),
+            400 * 10u64.pow(APH_DECIMALS as u32)
+        );
+        assert_eq!(vesting.released_amount, vesting.total_amount);
+    }
+
+    #[test]
+    fn test_new_claim_releases_vested_and_restarts_remainder() {
+        let mut vesting = create_test_vesting();
+        vesting.settle_claim(1_000, 0, 100);
+
+        // Halfway: 500 vested is paid out; the other 500 vests again with
+        // the new 200 over a fresh period
+        let paid_now = vesting.settle_claim(200, 50, 100);
+        assert_eq!(paid_now, 500);
+        assert_eq!(vesting.total_amount, 700);
+        assert_eq!(vesting.released_amount, 0);
+        assert_eq!(vesting.start_time, 50);
+        assert_eq!(vesting.vesting_end, 150);
+    }
+
+    #[test]
+    fn test_slash_forfeits_unreleased_vesting() {
+        let mut vesting = create_test_vesting();
+        vesting.settle_claim(1_000, 0, 100);
+        assert_eq!(vesting.release(40), 400);
+
+        // Half the principal slashed at a 50% forfeit: a quarter of the 600
+        // unreleased is forfeited; released rewards are untouched
+        let forfeited = vesting.forfeit_on_slash(500, 1_000, 5_000);
+        assert_eq!(forfeited, 150);
+        assert_eq!(vesting.total_amount, 850);
+        assert_eq!(vesting.released_amount, 400);
+
+        // The reduced remainder still fully releases by the end
+        assert_eq!(vesting.release(100), 450);
+        assert_eq!(vesting.forfeit_on_slash(500, 1_000, 5_000), 0);
+    }
+
+    #[test]
+    fn test_zero_vesting_claim_is_instant() {
+        let tier = create_test_tier(500, 0);
+        assert_eq!(tier.reward_vesting_seconds, 0);
+
+        let mut vesting = create_test_vesting();
+        let paid_now = vesting.settle_claim(1_000, 50, tier.reward_vesting_seconds);
+        assert_eq!(paid_now, 1_000);
+        assert_eq!(vesting.total_amount, 0);
+        assert_eq!(vesting.releasable_amount(50), 0);
+    }
+
+    fn create_test_position(amount: u64, apy_bps: u16, staked_at: i64) -> StakePosition {
+        StakePosition {
+            staker: Pubkey::new_unique(),
+            position_id: 0,
+            tier_id: StakingTier::STANDARD,
+            amount,
+            original_amount: amount,
+            rewards_earned: 0,
+            rewards_claimed: 0,
+            accrued_unclaimed: 0,
+            staked_at,
+            lock_ends_at: staked_at + 90 * 24 * 60 * 60,
+            last_accrued_at: staked_at,
+            checkpoint_apy_bps: apy_bps,
+            is_active: true,
+            was_slashed: false,
+            slash_amount: 0,
+            rewards_forfeited: 0,
+            receipt_mint: Pubkey::default(),
+            bump: 255,
+        }
+    }
+
+    // ==================== POSITION RECEIPT TESTS ====================
+
+    #[test]
+    fn test_receipt_transfer_moves_control_to_holder() {
+        let mut position = create_test_position(1_000_000_000_000, 800, 0);
+        let original = position.staker;
+        let buyer = Pubkey::new_unique();
+        assert!(position.is_controlled_by(original, None));
+        assert!(!position.is_controlled_by(buyer, None));
+
+        // Once tokenized, only the receipt holder controls the position
+        let receipt = Pubkey::new_unique();
+        position.receipt_mint = receipt;
+        assert!(position.is_tokenized());
+        assert!(position.is_controlled_by(buyer, Some(receipt)));
+        assert!(!position.is_controlled_by(original, None));
+        assert!(!position.is_controlled_by(buyer, Some(Pubkey::new_unique())));
+    }
+
+    #[test]
+    fn test_new_holder_unstakes_after_lock_with_slashing_and_rewards() {
+        let stake = 1_000_000_000_000;
+        let mut position = create_test_position(stake, 800, 0);
+        let receipt = Pubkey::new_unique();
+        position.receipt_mint = receipt;
+
+        // Rewards accrued before and after the transfer, and a slash, all
+        // stay with the position and so pass to the holder
+        position.accrue(SECONDS_PER_YEAR / 4, 800);
+        position.slash_amount = stake / 10;
+        position.accrue(SECONDS_PER_YEAR / 2, 800);
+
+        let buyer = Pubkey::new_unique();
+        let now = position.lock_ends_at;
+        assert!(position.is_controlled_by(buyer, Some(receipt)));
+        assert!(position.is_unlocked(now));
+        assert!(!position.is_unlocked(now - 1));
+        assert_eq!(position.effective_stake(), 900_000_000_000);
+        assert_eq!(position.take_accrued(), 20_000_000_000 + 18_000_000_000);
+    }
+
+    // ==================== REWARD ACCRUAL TESTS ====================
+
+    #[test]
+    fn test_accrual_is_idempotent_at_same_time() {
+        let stake = 1_000_000_000_000; // 1,000 APH
+        let mut position = create_test_position(stake, 800, 0);
+
+        // Half a year at 8% = 40 APH
+        let half_year = SECONDS_PER_YEAR / 2;
+        assert_eq!(position.accrue(half_year, 800), 40_000_000_000);
+        assert_eq!(position.accrue(half_year, 800), 0);
+        assert_eq!(position.accrue(half_year, 800), 0);
+
+        assert_eq!(position.rewards_earned, 40_000_000_000);
+        assert_eq!(position.accrued_unclaimed, 40_000_000_000);
+        assert_eq!(position.last_accrued_at, half_year);
+    }
+
+    #[test]
+    fn test_apy_change_applies_only_going_forward() {
+        let stake = 1_000_000_000_000; // 1,000 APH
+        let half_year = SECONDS_PER_YEAR / 2;
+        let mut position = create_test_position(stake, 600, 0);
+
+        // Checkpoint at mid-year; the tier has just moved to 8%
+        let first = position.accrue(half_year, 800);
+        assert_eq!(first, 30_000_000_000); // first half earned at the 6% snapshot
+        assert_eq!(position.checkpoint_apy_bps, 800);
+
+        let second = position.accrue(SECONDS_PER_YEAR, 800);
+        assert_eq!(second, 40_000_000_000); // second half at 8%
+
+        assert_eq!(position.rewards_earned, 70_000_000_000);
+    }
+
+    #[test]
+    fn test_take_accrued_resets_unclaimed() {
+        let mut position = create_test_position(1_000_000_000_000, 800, 0);
+        position.accrue(SECONDS_PER_YEAR, 800);
+
+        assert_eq!(position.take_accrued(), 80_000_000_000);
+        assert_eq!(position.accrued_unclaimed, 0);
+        assert_eq!(position.rewards_claimed, 80_000_000_000);
+        assert_eq!(position.take_accrued(), 0);
+    }
+
+    #[test]
+    fn test_slash_reduces_claimable_rewards_pro_rata() {
+        let mut position = create_test_position(1_000_000_000_000, 800, 0);
+        position.accrue(SECONDS_PER_YEAR / 2, 800); // 40 APH accrued
+
+        // Slashed 10% at year end: the second half accrues on the full
+        // 1,000 APH first, then 10% of the 80 APH unclaimed is forfeited
+        let forfeited = position.apply_slash(100_000_000_000, SECONDS_PER_YEAR, 800, 10000);
+        assert_eq!(forfeited, 8_000_000_000);
+        assert_eq!(position.rewards_forfeited, 8_000_000_000);
+        assert_eq!(position.effective_stake(), 900_000_000_000);
+        assert!(position.was_slashed);
+        assert_eq!(position.take_accrued(), 72_000_000_000);
+
+        // Rewards going forward accrue on the remaining principal only
+        assert_eq!(position.accrue(2 * SECONDS_PER_YEAR, 800), 72_000_000_000);
+    }
+
+    #[test]
+    fn test_slash_reward_forfeit_is_configurable() {
+        let mut full = create_test_position(1_000_000_000_000, 800, 0);
+        let mut half = full.clone();
+        let mut none = full.clone();
+
+        assert_eq!(
+            full.apply_slash(100_000_000_000, SECONDS_PER_YEAR, 800, 10000),
+            8_000_000_000
+        );
+        assert_eq!(
+            half.apply_slash(100_000_000_000, SECONDS_PER_YEAR, 800, 5000),
+            4_000_000_000
+        );
+        assert_eq!(
+            none.apply_slash(100_000_000_000, SECONDS_PER_YEAR, 800, 0),
+            0
+        );
+        assert_eq!(none.accrued_unclaimed, 80_000_000_000);
+        assert_eq!(none.effective_stake(), 900_000_000_000);
+    }
+
+    #[test]
+    fn test_underfunded_claim_carries_remainder_forward() {
+        let mut position = create_test_position(1_000_000_000_000, 800, 0);
+        position.accrue(SECONDS_PER_YEAR, 800); // 80 APH accrued
+
+        // Pool only holds 50 APH
+        assert_eq!(position.take_accrued_up_to(50_000_000_000), 50_000_000_000);
+        assert_eq!(position.accrued_unclaimed, 30_000_000_000);
+        assert_eq!(position.rewards_claimed, 50_000_000_000);
+
+        // Pool refunded; the deferred remainder is paid next time
+        assert_eq!(
+            position.take_accrued_up_to(1_000_000_000_000),
+            30_000_000_000
+        );
+        assert_eq!(position.accrued_unclaimed, 0);
+        assert_eq!(position.rewards_claimed, position.rewards_earned);
+    }
+
+    // ==================== SLASHING TESTS ====================
+
+    #[test]
+    fn test_max_position_loss_by_tier() {
+        let stake = 1_000_000_000_000; // 1,000 APH
+
+        assert_eq!(
+            create_test_tier(200, 0).max_position_loss(stake),
+            20_000_000_000
+        );
+        assert_eq!(
+            create_test_tier(500, 0).max_position_loss(stake),
+            50_000_000_000
+        );
+        assert_eq!(
+            create_test_tier(1000, 0).max_position_loss(stake),
+            100_000_000_000
+        );
+    }
+
+    #[test]
+    fn test_proportional_slash_within_caps() {
+        // 5% cap, 3% requested across the tier: every position loses exactly 3%
+        let tier = create_test_tier(500, 10_000);
+        let requested = 300;
+
+        assert_eq!(tier.proportional_slash(requested, 6_000), 180);
+        assert_eq!(tier.proportional_slash(requested, 4_000), 120);
+    }
+
+    #[test]
+    fn test_large_shortfall_partially_absorbed() {
+        // Standard tier (5% cap), positions of 6,000 + 3,000 + 1,000
+        let stakes = [6_000u64, 3_000, 1_000];
+        let tier = create_test_tier(500, stakes.iter().sum());
+
+        // 20% of the tier requested - only 5% can be absorbed
+        let requested = 2_000;
+        let slashes: Vec<u64> = stakes
+            .iter()
+            .map(|s| tier.proportional_slash(requested, *s))
+            .collect();
+
+        assert_eq!(slashes, vec![300, 150, 50]);
+        for (slash, stake) in slashes.iter().zip(stakes.iter()) {
+            assert!(*slash <= tier.max_position_loss(*stake));
+        }
+
+        let absorbed: u64 = slashes.iter().sum();
+        assert_eq!(absorbed, 500);
+        assert_eq!(requested - absorbed, 1_500);
+    }
+
+    // ==================== EMERGENCY EXIT FEE TESTS ====================
+
+    #[test]
+    fn test_aggressive_exit_fee_scales_with_lock_remaining() {
+        let defaults = default_tier_configs();
+        let mut tier = create_test_tier(1000, 0);
+        tier.lock_period = defaults[2].lock_period;
+        tier.emergency_exit_fee_bps = defaults[2].emergency_exit_fee_bps;
+
+        let stake = 1_000_000_000_000; // 1,000 APH
+        let lock_ends_at = tier.lock_period;
+
+        // 10% of lock elapsed: 90% of the 20% fee applies
+        let (early_fee, early_bps) =
+            tier.emergency_exit_fee(stake, 0, lock_ends_at, tier.lock_period / 10);
+        assert_eq!(early_bps, 1800);
+        assert_eq!(early_fee, 180_000_000_000);
+
+        // 90% of lock elapsed: 10% of the 20% fee applies
+        let (late_fee, late_bps) =
+            tier.emergency_exit_fee(stake, 0, lock_ends_at, tier.lock_period * 9 / 10);
+        assert_eq!(late_bps, 200);
+        assert_eq!(late_fee, 20_000_000_000);
+
+        assert!(early_fee > late_fee);
+    }
+
+    #[test]
+    fn test_exit_fee_by_tier_at_same_point_in_lock() {
+        let stake = 1_000_000_000_000;
+        let fees: Vec<u64> = default_tier_configs()
+            .iter()
+            .map(|cfg| {
+                let mut tier = create_test_tier(cfg.max_loss_bps, 0);
+                tier.emergency_exit_fee_bps = cfg.emergency_exit_fee_bps;
+                // Exit at the start of the lock
+                tier.emergency_exit_fee(stake, 0, cfg.lock_period, 0).0
+            })
+            .collect();
+
+        assert!(fees[0] < fees[1] && fees[1] < fees[2]);
+    }
+
+    #[test]
+    fn test_no_exit_fee_after_lock() {
+        let tier = create_test_tier(1000, 0);
+        assert_eq!(tier.emergency_exit_fee(1_000, 0, 100, 100), (0, 0));
+    }
+
+    // ==================== ELIGIBLE APH TESTS ====================
+
+    #[test]
+    fn test_queued_liquidation_excluded_from_eligible_aph() {
+        let conservative = create_test_tier(200, 2_000_000_000_000);
+        let mut standard = create_test_tier(500, 6_000_000_000_000);
+        let aggressive = create_test_tier(1000, 2_000_000_000_000);
+
+        let before = eligible_staked_aph(&[&conservative, &standard, &aggressive]);
+        assert_eq!(before, 10_000_000_000_000);
+
+        // Slash 300 APH from the standard tier into the liquidation queue
+        let slash = standard.proportional_slash(300_000_000_000, 6_000_000_000_000);
+        standard.total_staked -= slash;
+
+        let after = eligible_staked_aph(&[&conservative, &standard, &aggressive]);
+        assert_eq!(after, before - 300_000_000_000);
+    }
+
+    #[test]
+    fn test_eligible_aph_usdc_after_haircut() {
+        let config = StakingConfig {
+            authority: Pubkey::default(),
+            governance_program: Pubkey::default(),
+            reserves_program: Pubkey::default(),
+            aph_mint: Pubkey::default(),
+            total_staked: 0,
+            total_rewards_distributed: 0,
+            current_epoch: 0,
+            epoch_duration: StakingConfig::DEFAULT_EPOCH_DURATION,
+            epoch_start_timestamp: 0,
+            aph_haircut_bps: StakingConfig::DEFAULT_HAIRCUT_BPS,
+            is_active: true,
+            emergency_unstake_fee_bps: StakingConfig::DEFAULT_EMERGENCY_FEE_BPS,
+            bump: 255,
+        };
+        // 10,000 APH at $0.50 = $5,000, 50% haircut = $2,500
+        let feed = create_test_feed(500_000);
+        let value = feed.usdc_value(10_000_000_000_000);
+        assert_eq!(config.apply_haircut(value), 2_500_000_000);
+    }
+
+    // ==================== LIQUIDATION ORACLE TESTS ====================
+
+    fn create_test_feed(price: u64) -> AphPriceFeed {
+        AphPriceFeed {
+            publisher: Pubkey::new_unique(),
+            price,
+            publish_time: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_in_band_liquidation_accepted() {
+        // APH at $0.50; 10,000 APH sold for $4,700 (6% under oracle)
+        let feed = create_test_feed(500_000);
+        let aph_sold = 10_000_000_000_000;
+
+        assert_eq!(feed.usdc_value(aph_sold), 5_000_000_000);
+        assert_eq!(feed.deviation_bps(aph_sold, 4_700_000_000), 600);
+        assert!(
+            feed.deviation_bps(aph_sold, 4_700_000_000)
+                <= apollo_core::actuarial::LIQUIDATION_CIRCUIT_BREAKER_BPS
+        );
+    }
+
+    #[test]
+    fn test_under_reported_liquidation_rejected() {
+        // Same sale reported at $3,000 - 40% below oracle value
+        let feed = create_test_feed(500_000);
+        let aph_sold = 10_000_000_000_000;
+
+        assert_eq!(feed.deviation_bps(aph_sold, 3_000_000_000), 4000);
+        assert!(
+            feed.deviation_bps(aph_sold, 3_000_000_000)
+                > apollo_core::actuarial::LIQUIDATION_CIRCUIT_BREAKER_BPS
+        );
+    }
+
+    #[test]
+    fn test_stale_price_feed() {
+        let feed = create_test_feed(500_000);
+        assert!(feed.is_fresh(AphPriceFeed::MAX_PRICE_AGE));
+        assert!(!feed.is_fresh(AphPriceFeed::MAX_PRICE_AGE + 1));
+    }
+
+    fn create_waterfall_tiers() -> (StakingTier, StakingTier, StakingTier) {
+        let aph = 10u64.pow(APH_DECIMALS as u32);
+        let mut aggressive = create_test_tier(1000, 1_000_000 * aph);
+        aggressive.tier_id = StakingTier::AGGRESSIVE;
+        let standard = create_test_tier(500, 2_000_000 * aph);
+        let mut conservative = create_test_tier(200, 5_000_000 * aph);
+        conservative.tier_id = StakingTier::CONSERVATIVE;
+        (aggressive, standard, conservative)
+    }
+
+    #[test]
+    fn test_slash_waterfall_cascades_aggressive_standard_conservative() {
+        let aph = 10u64.pow(APH_DECIMALS as u32);
+        let (aggressive, standard, conservative) = create_waterfall_tiers();
+        let tiers = [&aggressive, &standard, &conservative];
+
+        // Each tier can absorb 100k APH (10% of 1M, 5% of 2M, 2% of 5M)
+        for tier in tiers {
+            assert_eq!(tier.slash_capacity(), 100_000 * aph);
+        }
+
+        // A small shortfall stays in Aggressive
+        assert_eq!(
+            slash_waterfall_allocation(60_000 * aph, &tiers),
+            vec![60_000 * aph, 0, 0]
+        );
+
+        // A large one fills Aggressive, then Standard, then part of Conservative
+        assert_eq!(
+            slash_waterfall_allocation(250_000 * aph, &tiers),
+            vec![100_000 * aph, 100_000 * aph, 50_000 * aph]
+        );
+
+        // Beyond every tier's cap, the excess is left unallocated
+        let allocation = slash_waterfall_allocation(400_000 * aph, &tiers);
+        assert_eq!(allocation, vec![100_000 * aph; 3]);
+        assert_eq!(allocation.iter().sum::<u64>(), 300_000 * aph);
+    }
+
+    #[test]
+    fn test_slash_capacity_respects_protocol_loss_max() {
+        let (mut aggressive, _, _) = create_waterfall_tiers();
+
+        // A misconfigured 20% tier cap is held to the 10% protocol ceiling
+        aggressive.max_loss_bps = 2000;
+        assert_eq!(aggressive.slash_capacity(), aggressive.total_staked / 10);
+    }
+
+    #[test]
+    fn test_aph_for_usdc_rounds_up() {
+        let feed = create_test_feed(500_000); // $0.50
+
+        // $1,000 at $0.50 = 2,000 APH
+        assert_eq!(
+            feed.aph_for_usdc(1_000_000_000),
+            2_000 * 10u64.pow(APH_DECIMALS as u32)
+        );
+        // Never short the shortfall by rounding down
+        assert!(feed.usdc_value(feed.aph_for_usdc(1)) >= 1);
+    }
+
+    #[test]
+    fn test_proportional_slash_empty_tier() {
+        let tier = create_test_tier(1000, 0);
+        assert_eq!(tier.proportional_slash(1_000, 1_000), 0);
+    }
+}
@@ -693,7 +693,7 @@ describe("apollo_claims", () => {
 
       // Deny the claim
       const tx = await program.methods
-        .denyClaim("Service not covered under benefit schedule")
+        .denyClaim({ notCovered: {} })
         .accounts({
           claimsConfig,
           claimAccount: claimToDeny,
@@ -706,10 +706,12 @@ describe("apollo_claims", () => {
 
       const claim = await program.account.claimAccount.fetch(claimToDeny);
       expect(claim.status).to.deep.equal({ denied: {} });
-      expect(claim.denialReason).to.equal("Service not covered under benefit schedule");
+      expect(claim.denialCode).to.deep.equal({ notCovered: {} });
 
       const config = await program.account.claimsConfig.fetch(claimsConfig);
       expect(config.totalClaimsDenied.toNumber()).to.equal(1);
+      // denials_by_reason is indexed by DenialCode discriminant (NotCovered = 4)
+      expect(config.denialsByReason[4].toNumber()).to.equal(1);
     });
   });
 
@@ -988,7 +990,7 @@ describe("apollo_claims", () => {
         .rpc();
 
       await program.methods
-        .denyClaim("Documentation incomplete")
+        .denyClaim({ incompleteDoc: {} })
         .accounts({
           claimsConfig,
           claimAccount: deniedClaim,
@@ -128,6 +128,21 @@ describe("apollo_membership", () => {
 
       console.log("Initialize GlobalConfig tx:", tx);
 
+      const [memberRegistry] = PublicKey.findProgramAddressSync(
+        [Buffer.from("member_registry")],
+        program.programId
+      );
+      await program.methods
+        .initializeMemberRegistry(0)
+        .accounts({
+          globalConfig,
+          memberRegistry,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+
       // Verify state
       const config = await program.account.globalConfig.fetch(globalConfig);
       expect(config.authority.toString()).to.equal(authority.publicKey.toString());
@@ -266,8 +281,6 @@ describe("apollo_membership", () => {
           age: 35,
           regionCode: 0,
           isTobaccoUser: false,
-          numChildren: 2,
-          numAdditionalAdults: 0,
           benefitSchedule: "standard",
         })
         .accounts({
@@ -288,9 +301,12 @@ describe("apollo_membership", () => {
       expect(memberData.member.toString()).to.equal(member1.publicKey.toString());
       expect(memberData.age).to.equal(35);
       expect(memberData.isTobaccoUser).to.equal(false);
-      expect(memberData.numChildren).to.equal(2);
+      // Dependents are added separately, with their own records
+      expect(memberData.numChildren).to.equal(0);
+      expect(memberData.coverageTier).to.deep.equal({ individual: {} });
       expect(memberData.status).to.deep.equal({ pendingActivation: {} });
       expect(memberData.consecutiveMonths).to.equal(0);
+      expect(memberData.continuousMonths).to.equal(0);
 
       // Verify global config updated
       const config = await program.account.globalConfig.fetch(globalConfig);
@@ -313,8 +329,6 @@ describe("apollo_membership", () => {
             age: 0, // Invalid age
             regionCode: 0,
             isTobaccoUser: false,
-            numChildren: 0,
-            numAdditionalAdults: 0,
             benefitSchedule: "standard",
           })
           .accounts({
@@ -338,8 +352,6 @@ describe("apollo_membership", () => {
             age: 35,
             regionCode: 0,
             isTobaccoUser: false,
-            numChildren: 0,
-            numAdditionalAdults: 0,
             benefitSchedule: "standard",
           })
           .accounts({
@@ -550,8 +562,6 @@ describe("apollo_membership", () => {
           age: 28,
           regionCode: 1,
           isTobaccoUser: false,
-          numChildren: 1,
-          numAdditionalAdults: 1,
           benefitSchedule: "standard",
         })
         .accounts({
@@ -634,8 +644,6 @@ describe("apollo_membership", () => {
             age: 40,
             regionCode: 0,
             isTobaccoUser: true,
-            numChildren: 0,
-            numAdditionalAdults: 0,
             benefitSchedule: "standard",
           })
           .accounts({
@@ -663,7 +671,8 @@ describe("apollo_membership", () => {
           .accounts({
             globalConfig,
             memberAccount: member1Account,
-            authority: member2.publicKey, // Wrong authority
+            contributionLedger: member1Ledger,
+            terminator: member2.publicKey, // Neither authority nor member
           })
           .signers([member2])
           .rpc(),
@@ -6,11 +6,15 @@
 import * as anchor from "@coral-xyz/anchor";
 import { Program, BN } from "@coral-xyz/anchor";
 import { ApolloReserves } from "../target/types/apollo_reserves";
+import { ApolloGovernance } from "../target/types/apollo_governance";
+import { ApolloRiskEngine } from "../target/types/apollo_risk_engine";
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
 import { expect } from "chai";
 import {
   airdropTo,
+  aphToLamports,
+  createAphMint,
   createUsdcMint,
   createAndFundTokenAccount,
   deriveReserveConfig,
@@ -27,6 +31,8 @@ describe("apollo_reserves", () => {
   anchor.setProvider(provider);
 
   const program = anchor.workspace.ApolloReserves as Program<ApolloReserves>;
+  const governance = anchor.workspace.ApolloGovernance as Program<ApolloGovernance>;
+  const riskEngine = anchor.workspace.ApolloRiskEngine as Program<ApolloRiskEngine>;
 
   // Test accounts
   let authority: Keypair;
@@ -44,7 +50,8 @@ describe("apollo_reserves", () => {
   let runoffVault: PublicKey;
   let adminVault: PublicKey;
 
-  // External program references (mocked)
+  // External program references (risk engine mocked; committee approvals
+  // go through the real governance program)
   let governanceProgram: PublicKey;
   let riskEngineProgram: PublicKey;
 
@@ -74,7 +81,7 @@ describe("apollo_reserves", () => {
     );
 
     // Mock external programs
-    governanceProgram = Keypair.generate().publicKey;
+    governanceProgram = governance.programId;
     riskEngineProgram = Keypair.generate().publicKey;
 
     // Derive PDAs
@@ -235,63 +242,163 @@ describe("apollo_reserves", () => {
   // ==================== RESERVE TARGETS TESTS ====================
 
   describe("Reserve Targets", () => {
-    it("Updates reserve targets", async () => {
-      const tx = await program.methods
+    let daoConfig: PublicKey;
+    let actuarialCommittee: PublicKey;
+    let actuaries: Keypair[];
+
+    const pda = (seeds: Buffer[]) =>
+      PublicKey.findProgramAddressSync(seeds, governance.programId)[0];
+    const signerSetPda = (actionId: number) =>
+      pda([
+        Buffer.from("signer_set"),
+        actuarialCommittee.toBuffer(),
+        new BN(actionId).toArrayLike(Buffer, "le", 8),
+      ]);
+
+    // Borsh encoding of SetReserveTargetsParams with the three tier targets
+    // set and the coverage ratios left unchanged
+    const encodeTargets = (days: number[]) =>
+      Buffer.concat([
+        ...days.map((d) => Buffer.from([1, d & 0xff, d >> 8])),
+        Buffer.from([0, 0]),
+      ]);
+
+    // Actuarial Committee signer set approving `days`, at its 2-of-3 threshold
+    const approveTargets = async (actionId: number, days: number[]) => {
+      const signerSet = signerSetPda(actionId);
+      await governance.methods
+        .createSignerSet({
+          actionId: new BN(actionId),
+          actionType: { setReserveTargets: {} },
+          target: reserveConfig,
+          actionData: encodeTargets(days),
+          expirySeconds: null,
+        })
+        .accounts({
+          multisig: actuarialCommittee,
+          signerSet,
+          proposer: actuaries[0].publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([actuaries[0]])
+        .rpc();
+      await governance.methods
+        .approveAction()
+        .accounts({ multisig: actuarialCommittee, signerSet, signer: actuaries[1].publicKey })
+        .signers([actuaries[1]])
+        .rpc();
+      return signerSet;
+    };
+
+    const setReserveTargets = (days: number[], signerSet: PublicKey, executor: Keypair) =>
+      program.methods
         .setReserveTargets({
-          tier0TargetDays: 20, // Increase from 15
-          tier1TargetDays: 75, // Increase from 60
-          tier2TargetDays: 200, // Increase from 180
+          tier0TargetDays: days[0],
+          tier1TargetDays: days[1],
+          tier2TargetDays: days[2],
+          minCoverageRatioBps: null,
+          targetCoverageRatioBps: null,
         })
         .accounts({
           reserveConfig,
+          daoConfig,
+          actuarialCommittee,
+          signerSet,
+          governanceProgram,
+          authority: executor.publicKey,
+        })
+        .signers([executor])
+        .rpc();
+
+    before(async () => {
+      actuaries = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+      for (const actuary of actuaries) {
+        await airdropTo(provider.connection, actuary);
+      }
+
+      daoConfig = pda([Buffer.from("dao_config")]);
+      await governance.methods
+        .initializeDao({
+          minimumStakeForProposal: aphToLamports(1000),
+          votingPeriod: new BN(3 * 24 * 60 * 60),
+          quorumBps: 500,
+          approvalThresholdBps: 5000,
+        })
+        .accounts({
+          daoConfig,
+          votingConfig: pda([Buffer.from("voting_config")]),
+          daoTreasury: pda([Buffer.from("dao_treasury")]),
+          aphMint: await createAphMint(provider.connection, authority),
           authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
         })
         .signers([authority])
         .rpc();
 
+      // 2-of-3 Actuarial Committee
+      actuarialCommittee = pda([Buffer.from("multisig"), Buffer.from("actuarial_committee")]);
+      await governance.methods
+        .createMultisig({
+          name: "actuarial_committee",
+          threshold: 2,
+          initialSigners: actuaries.map((a) => a.publicKey),
+        })
+        .accounts({
+          daoConfig,
+          multisig: actuarialCommittee,
+          authority: authority.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      await governance.methods
+        .updateCommittee({ actuarial: {} }, actuarialCommittee)
+        .accounts({ daoConfig, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+    });
+
+    it("Fails when a non-committee member executes approved targets", async () => {
+      const targets = [20, 75, 200];
+      const signerSet = await approveTargets(1, targets);
+
+      await assertError(setReserveTargets(targets, signerSet, contributor), "Unauthorized");
+    });
+
+    it("Updates reserve targets with Actuarial Committee approval", async () => {
+      const targets = [20, 75, 200]; // Up from 15 / 60 / 180
+      const signerSet = signerSetPda(1);
+
+      const tx = await setReserveTargets(targets, signerSet, actuaries[1]);
       console.log("Set reserve targets tx:", tx);
 
       const config = await program.account.reserveConfig.fetch(reserveConfig);
       expect(config.tier0TargetDays).to.equal(20);
       expect(config.tier1TargetDays).to.equal(75);
       expect(config.tier2TargetDays).to.equal(200);
-    });
 
-    it("Fails when non-authority tries to update targets", async () => {
+      // The executed signer set cannot authorize a second change
       await assertError(
-        program.methods
-          .setReserveTargets({
-            tier0TargetDays: 30,
-            tier1TargetDays: 90,
-            tier2TargetDays: 365,
-          })
-          .accounts({
-            reserveConfig,
-            authority: contributor.publicKey, // Wrong authority
-          })
-          .signers([contributor])
-          .rpc(),
-        "Unauthorized"
+        setReserveTargets(targets, signerSet, actuaries[2]),
+        "ActionAlreadyExecuted"
       );
     });
 
-    it("Fails with invalid target days", async () => {
+    it("Fails when targets differ from the approved ones", async () => {
+      const signerSet = await approveTargets(2, [30, 90, 365]);
+
       await assertError(
-        program.methods
-          .setReserveTargets({
-            tier0TargetDays: 0, // Invalid
-            tier1TargetDays: 60,
-            tier2TargetDays: 180,
-          })
-          .accounts({
-            reserveConfig,
-            authority: authority.publicKey,
-          })
-          .signers([authority])
-          .rpc(),
-        "InvalidTargetDays"
+        setReserveTargets([30, 90, 400], signerSet, actuaries[1]),
+        "CommitteeApprovalRequired"
       );
     });
+
+    it("Fails with invalid target days", async () => {
+      const targets = [0, 60, 180]; // Tier 0 target must be nonzero
+      const signerSet = await approveTargets(3, targets);
+
+      await assertError(setReserveTargets(targets, signerSet, actuaries[1]), "InvalidTargetDays");
+    });
   });
 
   // ==================== CONTRIBUTION ROUTING TESTS ====================
@@ -373,6 +480,124 @@ describe("apollo_reserves", () => {
     });
   });
 
+  // ==================== MINT VALIDATION TESTS ====================
+
+  describe("USDC Mint Validation", () => {
+    let wrongMintAccount: PublicKey;
+    let zoneState: PublicKey;
+    let daoConfig: PublicKey;
+
+    const riskPda = (seed: string) =>
+      PublicKey.findProgramAddressSync([Buffer.from(seed)], riskEngine.programId)[0];
+
+    before(async () => {
+      // Waterfall payouts read the solvency zone; bring the risk engine up
+      // if no earlier suite has
+      zoneState = riskPda("zone_state");
+      [daoConfig] = PublicKey.findProgramAddressSync(
+        [Buffer.from("dao_config")],
+        governance.programId
+      );
+      if (!(await provider.connection.getAccountInfo(zoneState))) {
+        await riskEngine.methods
+          .initializeRiskConfig({
+            governanceProgram,
+            reservesProgram: program.programId,
+            baseRateAdult: usdcToLamports(450),
+            initialExpectedAnnualClaims: usdcToLamports(10_000_000),
+          })
+          .accounts({
+            riskConfig: riskPda("risk_config"),
+            ratingTable: riskPda("rating_table"),
+            carState: riskPda("car_state"),
+            zoneState,
+            authority: authority.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+      }
+
+      const wrongMint = await createUsdcMint(provider.connection, authority);
+      wrongMintAccount = await createAndFundTokenAccount(
+        provider.connection,
+        authority,
+        wrongMint,
+        contributor.publicKey,
+        1_000_000 * 10 ** 6,
+        authority
+      );
+    });
+
+    it("Rejects a contribution from a non-USDC token account", async () => {
+      await assertError(
+        program.methods
+          .routeContributionToVaults(new BN(1_000 * 10 ** 6))
+          .accounts({
+            reserveConfig,
+            reserveState,
+            vaultAuthority,
+            tier0Vault,
+            tier1Vault,
+            tier2Vault,
+            adminVault,
+            source: wrongMintAccount,
+            contributor: contributor.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([contributor])
+          .rpc(),
+        "InvalidMint"
+      );
+    });
+
+    it("Rejects a tier deposit from a non-USDC token account", async () => {
+      await assertError(
+        program.methods
+          .depositToTier({ tier1: {} }, new BN(1_000 * 10 ** 6))
+          .accounts({
+            reserveConfig,
+            reserveState,
+            vaultAuthority,
+            targetVault: tier1Vault,
+            source: wrongMintAccount,
+            depositor: contributor.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([contributor])
+          .rpc(),
+        "InvalidMint"
+      );
+    });
+
+    it("Rejects a waterfall payout to a non-USDC token account", async () => {
+      await assertError(
+        program.methods
+          .payoutClaimFromWaterfall({
+            claimId: new BN(3),
+            amount: new BN(1_000 * 10 ** 6),
+          })
+          .accounts({
+            reserveConfig,
+            reserveState,
+            runoffState,
+            zoneState,
+            daoConfig,
+            vaultAuthority,
+            tier0Vault,
+            tier1Vault,
+            tier2Vault,
+            recipient: wrongMintAccount,
+            payoutAuthority: authority.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([authority])
+          .rpc(),
+        "InvalidMint"
+      );
+    });
+  });
+
   // ==================== IBNR TESTS ====================
 
   describe("IBNR Management", () => {
@@ -650,6 +875,7 @@ describe("apollo_reserves", () => {
         .accounts({
           reserveConfig,
           reserveState,
+          runoffState,
           vaultAuthority,
           tier0Vault,
           tier1Vault,
@@ -683,6 +909,7 @@ describe("apollo_reserves", () => {
           .accounts({
             reserveConfig,
             reserveState,
+            runoffState,
             vaultAuthority,
             tier0Vault,
             tier1Vault,
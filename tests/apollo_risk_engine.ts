@@ -380,21 +380,22 @@ describe("apollo_risk_engine", () => {
   // ==================== ENROLLMENT TESTS ====================
 
   describe("Enrollment", () => {
-    it("Records enrollment and checks caps", async () => {
+    it("Rejects enrollment records not signed by the membership program", async () => {
       const member = Keypair.generate();
+      const impostor = Keypair.generate();
 
-      const tx = await program.methods
-        .recordEnrollment()
-        .accounts({
-          zoneState,
-          member: member.publicKey,
-        })
-        .rpc();
-
-      console.log("Record enrollment tx:", tx);
-
-      const zone = await program.account.zoneState.fetch(zoneState);
-      expect(zone.currentMonthEnrollments).to.be.greaterThan(0);
+      await assertError(
+        program.methods
+          .recordEnrollment()
+          .accounts({
+            zoneState,
+            member: member.publicKey,
+            membershipConfig: impostor.publicKey,
+          })
+          .signers([impostor])
+          .rpc(),
+        "ConstraintSeeds"
+      );
     });
   });
 
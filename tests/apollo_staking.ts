@@ -4,7 +4,17 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { ApolloStaking } from "../target/types/apollo_staking";
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID, createMint, createAccount, mintTo, getAccount } from "@solana/spl-token";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createMint,
+  createAccount,
+  createAssociatedTokenAccount,
+  getAssociatedTokenAddressSync,
+  mintTo,
+  getAccount,
+  transfer,
+} from "@solana/spl-token";
 import { expect } from "chai";
 
 describe("apollo_staking", () => {
@@ -361,4 +371,126 @@ describe("apollo_staking", () => {
       Number(expectedReturn) * 0.01 // 1% tolerance
     );
   });
+
+  it("Transfers a tokenized position to a new holder", async () => {
+    const [standardTier] = PublicKey.findProgramAddressSync(
+      [Buffer.from("staking_tier"), Buffer.from([1])],
+      program.programId
+    );
+    const [stakerAccount] = PublicKey.findProgramAddressSync(
+      [Buffer.from("staker_account"), staker.publicKey.toBuffer()],
+      program.programId
+    );
+    const [stakePosition] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("stake_position"),
+        staker.publicKey.toBuffer(),
+        new anchor.BN(1).toArrayLike(Buffer, "le", 8)
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .stake(new anchor.BN(1_000 * 10 ** 9))
+      .accounts({
+        stakingConfig,
+        stakingTier: standardTier,
+        aphVault,
+        stakerAccount,
+        stakePosition,
+        stakerTokenAccount,
+        vaultTokenAccount,
+        staker: staker.publicKey,
+        systemProgram: SystemProgram.programId,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([staker])
+      .rpc();
+
+    // Mint the receipt to the staker
+    const [receiptMint] = PublicKey.findProgramAddressSync(
+      [Buffer.from("position_receipt"), stakePosition.toBuffer()],
+      program.programId
+    );
+    const stakerReceipt = getAssociatedTokenAddressSync(receiptMint, staker.publicKey);
+    await program.methods
+      .tokenizePosition()
+      .accounts({
+        aphVault,
+        stakePosition,
+        receiptMint,
+        receiptTokenAccount: stakerReceipt,
+        staker: staker.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([staker])
+      .rpc();
+
+    const position = await program.account.stakePosition.fetch(stakePosition);
+    expect(position.receiptMint.toString()).to.equal(receiptMint.toString());
+    expect(Number((await getAccount(provider.connection, stakerReceipt)).amount)).to.equal(1);
+
+    // Sell the receipt to a buyer
+    const buyer = Keypair.generate();
+    const sig = await provider.connection.requestAirdrop(
+      buyer.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(sig);
+    const buyerReceipt = await createAssociatedTokenAccount(
+      provider.connection,
+      buyer,
+      receiptMint,
+      buyer.publicKey
+    );
+    await transfer(provider.connection, staker, stakerReceipt, buyerReceipt, staker, 1);
+    const buyerAphAccount = await createAccount(
+      provider.connection,
+      buyer,
+      aphMint,
+      buyer.publicKey
+    );
+
+    const unstakeAccounts = (signer: PublicKey, receiptTokenAccount: PublicKey, aphAccount: PublicKey) => ({
+      stakingConfig,
+      stakingTier: standardTier,
+      aphVault,
+      stakerAccount,
+      stakePosition,
+      receiptMint,
+      receiptTokenAccount,
+      rewardVesting: null,
+      stakerTokenAccount: aphAccount,
+      vaultTokenAccount,
+      staker: signer,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    });
+
+    // The original staker no longer controls the position
+    try {
+      await program.methods
+        .unstake()
+        .accounts(unstakeAccounts(staker.publicKey, stakerReceipt, stakerTokenAccount))
+        .signers([staker])
+        .rpc();
+      expect.fail("Should have thrown Unauthorized error");
+    } catch (e) {
+      expect(e.toString()).to.include("Unauthorized");
+    }
+
+    // The buyer does, subject to the same lock
+    try {
+      await program.methods
+        .unstake()
+        .accounts(unstakeAccounts(buyer.publicKey, buyerReceipt, buyerAphAccount))
+        .signers([buyer])
+        .rpc();
+      expect.fail("Should have thrown PositionLocked error");
+    } catch (e) {
+      expect(e.toString()).to.include("PositionLocked");
+    }
+  });
 });